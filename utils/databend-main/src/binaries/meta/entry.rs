@@ -34,6 +34,7 @@ use databend_common_meta_types::MetaAPIError;
 use databend_common_meta_types::Node;
 use databend_common_tracing::init_logging;
 use databend_common_tracing::set_panic_hook;
+use databend_meta::api::EtcdServer;
 use databend_meta::api::GrpcServer;
 use databend_meta::api::HttpService;
 use databend_meta::configs::Config;
@@ -157,6 +158,10 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
     println!("gRPC API");
     println!("   listening at {}", conf.grpc_api_address);
     println!("   advertise:  {}", grpc_advertise);
+    if conf.etcd_api_enabled() {
+        println!("etcd API");
+        println!("   listening at {}", conf.etcd_api_address);
+    }
     println!("Raft API");
     println!("   listening at {}", raft_listen,);
     println!("   advertise:  {}", raft_advertise,);
@@ -193,6 +198,14 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
         stop_handler.push(Box::new(srv));
     }
 
+    // etcd compatible gRPC API service.
+    if conf.etcd_api_enabled() {
+        let mut srv = EtcdServer::create(conf.clone(), meta_node.clone());
+        info!("etcd API server listening on {}", conf.etcd_api_address);
+        srv.start().await.expect("Failed to start etcd API server");
+        stop_handler.push(Box::new(srv));
+    }
+
     // Join a raft cluster only after all service started.
     let join_res = meta_node
         .join_cluster(&conf.raft_config, conf.grpc_api_advertise_address())
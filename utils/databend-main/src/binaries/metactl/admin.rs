@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Change the membership and the leadership of a cluster via the admin HTTP API of a meta-service node.
+
+use crate::Config;
+
+/// Send a membership change request `action` to `/v1/ctrl/<action>`.
+pub async fn change_membership(config: &Config, action: &str) -> anyhow::Result<()> {
+    let node_id = config
+        .node_id
+        .ok_or_else(|| anyhow::anyhow!("--node-id is required to {}", action))?;
+
+    let mut query = vec![("node_id", node_id.to_string())];
+
+    if action == "add_learner" {
+        if config.learner_raft_address.is_empty() {
+            return Err(anyhow::anyhow!(
+                "--learner-raft-address is required to add a learner"
+            ));
+        }
+        query.push(("endpoint", config.learner_raft_address.clone()));
+
+        if let Some(addr) = &config.learner_grpc_address {
+            query.push(("grpc_api_advertise_address", addr.clone()));
+        }
+    }
+
+    let url = format!("http://{}/v1/ctrl/{}", config.admin_api_address, action);
+
+    let resp = reqwest::Client::new()
+        .post(&url)
+        .bearer_auth(&config.admin_api_token)
+        .query(&query)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    let body = resp.text().await?;
+
+    if !status.is_success() {
+        return Err(anyhow::anyhow!(
+            "{} node-{} failed: {}: {}",
+            action,
+            node_id,
+            status,
+            body
+        ));
+    }
+
+    println!("{} node-{}: done", action, node_id);
+    Ok(())
+}
@@ -0,0 +1,738 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Backups of a running meta-service to an object storage, and restoring from them.
+//!
+//! A backup is stored in a dir `<backup_id>/` in the storage:
+//! - `data.json`: the exported data, in the same format as `--export` outputs.
+//! - `manifest.json`: a [`BackupManifest`], it is written last.
+//!   A backup without a manifest is incomplete and is ignored.
+//!
+//! A full backup contains the state machine and the raft logs.
+//! An incremental backup contains only the raft logs since the end of the backup it is based on,
+//! thus restoring it requires every backup in the chain back to a full backup.
+//!
+//! Taking a backup does not block writes to the meta-service:
+//! the meta-service copies the raft logs before sending them,
+//! and the state machine is read from the current snapshot.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::BufRead;
+use std::io::Cursor;
+
+use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_api::deserialize_struct;
+use databend_common_meta_api::deserialize_u64;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_api::IdGenerator;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::principal::StageType;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::schema::DbIdList;
+use databend_common_meta_app::schema::DbIdListKey;
+use databend_common_meta_app::schema::TableIdList;
+use databend_common_meta_app::schema::TableIdListKey;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_meta_client::ClientHandle;
+use databend_common_meta_client::MetaGrpcClient;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_raft_store::ondisk::TREE_HEADER;
+use databend_common_meta_raft_store::sm_v002::leveled_store::leveled_map::LeveledMap;
+use databend_common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use databend_common_meta_raft_store::sm_v002::SMV002;
+use databend_common_meta_raft_store::state::RaftStateKey;
+use databend_common_meta_raft_store::state::RaftStateValue;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
+use databend_common_meta_types::TxnRequest;
+use databend_common_storage::init_operator;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::snapshot::import_data_from;
+use crate::Config;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DATA_FILE: &str = "data.json";
+
+/// The prefix of stage keys, in form of `__fd_stages/<tenant>/<stage_name>`.
+const STAGE_PREFIX: &str = "__fd_stages";
+
+/// Max number of keys to read in one request when restoring to a running meta-service.
+const RESTORE_BATCH_SIZE: usize = 64;
+
+/// Max number of updates to the id generator in one transaction, when advancing the seq.
+const SEQ_BUMP_BATCH_SIZE: u64 = 1024;
+
+/// Describes a complete backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// The id of the backup, it is also the name of the dir the backup is stored in.
+    ///
+    /// It is built from the creation time, so that sorting ids sorts backups by time.
+    pub id: String,
+
+    /// The id of the backup this one is an increment to, or `None` for a full backup.
+    pub base: Option<String>,
+
+    /// The raft logs at or after this index are included.
+    ///
+    /// It is 0 for a full backup.
+    pub since_log_index: u64,
+
+    /// The next incremental backup includes the logs since this index.
+    ///
+    /// It is the one after the last committed log:
+    /// the uncommitted logs may be overridden and are included again by the next increment.
+    pub next_log_index: u64,
+
+    /// The number of exported records.
+    pub records: u64,
+
+    pub created_at: DateTime<Utc>,
+}
+
+impl BackupManifest {
+    fn kind(&self) -> String {
+        match &self.base {
+            None => "full".to_string(),
+            Some(base) => format!("incremental on {}", base),
+        }
+    }
+}
+
+/// Take a backup of a running meta-service and store it in `--backup-location`.
+///
+/// With `--incremental`, it backs up only the logs since the latest backup.
+pub async fn backup(config: &Config) -> anyhow::Result<()> {
+    let op = backup_operator(config).await?;
+
+    let base = if config.incremental {
+        let latest = list_manifests(&op).await?.pop().ok_or_else(|| {
+            anyhow!("no backup found to base an incremental backup on, take a full backup first")
+        })?;
+        Some(latest)
+    } else {
+        None
+    };
+
+    let since_log_index = base.as_ref().map(|b| b.next_log_index);
+
+    let created_at = Utc::now();
+    let id = created_at.format("%Y%m%dT%H%M%S%.3fZ").to_string();
+
+    eprintln!("    From: online meta-service: {}", config.grpc_api_address);
+    eprintln!("    To:   {}/{}", config.backup_location, id);
+    if let Some(base) = &base {
+        eprintln!(
+            "    Incremental on: {}, since log index: {}",
+            base.id, base.next_log_index
+        );
+    }
+
+    let client = new_client(config)?;
+    let mut grpc_client = client.make_established_client().await?;
+
+    let exported = grpc_client
+        .export_v1(pb::ExportRequest {
+            chunk_size: config.export_chunk_size,
+            since_log_index,
+        })
+        .await?;
+    let mut stream = exported.into_inner();
+
+    let mut writer = op.writer(&format!("{}/{}", id, DATA_FILE)).await?;
+
+    let mut records = 0;
+    let mut committed = None;
+    let mut last_log_index = None;
+
+    while let Some(chunk) = stream.try_next().await? {
+        let mut buf = Vec::new();
+
+        for line in chunk.data {
+            let (tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+            if since_log_index.is_some() && tree_name.starts_with("state_machine/") {
+                return Err(anyhow!(
+                    "the meta-service does not support incremental export, upgrade it or take a full backup"
+                ));
+            }
+
+            match entry {
+                RaftStoreEntry::RaftStateKV {
+                    key: RaftStateKey::Committed,
+                    value: RaftStateValue::Committed(c),
+                } => {
+                    committed = c;
+                }
+                RaftStoreEntry::Logs { key, .. } => {
+                    last_log_index = std::cmp::max(last_log_index, Some(key));
+                }
+                _ => {}
+            }
+
+            buf.extend_from_slice(line.as_bytes());
+            buf.push(b'\n');
+            records += 1;
+        }
+
+        writer.write(buf).await?;
+    }
+
+    writer.close().await?;
+
+    let since = since_log_index.unwrap_or_default();
+    let next_log_index = match (committed, last_log_index) {
+        (Some(c), _) => c.index + 1,
+        (None, Some(last)) => last + 1,
+        (None, None) => since,
+    };
+
+    let manifest = BackupManifest {
+        id: id.clone(),
+        base: base.map(|b| b.id),
+        since_log_index: since,
+        next_log_index: std::cmp::max(next_log_index, since),
+        records,
+        created_at,
+    };
+
+    // The manifest is written last to mark the backup as complete.
+    op.write(
+        &format!("{}/{}", id, MANIFEST_FILE),
+        serde_json::to_vec_pretty(&manifest)?,
+    )
+    .await?;
+
+    eprintln!(
+        "Backup {} done: {} records, next log index: {}",
+        id, records, manifest.next_log_index
+    );
+
+    Ok(())
+}
+
+/// Print the complete backups in `--backup-location`, from the oldest to the latest.
+pub async fn list_backups(config: &Config) -> anyhow::Result<()> {
+    let op = backup_operator(config).await?;
+
+    for m in list_manifests(&op).await? {
+        println!(
+            "{}: {}, logs: [{}, {}), records: {}, created_at: {}",
+            m.id,
+            m.kind(),
+            m.since_log_index,
+            m.next_log_index,
+            m.records,
+            m.created_at
+        );
+    }
+
+    Ok(())
+}
+
+/// Restore from the backup `--backup-id`, or the latest one.
+///
+/// Without `--restore-tenant` or `--restore-database`, all data is restored into `--raft-dir`,
+/// the same as `--import` does.
+/// Otherwise, only the keys of the chosen tenants and databases are restored
+/// into a running meta-service at `--grpc-api-address`.
+pub async fn restore(config: &Config) -> anyhow::Result<()> {
+    let op = backup_operator(config).await?;
+
+    let chain = backup_chain(&op, config.backup_id.as_deref()).await?;
+
+    eprintln!("    From: {}", config.backup_location);
+    for m in chain.iter() {
+        eprintln!("      - {}: {}", m.id, m.kind());
+    }
+
+    let lines = merge_chain(&op, &chain).await?;
+
+    if config.restore_tenant.is_empty() && config.restore_database.is_empty() {
+        if config.raft_dir.is_none() {
+            return Err(anyhow!("--raft-dir is required to restore all data"));
+        }
+
+        let reader = Cursor::new(lines.join("\n").into_bytes());
+        return import_data_from(config, reader.lines()).await;
+    }
+
+    eprintln!("    Into: online meta-service: {}", config.grpc_api_address);
+
+    let sm = build_state_machine(&lines).await?;
+    let selected = select_kvs(&sm, &config.restore_tenant, &config.restore_database).await?;
+
+    let client = new_client(config)?;
+
+    // The ids of the restored objects are allocated from the seq of the backup.
+    // They must not be allocated again by the target.
+    bump_seq_past(&client, selected.max_id).await?;
+
+    restore_kvs(&client, selected.kvs).await
+}
+
+/// Build the operator to access `--backup-location`:
+/// `fs://<dir>`, `s3://<bucket>/<root>`, or an external stage `@<tenant>/<stage_name>`.
+async fn backup_operator(config: &Config) -> anyhow::Result<Operator> {
+    let location = config.backup_location.as_str();
+
+    let params = if let Some(root) = location.strip_prefix("fs://") {
+        StorageParams::Fs(StorageFsConfig {
+            root: root.to_string(),
+        })
+    } else if let Some(path) = location.strip_prefix("s3://") {
+        let (bucket, root) = path.split_once('/').unwrap_or((path, ""));
+
+        let mut s3 = StorageS3Config {
+            bucket: bucket.to_string(),
+            root: format!("/{}", root),
+            region: config.backup_s3_region.clone(),
+            access_key_id: config.backup_s3_access_key_id.clone(),
+            secret_access_key: config.backup_s3_secret_access_key.clone(),
+            ..Default::default()
+        };
+        if !config.backup_s3_endpoint.is_empty() {
+            s3.endpoint_url = config.backup_s3_endpoint.clone();
+        }
+        StorageParams::S3(s3)
+    } else if let Some(stage) = location.strip_prefix('@') {
+        stage_storage(config, stage).await?
+    } else {
+        return Err(anyhow!(
+            "invalid --backup-location: {:?}, expect fs://<dir>, s3://<bucket>/<root> or @<tenant>/<stage_name>",
+            location
+        ));
+    };
+
+    Ok(init_operator(&params)?)
+}
+
+/// Load the storage of an external stage from the meta-service.
+async fn stage_storage(config: &Config, stage: &str) -> anyhow::Result<StorageParams> {
+    let (tenant, stage_name) = stage
+        .split_once('/')
+        .ok_or_else(|| anyhow!("invalid stage: {:?}, expect @<tenant>/<stage_name>", stage))?;
+
+    let key = kvapi::KeyBuilder::new_prefixed(STAGE_PREFIX)
+        .push_str(tenant)
+        .push_str(stage_name)
+        .done();
+
+    let client = new_client(config)?;
+    let seq_v = client
+        .get_kv(&key)
+        .await?
+        .ok_or_else(|| anyhow!("stage not found: {}", stage))?;
+
+    let info: StageInfo = deserialize_struct(&seq_v.data)?;
+    if info.stage_type != StageType::External {
+        return Err(anyhow!(
+            "backups can only be stored in an external stage, but {} is {:?}",
+            stage,
+            info.stage_type
+        ));
+    }
+
+    Ok(info.stage_params.storage)
+}
+
+fn new_client(config: &Config) -> anyhow::Result<std::sync::Arc<ClientHandle>> {
+    let client = MetaGrpcClient::try_create(
+        vec![config.grpc_api_address.clone()],
+        "root",
+        "xxx",
+        None,
+        None,
+        None,
+    )?;
+    Ok(client)
+}
+
+/// Returns the manifests of all complete backups, sorted by id.
+async fn list_manifests(op: &Operator) -> anyhow::Result<Vec<BackupManifest>> {
+    let mut manifests = vec![];
+
+    for entry in op.list("/").await? {
+        if !entry.metadata().is_dir() {
+            continue;
+        }
+
+        let path = format!("{}{}", entry.path(), MANIFEST_FILE);
+        let data = match op.read(&path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                eprintln!("WARN: skip incomplete backup: {}", entry.path());
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        manifests.push(serde_json::from_slice::<BackupManifest>(&data)?);
+    }
+
+    manifests.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(manifests)
+}
+
+/// Returns the backups required to restore `backup_id` or the latest backup,
+/// starting from a full backup.
+async fn backup_chain(
+    op: &Operator,
+    backup_id: Option<&str>,
+) -> anyhow::Result<Vec<BackupManifest>> {
+    let manifests = list_manifests(op)
+        .await?
+        .into_iter()
+        .map(|m| (m.id.clone(), m))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut id = match backup_id {
+        Some(id) => id.to_string(),
+        None => manifests
+            .keys()
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow!("no backup found"))?,
+    };
+
+    let mut chain = vec![];
+    loop {
+        let m = manifests
+            .get(&id)
+            .ok_or_else(|| anyhow!("backup not found: {}", id))?;
+        chain.push(m.clone());
+
+        match &m.base {
+            None => break,
+            Some(base) => id = base.clone(),
+        }
+    }
+
+    chain.reverse();
+    Ok(chain)
+}
+
+/// Merge the data of a chain of backups into the lines of a single full export.
+///
+/// An increment overrides the logs at or after its `since_log_index`,
+/// and the other records of raft state and logs with the same key.
+async fn merge_chain(op: &Operator, chain: &[BackupManifest]) -> anyhow::Result<Vec<String>> {
+    let mut header = vec![];
+    let mut state_machine = vec![];
+    let mut raft = BTreeMap::new();
+    let mut logs = BTreeMap::new();
+
+    for (i, m) in chain.iter().enumerate() {
+        let data = op.read(&format!("{}/{}", m.id, DATA_FILE)).await?;
+
+        // Logs since an increment are replaced with those in it.
+        let _ = logs.split_off(&m.since_log_index);
+
+        for line in data.as_slice().lines() {
+            let line = line?;
+            let (tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+            if tree_name == TREE_HEADER {
+                if i == 0 {
+                    header.push(line);
+                }
+            } else if tree_name.starts_with("state_machine/") {
+                state_machine.push(line);
+            } else if let RaftStoreEntry::Logs { key, .. } = entry {
+                logs.insert(key, line);
+            } else {
+                let (k, _) = RaftStoreEntry::serialize(&entry)?;
+                raft.insert((tree_name, k.to_vec()), line);
+            }
+        }
+    }
+
+    let mut lines = header;
+    lines.extend(raft.into_values());
+    lines.extend(logs.into_values());
+    lines.extend(state_machine);
+    Ok(lines)
+}
+
+/// Build a state machine from the state machine data and the committed logs in the exported lines.
+async fn build_state_machine(lines: &[String]) -> anyhow::Result<SMV002> {
+    let mut importer = SMV002::new_importer();
+    let mut logs = vec![];
+    let mut committed = None;
+
+    for line in lines {
+        let (tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+
+        if tree_name.starts_with("state_machine/") {
+            importer.import(entry)?;
+            continue;
+        }
+
+        match entry {
+            RaftStoreEntry::Logs { value, .. } => logs.push(value),
+            RaftStoreEntry::RaftStateKV {
+                key: RaftStateKey::Committed,
+                value: RaftStateValue::Committed(c),
+            } => {
+                committed = c;
+            }
+            _ => {}
+        }
+    }
+
+    let mut sm = SMV002::default();
+    sm.replace(LeveledMap::new(importer.commit()));
+
+    let last_applied = *sm.sys_data_ref().last_applied_ref();
+    let to_apply = logs.into_iter().filter(|ent| {
+        Some(ent.log_id) > last_applied && committed.map_or(true, |c| ent.log_id <= c)
+    });
+
+    sm.apply_entries(to_apply).await?;
+
+    Ok(sm)
+}
+
+/// Returns true if `key` is `<prefix>/...`.
+fn has_prefix(key: &str, prefix: &str) -> bool {
+    key.strip_prefix(prefix)
+        .map_or(false, |rest| rest.starts_with('/'))
+}
+
+/// The keys selected to restore.
+struct SelectedKVs {
+    kvs: BTreeMap<String, SeqV>,
+    /// The max id of the selected databases and tables.
+    max_id: u64,
+}
+
+/// Select the keys of the chosen tenants and databases.
+///
+/// - A tenant owns every key in form of `<prefix>/<tenant>/...` and the databases of it.
+/// - A database, specified in form of `<tenant>/<db_name>`, owns its name keys,
+///   every key in form of `<prefix>/<db_id>/...` of the current and dropped ids of it,
+///   and the tables in it.
+/// - A table owns every key in form of `<prefix>/<table_id>/...`.
+///
+/// Ids are allocated from the global seq and never collide with each other, even of different kinds.
+async fn select_kvs(
+    sm: &SMV002,
+    tenants: &[String],
+    databases: &[String],
+) -> anyhow::Result<SelectedKVs> {
+    let now_ms = SeqV::<()>::now_ms();
+
+    let all = sm
+        .list_kv("")
+        .await?
+        .try_filter(|(_, v)| futures::future::ready(!v.is_expired(now_ms)))
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Index keys by the segment after the prefix: `<prefix>/<segment>/...`
+    let mut by_segment: BTreeMap<&str, Vec<&(String, SeqV)>> = BTreeMap::new();
+    for kv in all.iter() {
+        if let Some(segment) = kv.0.split('/').nth(1) {
+            by_segment.entry(segment).or_default().push(kv);
+        }
+    }
+
+    let mut selected = BTreeMap::new();
+    let mut db_ids = BTreeSet::new();
+
+    let mut select_db = |key: &str, value: &SeqV| -> anyhow::Result<()> {
+        if has_prefix(key, DatabaseNameIdent::PREFIX) {
+            db_ids.insert(deserialize_u64(&value.data)?.0);
+        } else if has_prefix(key, DbIdListKey::PREFIX) {
+            let id_list: DbIdList = deserialize_struct(&value.data)?;
+            db_ids.extend(id_list.id_list);
+        }
+        selected.insert(key.to_string(), value.clone());
+        Ok(())
+    };
+
+    for tenant in tenants {
+        let kvs = by_segment
+            .get(kvapi::KeyBuilder::escape(tenant).as_str())
+            .ok_or_else(|| anyhow!("tenant not found in backup: {}", tenant))?;
+
+        for (k, v) in kvs.iter().map(|kv| (&kv.0, &kv.1)) {
+            select_db(k, v)?;
+        }
+    }
+
+    for database in databases {
+        let (tenant, db_name) = database.split_once('/').ok_or_else(|| {
+            anyhow!(
+                "invalid database: {:?}, expect <tenant>/<db_name>",
+                database
+            )
+        })?;
+
+        let name_key = DatabaseNameIdent::new(tenant, db_name).to_string_key();
+        let id_list_key = DbIdListKey {
+            tenant: tenant.to_string(),
+            db_name: db_name.to_string(),
+        }
+        .to_string_key();
+
+        let mut found = false;
+        for (k, v) in all
+            .iter()
+            .filter(|(k, _)| *k == name_key || *k == id_list_key)
+        {
+            select_db(k, v)?;
+            found = true;
+        }
+
+        if !found {
+            return Err(anyhow!("database not found in backup: {}", database));
+        }
+    }
+
+    let mut table_ids = BTreeSet::new();
+    let mut max_id = db_ids.last().copied().unwrap_or_default();
+
+    for db_id in db_ids {
+        for (k, v) in by_segment
+            .get(db_id.to_string().as_str())
+            .into_iter()
+            .flatten()
+            .map(|kv| (&kv.0, &kv.1))
+        {
+            if has_prefix(k, DBIdTableName::PREFIX) {
+                table_ids.insert(deserialize_u64(&v.data)?.0);
+            } else if has_prefix(k, TableIdListKey::PREFIX) {
+                let id_list: TableIdList = deserialize_struct(&v.data)?;
+                table_ids.extend(id_list.id_list);
+            }
+            selected.insert(k.clone(), v.clone());
+        }
+    }
+
+    max_id = std::cmp::max(max_id, table_ids.last().copied().unwrap_or_default());
+
+    for table_id in table_ids {
+        for (k, v) in by_segment
+            .get(table_id.to_string().as_str())
+            .into_iter()
+            .flatten()
+            .map(|kv| (&kv.0, &kv.1))
+        {
+            selected.insert(k.clone(), v.clone());
+        }
+    }
+
+    Ok(SelectedKVs {
+        kvs: selected,
+        max_id,
+    })
+}
+
+/// Advance the seq of the meta-service past `max_id`,
+/// so that the restored ids are never allocated again.
+///
+/// Every id generator allocates ids from the global seq,
+/// thus advancing it with any of the generators bumps all of them.
+async fn bump_seq_past(client: &ClientHandle, max_id: u64) -> anyhow::Result<()> {
+    let generator = IdGenerator::table_id();
+
+    let mut seq = fetch_id(client, generator.clone()).await?;
+    while seq <= max_id {
+        let n = std::cmp::min(max_id - seq, SEQ_BUMP_BATCH_SIZE);
+        if n > 0 {
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: (0..n)
+                    .map(|_| pb::TxnOp::put(generator.to_string_key(), vec![]))
+                    .collect(),
+                else_then: vec![],
+            };
+            client.transaction(txn).await?;
+        }
+        seq = fetch_id(client, generator.clone()).await?;
+    }
+
+    eprintln!("Advanced the seq to {}, past the restored ids", seq);
+    Ok(())
+}
+
+/// Write the selected keys into a running meta-service.
+///
+/// The keys are written in a single transaction:
+/// it fails without writing anything if some of the keys already exist,
+/// or are created while restoring.
+async fn restore_kvs(client: &ClientHandle, kvs: BTreeMap<String, SeqV>) -> anyhow::Result<()> {
+    let kvs = kvs.into_iter().collect::<Vec<_>>();
+
+    let mut existing = vec![];
+    for batch in kvs.chunks(RESTORE_BATCH_SIZE) {
+        let keys = batch.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+        let got = client.mget_kv(&keys).await?;
+
+        for (k, v) in keys.into_iter().zip(got) {
+            if v.is_some() {
+                existing.push(k);
+            }
+        }
+    }
+
+    if !existing.is_empty() {
+        return Err(anyhow!(
+            "{} keys to restore already exist, remove them first: {:?}",
+            existing.len(),
+            &existing[..std::cmp::min(10, existing.len())]
+        ));
+    }
+
+    let txn = TxnRequest {
+        condition: kvs
+            .iter()
+            .map(|(k, _)| pb::TxnCondition::eq_seq(k, 0))
+            .collect(),
+        if_then: kvs
+            .iter()
+            .map(|(k, v)| {
+                let expire_at = v.get_expire_at_ms().map(|ms| ms / 1000);
+                pb::TxnOp::put_with_expire(k, v.data.clone(), expire_at)
+            })
+            .collect(),
+        else_then: vec![],
+    };
+
+    let reply = client.transaction(txn).await?;
+    if !reply.success || !reply.error.is_empty() {
+        return Err(anyhow!(
+            "keys are changed while restoring, nothing is restored: {}",
+            reply.error
+        ));
+    }
+
+    eprintln!("Restored {} keys", kvs.len());
+    Ok(())
+}
@@ -17,6 +17,8 @@
 mod grpc;
 use grpc::export_meta;
 
+mod admin;
+mod backup;
 pub(crate) mod reading;
 mod snapshot;
 
@@ -72,6 +74,27 @@ pub struct Config {
     )]
     pub grpc_api_address: String,
 
+    /// The admin HTTP API address of a meta-service node, used to change the cluster membership.
+    #[clap(long, default_value = "127.0.0.1:28002")]
+    pub admin_api_address: String,
+
+    /// The token to access the admin HTTP API, i.e., the `admin_api_token` of the meta-service.
+    #[clap(long, env = "METASRV_ADMIN_API_TOKEN", default_value = "")]
+    #[serde(skip_serializing, default)]
+    pub admin_api_token: String,
+
+    /// The node to add, promote, demote or transfer the leadership to.
+    #[clap(long)]
+    pub node_id: Option<u64>,
+
+    /// The raft API address of the node to add as a learner, in form of `<host>:<port>`.
+    #[clap(long, default_value = "")]
+    pub learner_raft_address: String,
+
+    /// The gRPC API advertise address of the node to add as a learner.
+    #[clap(long)]
+    pub learner_grpc_address: Option<String>,
+
     /// The dir to store persisted meta state, including raft logs, state machine etc.
     #[clap(long)]
     #[serde(alias = "kvsrv_raft_dir")]
@@ -84,6 +107,55 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub db: String,
 
+    /// Take a backup of a running meta-service at `grpc_api_address` and store it in `backup_location`.
+    #[clap(long)]
+    pub backup: bool,
+
+    /// When taking a backup, back up only the raft logs since the latest backup in `backup_location`.
+    #[clap(long)]
+    pub incremental: bool,
+
+    /// List the backups in `backup_location`.
+    #[clap(long)]
+    pub list_backups: bool,
+
+    /// Restore from a backup in `backup_location`.
+    ///
+    /// Without `restore_tenant` or `restore_database`, all data is restored into `raft_dir`, the same as `import`.
+    /// Otherwise only the chosen tenants and databases are restored into a running meta-service at `grpc_api_address`.
+    #[clap(long)]
+    pub restore: bool,
+
+    /// The id of the backup to restore. By default it is the latest one.
+    #[clap(long)]
+    pub backup_id: Option<String>,
+
+    /// Where backups are stored: `fs://<dir>`, `s3://<bucket>/<root>`, or an external stage `@<tenant>/<stage_name>`.
+    #[clap(long, default_value = "")]
+    pub backup_location: String,
+
+    /// The endpoint of the S3 compatible storage to store backups, e.g., a MinIO server.
+    #[clap(long, default_value = "")]
+    pub backup_s3_endpoint: String,
+
+    #[clap(long, default_value = "")]
+    pub backup_s3_region: String,
+
+    #[clap(long, env = "AWS_ACCESS_KEY_ID", default_value = "")]
+    pub backup_s3_access_key_id: String,
+
+    #[clap(long, env = "AWS_SECRET_ACCESS_KEY", default_value = "")]
+    #[serde(skip_serializing, default)]
+    pub backup_s3_secret_access_key: String,
+
+    /// Restore only the data of this tenant. It can be specified more than once.
+    #[clap(long)]
+    pub restore_tenant: Vec<String>,
+
+    /// Restore only this database, in form of `<tenant>/<db_name>`. It can be specified more than once.
+    #[clap(long)]
+    pub restore_database: Vec<String>,
+
     /// initial_cluster format: node_id=endpoint,grpc_api_addr
     #[clap(long)]
     pub initial_cluster: Vec<String>,
@@ -173,11 +245,30 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "add-learner" => admin::change_membership(&config, "add_learner").await,
+            "promote" => admin::change_membership(&config, "promote").await,
+            "demote" => admin::change_membership(&config, "demote").await,
+            "transfer-leader" => admin::change_membership(&config, "transfer_leader").await,
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd add-learner");
+                eprintln!("    Add a node as a learner.");
+                eprintln!(
+                    "    Requires --admin-api-address, --node-id and --learner-raft-address."
+                );
+                eprintln!("  --cmd promote");
+                eprintln!("    Promote a learner to a voter.");
+                eprintln!("    Requires --admin-api-address and --node-id.");
+                eprintln!("  --cmd demote");
+                eprintln!("    Demote a voter to a learner.");
+                eprintln!("    Requires --admin-api-address and --node-id.");
+                eprintln!("  --cmd transfer-leader");
+                eprintln!("    Transfer the leadership to a voter.");
+                eprintln!("    Requires --admin-api-address and --node-id.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
@@ -196,6 +287,22 @@ async fn main() -> anyhow::Result<()> {
         return snapshot::import_data(&config).await;
     }
 
+    if config.backup {
+        eprintln!();
+        eprintln!("Backup:");
+        return backup::backup(&config).await;
+    }
+
+    if config.list_backups {
+        return backup::list_backups(&config).await;
+    }
+
+    if config.restore {
+        eprintln!();
+        eprintln!("Restore:");
+        return backup::restore(&config).await;
+    }
+
     Err(anyhow::anyhow!("Nothing to do"))
 }
 
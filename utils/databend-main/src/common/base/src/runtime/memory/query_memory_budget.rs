@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use crate::runtime::memory::MemStat;
+
+/// The hard memory budget of a single query.
+///
+/// Unlike `MemStat::set_limit`, exceeding the budget never fails an allocation:
+/// spilling operators start to spill once the query reaches `spilling_ratio` of the budget,
+/// and the pipeline executor cancels the query if it still goes over the budget.
+pub struct QueryMemoryBudget {
+    mem_stat: Arc<MemStat>,
+    limit: i64,
+    spilling_limit: i64,
+}
+
+impl QueryMemoryBudget {
+    /// `spilling_ratio` is a percentage of `limit`, 0 disables spilling triggered by the budget.
+    pub fn create(mem_stat: Arc<MemStat>, limit: u64, spilling_ratio: u64) -> Arc<Self> {
+        let limit = limit as i64;
+        let spilling_limit = match spilling_ratio.min(100) {
+            0 => 0,
+            ratio => (limit as f64 * ratio as f64 / 100_f64) as i64,
+        };
+
+        Arc::new(QueryMemoryBudget {
+            mem_stat,
+            limit,
+            spilling_limit,
+        })
+    }
+
+    pub fn mem_stat(&self) -> &Arc<MemStat> {
+        &self.mem_stat
+    }
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+
+    #[inline]
+    pub fn get_memory_usage(&self) -> i64 {
+        self.mem_stat.get_memory_usage()
+    }
+
+    /// Check if spilling operators are allowed to spill when the query goes near its budget.
+    #[inline]
+    pub fn spilling_enabled(&self) -> bool {
+        self.spilling_limit > 0
+    }
+
+    /// Check if the query uses enough memory that spilling operators should spill.
+    #[inline]
+    pub fn should_spill(&self) -> bool {
+        self.spilling_limit > 0 && self.get_memory_usage() >= self.spilling_limit
+    }
+
+    /// Check if the query uses more memory than its budget.
+    #[inline]
+    pub fn is_exceeded(&self) -> bool {
+        self.limit > 0 && self.get_memory_usage() > self.limit
+    }
+}
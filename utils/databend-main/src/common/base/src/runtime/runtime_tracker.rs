@@ -54,6 +54,7 @@ use pin_project_lite::pin_project;
 
 use crate::runtime::memory::MemStat;
 use crate::runtime::memory::OutOfLimit;
+use crate::runtime::memory::QueryMemoryBudget;
 use crate::runtime::memory::StatBuffer;
 use crate::runtime::profile::Profile;
 
@@ -101,6 +102,7 @@ pub struct ThreadTracker {
 pub struct TrackingPayload {
     pub profile: Option<Arc<Profile>>,
     pub mem_stat: Option<Arc<MemStat>>,
+    pub query_memory_budget: Option<Arc<QueryMemoryBudget>>,
 }
 
 pub struct TrackingGuard {
@@ -159,6 +161,7 @@ impl ThreadTracker {
             payload: TrackingPayload {
                 profile: None,
                 mem_stat: None,
+                query_memory_budget: None,
             },
         }
     }
@@ -262,6 +265,19 @@ impl ThreadTracker {
         })
     }
 
+    /// Check if the query the current thread works for should spill because of its memory budget.
+    pub fn query_memory_should_spill() -> bool {
+        TRACKER
+            .try_with(|tracker| {
+                let tracker = tracker.borrow();
+                match tracker.payload.query_memory_budget.as_deref() {
+                    None => false,
+                    Some(budget) => budget.should_spill(),
+                }
+            })
+            .unwrap_or(false)
+    }
+
     pub fn record_memory<const ROLLBACK: bool>(batch: i64, cur: i64) -> Result<(), OutOfLimit> {
         let has_thread_local = TRACKER.try_with(|tracker: &RefCell<ThreadTracker>| {
             // We need to ensure no heap memory alloc or dealloc. it will cause panic of borrow recursive call.
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod mem_stat;
+mod query_memory_budget;
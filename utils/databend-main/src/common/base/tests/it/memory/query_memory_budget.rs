@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::QueryMemoryBudget;
+use databend_common_base::runtime::ThreadTracker;
+
+#[test]
+fn test_query_memory_budget() {
+    let mem_stat = MemStat::create("TEST".to_string());
+    let budget = QueryMemoryBudget::create(mem_stat.clone(), 1000, 50);
+
+    assert!(budget.spilling_enabled());
+
+    mem_stat.movein_memory(400);
+    assert!(!budget.should_spill());
+    assert!(!budget.is_exceeded());
+
+    mem_stat.movein_memory(200);
+    assert!(budget.should_spill());
+    assert!(!budget.is_exceeded());
+
+    mem_stat.movein_memory(500);
+    assert!(budget.should_spill());
+    assert!(budget.is_exceeded());
+
+    let budget = QueryMemoryBudget::create(mem_stat, 1000, 0);
+    assert!(!budget.spilling_enabled());
+    assert!(!budget.should_spill());
+    assert!(budget.is_exceeded());
+}
+
+#[test]
+fn test_query_memory_should_spill() {
+    assert!(!ThreadTracker::query_memory_should_spill());
+
+    let mem_stat = MemStat::create("TEST".to_string());
+    mem_stat.movein_memory(900);
+
+    let mut payload = ThreadTracker::new_tracking_payload();
+    payload.query_memory_budget = Some(QueryMemoryBudget::create(mem_stat, 1000, 80));
+
+    let guard = ThreadTracker::tracking(payload);
+    assert!(ThreadTracker::query_memory_should_spill());
+
+    drop(guard);
+    assert!(!ThreadTracker::query_memory_should_spill());
+}
@@ -149,6 +149,8 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    QueryMemoryLimitExceeded(1122),
+    UnknownRowAccessPolicy(1123),
 
     // Data Related Errors
 
@@ -192,6 +194,7 @@ build_exceptions! {
     // Index related errors.
     UnsupportedIndex(1601),
     RefreshIndexError(1602),
+    IndexOptionInvalid(1603),
 
     // Cloud control error codes
     CloudControlConnectError(1701),
@@ -224,6 +227,8 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    UnknownTableRef(2017),
+    TableRefAlreadyExists(2018),
 
     // User api error codes.
     UnknownUser(2201),
@@ -242,6 +247,11 @@ build_exceptions! {
     InvalidPassword(2215),
     RoleAlreadyExists(2216),
     IllegalRole(2217),
+    UnknownWorkloadGroup(2218),
+    WorkloadGroupAlreadyExists(2219),
+    IllegalWorkloadGroup(2220),
+    WorkloadGroupIsUsedByUser(2221),
+    WorkloadGroupQueueTimeout(2222),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -271,6 +281,9 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
+    RowAccessPolicyIsAttached(2323),
 
 
     // Cluster error codes.
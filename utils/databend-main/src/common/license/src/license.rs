@@ -33,6 +33,8 @@ pub enum Feature {
     ComputedColumn,
     StorageEncryption,
     Stream,
+    InvertedIndex,
+    RowAccessPolicy,
 }
 
 impl Display for Feature {
@@ -68,6 +70,12 @@ impl Display for Feature {
             Feature::Stream => {
                 write!(f, "stream")
             }
+            Feature::InvertedIndex => {
+                write!(f, "inverted_index")
+            }
+            Feature::RowAccessPolicy => {
+                write!(f, "row_access_policy")
+            }
         }
     }
 }
@@ -142,6 +142,8 @@ static BLOCK_INDEX_WRITE_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_index_write_bytes"));
 static BLOCK_INDEX_WRITE_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_block_index_write_milliseconds"));
+static DELETION_VECTOR_WRITE_NUMS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_deletion_vector_write_nums"));
 static BLOCK_INDEX_READ_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_block_index_read_bytes"));
 static COMPACT_BLOCK_READ_NUMS: LazyLock<Counter> =
@@ -488,6 +490,10 @@ pub fn metrics_inc_block_index_write_milliseconds(c: u64) {
     BLOCK_INDEX_WRITE_MILLISECONDS.observe(c as f64);
 }
 
+pub fn metrics_inc_deletion_vector_write_nums(c: u64) {
+    DELETION_VECTOR_WRITE_NUMS.inc_by(c);
+}
+
 pub fn metrics_inc_block_index_read_bytes(c: u64) {
     BLOCK_INDEX_READ_BYTES.inc_by(c);
 }
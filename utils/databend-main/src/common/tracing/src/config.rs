@@ -26,6 +26,7 @@ pub struct Config {
     pub profile: ProfileLogConfig,
     pub structlog: StructLogConfig,
     pub tracing: TracingConfig,
+    pub audit: AuditLogConfig,
 }
 
 impl Config {
@@ -243,6 +244,49 @@ impl Default for StructLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    pub dir: String,
+    /// Security event categories to record, all of them if empty.
+    pub categories: Vec<String>,
+    /// The key of the HMAC that chains the events.
+    pub secret_key: String,
+}
+
+impl AuditLogConfig {
+    /// All the security event categories that can be recorded.
+    pub const CATEGORIES: [&'static str; 5] =
+        ["login", "grant", "ddl", "network_policy", "privilege"];
+
+    pub fn is_enabled(&self, category: &str) -> bool {
+        self.on && (self.categories.is_empty() || self.categories.iter().any(|c| c == category))
+    }
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, dir={}, categories={}",
+            self.on,
+            self.dir,
+            self.categories.join(",")
+        )
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            dir: "".to_string(),
+            categories: vec![],
+            secret_key: "".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
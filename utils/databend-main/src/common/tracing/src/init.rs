@@ -157,6 +157,7 @@ pub fn init_logging(
     let mut query_logger = fern::Dispatch::new();
     let mut profile_logger = fern::Dispatch::new();
     let mut structlog_logger = fern::Dispatch::new();
+    let mut audit_logger = fern::Dispatch::new();
 
     // File logger
     if cfg.file.on {
@@ -251,12 +252,21 @@ pub fn init_logging(
             structlog_logger.chain(Box::new(structlog_log_file) as Box<dyn Write + Send>);
     }
 
+    // Audit logger
+    if cfg.audit.on && !cfg.audit.dir.is_empty() {
+        let (audit_log_file, flush_guard) =
+            new_file_log_writer(&cfg.audit.dir, log_name, cfg.file.limit);
+        guards.push(Box::new(flush_guard));
+        audit_logger = audit_logger.chain(Box::new(audit_log_file) as Box<dyn Write + Send>);
+    }
+
     let logger = fern::Dispatch::new()
         .chain(
             fern::Dispatch::new()
                 .level_for("databend::log::query", LevelFilter::Off)
                 .level_for("databend::log::profile", LevelFilter::Off)
                 .level_for("databend::log::structlog", LevelFilter::Off)
+                .level_for("databend::log::audit", LevelFilter::Off)
                 .filter({
                     let prefix_filter = cfg.file.prefix_filter.clone();
                     move |meta| {
@@ -286,6 +296,12 @@ pub fn init_logging(
                 .level(LevelFilter::Off)
                 .level_for("databend::log::structlog", LevelFilter::Info)
                 .chain(structlog_logger),
+        )
+        .chain(
+            fern::Dispatch::new()
+                .level(LevelFilter::Off)
+                .level_for("databend::log::audit", LevelFilter::Info)
+                .chain(audit_logger),
         );
 
     // Set global logger
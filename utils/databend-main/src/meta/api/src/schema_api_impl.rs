@@ -49,6 +49,7 @@ use databend_common_meta_app::app_error::UndropTableWithNoDropTime;
 use databend_common_meta_app::app_error::UnknownCatalog;
 use databend_common_meta_app::app_error::UnknownDatabaseId;
 use databend_common_meta_app::app_error::UnknownIndex;
+use databend_common_meta_app::app_error::UnknownRowAccessPolicy;
 use databend_common_meta_app::app_error::UnknownStreamId;
 use databend_common_meta_app::app_error::UnknownTable;
 use databend_common_meta_app::app_error::UnknownTableId;
@@ -58,6 +59,8 @@ use databend_common_meta_app::app_error::WrongShare;
 use databend_common_meta_app::app_error::WrongShareObject;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use databend_common_meta_app::schema::CatalogId;
 use databend_common_meta_app::schema::CatalogIdToName;
 use databend_common_meta_app::schema::CatalogInfo;
@@ -141,6 +144,9 @@ use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -152,6 +158,7 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::schema::TruncateTableReply;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReply;
@@ -3131,6 +3138,92 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            debug!(ident :% =(&tbid); "set_table_row_access_policy");
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            }
+            if req_seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let mut new_table_meta = table_meta.unwrap();
+            match &req.action {
+                SetTableRowAccessPolicyAction::Add(policy, columns) => {
+                    new_table_meta.row_access_policy = Some(TableRowAccessPolicy {
+                        policy: policy.clone(),
+                        columns: columns.clone(),
+                    });
+                }
+                SetTableRowAccessPolicyAction::Drop(_) => {
+                    new_table_meta.row_access_policy = None;
+                }
+            }
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            update_row_access_policy_table_ids(
+                self,
+                &req.action,
+                &mut txn_req,
+                &req.tenant,
+                req.table_id,
+            )
+            .await?;
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id :? =(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {
+                    share_table_info: get_share_table_info_map(self, &new_table_meta).await?,
+                });
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_drop_table_infos(
@@ -5333,6 +5426,46 @@ async fn update_mask_policy(
     Ok(())
 }
 
+/// Add the table to or remove it from the table id list of the row access policy.
+async fn update_row_access_policy_table_ids(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    action: &SetTableRowAccessPolicyAction,
+    txn_req: &mut TxnRequest,
+    tenant: &str,
+    table_id: u64,
+) -> Result<(), KVAppError> {
+    let (policy, attach) = match action {
+        SetTableRowAccessPolicyAction::Add(policy, _) => (policy, true),
+        SetTableRowAccessPolicyAction::Drop(policy) => (policy, false),
+    };
+
+    let key = RowAccessPolicyTableIdListKey::new(tenant, policy);
+    let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+        get_pb_value(kv_api, &key).await?;
+
+    // The list is deleted together with the policy, attaching a dropped policy fails here.
+    if attach && id_list_opt.is_none() {
+        return Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+            UnknownRowAccessPolicy::new(policy, format!("attach to table {}", table_id)),
+        )));
+    }
+
+    if let Some(mut id_list) = id_list_opt {
+        if attach {
+            id_list.id_list.insert(table_id);
+        } else {
+            id_list.id_list.remove(&table_id);
+        }
+
+        txn_req.condition.push(txn_cond_seq(&key, Eq, id_list_seq));
+        txn_req
+            .if_then
+            .push(txn_op_put(&key, serialize_struct(&id_list)?));
+    }
+
+    Ok(())
+}
+
 /// Return OK if a table lock exists by checking the seq.
 ///
 /// Otherwise returns TableLockExpired error
@@ -74,6 +74,22 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -398,6 +414,40 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("RowAccessPolicyIsAttached: `{name}` to {tables} tables while `{context}`")]
+pub struct RowAccessPolicyIsAttached {
+    name: String,
+    tables: usize,
+    context: String,
+}
+
+impl RowAccessPolicyIsAttached {
+    pub fn new(name: impl Into<String>, tables: usize, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tables,
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownBackgroundJob: `{name}` while `{context}`")]
 pub struct UnknownBackgroundJob {
@@ -1020,6 +1070,15 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
+    #[error(transparent)]
+    RowAccessPolicyIsAttached(#[from] RowAccessPolicyIsAttached),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1344,6 +1403,27 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exists", self.name)
+    }
+}
+
+impl AppErrorMessage for RowAccessPolicyIsAttached {
+    fn message(&self) -> String {
+        format!(
+            "Row access policy '{}' is attached to {} tables, drop it from the tables first",
+            self.name, self.tables
+        )
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1456,6 +1536,15 @@ impl From<AppError> for ErrorCode {
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
+            AppError::RowAccessPolicyIsAttached(err) => {
+                ErrorCode::RowAccessPolicyIsAttached(err.message())
+            }
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -26,6 +26,7 @@ pub mod background;
 pub mod data_mask;
 pub mod primitive;
 pub mod principal;
+pub mod row_access_policy;
 pub mod schema;
 pub mod share;
 pub mod storage;
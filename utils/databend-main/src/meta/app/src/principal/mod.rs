@@ -32,6 +32,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 pub use connection::*;
 pub use file_format::*;
@@ -68,3 +69,4 @@ pub use user_quota::UserQuota;
 pub use user_setting::UserSetting;
 pub use user_setting::UserSettingValue;
 pub use user_stage::*;
+pub use workload_group::WorkloadGroup;
@@ -23,6 +23,8 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const LDAP_AUTH_STR: &str = "ldap";
+const KEY_PAIR_AUTH_STR: &str = "key_pair";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +32,8 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    Ldap,
+    KeyPair,
 }
 
 impl std::str::FromStr for AuthType {
@@ -40,6 +44,8 @@ impl std::str::FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            LDAP_AUTH_STR => Ok(AuthType::Ldap),
+            KEY_PAIR_AUTH_STR => Ok(AuthType::KeyPair),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
         }
     }
@@ -52,6 +58,8 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::Ldap => LDAP_AUTH_STR,
+            AuthType::KeyPair => KEY_PAIR_AUTH_STR,
         }
     }
 
@@ -61,6 +69,8 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            LDAP_AUTH_STR,
+            KEY_PAIR_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -90,6 +100,10 @@ pub enum AuthInfo {
         hash_method: PasswordHashMethod,
     },
     JWT,
+    /// Password is verified against the configured LDAP server on login.
+    Ldap,
+    /// Tokens signed by the client are verified with the public keys in the user option.
+    KeyPair,
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -107,6 +121,8 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::Ldap => Ok(AuthInfo::Ldap),
+            AuthType::KeyPair => Ok(AuthInfo::KeyPair),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -165,6 +181,8 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::Ldap => AuthType::Ldap,
+            AuthInfo::KeyPair => AuthType::KeyPair,
             AuthInfo::Password {
                 hash_value: _,
                 hash_method: t,
@@ -181,7 +199,7 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::Ldap | AuthInfo::KeyPair => "".to_string(),
         }
     }
 
@@ -207,13 +207,15 @@ pub enum GrantObject {
     DatabaseById(String, u64),
     Table(String, String, String),
     TableById(String, u64, u64),
+    /// A single column of a table, identified by catalog, db_id, table_id and column_id.
+    TableColumnById(String, u64, u64, u32),
     UDF(String),
     Stage(String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, and a Table object contains all of its columns.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -230,6 +232,10 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (
+                GrantObject::DatabaseById(lcat, ldb),
+                GrantObject::TableColumnById(rcat, rdb, _, _),
+            ) => lcat == rcat && ldb == rdb,
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
@@ -238,6 +244,19 @@ impl GrantObject {
                 GrantObject::TableById(lcat, lhs_db, lhs_table),
                 GrantObject::TableById(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::TableById(lcat, lhs_db, lhs_table),
+                GrantObject::TableColumnById(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::TableColumnById(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::TableColumnById(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
@@ -255,6 +274,9 @@ impl GrantObject {
             GrantObject::Table(_, _, _) | GrantObject::TableById(_, _, _) => {
                 UserPrivilegeSet::available_privileges_on_table(available_ownership)
             }
+            GrantObject::TableColumnById(_, _, _, _) => {
+                UserPrivilegeSet::available_privileges_on_column()
+            }
             GrantObject::UDF(_) => {
                 UserPrivilegeSet::available_privileges_on_udf(available_ownership)
             }
@@ -268,7 +290,9 @@ impl GrantObject {
         match self {
             GrantObject::Global | GrantObject::Stage(_) | GrantObject::UDF(_) => None,
             GrantObject::Database(cat, _) | GrantObject::DatabaseById(cat, _) => Some(cat.clone()),
-            GrantObject::Table(cat, _, _) | GrantObject::TableById(cat, _, _) => Some(cat.clone()),
+            GrantObject::Table(cat, _, _)
+            | GrantObject::TableById(cat, _, _)
+            | GrantObject::TableColumnById(cat, _, _, _) => Some(cat.clone()),
         }
     }
 }
@@ -285,6 +309,9 @@ impl fmt::Display for GrantObject {
             GrantObject::TableById(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::TableColumnById(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'({})", cat, db, table, column)
+            }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
         }
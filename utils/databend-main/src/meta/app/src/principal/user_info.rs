@@ -164,6 +164,15 @@ pub struct UserOption {
     network_policy: Option<String>,
 
     password_policy: Option<String>,
+
+    workload_group: Option<String>,
+
+    max_query_memory_usage: Option<u64>,
+
+    /// PEM public keys for key pair auth, the second slot is for key rotation.
+    public_key: Option<String>,
+
+    public_key_2: Option<String>,
 }
 
 impl UserOption {
@@ -173,6 +182,10 @@ impl UserOption {
             default_role: None,
             network_policy: None,
             password_policy: None,
+            workload_group: None,
+            max_query_memory_usage: None,
+            public_key: None,
+            public_key_2: None,
         }
     }
 
@@ -200,6 +213,26 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
+    pub fn with_max_query_memory_usage(mut self, max_query_memory_usage: Option<u64>) -> Self {
+        self.max_query_memory_usage = max_query_memory_usage;
+        self
+    }
+
+    pub fn with_public_key(mut self, public_key: Option<String>) -> Self {
+        self.public_key = public_key;
+        self
+    }
+
+    pub fn with_public_key_2(mut self, public_key_2: Option<String>) -> Self {
+        self.public_key_2 = public_key_2;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -221,6 +254,30 @@ impl UserOption {
         self.password_policy.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
+    pub fn max_query_memory_usage(&self) -> Option<u64> {
+        self.max_query_memory_usage
+    }
+
+    pub fn public_key(&self) -> Option<&String> {
+        self.public_key.as_ref()
+    }
+
+    pub fn public_key_2(&self) -> Option<&String> {
+        self.public_key_2.as_ref()
+    }
+
+    /// All public keys set on the user, in slot order.
+    pub fn public_keys(&self) -> Vec<&String> {
+        self.public_key
+            .iter()
+            .chain(self.public_key_2.iter())
+            .collect()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -233,6 +290,22 @@ impl UserOption {
         self.password_policy = password_policy;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
+    pub fn set_max_query_memory_usage(&mut self, max_query_memory_usage: Option<u64>) {
+        self.max_query_memory_usage = max_query_memory_usage;
+    }
+
+    pub fn set_public_key(&mut self, public_key: Option<String>) {
+        self.public_key = public_key;
+    }
+
+    pub fn set_public_key_2(&mut self, public_key_2: Option<String>) {
+        self.public_key_2 = public_key_2;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -185,6 +185,11 @@ impl UserPrivilegeSet {
         }
     }
 
+    /// Only the row-level DML privileges can be narrowed down to a column.
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select | Insert | Update }).into()
+    }
+
     pub fn available_privileges_on_stage(available_ownership: bool) -> Self {
         if available_ownership {
             make_bitflags!(UserPrivilegeType::{  Read | Write | Ownership }).into()
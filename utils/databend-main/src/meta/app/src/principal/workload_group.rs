@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A workload group limits how many queries of its members run at the same time and how
+/// much of the node they may use. Queries over the limits wait in the group's admission queue.
+///
+/// Every limit uses `0` to mean "unlimited".
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// Maximum number of queries of this group running at the same time on one node.
+    pub max_concurrency: u64,
+    /// Maximum memory in bytes all running queries of this group may use on one node.
+    pub max_memory_usage: u64,
+    /// Percentage of the node's CPUs a query of this group may use, in `[1, 100]`.
+    pub cpu_share: u64,
+    /// Seconds a query may wait in the queue before it is rejected.
+    pub queue_timeout_secs: u64,
+    /// Roles bound to this group. Users without an explicit workload group fall into
+    /// the group of their current role.
+    pub roles: Vec<String>,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
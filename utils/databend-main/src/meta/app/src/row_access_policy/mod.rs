@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::schema::CreateOption;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RowAccessPolicyNameIdent {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl Display for RowAccessPolicyNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.name)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RowAccessPolicyId {
+    pub id: u64,
+}
+
+impl Display for RowAccessPolicyId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+/// A boolean expression that decides which rows of a table are visible to a query.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RowAccessPolicyMeta {
+    // Vec<(arg_name, arg_type)>, the columns of the table are bound to the args by position.
+    pub args: Vec<(String, String)>,
+    pub body: String,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl From<CreateRowAccessPolicyReq> for RowAccessPolicyMeta {
+    fn from(p: CreateRowAccessPolicyReq) -> Self {
+        RowAccessPolicyMeta {
+            args: p.args.clone(),
+            body: p.body.clone(),
+            comment: p.comment.clone(),
+            create_on: p.create_on,
+            update_on: None,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateRowAccessPolicyReq {
+    pub create_option: CreateOption,
+    pub name: RowAccessPolicyNameIdent,
+    pub args: Vec<(String, String)>,
+    pub body: String,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateRowAccessPolicyReply {
+    pub id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropRowAccessPolicyReq {
+    pub if_exists: bool,
+    pub name: RowAccessPolicyNameIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropRowAccessPolicyReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetRowAccessPolicyReq {
+    pub name: RowAccessPolicyNameIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetRowAccessPolicyReply {
+    pub policy: RowAccessPolicyMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RowAccessPolicyTableIdListKey {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl RowAccessPolicyTableIdListKey {
+    pub fn new(tenant: impl ToString, name: impl ToString) -> Self {
+        RowAccessPolicyTableIdListKey {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Display for RowAccessPolicyTableIdListKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.name)
+    }
+}
+
+/// The ids of the tables a row access policy is attached to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, Default, PartialEq)]
+pub struct RowAccessPolicyTableIdList {
+    pub id_list: BTreeSet<u64>,
+}
+
+mod kvapi_key_impl {
+    use databend_common_meta_kvapi::kvapi;
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use super::RowAccessPolicyId;
+    use super::RowAccessPolicyNameIdent;
+    use super::RowAccessPolicyTableIdListKey;
+    use crate::row_access_policy::RowAccessPolicyMeta;
+    use crate::row_access_policy::RowAccessPolicyTableIdList;
+    use crate::tenant::Tenant;
+
+    /// __fd_row_access_policy/<tenant>/<name> -> <row_access_policy_id>
+    impl kvapi::Key for RowAccessPolicyNameIdent {
+        const PREFIX: &'static str = "__fd_row_access_policy";
+
+        type ValueType = RowAccessPolicyId;
+
+        /// It belongs to a tenant
+        fn parent(&self) -> Option<String> {
+            Some(Tenant::new(&self.tenant).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let name = p.next_str()?;
+            p.done()?;
+
+            Ok(RowAccessPolicyNameIdent { tenant, name })
+        }
+    }
+
+    /// "__fd_row_access_policy_by_id/<id>"
+    impl kvapi::Key for RowAccessPolicyId {
+        const PREFIX: &'static str = "__fd_row_access_policy_by_id";
+
+        type ValueType = RowAccessPolicyMeta;
+
+        fn parent(&self) -> Option<String> {
+            None
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let id = p.next_u64()?;
+            p.done()?;
+
+            Ok(RowAccessPolicyId { id })
+        }
+    }
+
+    /// "__fd_row_access_policy_id_list/<tenant>/<name>" -> <table_id_list>
+    impl kvapi::Key for RowAccessPolicyTableIdListKey {
+        const PREFIX: &'static str = "__fd_row_access_policy_id_list";
+
+        type ValueType = RowAccessPolicyTableIdList;
+
+        /// It belongs to a tenant
+        fn parent(&self) -> Option<String> {
+            Some(Tenant::new(&self.tenant).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let name = p.next_str()?;
+            p.done()?;
+
+            Ok(RowAccessPolicyTableIdListKey { tenant, name })
+        }
+    }
+
+    impl kvapi::Value for RowAccessPolicyId {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            [self.to_string_key()]
+        }
+    }
+
+    impl kvapi::Value for RowAccessPolicyMeta {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    impl kvapi::Value for RowAccessPolicyTableIdList {
+        /// It contains table ids but it does not own these table in the meta-data hierarchy.
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
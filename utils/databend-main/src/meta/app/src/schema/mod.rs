@@ -87,6 +87,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
@@ -94,10 +97,14 @@ pub use table::TableIdList;
 pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
+pub use table::TableIndex;
 pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRef;
+pub use table::TableRefKind;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
@@ -111,6 +118,8 @@ pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table::PENDING_CLONE_REF_TTL_IN_SECS;
+pub use table::TABLE_CLONE_REF_PREFIX;
 pub use virtual_column::CreateVirtualColumnReply;
 pub use virtual_column::CreateVirtualColumnReq;
 pub use virtual_column::DropVirtualColumnReply;
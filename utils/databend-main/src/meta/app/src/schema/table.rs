@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
@@ -218,6 +219,12 @@ pub struct TableStatistics {
 
     /// number of blocks
     pub number_of_blocks: Option<u64>,
+
+    /// number of rows inserted, updated or deleted since the table was last analyzed
+    pub rows_modified_since_analyze: Option<u64>,
+
+    /// time of the last `ANALYZE TABLE`
+    pub analyzed_on: Option<DateTime<Utc>>,
 }
 
 /// The essential state that defines what a table is.
@@ -249,6 +256,99 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // inverted indexes of the table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+    // tags, branches and clones of the table snapshots, keyed by ref name.
+    pub refs: BTreeMap<String, TableRef>,
+    // the row access policy attached to the table, if any.
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub name: String,
+    pub column_ids: Vec<ColumnId>,
+    // if true, index will create after data written to databend,
+    // no need execute refresh index manually.
+    pub sync_creation: bool,
+    // if the index columns or options change,
+    // the index data needs to be regenerated,
+    // version is used to identify each change.
+    pub version: String,
+    // index options specify the index configs, like tokenizer.
+    pub options: BTreeMap<String, String>,
+}
+
+/// The prefix of the ref names that are created by `CREATE TABLE ... CLONE`.
+pub const TABLE_CLONE_REF_PREFIX: &str = "__clone_";
+
+/// The pending ref of a clone that is neither renamed nor released within this period,
+/// e.g. the query creating the clone crashed, is expired and no longer protects its snapshot.
+pub const PENDING_CLONE_REF_TTL_IN_SECS: i64 = 24 * 60 * 60;
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableRefKind {
+    Tag = 0,
+    Branch = 1,
+    Clone = 2,
+}
+
+impl Display for TableRefKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TableRefKind::Tag => write!(f, "TAG"),
+            TableRefKind::Branch => write!(f, "BRANCH"),
+            TableRefKind::Clone => write!(f, "CLONE"),
+        }
+    }
+}
+
+/// A named reference to a snapshot of the table.
+///
+/// The files of the referenced snapshot are protected from purge and vacuum,
+/// until the reference is dropped.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRef {
+    pub kind: TableRefKind,
+    pub snapshot_location: String,
+    // the id of the table that is cloned from the snapshot, only set for clone refs.
+    pub cloned_table_id: Option<MetaId>,
+    pub created_on: DateTime<Utc>,
+}
+
+/// A row access policy attached to a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    // the columns passed to the args of the policy, in order.
+    pub columns: Vec<String>,
+}
+
+impl TableRef {
+    pub fn clone_ref_name(table_id: MetaId) -> String {
+        format!("{}{}", TABLE_CLONE_REF_PREFIX, table_id)
+    }
+
+    /// The ref that pins the snapshot of a clone while the clone is being created.
+    pub fn pending_clone_ref_name(query_id: &str) -> String {
+        format!("{}pending_{}", TABLE_CLONE_REF_PREFIX, query_id)
+    }
+
+    /// Returns true if this is the pending ref of a clone that has outlived its TTL.
+    pub fn is_expired_pending_clone(&self, now: DateTime<Utc>) -> bool {
+        self.kind == TableRefKind::Clone
+            && self.cloned_table_id.is_none()
+            && (now - self.created_on).num_seconds() >= PENDING_CLONE_REF_TTL_IN_SECS
+    }
 }
 
 impl TableMeta {
@@ -363,6 +463,9 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            indexes: BTreeMap::new(),
+            refs: BTreeMap::new(),
+            row_access_policy: None,
         }
     }
 }
@@ -698,6 +801,27 @@ pub struct SetTableColumnMaskPolicyReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy name and columns
+    Add(String, Vec<String>),
+    // prev policy name
+    Drop(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: String,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
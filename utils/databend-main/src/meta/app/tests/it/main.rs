@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod file_format;
+mod table_ref;
 mod user_grant;
 mod user_info;
 mod user_privilege;
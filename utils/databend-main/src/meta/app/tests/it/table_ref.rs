@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_exception::exception::Result;
+use databend_common_meta_app::schema::TableRef;
+use databend_common_meta_app::schema::TableRefKind;
+use databend_common_meta_app::schema::PENDING_CLONE_REF_TTL_IN_SECS;
+
+#[test]
+fn test_expired_pending_clone_ref() -> Result<()> {
+    let now = Utc::now();
+    let expired_on = now - Duration::seconds(PENDING_CLONE_REF_TTL_IN_SECS);
+    let pending = TableRef {
+        kind: TableRefKind::Clone,
+        snapshot_location: "1/2/_ss/s.mpk".to_string(),
+        cloned_table_id: None,
+        created_on: now,
+    };
+    assert!(!pending.is_expired_pending_clone(now));
+
+    let expired = TableRef {
+        created_on: expired_on,
+        ..pending.clone()
+    };
+    assert!(expired.is_expired_pending_clone(now));
+
+    // the refs of the created clones and the tags never expire.
+    let cloned = TableRef {
+        cloned_table_id: Some(3),
+        ..expired.clone()
+    };
+    assert!(!cloned.is_expired_pending_clone(now));
+    let tag = TableRef {
+        kind: TableRefKind::Tag,
+        ..expired
+    };
+    assert!(!tag.is_expired_pending_clone(now));
+
+    Ok(())
+}
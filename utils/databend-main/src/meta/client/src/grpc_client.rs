@@ -909,6 +909,7 @@ impl MetaGrpcClient {
             client
                 .export_v1(pb::ExportRequest {
                     chunk_size: export_request.chunk_size,
+                    since_log_index: None,
                 })
                 .await?
         } else {
@@ -108,6 +108,14 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 /// - 2024-03-01: since: TODO(update me when merged):
 ///   client: `MetaSpec` use `ttl`, remove `expire_at`, require 1.2.258
 ///
+/// - 2024-03-04: since: TODO(update me when merged):
+///   server: add `initial_flush` and `since_seq` to `WatchRequest`, `is_initialization` to `WatchResponse`;
+///           An older server ignores them and watches only the changes afterwards.
+///
+/// - 2024-03-05: since: TODO(update me when merged):
+///   server: add `since_log_index` to `ExportRequest` to export only the logs since then;
+///           An older server ignores it and exports all data.
+///
 /// Server feature set:
 /// ```yaml
 /// server_features:
@@ -0,0 +1,410 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Leases: keys that expire together, and are renewed or deleted together.
+//!
+//! A lease is built on the expiration of keys, thus it is available on every [`kvapi::KVApi`]:
+//!
+//! - A lease is stored as a key `__fd_leases/ids/<id>` that expires after the granted ttl.
+//! - A key attached to a lease is written with the same expiration time as the lease,
+//!   along with a marker `__fd_leases/keys/<id>/<key>`.
+//!   A key is no longer attached once it is rewritten with another expiration time.
+//! - A keep-alive rewrites the lease and the attached keys with a new expiration time in one transaction.
+//!   Thus it bumps the `seq` of the attached keys.
+//! - A revoke deletes the lease and the attached keys in one transaction.
+//!
+//! Attaching a key rewrites the lease record too,
+//! so that a concurrent keep-alive or revoke, which is conditioned on the lease `seq`, retries and sees it.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::txn_op_response::Response;
+use databend_common_meta_types::Change;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
+use databend_common_meta_types::TxnReply;
+use databend_common_meta_types::TxnRequest;
+use databend_common_meta_types::UpsertKV;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+
+use crate::kvapi;
+use crate::kvapi::UpsertKVReply;
+
+pub type LeaseId = u64;
+
+/// All of the keys in which leases are stored start with this prefix.
+///
+/// They are maintained by [`LeaseApi`] and should not be written directly.
+pub const LEASE_PREFIX: &str = "__fd_leases/";
+
+/// Its `seq` is used as the id of a new lease.
+const LEASE_ID_GEN: &str = "__fd_leases/id_gen";
+
+/// Max number of times to retry a lease update that conflicts with another one.
+const MAX_RETRY: usize = 8;
+
+fn lease_ids_prefix() -> String {
+    format!("{}ids/", LEASE_PREFIX)
+}
+
+fn lease_key(id: LeaseId) -> String {
+    format!("{}ids/{}", LEASE_PREFIX, id)
+}
+
+fn attachment_prefix(id: LeaseId) -> String {
+    format!("{}keys/{}/", LEASE_PREFIX, id)
+}
+
+/// Errors returned by [`LeaseApi`].
+#[derive(Debug, thiserror::Error)]
+pub enum LeaseError<E> {
+    #[error("lease {0} not found")]
+    NotFound(LeaseId),
+
+    #[error("lease {0} already exists")]
+    AlreadyExists(LeaseId),
+
+    #[error("lease ttl must be at least one second, but got {0:?}")]
+    InvalidTtl(Duration),
+
+    #[error("lease {0} is being updated concurrently, retry later")]
+    Conflict(LeaseId),
+
+    #[error("lease transaction failed: {0}")]
+    TxnFailed(String),
+
+    #[error(transparent)]
+    KVApi(#[from] E),
+}
+
+/// The state of a lease.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaseInfo {
+    pub id: LeaseId,
+
+    /// The `seq` of the lease record, it changes when the lease or the keys attached to it are updated.
+    pub seq: u64,
+
+    /// The ttl the lease is granted and renewed with.
+    pub granted_ttl: Duration,
+
+    /// When the lease and the keys attached to it expire, in milliseconds since 1970.
+    pub expire_at_ms: u64,
+}
+
+impl LeaseInfo {
+    fn new(id: LeaseId, lease: SeqV) -> Self {
+        let secs = std::str::from_utf8(&lease.data)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default();
+
+        Self {
+            id,
+            seq: lease.seq,
+            granted_ttl: Duration::from_secs(secs),
+            expire_at_ms: lease.eval_expire_at_ms(),
+        }
+    }
+
+    /// The remaining time to live at `now_ms`.
+    pub fn ttl_at(&self, now_ms: u64) -> Duration {
+        Duration::from_millis(self.expire_at_ms.saturating_sub(now_ms))
+    }
+
+    /// Build the transaction operations that put a key attached to this lease.
+    ///
+    /// The first operation puts the key;
+    /// the others record the attachment and rewrite the lease without changing its expiration time.
+    pub fn put_ops(&self, key: &str, value: Vec<u8>) -> [pb::TxnOp; 3] {
+        let expire_at = Some(self.expire_at_ms / 1000);
+        [
+            pb::TxnOp::put_with_expire(key, value, expire_at),
+            pb::TxnOp::put_with_expire(
+                format!("{}{}", attachment_prefix(self.id), key),
+                vec![],
+                expire_at,
+            ),
+            pb::TxnOp::put_with_expire(
+                lease_key(self.id),
+                self.granted_ttl.as_secs().to_string().into_bytes(),
+                expire_at,
+            ),
+        ]
+    }
+
+    /// Build a transaction condition that holds if the lease is not updated since it is read.
+    pub fn unchanged(&self) -> pb::TxnCondition {
+        pb::TxnCondition::eq_seq(lease_key(self.id), self.seq)
+    }
+}
+
+/// Run a transaction, treat a failed one as an error.
+async fn lease_txn<KV>(kv: &KV, txn: TxnRequest) -> Result<TxnReply, LeaseError<KV::Error>>
+where KV: kvapi::KVApi + ?Sized {
+    let reply = kv.transaction(txn).await?;
+
+    if !reply.error.is_empty() {
+        return Err(LeaseError::TxnFailed(reply.error));
+    }
+    Ok(reply)
+}
+
+/// Return the value of the `index`-th response, which is a `get` or a `put`.
+///
+/// For a `put` it is the previous value.
+fn txn_value(reply: &TxnReply, index: usize) -> Option<SeqV> {
+    let resp = reply.responses.get(index)?.response.as_ref()?;
+    let v = match resp {
+        Response::Get(g) => g.value.clone(),
+        Response::Put(p) => p.prev_value.clone(),
+        _ => None,
+    };
+    v.map(SeqV::from)
+}
+
+/// Returns the keys still attached to the lease, and all the attachment markers.
+///
+/// A key that is rewritten without the lease, or deleted, is no longer attached,
+/// although its marker may still be there.
+async fn attached_keys<KV>(
+    kv: &KV,
+    lease: &LeaseInfo,
+) -> Result<(Vec<(String, SeqV)>, Vec<String>), KV::Error>
+where
+    KV: kvapi::KVApi + ?Sized,
+{
+    let prefix = attachment_prefix(lease.id);
+    let markers = kv
+        .prefix_list_kv(&prefix)
+        .await?
+        .into_iter()
+        .map(|(k, _)| k)
+        .collect::<Vec<_>>();
+
+    let keys = markers
+        .iter()
+        .map(|m| m[prefix.len()..].to_string())
+        .collect::<Vec<_>>();
+
+    let values = kv.mget_kv(&keys).await?;
+
+    let attached = keys
+        .into_iter()
+        .zip(values)
+        .filter_map(|(k, v)| v.map(|v| (k, v)))
+        .filter(|(_, v)| v.get_expire_at_ms() == Some(lease.expire_at_ms))
+        .collect();
+
+    Ok((attached, markers))
+}
+
+/// API of leases, available on every [`kvapi::KVApi`].
+///
+/// See the [module level documentation](self) for how leases are stored.
+#[async_trait]
+pub trait LeaseApi: kvapi::KVApi {
+    /// Grant a lease that expires after `ttl` unless it is kept alive.
+    ///
+    /// `ttl` is rounded down to seconds. A new lease id is allocated if `id` is `None`.
+    async fn lease_grant(
+        &self,
+        id: Option<LeaseId>,
+        ttl: Duration,
+    ) -> Result<LeaseInfo, LeaseError<Self::Error>> {
+        let secs = ttl.as_secs();
+        if secs == 0 {
+            return Err(LeaseError::InvalidTtl(ttl));
+        }
+
+        let id = match id {
+            Some(id) => id,
+            None => {
+                let change = self.upsert_kv(UpsertKV::update(LEASE_ID_GEN, b"")).await?;
+                change.result.map(|x| x.seq).unwrap_or_default()
+            }
+        };
+
+        let txn = TxnRequest {
+            condition: vec![pb::TxnCondition::eq_seq(lease_key(id), 0)],
+            if_then: vec![
+                pb::TxnOp::put_with_ttl(
+                    lease_key(id),
+                    secs.to_string().into_bytes(),
+                    Some(secs * 1000),
+                ),
+                pb::TxnOp::get(lease_key(id)),
+            ],
+            else_then: vec![],
+        };
+
+        let reply = lease_txn(self, txn).await?;
+        if !reply.success {
+            return Err(LeaseError::AlreadyExists(id));
+        }
+
+        let lease = txn_value(&reply, 1).ok_or(LeaseError::NotFound(id))?;
+        Ok(LeaseInfo::new(id, lease))
+    }
+
+    /// Get a lease, or `None` if it does not exist or has expired.
+    async fn lease_get(&self, id: LeaseId) -> Result<Option<LeaseInfo>, Self::Error> {
+        let got = self.get_kv(&lease_key(id)).await?;
+        Ok(got.map(|v| LeaseInfo::new(id, v)))
+    }
+
+    /// List all the leases that have not expired.
+    async fn lease_list(&self) -> Result<Vec<LeaseInfo>, Self::Error> {
+        let prefix = lease_ids_prefix();
+        let leases = self
+            .prefix_list_kv(&prefix)
+            .await?
+            .into_iter()
+            .filter_map(|(k, v)| {
+                let id = k[prefix.len()..].parse().ok()?;
+                Some(LeaseInfo::new(id, v))
+            })
+            .collect();
+
+        Ok(leases)
+    }
+
+    /// Update or insert a key attached to a lease, the key expires along with the lease.
+    ///
+    /// Returns the value before and after the update.
+    async fn lease_upsert_kv(
+        &self,
+        id: LeaseId,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<UpsertKVReply, LeaseError<Self::Error>> {
+        for _ in 0..MAX_RETRY {
+            let lease = self.lease_get(id).await?.ok_or(LeaseError::NotFound(id))?;
+
+            let mut ops = Vec::from(lease.put_ops(key, value.clone()));
+            ops.push(pb::TxnOp::get(key));
+
+            let txn = TxnRequest {
+                condition: vec![lease.unchanged()],
+                if_then: ops,
+                else_then: vec![],
+            };
+
+            let reply = lease_txn(self, txn).await?;
+            if reply.success {
+                let prev = txn_value(&reply, 0);
+                let result = txn_value(&reply, reply.responses.len() - 1);
+                return Ok(Change::new(prev, result));
+            }
+        }
+        Err(LeaseError::Conflict(id))
+    }
+
+    /// Return the keys attached to a lease.
+    async fn lease_keys(
+        &self,
+        id: LeaseId,
+    ) -> Result<Vec<(String, SeqV)>, LeaseError<Self::Error>> {
+        let lease = self.lease_get(id).await?.ok_or(LeaseError::NotFound(id))?;
+        let (attached, _markers) = attached_keys(self, &lease).await?;
+        Ok(attached)
+    }
+
+    /// Extend the lease and the keys attached to it by its granted ttl.
+    ///
+    /// Markers of the keys that are no longer attached are removed.
+    async fn lease_keep_alive(&self, id: LeaseId) -> Result<LeaseInfo, LeaseError<Self::Error>> {
+        for _ in 0..MAX_RETRY {
+            let lease = self.lease_get(id).await?.ok_or(LeaseError::NotFound(id))?;
+            let (attached, markers) = attached_keys(self, &lease).await?;
+
+            let secs = lease.granted_ttl.as_secs();
+            let ttl_ms = Some(secs * 1000);
+            let prefix = attachment_prefix(id);
+
+            let mut condition = vec![lease.unchanged()];
+            let mut ops = vec![
+                pb::TxnOp::put_with_ttl(lease_key(id), secs.to_string().into_bytes(), ttl_ms),
+                pb::TxnOp::get(lease_key(id)),
+            ];
+
+            for (k, v) in attached.iter() {
+                condition.push(pb::TxnCondition::eq_seq(k, v.seq));
+                ops.push(pb::TxnOp::put_with_ttl(k, v.data.clone(), ttl_ms));
+            }
+            for m in markers {
+                if attached.iter().any(|(k, _)| m[prefix.len()..] == *k) {
+                    ops.push(pb::TxnOp::put_with_ttl(m, vec![], ttl_ms));
+                } else {
+                    ops.push(pb::TxnOp::delete(m));
+                }
+            }
+
+            let txn = TxnRequest {
+                condition,
+                if_then: ops,
+                else_then: vec![],
+            };
+
+            let reply = lease_txn(self, txn).await?;
+            if reply.success {
+                let lease = txn_value(&reply, 1).ok_or(LeaseError::NotFound(id))?;
+                return Ok(LeaseInfo::new(id, lease));
+            }
+        }
+        Err(LeaseError::Conflict(id))
+    }
+
+    /// Keep alive the lease every time its id is received from the input stream.
+    ///
+    /// The output stream yields one result for every received id, in order.
+    fn lease_keep_alive_stream<'a>(
+        &'a self,
+        ids: BoxStream<'a, LeaseId>,
+    ) -> BoxStream<'a, Result<LeaseInfo, LeaseError<Self::Error>>> {
+        ids.then(move |id| self.lease_keep_alive(id)).boxed()
+    }
+
+    /// Delete the lease and all the keys attached to it in one transaction.
+    async fn lease_revoke(&self, id: LeaseId) -> Result<(), LeaseError<Self::Error>> {
+        for _ in 0..MAX_RETRY {
+            let lease = self.lease_get(id).await?.ok_or(LeaseError::NotFound(id))?;
+            let (attached, markers) = attached_keys(self, &lease).await?;
+
+            let mut ops = vec![pb::TxnOp::delete(lease_key(id))];
+            ops.extend(markers.into_iter().map(pb::TxnOp::delete));
+            ops.extend(
+                attached
+                    .into_iter()
+                    .map(|(k, v)| pb::TxnOp::delete_exact(k, Some(v.seq))),
+            );
+
+            let txn = TxnRequest {
+                condition: vec![lease.unchanged()],
+                if_then: ops,
+                else_then: vec![],
+            };
+
+            if lease_txn(self, txn).await?.success {
+                return Ok(());
+            }
+        }
+        Err(LeaseError::Conflict(id))
+    }
+}
+
+impl<T: kvapi::KVApi + ?Sized> LeaseApi for T {}
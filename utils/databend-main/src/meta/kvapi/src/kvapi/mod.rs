@@ -18,6 +18,7 @@ mod item;
 mod key;
 mod key_builder;
 mod key_parser;
+mod lease;
 mod message;
 mod prefix;
 mod test_suite;
@@ -34,6 +35,11 @@ pub use key::Key;
 pub use key::KeyError;
 pub use key_builder::KeyBuilder;
 pub use key_parser::KeyParser;
+pub use lease::LeaseApi;
+pub use lease::LeaseError;
+pub use lease::LeaseId;
+pub use lease::LeaseInfo;
+pub use lease::LEASE_PREFIX;
 pub use message::GetKVReply;
 pub use message::GetKVReq;
 pub use message::ListKVReply;
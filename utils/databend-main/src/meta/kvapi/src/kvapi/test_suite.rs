@@ -37,12 +37,16 @@ use databend_common_meta_types::TxnPutResponse;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
 use databend_common_meta_types::With;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
 use log::debug;
 use log::info;
 use minitrace::full_name;
 use minitrace::func_name;
 
 use crate::kvapi;
+use crate::kvapi::LeaseApi;
+use crate::kvapi::LeaseError;
 use crate::kvapi::UpsertKVReq;
 
 pub struct TestSuite {}
@@ -73,6 +77,7 @@ impl kvapi::TestSuite {
             .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_lease(&builder.build().await).await?;
 
         // Run cross node test on every 2 adjacent nodes
         let mut i = 0;
@@ -1041,6 +1046,88 @@ impl kvapi::TestSuite {
 
 /// Test that write and read should be forwarded to leader
 impl kvapi::TestSuite {
+    #[minitrace::trace]
+    pub async fn kv_lease<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        // - Grant a lease and attach keys to it.
+        // - Keep alive extends the attached keys.
+        // - Revoke deletes the attached keys.
+        // - Attached keys expire along with the lease.
+
+        info!("--- {}", full_name!());
+
+        info!("--- grant");
+        let lease = kv.lease_grant(None, Duration::from_secs(5)).await?;
+        assert_eq!(Duration::from_secs(5), lease.granted_ttl);
+
+        let res = kv.lease_grant(Some(lease.id), Duration::from_secs(5)).await;
+        assert!(matches!(res, Err(LeaseError::AlreadyExists(id)) if id == lease.id));
+
+        let res = kv.lease_grant(None, Duration::from_millis(500)).await;
+        assert!(matches!(res, Err(LeaseError::InvalidTtl(_))));
+
+        info!("--- attach keys");
+        {
+            kv.lease_upsert_kv(lease.id, "lease_k1", b("v1")).await?;
+
+            let res = kv.lease_upsert_kv(lease.id, "lease_k2", b("v2")).await?;
+            assert_eq!(None, res.prev);
+            assert_eq!(Some(b("v2")), res.result.map(|x| x.data));
+
+            kv.upsert_kv(UpsertKVReq::update("lease_k3", b"v3")).await?;
+
+            let keys = kv.lease_keys(lease.id).await?;
+            let keys = keys.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+            assert_eq!(vec!["lease_k1".to_string(), "lease_k2".to_string()], keys);
+
+            let leases = kv.lease_list().await?;
+            let ids = leases.into_iter().map(|l| l.id).collect::<Vec<_>>();
+            assert_eq!(vec![lease.id], ids);
+        }
+
+        info!("--- keep alive extends the attached keys");
+        {
+            tokio::time::sleep(Duration::from_millis(2_000)).await;
+
+            let ids = futures_util::stream::iter([lease.id, lease.id]).boxed();
+            let kept = kv
+                .lease_keep_alive_stream(ids)
+                .try_collect::<Vec<_>>()
+                .await?;
+            assert_eq!(2, kept.len());
+            assert_eq!(Duration::from_secs(5), kept[1].granted_ttl);
+
+            // The lease would have expired without keep alive.
+            tokio::time::sleep(Duration::from_millis(3_100)).await;
+            assert!(kv.get_kv("lease_k1").await?.is_some(), "kept alive");
+            assert!(kv.lease_get(lease.id).await?.is_some(), "kept alive");
+        }
+
+        info!("--- revoke deletes the attached keys");
+        {
+            kv.lease_revoke(lease.id).await?;
+
+            assert!(kv.get_kv("lease_k1").await?.is_none());
+            assert!(kv.get_kv("lease_k2").await?.is_none());
+            assert!(kv.get_kv("lease_k3").await?.is_some(), "not attached");
+            assert!(kv.lease_get(lease.id).await?.is_none());
+
+            let res = kv.lease_keep_alive(lease.id).await;
+            assert!(matches!(res, Err(LeaseError::NotFound(_))));
+        }
+
+        info!("--- attached keys expire along with the lease");
+        {
+            let lease = kv.lease_grant(None, Duration::from_secs(1)).await?;
+            kv.lease_upsert_kv(lease.id, "lease_k4", b("v4")).await?;
+
+            tokio::time::sleep(Duration::from_millis(2_100)).await;
+            assert!(kv.get_kv("lease_k4").await?.is_none());
+            assert!(kv.lease_get(lease.id).await?.is_none());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     pub async fn kv_write_read_across_nodes<KV: kvapi::KVApi>(
         &self,
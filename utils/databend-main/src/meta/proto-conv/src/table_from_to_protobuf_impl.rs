@@ -15,6 +15,7 @@
 //! This mod is the key point about compatibility.
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
@@ -24,6 +25,7 @@ use databend_common_expression as ex;
 use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -218,6 +220,20 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+            refs: p
+                .refs
+                .into_iter()
+                .map(|(name, table_ref)| Ok((name, mt::TableRef::from_pb(table_ref)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+            row_access_policy: p
+                .row_access_policy
+                .map(mt::TableRowAccessPolicy::from_pb)
+                .transpose()?,
         };
         Ok(v)
     }
@@ -254,6 +270,111 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
+            refs: self
+                .refs
+                .iter()
+                .map(|(name, table_ref)| Ok((name.clone(), table_ref.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|policy| policy.to_pb())
+                .transpose()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            column_ids: p.column_ids,
+            sync_creation: p.sync_creation,
+            version: p.version,
+            options: p.options,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+            sync_creation: self.sync_creation,
+            version: self.version.clone(),
+            options: self.options.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRef {
+    type PB = pb::TableRef;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRef) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            kind: FromPrimitive::from_i32(p.kind).ok_or_else(|| Incompatible {
+                reason: format!("invalid table ref kind: {}", p.kind),
+            })?,
+            snapshot_location: p.snapshot_location,
+            cloned_table_id: p.cloned_table_id,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRef, Incompatible> {
+        let p = pb::TableRef {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            kind: self.kind as i32,
+            snapshot_location: self.snapshot_location.clone(),
+            cloned_table_id: self.cloned_table_id,
+            created_on: self.created_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
         };
         Ok(p)
     }
@@ -274,6 +395,11 @@ impl FromToProto for mt::TableStatistics {
             index_data_bytes: p.index_data_bytes,
             number_of_segments: p.number_of_segments,
             number_of_blocks: p.number_of_blocks,
+            rows_modified_since_analyze: p.rows_modified_since_analyze,
+            analyzed_on: match p.analyzed_on {
+                Some(analyzed_on) => Some(DateTime::<Utc>::from_pb(analyzed_on)?),
+                None => None,
+            },
         };
 
         Ok(v)
@@ -289,6 +415,11 @@ impl FromToProto for mt::TableStatistics {
             index_data_bytes: self.index_data_bytes,
             number_of_segments: self.number_of_segments,
             number_of_blocks: self.number_of_blocks,
+            rows_modified_since_analyze: self.rows_modified_since_analyze,
+            analyzed_on: match self.analyzed_on {
+                Some(analyzed_on) => Some(analyzed_on.to_pb()?),
+                None => None,
+            },
         };
         Ok(p)
     }
@@ -47,6 +47,12 @@ impl FromToProto for mt::principal::AuthInfo {
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => {
                 Ok(mt::principal::AuthInfo::JWT)
             }
+            Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})) => {
+                Ok(mt::principal::AuthInfo::Ldap)
+            }
+            Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {})) => {
+                Ok(mt::principal::AuthInfo::KeyPair)
+            }
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -68,6 +74,12 @@ impl FromToProto for mt::principal::AuthInfo {
                 Some(pb::auth_info::Info::None(pb::auth_info::None {}))
             }
             mt::principal::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::principal::AuthInfo::Ldap => {
+                Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {}))
+            }
+            mt::principal::AuthInfo::KeyPair => {
+                Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {}))
+            }
             mt::principal::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -100,7 +112,11 @@ impl FromToProto for mt::principal::UserOption {
             .with_flags(flags)
             .with_default_role(p.default_role)
             .with_network_policy(p.network_policy)
-            .with_password_policy(p.password_policy))
+            .with_password_policy(p.password_policy)
+            .with_workload_group(p.workload_group)
+            .with_max_query_memory_usage(p.max_query_memory_usage)
+            .with_public_key(p.public_key)
+            .with_public_key_2(p.public_key_2))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -111,6 +127,10 @@ impl FromToProto for mt::principal::UserOption {
             default_role: self.default_role().cloned(),
             network_policy: self.network_policy().cloned(),
             password_policy: self.password_policy().cloned(),
+            workload_group: self.workload_group().cloned(),
+            max_query_memory_usage: self.max_query_memory_usage(),
+            public_key: self.public_key().cloned(),
+            public_key_2: self.public_key_2().cloned(),
         })
     }
 }
@@ -172,6 +192,16 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::TableById(catalog, db, table)),
+            Some(pb::grant_object::Object::Tablecolumnbyid(
+                pb::grant_object::GrantTableColumnIdObject {
+                    catalog,
+                    db,
+                    table,
+                    column,
+                },
+            )) => Ok(mt::principal::GrantObject::TableColumnById(
+                catalog, db, table, column,
+            )),
             Some(pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf })) => {
                 Ok(mt::principal::GrantObject::UDF(udf))
             }
@@ -215,6 +245,16 @@ impl FromToProto for mt::principal::GrantObject {
                     table: *table,
                 }),
             ),
+            mt::principal::GrantObject::TableColumnById(catalog, db, table, column) => {
+                Some(pb::grant_object::Object::Tablecolumnbyid(
+                    pb::grant_object::GrantTableColumnIdObject {
+                        catalog: catalog.clone(),
+                        db: *db,
+                        table: *table,
+                        column: *column,
+                    },
+                ))
+            }
             mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
                 pb::grant_object::GrantUdfObject { udf: udf.clone() },
             )),
@@ -505,3 +545,47 @@ impl FromToProto for mt::principal::PasswordPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name.clone(),
+            max_concurrency: p.max_concurrency,
+            max_memory_usage: p.max_memory_usage,
+            cpu_share: p.cpu_share,
+            queue_timeout_secs: p.queue_timeout_secs,
+            roles: p.roles.clone(),
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            max_concurrency: self.max_concurrency,
+            max_memory_usage: self.max_memory_usage,
+            cpu_share: self.cpu_share,
+            queue_timeout_secs: self.queue_timeout_secs,
+            roles: self.roles.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
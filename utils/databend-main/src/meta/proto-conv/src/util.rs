@@ -110,7 +110,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (78, "2024-01-29: Refactor: GrantEntry::UserPrivilegeType and ShareGrantEntry::ShareGrantObjectPrivilege use from_bits_truncate deserialize", ),
     (79, "2024-01-31: Add: udf.proto/UserDefinedFunction add created_on field", ),
     (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type"),
-    (81, "2024-03-94: Add: Add: udf.udf_script")
+    (81, "2024-03-94: Add: Add: udf.udf_script"),
+    (82, "2024-03-18: Add: user.proto/WorkloadGroup and UserOption::workload_group", ),
+    (83, "2024-03-20: Add: user.proto/UserOption::max_query_memory_usage", ),
+    (84, "2024-03-22: Add: table.proto/TableStatistics::rows_modified_since_analyze and analyzed_on", ),
+    (85, "2024-03-25: Add: table.proto/TableMeta::indexes and TableIndex", ),
+    (86, "2024-03-27: Add: table.proto/TableMeta::refs and TableRef", ),
+    (87, "2024-03-29: Add: row_access_policy.proto/RowAccessPolicyMeta and table.proto/TableMeta::row_access_policy", ),
+    (88, "2024-04-01: Add: user.proto/GrantTableColumnIdObject", ),
+    (89, "2024-04-03: Add: user.proto/AuthInfo::Ldap", ),
+    (90, "2024-04-05: Add: user.proto/AuthInfo::KeyPair, UserOption::public_key and public_key_2", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
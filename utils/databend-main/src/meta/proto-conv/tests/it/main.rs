@@ -84,3 +84,12 @@ mod v077_s3_remove_allow_anonymous;
 mod v078_grantentry;
 mod v079_udf_created_on;
 mod v081_udf_script;
+mod v082_workload_group;
+mod v083_user_option_max_query_memory_usage;
+mod v084_table_statistics_analyzed;
+mod v085_table_index;
+mod v086_table_ref;
+mod v087_row_access_policy;
+mod v088_user_grant_column;
+mod v089_user_auth_ldap;
+mod v090_user_key_pair;
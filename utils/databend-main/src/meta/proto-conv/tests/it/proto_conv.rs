@@ -213,6 +213,9 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {},
+        refs: btreemap! {},
+        row_access_policy: None,
     }
 }
 
@@ -306,6 +309,8 @@ fn new_table_statistics() -> databend_common_meta_app::schema::TableStatistics {
         index_data_bytes: 20,
         number_of_segments: Some(1),
         number_of_blocks: Some(2),
+        rows_modified_since_analyze: None,
+        analyzed_on: None,
     }
 }
 
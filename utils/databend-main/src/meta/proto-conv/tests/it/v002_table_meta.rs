@@ -143,6 +143,9 @@ fn test_decode_v2_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        indexes: btreemap! {},
+        refs: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
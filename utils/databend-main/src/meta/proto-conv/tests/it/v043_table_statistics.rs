@@ -38,6 +38,8 @@ fn test_decode_v43_table_statistics() -> anyhow::Result<()> {
         index_data_bytes: 20,
         number_of_segments: Some(1),
         number_of_blocks: Some(2),
+        rows_modified_since_analyze: None,
+        analyzed_on: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
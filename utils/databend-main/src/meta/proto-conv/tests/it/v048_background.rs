@@ -72,6 +72,8 @@ fn test_decode_v48_background_task_case_2() -> anyhow::Result<()> {
                 index_data_bytes: 40,
                 number_of_segments: Some(10),
                 number_of_blocks: Some(11),
+                rows_modified_since_analyze: None,
+                analyzed_on: None,
             }),
             after_compaction_stats: Some(TableStatistics {
                 number_of_rows: 1000,
@@ -80,6 +82,8 @@ fn test_decode_v48_background_task_case_2() -> anyhow::Result<()> {
                 index_data_bytes: 4,
                 number_of_segments: Some(5),
                 number_of_blocks: Some(6),
+                rows_modified_since_analyze: None,
+                analyzed_on: None,
             }),
             total_compaction_time: Some(Duration::from_secs(100)),
         }),
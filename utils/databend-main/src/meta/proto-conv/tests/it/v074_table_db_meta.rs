@@ -92,6 +92,9 @@ fn test_decode_v74_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {},
+        refs: btreemap! {},
+        row_access_policy: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v74.as_slice(), 74, want())?;
@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::UserOptionFlag;
+use databend_common_meta_app::principal::WorkloadGroup;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v82_workload_group() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 101, 116, 108, 16, 4, 24, 128, 128, 128, 128, 32, 32, 50, 40, 60, 50, 8, 101, 116,
+        108, 95, 114, 111, 108, 101, 50, 6, 108, 111, 97, 100, 101, 114, 58, 10, 98, 97, 116, 99,
+        104, 32, 106, 111, 98, 115, 66, 23, 50, 48, 50, 52, 45, 48, 51, 45, 49, 56, 32, 49, 48, 58,
+        48, 48, 58, 48, 48, 32, 85, 84, 67, 74, 23, 50, 48, 50, 52, 45, 48, 51, 45, 49, 56, 32, 49,
+        49, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 82, 168, 6, 24,
+    ];
+
+    let want = || WorkloadGroup {
+        name: "etl".to_string(),
+        max_concurrency: 4,
+        max_memory_usage: 8 * 1024 * 1024 * 1024,
+        cpu_share: 50,
+        queue_timeout_secs: 60,
+        roles: vec!["etl_role".to_string(), "loader".to_string()],
+        comment: "batch jobs".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 3, 18, 10, 0, 0).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2024, 3, 18, 11, 0, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 82, want())
+}
+
+#[test]
+fn test_decode_v82_user_option_workload_group() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 42, 3, 101, 116, 108, 160, 6, 82, 168, 6, 24,
+    ];
+
+    let want = || {
+        UserOption::default()
+            .with_set_flag(UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".to_string()))
+            .with_workload_group(Some("etl".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 82, want())
+}
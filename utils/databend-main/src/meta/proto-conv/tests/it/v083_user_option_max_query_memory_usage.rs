@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::UserOptionFlag;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+#[test]
+fn test_decode_v83_user_option_max_query_memory_usage() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 48, 128, 128, 128, 128, 8, 160, 6, 83, 168, 6, 24,
+    ];
+
+    let want = || {
+        UserOption::default()
+            .with_set_flag(UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".to_string()))
+            .with_max_query_memory_usage(Some(2 * 1024 * 1024 * 1024))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 83, want())
+}
@@ -0,0 +1,171 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_expression as ce;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ComputedExpr;
+use databend_common_meta_app::schema as mt;
+use maplit::btreemap;
+use maplit::btreeset;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v87_table_meta() -> anyhow::Result<()> {
+    let table_meta_v87 = vec![
+        10, 223, 1, 10, 51, 10, 8, 110, 117, 108, 108, 97, 98, 108, 101, 18, 5, 97, 32, 43, 32, 51,
+        26, 26, 178, 2, 17, 154, 2, 8, 42, 0, 160, 6, 87, 168, 6, 24, 160, 6, 87, 168, 6, 24, 160,
+        6, 87, 168, 6, 24, 160, 6, 87, 168, 6, 24, 10, 27, 10, 6, 115, 116, 114, 105, 110, 103, 26,
+        9, 146, 2, 0, 160, 6, 87, 168, 6, 24, 32, 1, 160, 6, 87, 168, 6, 24, 10, 62, 10, 14, 118,
+        105, 114, 116, 117, 97, 108, 95, 115, 116, 114, 105, 110, 103, 26, 9, 146, 2, 0, 160, 6,
+        87, 168, 6, 24, 32, 2, 42, 25, 10, 17, 116, 111, 95, 98, 97, 115, 101, 54, 52, 40, 115,
+        116, 114, 105, 110, 103, 41, 160, 6, 87, 168, 6, 24, 160, 6, 87, 168, 6, 24, 10, 59, 10,
+        13, 115, 116, 111, 114, 101, 100, 95, 115, 116, 114, 105, 110, 103, 26, 9, 146, 2, 0, 160,
+        6, 87, 168, 6, 24, 32, 3, 42, 23, 18, 15, 114, 101, 118, 101, 114, 115, 101, 40, 115, 116,
+        114, 105, 110, 103, 41, 160, 6, 87, 168, 6, 24, 160, 6, 87, 168, 6, 24, 18, 6, 10, 1, 97,
+        18, 1, 98, 24, 4, 160, 6, 87, 168, 6, 24, 34, 10, 40, 97, 32, 43, 32, 50, 44, 32, 98, 41,
+        42, 10, 10, 3, 120, 121, 122, 18, 3, 102, 111, 111, 50, 2, 52, 52, 58, 10, 10, 3, 97, 98,
+        99, 18, 3, 100, 101, 102, 64, 0, 74, 10, 40, 97, 32, 43, 32, 50, 44, 32, 98, 41, 82, 7,
+        100, 101, 102, 97, 117, 108, 116, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32,
+        49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45,
+        50, 57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 178, 1, 13, 116, 97, 98, 108,
+        101, 95, 99, 111, 109, 109, 101, 110, 116, 186, 1, 6, 160, 6, 87, 168, 6, 24, 202, 1, 1,
+        99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1,
+        99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1,
+        99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1, 99, 202, 1, 1,
+        99, 202, 1, 1, 99, 202, 1, 1, 99, 226, 1, 1, 1, 234, 1, 6, 10, 1, 97, 18, 1, 98, 250, 1,
+        52, 10, 4, 105, 100, 120, 49, 18, 44, 10, 4, 105, 100, 120, 49, 18, 2, 1, 2, 24, 1, 34, 2,
+        118, 49, 42, 20, 10, 9, 116, 111, 107, 101, 110, 105, 122, 101, 114, 18, 7, 101, 110, 103,
+        108, 105, 115, 104, 160, 6, 87, 168, 6, 24, 130, 2, 67, 10, 9, 95, 95, 99, 108, 111, 110,
+        101, 95, 55, 18, 54, 8, 2, 18, 17, 49, 47, 50, 47, 95, 115, 115, 47, 48, 49, 95, 118, 52,
+        46, 109, 112, 107, 24, 7, 34, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 87, 168, 6, 24, 130, 2, 58, 10, 4, 116, 97,
+        103, 49, 18, 50, 18, 17, 49, 47, 50, 47, 95, 115, 115, 47, 48, 50, 95, 118, 52, 46, 109,
+        112, 107, 34, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 160, 6, 87, 168, 6, 24, 138, 2, 26, 10, 10, 114, 97, 112, 95, 114, 101,
+        103, 105, 111, 110, 18, 6, 115, 116, 114, 105, 110, 103, 160, 6, 87, 168, 6, 24, 160, 6,
+        87, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ce::TableSchema::new_from(
+            vec![
+                ce::TableField::new(
+                    "nullable",
+                    ce::TableDataType::Nullable(Box::new(ce::TableDataType::Number(
+                        NumberDataType::Int8,
+                    ))),
+                )
+                .with_default_expr(Some("a + 3".to_string())),
+                ce::TableField::new("string", ce::TableDataType::String),
+                ce::TableField::new("virtual_string", ce::TableDataType::String)
+                    .with_computed_expr(Some(ComputedExpr::Virtual(
+                        "to_base64(string)".to_string(),
+                    ))),
+                ce::TableField::new("stored_string", ce::TableDataType::String)
+                    .with_computed_expr(Some(ComputedExpr::Stored("reverse(string)".to_string()))),
+            ],
+            btreemap! {s("a") => s("b")},
+        )),
+        catalog: "default".to_string(),
+        engine: "44".to_string(),
+        storage_params: None,
+        part_prefix: "".to_string(),
+        engine_options: btreemap! {s("abc") => s("def")},
+        options: btreemap! {s("xyz") => s("foo")},
+        default_cluster_key: Some("(a + 2, b)".to_string()),
+        cluster_keys: vec!["(a + 2, b)".to_string()],
+        default_cluster_key_id: Some(0),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+        comment: s("table_comment"),
+        field_comments: vec!["c".to_string(); 21],
+        drop_on: None,
+        statistics: Default::default(),
+        shared_by: btreeset! {1},
+        column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {s("idx1") => mt::TableIndex {
+            name: s("idx1"),
+            column_ids: vec![1, 2],
+            sync_creation: true,
+            version: s("v1"),
+            options: btreemap! {s("tokenizer") => s("english")},
+        }},
+        refs: btreemap! {
+            s("__clone_7") => mt::TableRef {
+                kind: mt::TableRefKind::Clone,
+                snapshot_location: s("1/2/_ss/01_v4.mpk"),
+                cloned_table_id: Some(7),
+                created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+            },
+            s("tag1") => mt::TableRef {
+                kind: mt::TableRefKind::Tag,
+                snapshot_location: s("1/2/_ss/02_v4.mpk"),
+                cloned_table_id: None,
+                created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+            },
+        },
+        row_access_policy: Some(mt::TableRowAccessPolicy {
+            policy: s("rap_region"),
+            columns: vec![s("string")],
+        }),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_meta_v87.as_slice(), 87, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v87_row_access_policy() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 16, 10, 6, 114, 101, 103, 105, 111, 110, 18, 6, 83, 84, 82, 73, 78, 71, 18, 41, 99,
+        117, 114, 114, 101, 110, 116, 95, 114, 111, 108, 101, 40, 41, 32, 61, 32, 39, 97, 100, 109,
+        105, 110, 39, 32, 79, 82, 32, 114, 101, 103, 105, 111, 110, 32, 61, 32, 39, 117, 115, 39,
+        26, 12, 115, 111, 109, 101, 32, 99, 111, 109, 109, 101, 110, 116, 34, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6,
+        87, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::row_access_policy::RowAccessPolicyMeta {
+        args: vec![(s("region"), s("STRING"))],
+        body: s("current_role() = 'admin' OR region = 'us'"),
+        comment: Some(s("some comment")),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 87, want())
+}
+
+fn s(ss: impl ToString) -> String {
+    ss.to_string()
+}
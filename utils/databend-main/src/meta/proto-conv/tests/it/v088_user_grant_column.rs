@@ -0,0 +1,73 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The user_info_v88 bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v88_user() -> anyhow::Result<()> {
+    let user_info_v88 = vec![
+        10, 2, 117, 49, 18, 1, 37, 26, 8, 10, 0, 160, 6, 88, 168, 6, 24, 34, 74, 10, 33, 10, 23,
+        66, 15, 10, 7, 100, 101, 102, 97, 117, 108, 116, 16, 1, 24, 10, 32, 2, 160, 6, 88, 168, 6,
+        24, 16, 36, 160, 6, 88, 168, 6, 24, 10, 31, 10, 21, 58, 13, 10, 7, 100, 101, 102, 97, 117,
+        108, 116, 16, 1, 24, 10, 160, 6, 88, 168, 6, 24, 16, 8, 160, 6, 88, 168, 6, 24, 160, 6, 88,
+        168, 6, 24, 42, 6, 160, 6, 88, 168, 6, 24, 50, 6, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168,
+        6, 24,
+    ];
+    let want = || mt::principal::UserInfo {
+        name: "u1".to_string(),
+        hostname: "%".to_string(),
+        auth_info: Default::default(),
+        grants: UserGrantSet::new(
+            vec![
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::TableColumnById("default".to_string(), 1, 10, 2),
+                    make_bitflags!(UserPrivilegeType::{Select | Update}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::TableById("default".to_string(), 1, 10),
+                    make_bitflags!(UserPrivilegeType::{Insert}),
+                ),
+            ],
+            HashSet::new(),
+        ),
+        quota: Default::default(),
+        option: Default::default(),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_info_v88.as_slice(), 88, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app as mt;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The user_info_v89 bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v89_user() -> anyhow::Result<()> {
+    let user_info_v89 = vec![
+        10, 2, 117, 49, 18, 1, 37, 26, 8, 34, 0, 160, 6, 89, 168, 6, 24, 34, 6, 160, 6, 89, 168, 6,
+        24, 42, 6, 160, 6, 89, 168, 6, 24, 50, 6, 160, 6, 89, 168, 6, 24, 160, 6, 89, 168, 6, 24,
+    ];
+    let want = || mt::principal::UserInfo {
+        name: "u1".to_string(),
+        hostname: "%".to_string(),
+        auth_info: mt::principal::AuthInfo::Ldap,
+        grants: Default::default(),
+        quota: Default::default(),
+        option: Default::default(),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_info_v89.as_slice(), 89, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::UserOption;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The user_info_v90 bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v90_user() -> anyhow::Result<()> {
+    let user_info_v90 = vec![
+        10, 2, 117, 49, 18, 1, 37, 26, 8, 42, 0, 160, 6, 90, 168, 6, 24, 34, 6, 160, 6, 90, 168, 6,
+        24, 42, 6, 160, 6, 90, 168, 6, 24, 50, 16, 58, 3, 112, 107, 49, 66, 3, 112, 107, 50, 160,
+        6, 90, 168, 6, 24, 160, 6, 90, 168, 6, 24,
+    ];
+    let want = || mt::principal::UserInfo {
+        name: "u1".to_string(),
+        hostname: "%".to_string(),
+        auth_info: mt::principal::AuthInfo::KeyPair,
+        grants: Default::default(),
+        quota: Default::default(),
+        option: UserOption::default()
+            .with_public_key(Some("pk1".to_string()))
+            .with_public_key_2(Some("pk2".to_string())),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_info_v90.as_slice(), 90, want())?;
+
+    Ok(())
+}
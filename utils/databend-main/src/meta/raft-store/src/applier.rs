@@ -54,6 +54,7 @@ use log::error;
 use log::info;
 use num::FromPrimitive;
 
+use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::SMV002;
 
 /// A helper that applies raft log `Entry` to the state machine.
@@ -63,8 +64,8 @@ pub struct Applier<'a> {
     /// The context of the current applying log.
     cmd_ctx: CmdContext,
 
-    /// The changes has been made by the applying one log entry
-    changes: Vec<Change<Vec<u8>, String>>,
+    /// The changes has been made by the applying one log entry, with the seq each is made at.
+    changes: Vec<(u64, Change<Vec<u8>, String>)>,
 }
 
 impl<'a> Applier<'a> {
@@ -88,6 +89,9 @@ impl<'a> Applier<'a> {
 
         self.cmd_ctx = CmdContext::from_millis(log_time_ms);
 
+        let curr_seq = self.sm.sys_data_ref().curr_seq();
+        self.sm.change_history.start(curr_seq);
+
         self.clean_expired_kvs(log_time_ms).await?;
 
         *self.sm.sys_data_mut().last_applied_mut() = Some(*log_id);
@@ -113,9 +117,14 @@ impl<'a> Applier<'a> {
             }
         };
 
-        // Send queued change events to subscriber
-        if let Some(subscriber) = &self.sm.subscriber {
-            for event in self.changes.drain(..) {
+        // Record queued change events and send them to subscriber.
+        //
+        // Both are done before the state machine is unlocked,
+        // so that a watcher reading the history under the lock does not miss or repeat a change.
+        for (seq, event) in self.changes.drain(..) {
+            self.sm.change_history.push(seq, event.clone());
+
+            if let Some(subscriber) = &self.sm.subscriber {
                 subscriber.kv_changed(event);
             }
         }
@@ -510,8 +519,14 @@ impl<'a> Applier<'a> {
             return;
         }
 
+        // A delete does not increase the seq, it is made at the current seq.
+        let seq = match &result {
+            Some(seq_v) => seq_v.seq,
+            None => self.sm.sys_data_ref().curr_seq(),
+        };
+
         self.changes
-            .push(Change::new(prev, result).with_id(key.to_string()))
+            .push((seq, Change::new(prev, result).with_id(key.to_string())))
     }
 
     /// Retrieve the proposing time from a raft-log.
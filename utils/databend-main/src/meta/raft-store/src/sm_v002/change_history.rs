@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_meta_types::Change;
+
+/// The changes requested by a watcher are no longer retained.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("changes since seq {since_seq} are compacted, the oldest retained seq is {first_seq}")]
+pub struct ChangeHistoryCompacted {
+    pub since_seq: u64,
+    pub first_seq: u64,
+}
+
+/// The most recent kv changes applied to the state machine, for a watcher to resume from.
+///
+/// Every change is recorded with the seq it is made at:
+/// the seq of the new value, or the current seq for a delete, which does not increase the seq.
+///
+/// The history is kept only in memory:
+/// it starts empty when the state machine is built or replaced,
+/// and the oldest changes are dropped when there are more than `capacity` of them.
+#[derive(Debug)]
+pub struct ChangeHistory {
+    capacity: usize,
+
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    /// All of the changes at or after this seq are retained.
+    ///
+    /// `None` if no log has been applied since the history started.
+    complete_since: Option<u64>,
+}
+
+impl Default for ChangeHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl ChangeHistory {
+    pub const DEFAULT_CAPACITY: usize = 16 * 1024;
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            changes: VecDeque::new(),
+            complete_since: None,
+        }
+    }
+
+    /// Start recording changes made after `curr_seq`, if it is not started yet.
+    ///
+    /// Changes at `curr_seq` may have been made before the history started,
+    /// thus the history is complete only since the next seq.
+    pub fn start(&mut self, curr_seq: u64) {
+        self.complete_since.get_or_insert(curr_seq + 1);
+    }
+
+    /// Append a change made at `seq`, and drop the oldest one if it exceeds the capacity.
+    pub fn push(&mut self, seq: u64, change: Change<Vec<u8>, String>) {
+        self.changes.push_back((seq, change));
+
+        if self.changes.len() > self.capacity {
+            if let Some((dropped, _)) = self.changes.pop_front() {
+                // Other changes at the dropped seq may still be retained,
+                // but it is no longer complete.
+                let since = self.complete_since.get_or_insert(0);
+                *since = (*since).max(dropped + 1);
+            }
+        }
+    }
+
+    /// Drop all recorded changes, and start again with the next applied log.
+    pub fn reset(&mut self) {
+        self.changes.clear();
+        self.complete_since = None;
+    }
+
+    /// Return the retained changes made at or after `since_seq`, in the order they are applied.
+    ///
+    /// `curr_seq` is the current seq of the state machine.
+    /// It returns an error if some of these changes are no longer retained.
+    pub fn changes_since(
+        &self,
+        since_seq: u64,
+        curr_seq: u64,
+    ) -> Result<impl Iterator<Item = &Change<Vec<u8>, String>>, ChangeHistoryCompacted> {
+        let first_seq = self.complete_since.unwrap_or(curr_seq + 1);
+
+        // No change is made at seq 0,
+        // and nothing has been changed since `since_seq` if it is greater than `curr_seq`.
+        if since_seq.max(1) < first_seq && since_seq <= curr_seq {
+            return Err(ChangeHistoryCompacted {
+                since_seq,
+                first_seq,
+            });
+        }
+
+        let start = self.changes.partition_point(|(seq, _)| *seq < since_seq);
+        Ok(self.changes.range(start..).map(|(_, change)| change))
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_types::new_log_id;
+use databend_common_meta_types::Change;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::Entry;
+use databend_common_meta_types::EntryPayload;
+use databend_common_meta_types::LogEntry;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::UpsertKV;
+use pretty_assertions::assert_eq;
+
+use crate::sm_v002::leveled_store::leveled_map::LeveledMap;
+use crate::sm_v002::ChangeHistory;
+use crate::sm_v002::ChangeHistoryCompacted;
+use crate::sm_v002::SMV002;
+
+#[test]
+fn test_change_history_compact() -> anyhow::Result<()> {
+    let mut h = ChangeHistory::new(3);
+    h.start(0);
+
+    for seq in 1..=3 {
+        h.push(seq, change("a", seq));
+    }
+
+    let got = h.changes_since(0, 3)?.cloned().collect::<Vec<_>>();
+    assert_eq!(got, vec![change("a", 1), change("a", 2), change("a", 3)]);
+
+    let got = h.changes_since(3, 3)?.cloned().collect::<Vec<_>>();
+    assert_eq!(got, vec![change("a", 3)]);
+
+    let got = h.changes_since(4, 3)?.count();
+    assert_eq!(got, 0);
+
+    // Drop the change at seq 1
+    h.push(4, change("a", 4));
+
+    let got = h.changes_since(1, 4).err();
+    assert_eq!(
+        got,
+        Some(ChangeHistoryCompacted {
+            since_seq: 1,
+            first_seq: 2
+        })
+    );
+
+    let got = h.changes_since(2, 4)?.cloned().collect::<Vec<_>>();
+    assert_eq!(got, vec![change("a", 2), change("a", 3), change("a", 4)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_change_history_not_started() -> anyhow::Result<()> {
+    let h = ChangeHistory::new(3);
+
+    // Changes before the history started are unknown.
+    let got = h.changes_since(5, 5).err();
+    assert_eq!(
+        got,
+        Some(ChangeHistoryCompacted {
+            since_seq: 5,
+            first_seq: 6
+        })
+    );
+
+    // Nothing changed after the current seq.
+    let got = h.changes_since(6, 5)?.count();
+    assert_eq!(got, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sm_records_change_history() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    sm.apply_entries([
+        ent(1, UpsertKV::update("a", b"a0")),
+        ent(2, UpsertKV::update("b", b"b0")),
+        ent(3, UpsertKV::delete("a")),
+        ent(4, UpsertKV::update("a", b"a1")),
+    ])
+    .await?;
+
+    let got = sm.changes_since(0)?.cloned().collect::<Vec<_>>();
+    assert_eq!(got, vec![
+        Change::new(None, Some(SeqV::new(1, b("a0")))).with_id(s("a")),
+        Change::new(None, Some(SeqV::new(2, b("b0")))).with_id(s("b")),
+        Change::new(Some(SeqV::new(1, b("a0"))), None).with_id(s("a")),
+        Change::new(None, Some(SeqV::new(3, b("a1")))).with_id(s("a")),
+    ]);
+
+    // The delete is made at seq 2, when the current seq is 2.
+    let got = sm.changes_since(2)?.count();
+    assert_eq!(got, 3);
+
+    // Replacing the data drops the history.
+    let mut levels = LeveledMap::default();
+    *levels.writable_mut().sys_data_mut() = sm.sys_data_ref().clone();
+    sm.replace(levels);
+
+    let got = sm.changes_since(2).err();
+    assert_eq!(
+        got,
+        Some(ChangeHistoryCompacted {
+            since_seq: 2,
+            first_seq: 4
+        })
+    );
+
+    Ok(())
+}
+
+fn ent(index: u64, upsert: UpsertKV) -> Entry {
+    Entry {
+        log_id: new_log_id(1, 0, index),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::UpsertKV(upsert),
+        }),
+    }
+}
+
+fn change(key: &str, seq: u64) -> Change<Vec<u8>, String> {
+    Change::new(None, Some(SeqV::new(seq, b(seq)))).with_id(s(key))
+}
+
+fn s(x: impl ToString) -> String {
+    x.to_string()
+}
+
+fn b(x: impl ToString) -> Vec<u8> {
+    x.to_string().as_bytes().to_vec()
+}
@@ -50,7 +50,7 @@ pub struct LeveledMap {
 }
 
 impl LeveledMap {
-    pub(crate) fn new(writable: Level) -> Self {
+    pub fn new(writable: Level) -> Self {
         Self {
             writable,
             frozen: Default::default(),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod change_history;
 pub mod leveled_store;
 pub(in crate::sm_v002) mod marked;
 #[allow(clippy::module_inception)]
@@ -22,11 +23,15 @@ mod writer_v002;
 
 mod importer;
 
+#[cfg(test)]
+mod change_history_test;
 #[cfg(test)]
 mod sm_v002_test;
 #[cfg(test)]
 mod snapshot_view_v002_test;
 
+pub use change_history::ChangeHistory;
+pub use change_history::ChangeHistoryCompacted;
 pub use importer::Importer;
 pub use sm_v002::SMV002;
 pub use snapshot_store::SnapshotStoreError;
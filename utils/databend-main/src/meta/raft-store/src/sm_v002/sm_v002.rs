@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::future;
 use std::io;
+use std::ops::RangeBounds;
 use std::sync::Arc;
 
 use databend_common_meta_kvapi::kvapi;
@@ -23,6 +24,7 @@ use databend_common_meta_kvapi::kvapi::UpsertKVReply;
 use databend_common_meta_kvapi::kvapi::UpsertKVReq;
 use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::AppliedState;
+use databend_common_meta_types::Change;
 use databend_common_meta_types::CmdContext;
 use databend_common_meta_types::Entry;
 use databend_common_meta_types::EvalExpireTime;
@@ -59,6 +61,8 @@ use crate::sm_v002::leveled_store::sys_data::SysData;
 use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::marked::Marked;
 use crate::sm_v002::sm_v002;
+use crate::sm_v002::ChangeHistory;
+use crate::sm_v002::ChangeHistoryCompacted;
 use crate::sm_v002::Importer;
 use crate::sm_v002::SnapshotViewV002;
 use crate::state_machine::sm::BlockingConfig;
@@ -131,6 +135,9 @@ pub struct SMV002 {
 
     /// subscriber of state machine data
     pub(crate) subscriber: Option<Box<dyn StateMachineSubscriber>>,
+
+    /// The recent kv changes, for a watcher to resume from.
+    pub(crate) change_history: ChangeHistory,
 }
 
 impl SMV002 {
@@ -287,6 +294,31 @@ impl SMV002 {
         Ok(strm.boxed())
     }
 
+    /// List kv entries in a range, including the expired ones.
+    pub async fn range_kv<R>(&self, range: R) -> Result<Vec<(String, SeqV)>, io::Error>
+    where R: RangeBounds<String> + Clone + Send + Sync + 'static {
+        let strm = self.levels.str_map().range(range).await?;
+
+        // Skip tombstone
+        strm.try_filter_map(|(k, marked)| {
+            let seqv = Into::<Option<SeqV>>::into(marked);
+            future::ready(Ok(seqv.map(|x| (k, x))))
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Return the retained kv changes made at or after `since_seq`, in the order they are applied.
+    ///
+    /// It returns an error if some of them are no longer retained.
+    pub fn changes_since(
+        &self,
+        since_seq: u64,
+    ) -> Result<impl Iterator<Item = &Change<Vec<u8>, String>>, ChangeHistoryCompacted> {
+        let curr_seq = self.sys_data_ref().curr_seq();
+        self.change_history.changes_since(since_seq, curr_seq)
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -354,6 +386,9 @@ impl SMV002 {
 
         self.levels = level;
 
+        // The changes before the installed data are unknown.
+        self.change_history.reset();
+
         // The installed data may not cleaned up all expired keys, if it is built with an older state machine.
         // So we need to reset the cursor then the next time applying a log it will cleanup all expired.
         self.expire_cursor = ExpireKey::new(0, 0);
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! etcd authentication of root, with the password configured for the etcd API.
+//!
+//! `Authenticate` checks the password against `etcd_api_password` and returns a token,
+//! which etcd clients send in the `token` metadata of the following requests.
+
+use databend_common_grpc::GrpcClaim;
+use databend_common_meta_types::etcd::etcdserverpb::auth_server::Auth;
+use databend_common_meta_types::etcd::etcdserverpb::AuthenticateRequest;
+use databend_common_meta_types::etcd::etcdserverpb::AuthenticateResponse;
+use log::debug;
+use tonic::metadata::MetadataMap;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+use crate::api::etcd::EtcdServiceImpl;
+use crate::api::secret_eq;
+
+/// The metadata key of the token, as sent by etcd clients.
+const TOKEN_METADATA_KEY: &str = "token";
+
+impl EtcdServiceImpl {
+    /// Verify the token of a request, it is the interceptor of the services but Auth.
+    pub fn check_token(&self, metadata: &MetadataMap) -> Result<GrpcClaim, Status> {
+        let token = metadata
+            .get(TOKEN_METADATA_KEY)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| Status::unauthenticated("etcdserver: user name is empty"))?;
+
+        self.token
+            .try_verify_token(token.to_string())
+            .map_err(|e| Status::unauthenticated(format!("etcdserver: invalid auth token: {}", e)))
+    }
+}
+
+#[tonic::async_trait]
+impl Auth for EtcdServiceImpl {
+    async fn authenticate(
+        &self,
+        request: Request<AuthenticateRequest>,
+    ) -> Result<Response<AuthenticateResponse>, Status> {
+        let req = request.into_inner();
+
+        let user = "root";
+        if req.name != user || !secret_eq(req.password.as_bytes(), self.password.as_bytes()) {
+            return Err(Status::unauthenticated(
+                "etcdserver: authentication failed, invalid user ID or password",
+            ));
+        }
+
+        let token = self
+            .token
+            .try_create_token(GrpcClaim {
+                username: user.to_string(),
+            })
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        debug!("etcd authenticate OK: {}", req.name);
+        Ok(Response::new(AuthenticateResponse {
+            header: Some(self.header(0).await),
+            token,
+        }))
+    }
+}
@@ -0,0 +1,425 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::LeaseApi;
+use databend_common_meta_types::etcd::etcdserverpb::compare;
+use databend_common_meta_types::etcd::etcdserverpb::kv_server::Kv;
+use databend_common_meta_types::etcd::etcdserverpb::range_request::SortOrder;
+use databend_common_meta_types::etcd::etcdserverpb::range_request::SortTarget;
+use databend_common_meta_types::etcd::etcdserverpb::request_op;
+use databend_common_meta_types::etcd::etcdserverpb::response_op;
+use databend_common_meta_types::etcd::etcdserverpb::Compare;
+use databend_common_meta_types::etcd::etcdserverpb::DeleteRangeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::DeleteRangeResponse;
+use databend_common_meta_types::etcd::etcdserverpb::PutRequest;
+use databend_common_meta_types::etcd::etcdserverpb::PutResponse;
+use databend_common_meta_types::etcd::etcdserverpb::RangeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::RangeResponse;
+use databend_common_meta_types::etcd::etcdserverpb::RequestOp;
+use databend_common_meta_types::etcd::etcdserverpb::ResponseOp;
+use databend_common_meta_types::etcd::etcdserverpb::TxnRequest as EtcdTxnRequest;
+use databend_common_meta_types::etcd::etcdserverpb::TxnResponse as EtcdTxnResponse;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::txn_condition::Target;
+use databend_common_meta_types::txn_op_response::Response as TxnOpResponse;
+use databend_common_meta_types::ConditionResult;
+use databend_common_meta_types::GrpcHelper;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::TxnRequest;
+use databend_common_meta_types::UpsertKV;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+use crate::api::etcd::etcd_lease::lease_id;
+use crate::api::etcd::etcd_lease::lease_status;
+use crate::api::etcd::key_range::is_internal_key;
+use crate::api::etcd::key_range::KeyRange;
+use crate::api::etcd::EtcdServiceImpl;
+use crate::metrics::RequestInFlight;
+
+/// The etcd operations a meta-service transaction is built from.
+///
+/// An operation on a leased key is translated into two meta-service operations,
+/// only the response of the first one is returned to the client.
+enum EtcdOp {
+    Range,
+    Put { prev_kv: bool },
+    Delete { prev_kv: bool },
+}
+
+impl EtcdServiceImpl {
+    async fn list_range(&self, range: &KeyRange) -> Result<Vec<(String, SeqV)>, Status> {
+        let kvs = match range {
+            KeyRange::Key(key) => {
+                let v = self
+                    .meta_node
+                    .get_kv(key)
+                    .await
+                    .map_err(GrpcHelper::internal_err)?;
+                v.map(|v| (key.clone(), v)).into_iter().collect()
+            }
+            _ => self
+                .meta_node
+                .prefix_list_kv(&range.prefix())
+                .await
+                .map_err(GrpcHelper::internal_err)?
+                .into_iter()
+                .filter(|(k, _)| range.contains(k))
+                .collect::<Vec<_>>(),
+        };
+
+        Ok(kvs
+            .into_iter()
+            .filter(|(k, _)| !is_internal_key(k))
+            .collect())
+    }
+
+    fn build_condition(cmp: Compare) -> Result<pb::TxnCondition, Status> {
+        if !cmp.range_end.is_empty() {
+            return Err(Self::unimplemented("compare on a range"));
+        }
+
+        let expected = match cmp.result() {
+            compare::CompareResult::Equal => ConditionResult::Eq,
+            compare::CompareResult::Greater => ConditionResult::Gt,
+            compare::CompareResult::Less => ConditionResult::Lt,
+            compare::CompareResult::NotEqual => ConditionResult::Ne,
+        };
+
+        // `create_revision` and `mod_revision` are both the `seq` of a key.
+        // The `version` of a key is 1, or 0 if it does not exist.
+        let target = match cmp.target_union {
+            Some(compare::TargetUnion::Value(v)) => Target::Value(v),
+            Some(compare::TargetUnion::ModRevision(rev)) => Target::Seq(rev as u64),
+            Some(compare::TargetUnion::CreateRevision(rev)) => Target::Seq(rev as u64),
+            Some(compare::TargetUnion::Version(0)) => Target::Seq(0),
+            Some(compare::TargetUnion::Version(_)) => {
+                return Err(Self::unimplemented("compare to a non-zero version"));
+            }
+            Some(compare::TargetUnion::Lease(_)) => {
+                return Err(Self::unimplemented("compare to a lease"));
+            }
+            None => Target::Seq(0),
+        };
+
+        Ok(pb::TxnCondition {
+            key: KeyRange::to_key(cmp.key)?,
+            expected: expected as i32,
+            target: Some(target),
+        })
+    }
+
+    /// Translate an operation of an etcd transaction into meta-service operations.
+    async fn build_ops(&self, op: RequestOp) -> Result<(EtcdOp, Vec<pb::TxnOp>), Status> {
+        let request = op
+            .request
+            .ok_or_else(|| Status::invalid_argument("etcd gateway: empty request op"))?;
+
+        match request {
+            request_op::Request::RequestRange(r) => {
+                let key = match KeyRange::new(r.key, r.range_end)? {
+                    KeyRange::Key(key) => key,
+                    _ => return Err(Self::unimplemented("range of keys in a txn")),
+                };
+                Ok((EtcdOp::Range, vec![pb::TxnOp::get(key)]))
+            }
+            request_op::Request::RequestPut(p) => {
+                if p.ignore_value || p.ignore_lease {
+                    return Err(Self::unimplemented("ignore_value or ignore_lease"));
+                }
+                let key = KeyRange::to_key(p.key)?;
+                let op = EtcdOp::Put { prev_kv: p.prev_kv };
+                if p.lease == 0 {
+                    Ok((op, vec![pb::TxnOp::put(key, p.value)]))
+                } else {
+                    let lease = self.get_lease(p.lease).await?;
+                    Ok((op, Vec::from(lease.put_ops(&key, p.value))))
+                }
+            }
+            request_op::Request::RequestDeleteRange(d) => {
+                let key = match KeyRange::new(d.key, d.range_end)? {
+                    KeyRange::Key(key) => key,
+                    _ => return Err(Self::unimplemented("deleting a range of keys in a txn")),
+                };
+                let op = EtcdOp::Delete { prev_kv: d.prev_kv };
+                Ok((op, vec![pb::TxnOp::delete(key)]))
+            }
+            request_op::Request::RequestTxn(_) => Err(Self::unimplemented("nested txn")),
+        }
+    }
+
+    async fn build_branch(
+        &self,
+        ops: Vec<RequestOp>,
+    ) -> Result<(Vec<(EtcdOp, usize)>, Vec<pb::TxnOp>), Status> {
+        let mut etcd_ops = vec![];
+        let mut meta_ops = vec![];
+
+        for op in ops {
+            let (etcd_op, ops) = self.build_ops(op).await?;
+            etcd_ops.push((etcd_op, ops.len()));
+            meta_ops.extend(ops);
+        }
+
+        Ok((etcd_ops, meta_ops))
+    }
+
+    fn build_response(
+        etcd_op: &EtcdOp,
+        resp: pb::TxnOpResponse,
+    ) -> Result<response_op::Response, Status> {
+        let resp = resp
+            .response
+            .ok_or_else(|| Status::internal("etcd gateway: empty txn op response"))?;
+
+        let r = match (etcd_op, resp) {
+            (EtcdOp::Range, TxnOpResponse::Get(g)) => {
+                let kvs = g
+                    .value
+                    .map(|v| Self::pb_key_value(g.key, v))
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                response_op::Response::ResponseRange(RangeResponse {
+                    header: None,
+                    count: kvs.len() as i64,
+                    kvs,
+                    more: false,
+                })
+            }
+            (EtcdOp::Put { prev_kv }, TxnOpResponse::Put(p)) => {
+                response_op::Response::ResponsePut(PutResponse {
+                    header: None,
+                    prev_kv: p
+                        .prev_value
+                        .filter(|_| *prev_kv)
+                        .map(|v| Self::pb_key_value(p.key, v)),
+                })
+            }
+            (EtcdOp::Delete { prev_kv }, TxnOpResponse::Delete(d)) => {
+                let prev_kvs = d
+                    .prev_value
+                    .filter(|_| *prev_kv)
+                    .map(|v| Self::pb_key_value(d.key, v))
+                    .into_iter()
+                    .collect();
+                response_op::Response::ResponseDeleteRange(DeleteRangeResponse {
+                    header: None,
+                    deleted: d.success as i64,
+                    prev_kvs,
+                })
+            }
+            (_, resp) => {
+                return Err(Status::internal(format!(
+                    "etcd gateway: unexpected txn op response: {:?}",
+                    resp
+                )));
+            }
+        };
+        Ok(r)
+    }
+}
+
+#[async_trait::async_trait]
+impl Kv for EtcdServiceImpl {
+    async fn range(
+        &self,
+        request: Request<RangeRequest>,
+    ) -> Result<Response<RangeResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+
+        if req.revision > 0 {
+            return Err(Self::unimplemented("reading a past revision"));
+        }
+
+        let range = KeyRange::new(req.key.clone(), req.range_end.clone())?;
+        let mut kvs = self.list_range(&range).await?;
+
+        kvs.retain(|(_, v)| {
+            let seq = v.seq as i64;
+            (req.min_mod_revision == 0 || seq >= req.min_mod_revision)
+                && (req.max_mod_revision == 0 || seq <= req.max_mod_revision)
+                && (req.min_create_revision == 0 || seq >= req.min_create_revision)
+                && (req.max_create_revision == 0 || seq <= req.max_create_revision)
+        });
+
+        // Listed keys are in ascending order.
+        match req.sort_target() {
+            SortTarget::Key | SortTarget::Version => {}
+            SortTarget::Create | SortTarget::Mod => kvs.sort_by_key(|(_, v)| v.seq),
+            SortTarget::Value => kvs.sort_by(|(_, a), (_, b)| a.data.cmp(&b.data)),
+        }
+        if req.sort_order() == SortOrder::Descend {
+            kvs.reverse();
+        }
+
+        let count = kvs.len() as i64;
+        let more = req.limit > 0 && count > req.limit;
+        if more {
+            kvs.truncate(req.limit as usize);
+        }
+        if req.count_only {
+            kvs.clear();
+        }
+
+        let kvs = kvs
+            .into_iter()
+            .map(|(k, v)| Self::key_value(k, v, req.keys_only))
+            .collect();
+
+        Ok(Response::new(RangeResponse {
+            header: Some(self.header(0).await),
+            kvs,
+            more,
+            count,
+        }))
+    }
+
+    async fn put(&self, request: Request<PutRequest>) -> Result<Response<PutResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+
+        if req.ignore_value || req.ignore_lease {
+            return Err(Self::unimplemented("ignore_value or ignore_lease"));
+        }
+
+        let key = KeyRange::to_key(req.key)?;
+        if is_internal_key(&key) {
+            return Err(Status::invalid_argument(format!(
+                "etcd gateway: key {} is reserved",
+                key
+            )));
+        }
+
+        let change = if req.lease == 0 {
+            self.meta_node
+                .upsert_kv(UpsertKV::update(&key, &req.value))
+                .await
+                .map_err(GrpcHelper::internal_err)?
+        } else {
+            self.meta_node
+                .lease_upsert_kv(lease_id(req.lease)?, &key, req.value)
+                .await
+                .map_err(lease_status)?
+        };
+        let seq = change.result.as_ref().map(|x| x.seq).unwrap_or_default();
+
+        Ok(Response::new(PutResponse {
+            header: Some(self.header(seq).await),
+            prev_kv: change
+                .prev
+                .filter(|_| req.prev_kv)
+                .map(|v| Self::key_value(key, v, false)),
+        }))
+    }
+
+    /// Delete the keys in a range in one transaction.
+    ///
+    /// A key that is changed after the range is read is not deleted.
+    async fn delete_range(
+        &self,
+        request: Request<DeleteRangeRequest>,
+    ) -> Result<Response<DeleteRangeResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+
+        let range = KeyRange::new(req.key, req.range_end)?;
+        let kvs = self.list_range(&range).await?;
+
+        let ops = kvs
+            .iter()
+            .map(|(k, v)| pb::TxnOp::delete_exact(k, Some(v.seq)))
+            .collect::<Vec<_>>();
+
+        let mut deleted = 0;
+        let mut prev_kvs = vec![];
+        if !ops.is_empty() {
+            let reply = self.meta_txn(TxnRequest::unconditional(ops)).await?;
+            for resp in reply.responses {
+                if let Some(TxnOpResponse::Delete(d)) = resp.response {
+                    if !d.success {
+                        continue;
+                    }
+                    deleted += 1;
+                    if let Some(prev) = d.prev_value.filter(|_| req.prev_kv) {
+                        prev_kvs.push(Self::pb_key_value(d.key, prev));
+                    }
+                }
+            }
+        }
+
+        Ok(Response::new(DeleteRangeResponse {
+            header: Some(self.header(0).await),
+            deleted,
+            prev_kvs,
+        }))
+    }
+
+    /// Translate an etcd transaction into one meta-service transaction.
+    ///
+    /// Only single key operations are supported.
+    async fn txn(
+        &self,
+        request: Request<EtcdTxnRequest>,
+    ) -> Result<Response<EtcdTxnResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+
+        let condition = req
+            .compare
+            .into_iter()
+            .map(Self::build_condition)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (success_ops, if_then) = self.build_branch(req.success).await?;
+        let (failure_ops, else_then) = self.build_branch(req.failure).await?;
+
+        let reply = self
+            .meta_txn(TxnRequest {
+                condition,
+                if_then,
+                else_then,
+            })
+            .await?;
+
+        let etcd_ops = if reply.success {
+            success_ops
+        } else {
+            failure_ops
+        };
+
+        // Skip the responses of the attachment markers.
+        let mut meta_responses = reply.responses.into_iter();
+        let mut responses = vec![];
+        for (etcd_op, n) in etcd_ops {
+            let resp = meta_responses
+                .next()
+                .ok_or_else(|| Status::internal("etcd gateway: missing txn op response"))?;
+            responses.push(ResponseOp {
+                response: Some(Self::build_response(&etcd_op, resp)?),
+            });
+            for _ in 1..n {
+                meta_responses.next();
+            }
+        }
+
+        Ok(Response::new(EtcdTxnResponse {
+            header: Some(self.header(0).await),
+            succeeded: reply.success,
+            responses,
+        }))
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! etcd leases, served by the meta-service [`LeaseApi`].
+//!
+//! A keep-alive rewrites the keys attached to the lease.
+//! Thus it bumps the `mod_revision` of them and watchers see it as PUT events.
+
+use std::time::Duration;
+
+use databend_common_meta_kvapi::kvapi::LeaseApi;
+use databend_common_meta_kvapi::kvapi::LeaseError;
+use databend_common_meta_kvapi::kvapi::LeaseInfo;
+use databend_common_meta_types::etcd::etcdserverpb::lease_server::Lease;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseGrantRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseGrantResponse;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseKeepAliveRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseKeepAliveResponse;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseLeasesRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseLeasesResponse;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseRevokeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseRevokeResponse;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseStatus;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseTimeToLiveRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseTimeToLiveResponse;
+use databend_common_meta_types::GrpcHelper;
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::SeqV;
+use futures::StreamExt;
+use tonic::codegen::BoxStream;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use crate::api::etcd::EtcdServiceImpl;
+use crate::metrics::RequestInFlight;
+
+/// Convert an etcd lease id, which is positive, to a meta-service lease id.
+pub(crate) fn lease_id(id: i64) -> Result<u64, Status> {
+    if id <= 0 {
+        return Err(Status::invalid_argument(format!(
+            "etcd gateway: invalid lease id: {}",
+            id
+        )));
+    }
+    Ok(id as u64)
+}
+
+pub(crate) fn lease_status(e: LeaseError<MetaAPIError>) -> Status {
+    match e {
+        LeaseError::NotFound(_) => Status::not_found("etcdserver: requested lease not found"),
+        LeaseError::AlreadyExists(_) => {
+            Status::failed_precondition("etcdserver: lease already exists")
+        }
+        LeaseError::InvalidTtl(_) => Status::invalid_argument(e.to_string()),
+        LeaseError::Conflict(_) => Status::aborted(e.to_string()),
+        LeaseError::TxnFailed(_) => Status::internal(e.to_string()),
+        LeaseError::KVApi(e) => GrpcHelper::internal_err(e),
+    }
+}
+
+impl EtcdServiceImpl {
+    /// Return the lease, or a `NotFound` error if it does not exist or has expired.
+    pub(crate) async fn get_lease(&self, id: i64) -> Result<LeaseInfo, Status> {
+        let id = lease_id(id)?;
+        let lease = self
+            .meta_node
+            .lease_get(id)
+            .await
+            .map_err(GrpcHelper::internal_err)?;
+
+        lease.ok_or_else(|| lease_status(LeaseError::NotFound(id)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Lease for EtcdServiceImpl {
+    async fn lease_grant(
+        &self,
+        request: Request<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseGrantResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+
+        if req.ttl <= 0 {
+            return Err(Status::invalid_argument(
+                "etcd gateway: lease TTL must be positive",
+            ));
+        }
+
+        let id = if req.id != 0 {
+            Some(lease_id(req.id)?)
+        } else {
+            None
+        };
+
+        let lease = self
+            .meta_node
+            .lease_grant(id, Duration::from_secs(req.ttl as u64))
+            .await
+            .map_err(lease_status)?;
+
+        Ok(Response::new(LeaseGrantResponse {
+            header: Some(self.header(0).await),
+            id: lease.id as i64,
+            ttl: req.ttl,
+            error: "".to_string(),
+        }))
+    }
+
+    async fn lease_revoke(
+        &self,
+        request: Request<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseRevokeResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let id = lease_id(request.into_inner().id)?;
+
+        self.meta_node
+            .lease_revoke(id)
+            .await
+            .map_err(lease_status)?;
+
+        Ok(Response::new(LeaseRevokeResponse {
+            header: Some(self.header(0).await),
+        }))
+    }
+
+    type LeaseKeepAliveStream = BoxStream<LeaseKeepAliveResponse>;
+
+    async fn lease_keep_alive(
+        &self,
+        request: Request<Streaming<LeaseKeepAliveRequest>>,
+    ) -> Result<Response<Self::LeaseKeepAliveStream>, Status> {
+        let this = self.clone();
+
+        let strm = request.into_inner().then(move |req| {
+            let this = this.clone();
+            async move {
+                let id = req?.id;
+
+                // etcd responds with ttl 0 if the lease does not exist.
+                let ttl = match this.meta_node.lease_keep_alive(lease_id(id)?).await {
+                    Ok(lease) => lease.granted_ttl.as_secs() as i64,
+                    Err(LeaseError::NotFound(_)) => 0,
+                    Err(e) => return Err(lease_status(e)),
+                };
+
+                Ok(LeaseKeepAliveResponse {
+                    header: Some(this.header(0).await),
+                    id,
+                    ttl,
+                })
+            }
+        });
+
+        Ok(Response::new(Box::pin(strm)))
+    }
+
+    async fn lease_time_to_live(
+        &self,
+        request: Request<LeaseTimeToLiveRequest>,
+    ) -> Result<Response<LeaseTimeToLiveResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+        let req = request.into_inner();
+        let id = lease_id(req.id)?;
+
+        let lease = self
+            .meta_node
+            .lease_get(id)
+            .await
+            .map_err(GrpcHelper::internal_err)?;
+
+        let Some(lease) = lease else {
+            return Ok(Response::new(LeaseTimeToLiveResponse {
+                header: Some(self.header(0).await),
+                id: req.id,
+                ttl: -1,
+                granted_ttl: 0,
+                keys: vec![],
+            }));
+        };
+
+        let keys = if req.keys {
+            let attached = self.meta_node.lease_keys(id).await.map_err(lease_status)?;
+            attached.into_iter().map(|(k, _)| k.into_bytes()).collect()
+        } else {
+            vec![]
+        };
+
+        let ttl = lease.ttl_at(SeqV::<()>::now_ms());
+
+        Ok(Response::new(LeaseTimeToLiveResponse {
+            header: Some(self.header(0).await),
+            id: req.id,
+            ttl: ttl.as_secs() as i64,
+            granted_ttl: lease.granted_ttl.as_secs() as i64,
+            keys,
+        }))
+    }
+
+    async fn lease_leases(
+        &self,
+        _request: Request<LeaseLeasesRequest>,
+    ) -> Result<Response<LeaseLeasesResponse>, Status> {
+        let _guard = RequestInFlight::guard();
+
+        let leases = self
+            .meta_node
+            .lease_list()
+            .await
+            .map_err(GrpcHelper::internal_err)?
+            .into_iter()
+            .map(|lease| LeaseStatus {
+                id: lease.id as i64,
+            })
+            .collect();
+
+        Ok(Response::new(LeaseLeasesResponse {
+            header: Some(self.header(0).await),
+            leases,
+        }))
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_grpc::GrpcToken;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::etcd::etcdserverpb::ResponseHeader;
+use databend_common_meta_types::etcd::mvccpb::KeyValue;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::GrpcHelper;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::TxnReply;
+use databend_common_meta_types::TxnRequest;
+use tonic::Status;
+
+use crate::meta_service::MetaNode;
+
+/// Serves the etcd v3 KV, Watch, Lease and Auth services on top of the meta-service.
+///
+/// The meta-service keeps only the latest version of a key, tagged with a
+/// globally unique `seq`, which is used as the etcd revision:
+///
+/// - `create_revision` and `mod_revision` of a key are both its `seq`, and its `version` is 1.
+/// - A response header carries the last `seq` seen by this node as the revision.
+/// - Reading or watching a past revision is not supported.
+///
+/// All the services but Auth require the token returned by `Authenticate`.
+#[derive(Clone)]
+pub struct EtcdServiceImpl {
+    pub(crate) token: Arc<GrpcToken>,
+    pub(crate) meta_node: Arc<MetaNode>,
+    /// The password of root, which `Authenticate` verifies.
+    pub(crate) password: String,
+}
+
+impl EtcdServiceImpl {
+    pub fn create(meta_node: Arc<MetaNode>, password: String) -> Self {
+        Self {
+            token: Arc::new(GrpcToken::create()),
+            meta_node,
+            password,
+        }
+    }
+
+    /// Build a response header, with the revision being at least `seen`.
+    pub(crate) async fn header(&self, seen: u64) -> ResponseHeader {
+        let last_seq = self.meta_node.get_last_seq().await;
+        let raft_term = self.meta_node.raft.metrics().borrow().current_term;
+
+        ResponseHeader {
+            cluster_id: 0,
+            member_id: self.meta_node.sto.id,
+            revision: std::cmp::max(seen, last_seq) as i64,
+            raft_term,
+        }
+    }
+
+    /// Run a transaction through raft, a failed transaction is returned as an error.
+    ///
+    /// The returned reply tells whether the conditions are met.
+    pub(crate) async fn meta_txn(&self, txn: TxnRequest) -> Result<TxnReply, Status> {
+        let reply = self
+            .meta_node
+            .transaction(txn)
+            .await
+            .map_err(GrpcHelper::internal_err)?;
+
+        if !reply.error.is_empty() {
+            return Err(Status::internal(reply.error));
+        }
+        Ok(reply)
+    }
+
+    pub(crate) fn key_value(key: String, seq_v: SeqV, keys_only: bool) -> KeyValue {
+        KeyValue {
+            key: key.into_bytes(),
+            create_revision: seq_v.seq as i64,
+            mod_revision: seq_v.seq as i64,
+            version: 1,
+            value: if keys_only { vec![] } else { seq_v.data },
+            lease: 0,
+        }
+    }
+
+    pub(crate) fn pb_key_value(key: String, seq_v: pb::SeqV) -> KeyValue {
+        Self::key_value(key, SeqV::from(seq_v), false)
+    }
+
+    pub(crate) fn unimplemented(what: &str) -> Status {
+        Status::unimplemented(format!("etcd gateway: {} is not supported", what))
+    }
+}
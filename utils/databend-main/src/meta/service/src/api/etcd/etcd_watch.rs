@@ -0,0 +1,269 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::tokio::task::JoinHandle;
+use databend_common_meta_types::etcd::etcdserverpb::watch_create_request::FilterType;
+use databend_common_meta_types::etcd::etcdserverpb::watch_request::RequestUnion;
+use databend_common_meta_types::etcd::etcdserverpb::watch_server::Watch;
+use databend_common_meta_types::etcd::etcdserverpb::WatchCreateRequest;
+use databend_common_meta_types::etcd::etcdserverpb::WatchRequest;
+use databend_common_meta_types::etcd::etcdserverpb::WatchResponse;
+use databend_common_meta_types::etcd::mvccpb::event::EventType;
+use databend_common_meta_types::etcd::mvccpb::Event;
+use databend_common_meta_types::etcd::mvccpb::KeyValue;
+use databend_common_meta_types::protobuf as pb;
+use futures::StreamExt;
+use log::info;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::codegen::BoxStream;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use crate::api::etcd::key_range::is_internal_key;
+use crate::api::etcd::key_range::KeyRange;
+use crate::api::etcd::EtcdServiceImpl;
+use crate::watcher::WatchStream;
+
+type EtcdWatchSender = mpsc::Sender<Result<WatchResponse, Status>>;
+
+/// The id etcd uses for responses not belonging to any watch, such as a progress notification.
+const INVALID_WATCH_ID: i64 = -1;
+
+impl EtcdServiceImpl {
+    /// Serve the watch requests sent in one etcd watch stream.
+    ///
+    /// All watches created in the stream are removed when the client closes it.
+    async fn serve_watch(self, mut requests: Streaming<WatchRequest>, tx: EtcdWatchSender) {
+        let mut watches: BTreeMap<i64, JoinHandle<()>> = BTreeMap::new();
+        let mut next_watch_id = 0;
+
+        while let Some(Ok(req)) = requests.next().await {
+            let resp = match req.request_union {
+                Some(RequestUnion::CreateRequest(create)) => {
+                    let watch_id = if create.watch_id != 0 {
+                        create.watch_id
+                    } else {
+                        while watches.contains_key(&next_watch_id) {
+                            next_watch_id += 1;
+                        }
+                        next_watch_id
+                    };
+
+                    if watches.contains_key(&watch_id) {
+                        WatchResponse {
+                            header: Some(self.header(0).await),
+                            watch_id,
+                            created: true,
+                            canceled: true,
+                            cancel_reason: "etcdserver: duplicate watch ID".to_string(),
+                            ..Default::default()
+                        }
+                    } else {
+                        match self.create_watch(watch_id, create, tx.clone()).await {
+                            Ok(handle) => {
+                                watches.insert(watch_id, handle);
+                                continue;
+                            }
+                            Err(resp) => resp,
+                        }
+                    }
+                }
+                Some(RequestUnion::CancelRequest(cancel)) => {
+                    let Some(handle) = watches.remove(&cancel.watch_id) else {
+                        continue;
+                    };
+                    handle.abort();
+                    WatchResponse {
+                        header: Some(self.header(0).await),
+                        watch_id: cancel.watch_id,
+                        canceled: true,
+                        ..Default::default()
+                    }
+                }
+                Some(RequestUnion::ProgressRequest(_)) => WatchResponse {
+                    header: Some(self.header(0).await),
+                    watch_id: INVALID_WATCH_ID,
+                    ..Default::default()
+                },
+                None => continue,
+            };
+
+            if tx.send(Ok(resp)).await.is_err() {
+                break;
+            }
+        }
+
+        info!("etcd watch stream closed, remove {} watches", watches.len());
+        for handle in watches.values() {
+            handle.abort();
+        }
+    }
+
+    /// Register a meta-service watcher and spawn a task forwarding its events.
+    ///
+    /// Returns the response canceling the watch if it can not be created.
+    async fn create_watch(
+        &self,
+        watch_id: i64,
+        create: WatchCreateRequest,
+        tx: EtcdWatchSender,
+    ) -> Result<JoinHandle<()>, WatchResponse> {
+        let header = self.header(0).await;
+        let canceled = |reason: String| WatchResponse {
+            header: Some(header.clone()),
+            watch_id,
+            created: true,
+            canceled: true,
+            cancel_reason: reason,
+            ..Default::default()
+        };
+
+        // Changes before now are not kept, the client has to read the range again.
+        if create.start_revision > 0 && create.start_revision <= header.revision {
+            return Err(WatchResponse {
+                compact_revision: header.revision + 1,
+                ..canceled("etcd gateway: watching a past revision is not supported".to_string())
+            });
+        }
+
+        let range = KeyRange::new(create.key.clone(), create.range_end.clone())
+            .map_err(|status| canceled(status.message().to_string()))?;
+        let (key, key_end) = range.to_watch_range();
+
+        let (watcher_tx, watcher_rx) = mpsc::channel(4);
+        let watcher = self
+            .meta_node
+            .add_watcher(
+                pb::WatchRequest {
+                    key,
+                    key_end,
+                    filter_type: pb::watch_request::FilterType::All as i32,
+                    initial_flush: false,
+                    since_seq: None,
+                },
+                watcher_tx,
+            )
+            .await
+            .map_err(|e| canceled(e.to_string()))?;
+        let strm = WatchStream::new(
+            watcher_rx,
+            watcher,
+            self.meta_node.dispatcher_handle.clone(),
+        );
+
+        // Respond `created` before any event of this watch.
+        let _ = tx
+            .send(Ok(WatchResponse {
+                header: Some(header.clone()),
+                watch_id,
+                created: true,
+                ..Default::default()
+            }))
+            .await;
+
+        let this = self.clone();
+        let handle =
+            databend_common_base::runtime::spawn(this.forward_events(watch_id, create, strm, tx));
+        Ok(handle)
+    }
+
+    async fn forward_events(
+        self,
+        watch_id: i64,
+        create: WatchCreateRequest,
+        mut strm: WatchStream<Result<pb::WatchResponse, Status>>,
+        tx: EtcdWatchSender,
+    ) {
+        let no_put = create.filters().any(|f| f == FilterType::Noput);
+        let no_delete = create.filters().any(|f| f == FilterType::Nodelete);
+
+        while let Some(Ok(resp)) = strm.next().await {
+            let Some(ev) = resp.event else {
+                continue;
+            };
+            if is_internal_key(&ev.key) {
+                continue;
+            }
+
+            let prev_kv = ev
+                .prev
+                .filter(|_| create.prev_kv)
+                .map(|v| Self::pb_key_value(ev.key.clone(), v));
+
+            let (header, event) = match ev.current {
+                Some(current) => {
+                    if no_put {
+                        continue;
+                    }
+                    let header = self.header(current.seq).await;
+                    let event = Event {
+                        r#type: EventType::Put as i32,
+                        kv: Some(Self::pb_key_value(ev.key, current)),
+                        prev_kv,
+                    };
+                    (header, event)
+                }
+                None => {
+                    if no_delete {
+                        continue;
+                    }
+                    // The revision a key is deleted at is not kept, use the latest one.
+                    let header = self.header(0).await;
+                    let event = Event {
+                        r#type: EventType::Delete as i32,
+                        kv: Some(KeyValue {
+                            key: ev.key.into_bytes(),
+                            mod_revision: header.revision,
+                            ..Default::default()
+                        }),
+                        prev_kv,
+                    };
+                    (header, event)
+                }
+            };
+
+            let resp = WatchResponse {
+                header: Some(header),
+                watch_id,
+                events: vec![event],
+                ..Default::default()
+            };
+            if tx.send(Ok(resp)).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Watch for EtcdServiceImpl {
+    type WatchStream = BoxStream<WatchResponse>;
+
+    async fn watch(
+        &self,
+        request: Request<Streaming<WatchRequest>>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let (tx, rx) = mpsc::channel(16);
+
+        let this = self.clone();
+        databend_common_base::runtime::spawn(this.serve_watch(request.into_inner(), tx));
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
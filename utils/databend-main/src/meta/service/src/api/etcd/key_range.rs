@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_kvapi::kvapi::LEASE_PREFIX;
+use tonic::Status;
+
+/// Returns true if the key stores internal state, such as leases.
+///
+/// Such keys are hidden from etcd clients.
+pub(crate) fn is_internal_key(key: &str) -> bool {
+    key.starts_with(LEASE_PREFIX)
+}
+
+/// The keys an etcd request applies to, built from `key` and `range_end`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum KeyRange {
+    /// A single key: `range_end` is empty.
+    Key(String),
+
+    /// All keys `>= key`: `range_end` is `\0`.
+    From(String),
+
+    /// Keys in `[key, range_end)`.
+    Range(String, String),
+}
+
+impl KeyRange {
+    pub(crate) fn new(key: Vec<u8>, range_end: Vec<u8>) -> Result<Self, Status> {
+        let key = Self::to_key(key)?;
+
+        if range_end.is_empty() {
+            if key.is_empty() {
+                return Err(Status::invalid_argument("etcdserver: key is not provided"));
+            }
+            return Ok(KeyRange::Key(key));
+        }
+
+        if range_end == [0] {
+            return Ok(KeyRange::From(key));
+        }
+
+        Ok(KeyRange::Range(key, Self::to_key(range_end)?))
+    }
+
+    /// Meta-service keys are strings, keys that are not utf-8 are rejected.
+    pub(crate) fn to_key(key: Vec<u8>) -> Result<String, Status> {
+        String::from_utf8(key)
+            .map_err(|e| Status::invalid_argument(format!("etcd gateway: key is not utf-8: {}", e)))
+    }
+
+    pub(crate) fn contains(&self, k: &str) -> bool {
+        match self {
+            KeyRange::Key(key) => k == key,
+            KeyRange::From(start) => k >= start.as_str(),
+            KeyRange::Range(start, end) => k >= start.as_str() && k < end.as_str(),
+        }
+    }
+
+    /// The longest prefix shared by every key in the range.
+    ///
+    /// The range is read by listing this prefix and filtering the result with
+    /// [`contains()`](Self::contains).
+    pub(crate) fn prefix(&self) -> String {
+        match self {
+            KeyRange::Key(key) => key.clone(),
+            KeyRange::From(_) => "".to_string(),
+            KeyRange::Range(start, end) => start
+                .chars()
+                .zip(end.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect(),
+        }
+    }
+
+    /// Build the `key` and `key_end` of a meta-service watch request.
+    pub(crate) fn to_watch_range(&self) -> (String, Option<String>) {
+        match self {
+            KeyRange::Key(key) => (key.clone(), None),
+            KeyRange::From(start) => (start.clone(), Some(char::MAX.to_string())),
+            KeyRange::Range(start, end) => (start.clone(), Some(end.clone())),
+        }
+    }
+}
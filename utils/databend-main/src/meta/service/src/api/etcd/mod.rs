@@ -0,0 +1,24 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An etcd v3 compatible gRPC API, for etcd tools to access the meta data.
+
+mod etcd_auth;
+mod etcd_kv;
+mod etcd_lease;
+mod etcd_service;
+mod etcd_watch;
+mod key_range;
+
+pub use etcd_service::EtcdServiceImpl;
@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use anyerror::AnyError;
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::sync::oneshot;
+use databend_common_base::base::tokio::sync::oneshot::Sender;
+use databend_common_base::base::tokio::task::JoinHandle;
+use databend_common_base::base::Stoppable;
+use databend_common_meta_types::etcd::etcdserverpb::auth_server::AuthServer;
+use databend_common_meta_types::etcd::etcdserverpb::kv_server::KvServer;
+use databend_common_meta_types::etcd::etcdserverpb::lease_server::LeaseServer;
+use databend_common_meta_types::etcd::etcdserverpb::watch_server::WatchServer;
+use databend_common_meta_types::etcd::FILE_DESCRIPTOR_SET;
+use databend_common_meta_types::GrpcConfig;
+use databend_common_meta_types::MetaNetworkError;
+use futures::future::Either;
+use log::info;
+use minitrace::prelude::*;
+use tonic::codegen::InterceptedService;
+use tonic::transport::Server;
+use tonic::Request;
+use tonic::Status;
+
+use crate::api::etcd::EtcdServiceImpl;
+use crate::api::GrpcServer;
+use crate::configs::Config;
+use crate::meta_service::MetaNode;
+
+/// Serves the etcd v3 compatible gRPC API at `etcd_api_address`.
+///
+/// Clients must authenticate with the etcd `Auth.Authenticate` first.
+///
+/// Unlike [`GrpcServer`], stopping it does not stop the meta node.
+pub struct EtcdServer {
+    conf: Config,
+    meta_node: Arc<MetaNode>,
+    join_handle: Option<JoinHandle<()>>,
+    stop_tx: Option<Sender<()>>,
+}
+
+impl EtcdServer {
+    pub fn create(conf: Config, meta_node: Arc<MetaNode>) -> Self {
+        Self {
+            conf,
+            meta_node,
+            join_handle: None,
+            stop_tx: None,
+        }
+    }
+
+    async fn do_start(&mut self) -> Result<(), MetaNetworkError> {
+        // For sending signal when server started.
+        let (started_tx, started_rx) = oneshot::channel::<()>();
+        // For receive stop signal.
+        let (stop_tx, stop_rx) = oneshot::channel::<()>();
+
+        let reflect_srv = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+            .build()
+            .unwrap();
+
+        let builder = Server::builder();
+
+        let tls_conf = GrpcServer::tls_config(&self.conf)
+            .await
+            .map_err(|e| MetaNetworkError::TLSConfigError(AnyError::new(&e)))?;
+
+        let mut builder = if let Some(tls_conf) = tls_conf {
+            info!("etcd API TLS enabled");
+            builder
+                .tls_config(tls_conf)
+                .map_err(|e| MetaNetworkError::TLSConfigError(AnyError::new(&e)))?
+        } else {
+            builder
+        };
+
+        let addr = self.conf.etcd_api_address.parse::<std::net::SocketAddr>()?;
+
+        info!("etcd API addr: {}", addr);
+
+        let etcd_impl = Arc::new(EtcdServiceImpl::create(
+            self.meta_node.clone(),
+            self.conf.etcd_api_password.clone(),
+        ));
+
+        // Every service but Auth requires the token returned by `Authenticate`.
+        let check_token = {
+            let etcd_impl = etcd_impl.clone();
+            move |req: Request<()>| -> Result<Request<()>, Status> {
+                etcd_impl.check_token(req.metadata())?;
+                Ok(req)
+            }
+        };
+        let kv_srv = KvServer::from_arc(etcd_impl.clone())
+            .max_decoding_message_size(GrpcConfig::MAX_DECODING_SIZE)
+            .max_encoding_message_size(GrpcConfig::MAX_ENCODING_SIZE);
+        let kv_srv = InterceptedService::new(kv_srv, check_token.clone());
+        let watch_srv = InterceptedService::new(
+            WatchServer::from_arc(etcd_impl.clone()),
+            check_token.clone(),
+        );
+        let lease_srv =
+            InterceptedService::new(LeaseServer::from_arc(etcd_impl.clone()), check_token);
+        let auth_srv = AuthServer::from_arc(etcd_impl);
+
+        let j = databend_common_base::runtime::spawn(
+            async move {
+                let res = builder
+                    .add_service(reflect_srv)
+                    .add_service(kv_srv)
+                    .add_service(watch_srv)
+                    .add_service(lease_srv)
+                    .add_service(auth_srv)
+                    .serve_with_shutdown(addr, async move {
+                        let _ = started_tx.send(());
+                        info!("etcd API starts to wait for stop signal: {}", addr);
+                        let _ = stop_rx.await;
+                        info!("etcd API receives stop signal: {}", addr);
+                    })
+                    .await;
+
+                info!("etcd API returned res: {:?}", res);
+            }
+            .in_span(Span::enter_with_local_parent("spawn-etcd-grpc")),
+        );
+
+        started_rx
+            .await
+            .expect("maybe address already in use, try to use another port");
+
+        self.join_handle = Some(j);
+        self.stop_tx = Some(stop_tx);
+
+        Ok(())
+    }
+
+    async fn do_stop(&mut self, force: Option<tokio::sync::broadcast::Receiver<()>>) {
+        if let Some(tx) = self.stop_tx.take() {
+            let _ = tx.send(());
+        }
+
+        if let Some(j) = self.join_handle.take() {
+            if let Some(mut f) = force {
+                let f = Box::pin(f.recv());
+                let j = Box::pin(j);
+
+                match futures::future::select(f, j).await {
+                    Either::Left((_x, j)) => {
+                        info!("received force shutdown signal");
+                        j.abort();
+                    }
+                    Either::Right(_) => {
+                        info!("Done: graceful shutdown");
+                    }
+                }
+            } else {
+                let res = j.await;
+                info!("Done: waiting for join handle: res: {:?}", res);
+            }
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Stoppable for EtcdServer {
+    type Error = AnyError;
+
+    async fn start(&mut self) -> Result<(), Self::Error> {
+        info!("EtcdServer::start");
+        let res = self.do_start().await;
+
+        res.map_err(|e: MetaNetworkError| AnyError::new(&e))?;
+        info!("Done EtcdServer::start");
+        Ok(())
+    }
+
+    async fn stop(
+        &mut self,
+        force: Option<tokio::sync::broadcast::Receiver<()>>,
+    ) -> Result<(), Self::Error> {
+        info!("EtcdServer::stop");
+        self.do_stop(force).await;
+        info!("Done EtcdServer::stop");
+        Ok(())
+    }
+}
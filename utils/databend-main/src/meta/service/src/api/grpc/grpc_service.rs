@@ -344,7 +344,7 @@ impl MetaService for MetaServiceImpl {
         let _guard = RequestInFlight::guard();
 
         let meta_node = &self.meta_node;
-        let strm = meta_node.sto.inner().export();
+        let strm = meta_node.sto.inner().export(None);
 
         let chunk_size = 32;
         // - Chunk up upto 32 Ok items inside a Vec<String>;
@@ -365,6 +365,8 @@ impl MetaService for MetaServiceImpl {
     ///
     /// Including header, raft state, logs and state machine.
     /// The exported data is a series of JSON encoded strings of `RaftStoreEntry`.
+    ///
+    /// If `since_log_index` is specified, only the logs since then are exported, without the state machine.
     async fn export_v1(
         &self,
         request: Request<pb::ExportRequest>,
@@ -372,7 +374,10 @@ impl MetaService for MetaServiceImpl {
         let _guard = RequestInFlight::guard();
 
         let meta_node = &self.meta_node;
-        let strm = meta_node.sto.inner().export();
+        let strm = meta_node
+            .sto
+            .inner()
+            .export(request.get_ref().since_log_index);
 
         let chunk_size = request.get_ref().chunk_size.unwrap_or(32) as usize;
         // - Chunk up upto `chunk_size` Ok items inside a Vec<String>;
@@ -397,18 +402,15 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
-        let add_res = mn.add_watcher(request.into_inner(), tx).await;
+        let (watcher, initial) = mn
+            .add_watcher_with_initial(request.into_inner(), tx)
+            .await?;
 
-        match add_res {
-            Ok(watcher) => {
-                let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
-                Ok(Response::new(Box::pin(stream) as Self::WatchStream))
-            }
-            Err(e) => {
-                // TODO: test error return.
-                Err(Status::invalid_argument(e))
-            }
-        }
+        let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+        // The initial key-values or the retained changes go first.
+        let stream = futures::stream::iter(initial.into_iter().map(Ok)).chain(stream);
+
+        Ok(Response::new(Box::pin(stream) as Self::WatchStream))
     }
 
     async fn member_list(
@@ -169,7 +169,9 @@ impl GrpcServer {
         }
     }
 
-    async fn tls_config(conf: &Config) -> Result<Option<ServerTlsConfig>, std::io::Error> {
+    pub(crate) async fn tls_config(
+        conf: &Config,
+    ) -> Result<Option<ServerTlsConfig>, std::io::Error> {
         if conf.tls_rpc_server_enabled() {
             let cert = tokio::fs::read(conf.grpc_tls_server_cert.as_str()).await?;
             let key = tokio::fs::read(conf.grpc_tls_server_key.as_str()).await?;
@@ -18,5 +18,12 @@ use crate::configs::Config;
 
 #[poem::handler]
 pub async fn config_handler(cfg: Data<&Config>) -> String {
-    format!("{:?}", cfg.0)
+    let mut cfg = cfg.0.clone();
+    if !cfg.admin_api_token.is_empty() {
+        cfg.admin_api_token = "******".to_string();
+    }
+    if !cfg.etcd_api_password.is_empty() {
+        cfg.etcd_api_password = "******".to_string();
+    }
+    format!("{:?}", cfg)
 }
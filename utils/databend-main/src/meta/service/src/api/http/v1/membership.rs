@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Admin APIs to change the membership and the leadership of a cluster.
+//!
+//! A request must provide the configured `admin_api_token` in header `Authorization: Bearer <token>`.
+//! A request can be sent to any node in the cluster; it is forwarded to the leader.
+
+use std::sync::Arc;
+
+use databend_common_meta_types::Endpoint;
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::MetaDataError;
+use databend_common_meta_types::NodeId;
+use poem::http::header;
+use poem::http::HeaderMap;
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+use poem::web::Query;
+
+use crate::api::secret_eq;
+use crate::configs::Config;
+use crate::message::AddLearnerRequest;
+use crate::meta_service::MetaNode;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct NodeQuery {
+    pub node_id: NodeId,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AddLearnerQuery {
+    pub node_id: NodeId,
+    /// The raft API address of the node, in form of `<host>:<port>`.
+    pub endpoint: String,
+    pub grpc_api_advertise_address: Option<String>,
+}
+
+/// Add a node to the cluster as a learner, which receives logs but does not vote.
+///
+/// The node must have been started without `--single` or `--join`, so that it has no data.
+#[poem::handler]
+pub async fn add_learner(
+    meta_node: Data<&Arc<MetaNode>>,
+    cfg: Data<&Config>,
+    headers: &HeaderMap,
+    Query(query): Query<AddLearnerQuery>,
+) -> poem::Result<impl IntoResponse> {
+    authenticate(cfg.0, headers)?;
+
+    let endpoint = Endpoint::parse(&query.endpoint).map_err(|e| {
+        poem::Error::from_string(format!("invalid endpoint: {}", e), StatusCode::BAD_REQUEST)
+    })?;
+
+    let req = AddLearnerRequest {
+        node_id: query.node_id,
+        endpoint,
+        grpc_api_advertise_address: query.grpc_api_advertise_address,
+    };
+    meta_node.add_learner(req).await.map_err(api_error)?;
+
+    Ok(Json(()))
+}
+
+/// Promote a learner to a voter.
+///
+/// It is refused if the learner is not up to date or a majority of the new voters are not up to date.
+#[poem::handler]
+pub async fn promote(
+    meta_node: Data<&Arc<MetaNode>>,
+    cfg: Data<&Config>,
+    headers: &HeaderMap,
+    Query(query): Query<NodeQuery>,
+) -> poem::Result<impl IntoResponse> {
+    authenticate(cfg.0, headers)?;
+    meta_node.promote(query.node_id).await.map_err(api_error)?;
+    Ok(Json(()))
+}
+
+/// Demote a voter to a learner.
+///
+/// It is refused if no voter is left or a majority of the remaining voters are not up to date.
+#[poem::handler]
+pub async fn demote(
+    meta_node: Data<&Arc<MetaNode>>,
+    cfg: Data<&Config>,
+    headers: &HeaderMap,
+    Query(query): Query<NodeQuery>,
+) -> poem::Result<impl IntoResponse> {
+    authenticate(cfg.0, headers)?;
+    meta_node.demote(query.node_id).await.map_err(api_error)?;
+    Ok(Json(()))
+}
+
+/// Transfer the leadership to a voter, e.g., before restarting the leader for maintenance.
+///
+/// It is refused if the target voter does not have all the logs of the leader.
+#[poem::handler]
+pub async fn transfer_leader(
+    meta_node: Data<&Arc<MetaNode>>,
+    cfg: Data<&Config>,
+    headers: &HeaderMap,
+    Query(query): Query<NodeQuery>,
+) -> poem::Result<impl IntoResponse> {
+    authenticate(cfg.0, headers)?;
+    meta_node
+        .transfer_leader(query.node_id)
+        .await
+        .map_err(api_error)?;
+    Ok(Json(()))
+}
+
+fn authenticate(cfg: &Config, headers: &HeaderMap) -> poem::Result<()> {
+    if cfg.admin_api_token.is_empty() {
+        return Err(poem::Error::from_string(
+            "membership API is disabled: admin_api_token is not configured",
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    let authed = token.is_some_and(|t| secret_eq(t.as_bytes(), cfg.admin_api_token.as_bytes()));
+    if !authed {
+        return Err(poem::Error::from_string(
+            "invalid admin api token",
+            StatusCode::UNAUTHORIZED,
+        ));
+    }
+
+    Ok(())
+}
+
+fn api_error(e: MetaAPIError) -> poem::Error {
+    let status = match &e {
+        MetaAPIError::DataError(MetaDataError::MembershipRefused(_))
+        | MetaAPIError::RemoteError(MetaDataError::MembershipRefused(_)) => StatusCode::CONFLICT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    poem::Error::from_string(e.to_string(), status)
+}
@@ -31,6 +31,7 @@ use log::warn;
 use poem::get;
 use poem::listener::RustlsCertificate;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
@@ -70,6 +71,19 @@ impl HttpService {
                 "/v1/ctrl/block_compact_snapshot",
                 get(super::http::v1::ctrl::block_compact_snapshot),
             )
+            .at(
+                "/v1/ctrl/add_learner",
+                post(super::http::v1::membership::add_learner),
+            )
+            .at(
+                "/v1/ctrl/promote",
+                post(super::http::v1::membership::promote),
+            )
+            .at("/v1/ctrl/demote", post(super::http::v1::membership::demote))
+            .at(
+                "/v1/ctrl/transfer_leader",
+                post(super::http::v1::membership::transfer_leader),
+            )
             .at(
                 "/v1/cluster/nodes",
                 get(super::http::v1::cluster_state::nodes_handler),
@@ -14,10 +14,26 @@
 
 // The api module only used for internal communication, such as GRPC between cluster and the managed HTTP REST API.
 
+pub mod etcd;
+mod etcd_server;
 pub mod grpc;
 mod grpc_server;
 pub mod http;
 mod http_service;
 
+pub use etcd_server::EtcdServer;
 pub use grpc_server::GrpcServer;
 pub use http_service::HttpService;
+
+/// Compare a secret provided by a client with the configured one, in a time that does not
+/// depend on how many leading bytes match.
+pub(crate) fn secret_eq(provided: &[u8], expected: &[u8]) -> bool {
+    if provided.len() != expected.len() {
+        return false;
+    }
+    provided
+        .iter()
+        .zip(expected)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
@@ -35,11 +35,19 @@ pub struct Config {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    /// The token to access the admin APIs that change the cluster membership. These APIs are disabled if it is empty.
+    #[serde(skip_serializing)]
+    pub admin_api_token: String,
     pub grpc_api_address: String,
     pub grpc_api_advertise_host: Option<String>,
     /// Certificate for server to identify itself
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
+    /// Listening address of the etcd v3 compatible gRPC API, disabled if empty.
+    pub etcd_api_address: String,
+    /// The password of root to authenticate with the etcd v3 compatible gRPC API.
+    #[serde(skip_serializing)]
+    pub etcd_api_password: String,
     pub raft_config: RaftConfig,
 }
 
@@ -58,10 +66,13 @@ impl Default for Config {
             admin_api_address: "127.0.0.1:28002".to_string(),
             admin_tls_server_cert: "".to_string(),
             admin_tls_server_key: "".to_string(),
+            admin_api_token: "".to_string(),
             grpc_api_address: "127.0.0.1:9191".to_string(),
             grpc_api_advertise_host: None,
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
+            etcd_api_address: "".to_string(),
+            etcd_api_password: "".to_string(),
             raft_config: Default::default(),
         }
     }
@@ -84,6 +95,19 @@ impl Config {
                 e, self.grpc_api_address
             ))
         })?;
+        if self.etcd_api_enabled() {
+            let _a: SocketAddr = self.etcd_api_address.parse().map_err(|e| {
+                MetaStartupError::InvalidConfig(format!(
+                    "{} while parsing {}",
+                    e, self.etcd_api_address
+                ))
+            })?;
+            if self.etcd_api_password.is_empty() {
+                return Err(MetaStartupError::InvalidConfig(
+                    "etcd_api_password is required to enable the etcd API".to_string(),
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -129,6 +153,10 @@ impl Config {
         }
     }
 
+    pub fn etcd_api_enabled(&self) -> bool {
+        !self.etcd_api_address.is_empty()
+    }
+
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
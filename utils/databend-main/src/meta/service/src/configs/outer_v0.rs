@@ -20,6 +20,7 @@ use clap::Parser;
 use databend_common_meta_raft_store::config::get_default_raft_advertise_host;
 use databend_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use databend_common_meta_types::MetaStartupError;
+use databend_common_tracing::AuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig;
@@ -109,6 +110,13 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub admin_tls_server_key: String,
 
+    /// Token to access the admin APIs that change the cluster membership
+    ///
+    /// A request must provide it in header `Authorization: Bearer <token>`.
+    /// Leave it empty to disable these APIs.
+    #[clap(long, default_value = "")]
+    pub admin_api_token: String,
+
     /// Listening address for public APIs
     ///
     /// This address is only used by meta service to build a listening endpoint.
@@ -128,6 +136,20 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub grpc_tls_server_key: String,
 
+    /// Listening address for the etcd v3 compatible gRPC API
+    ///
+    /// It serves the etcd KV, Watch and Lease services, so that etcd tools can access the meta data,
+    /// clients authenticate as the meta-service user with the etcd Auth service.
+    /// It shares the TLS config of the gRPC API. Leave it empty to disable it.
+    #[clap(long, default_value = "")]
+    pub etcd_api_address: String,
+
+    /// Password of root for the etcd v3 compatible gRPC API
+    ///
+    /// etcd clients authenticate as `root` with it. It is required if the etcd API is enabled.
+    #[clap(long, default_value = "")]
+    pub etcd_api_password: String,
+
     #[clap(flatten)]
     pub raft_config: RaftConfig,
 }
@@ -161,10 +183,13 @@ impl From<Config> for InnerConfig {
             admin_api_address: outer.admin_api_address,
             admin_tls_server_cert: outer.admin_tls_server_cert,
             admin_tls_server_key: outer.admin_tls_server_key,
+            admin_api_token: outer.admin_api_token,
             grpc_api_address: outer.grpc_api_address,
             grpc_api_advertise_host: outer.grpc_api_advertise_host,
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
+            etcd_api_address: outer.etcd_api_address,
+            etcd_api_password: outer.etcd_api_password,
             raft_config: outer.raft_config.into(),
         }
     }
@@ -187,10 +212,13 @@ impl From<InnerConfig> for Config {
             admin_api_address: inner.admin_api_address,
             admin_tls_server_cert: inner.admin_tls_server_cert,
             admin_tls_server_key: inner.admin_tls_server_key,
+            admin_api_token: inner.admin_api_token,
             grpc_api_address: inner.grpc_api_address,
             grpc_api_advertise_host: inner.grpc_api_advertise_host,
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
+            etcd_api_address: inner.etcd_api_address,
+            etcd_api_password: inner.etcd_api_password,
             raft_config: inner.raft_config.into(),
         }
     }
@@ -274,10 +302,13 @@ pub struct ConfigViaEnv {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    pub admin_api_token: String,
     pub metasrv_grpc_api_address: String,
     pub metasrv_grpc_api_advertise_host: Option<String>,
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
+    pub metasrv_etcd_api_address: String,
+    pub metasrv_etcd_api_password: String,
 
     pub config_id: String,
     pub kvsrv_listen_host: String,
@@ -321,10 +352,13 @@ impl From<Config> for ConfigViaEnv {
             admin_api_address: cfg.admin_api_address,
             admin_tls_server_cert: cfg.admin_tls_server_cert,
             admin_tls_server_key: cfg.admin_tls_server_key,
+            admin_api_token: cfg.admin_api_token,
             metasrv_grpc_api_address: cfg.grpc_api_address,
             metasrv_grpc_api_advertise_host: cfg.grpc_api_advertise_host,
             grpc_tls_server_cert: cfg.grpc_tls_server_cert,
             grpc_tls_server_key: cfg.grpc_tls_server_key,
+            metasrv_etcd_api_address: cfg.etcd_api_address,
+            metasrv_etcd_api_password: cfg.etcd_api_password,
             config_id: cfg.raft_config.config_id,
             kvsrv_listen_host: cfg.raft_config.raft_listen_host,
             kvsrv_advertise_host: cfg.raft_config.raft_advertise_host,
@@ -405,10 +439,13 @@ impl Into<Config> for ConfigViaEnv {
             admin_api_address: self.admin_api_address,
             admin_tls_server_cert: self.admin_tls_server_cert,
             admin_tls_server_key: self.admin_tls_server_key,
+            admin_api_token: self.admin_api_token,
             grpc_api_address: self.metasrv_grpc_api_address,
             grpc_api_advertise_host: self.metasrv_grpc_api_advertise_host,
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
+            etcd_api_address: self.metasrv_etcd_api_address,
+            etcd_api_password: self.metasrv_etcd_api_password,
             raft_config,
         }
     }
@@ -601,6 +638,7 @@ impl Into<InnerLogConfig> for LogConfig {
             profile: ProfileLogConfig::default(),
             structlog: StructLogConfig::default(),
             tracing: TracingConfig::default(),
+            audit: AuditLogConfig::default(),
         }
     }
 }
@@ -59,6 +59,32 @@ pub struct LeaveRequest {
     pub node_id: NodeId,
 }
 
+/// Add a node to the cluster as a learner, which receives logs but does not vote.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddLearnerRequest {
+    pub node_id: NodeId,
+    pub endpoint: Endpoint,
+    pub grpc_api_advertise_address: Option<String>,
+}
+
+/// Promote a learner to a voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PromoteRequest {
+    pub node_id: NodeId,
+}
+
+/// Demote a voter to a learner.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DemoteRequest {
+    pub node_id: NodeId,
+}
+
+/// Check if the leadership can be transferred to a voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransferLeaderRequest {
+    pub node_id: NodeId,
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -75,6 +101,16 @@ pub enum ForwardRequestBody {
     Join(JoinRequest),
     Leave(LeaveRequest),
 
+    AddLearner(AddLearnerRequest),
+    Promote(PromoteRequest),
+    Demote(DemoteRequest),
+    TransferLeader(TransferLeaderRequest),
+
+    /// Let the receiving node start an election.
+    ///
+    /// Unlike other requests it is handled by the node it is sent to, not by the leader.
+    Elect,
+
     Write(LogEntry),
 
     GetKV(GetKVReq),
@@ -141,6 +177,11 @@ pub enum ForwardResponse {
 
     Join(()),
     Leave(()),
+    AddLearner(()),
+    Promote(()),
+    Demote(()),
+    TransferLeader(()),
+    Elect(()),
     AppliedState(AppliedState),
 
     GetKV(GetKVReply),
@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::fmt::Display;
 
+use anyerror::AnyError;
 use databend_common_base::base::tokio::sync::RwLockReadGuard;
 use databend_common_meta_client::MetaGrpcReadReq;
 use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use databend_common_meta_raft_store::sm_v002::SMV002;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
+use databend_common_meta_sled_store::openraft::LogIdOptionExt;
 use databend_common_meta_stoerr::MetaStorageError;
 use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::AppliedState;
@@ -33,6 +36,7 @@ use databend_common_meta_types::MetaOperationError;
 use databend_common_meta_types::Node;
 use databend_common_meta_types::NodeId;
 use databend_common_meta_types::RaftError;
+use databend_common_meta_types::RaftMetrics;
 use databend_common_meta_types::SeqV;
 use databend_common_metrics::count::Count;
 use futures::StreamExt;
@@ -42,11 +46,15 @@ use maplit::btreemap;
 use maplit::btreeset;
 use tonic::codegen::BoxStream;
 
+use crate::message::AddLearnerRequest;
+use crate::message::DemoteRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
+use crate::message::TransferLeaderRequest;
 use crate::meta_service::meta_node::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
@@ -54,6 +62,9 @@ use crate::metrics::ProposalPending;
 use crate::request_handling::Handler;
 use crate::store::RaftStore;
 
+/// The max number of logs a node can lag behind the leader to be considered up to date.
+const MAX_LAG: u64 = 1000;
+
 /// The container of APIs of the leader in a meta service cluster.
 ///
 /// A leader does not imply it is actually the leader granted by the cluster.
@@ -83,6 +94,24 @@ impl<'a> Handler<ForwardRequestBody> for MetaLeader<'a> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
+            ForwardRequestBody::AddLearner(req) => {
+                self.add_learner(req).await?;
+                Ok(ForwardResponse::AddLearner(()))
+            }
+            ForwardRequestBody::Promote(req) => {
+                self.promote(req).await?;
+                Ok(ForwardResponse::Promote(()))
+            }
+            ForwardRequestBody::Demote(req) => {
+                self.demote(req).await?;
+                Ok(ForwardResponse::Demote(()))
+            }
+            ForwardRequestBody::TransferLeader(req) => {
+                self.check_transfer_leader(req)?;
+                Ok(ForwardResponse::TransferLeader(()))
+            }
+            // The leader is already elected.
+            ForwardRequestBody::Elect => Ok(ForwardResponse::Elect(())),
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
@@ -245,6 +274,139 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Add a node to the cluster as a learner and start replicating logs to it.
+    ///
+    /// If the node is already in cluster membership, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn add_learner(&self, req: AddLearnerRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if membership.nodes().any(|(id, _)| *id == node_id) {
+            return Ok(());
+        }
+
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::AddNode {
+                node_id,
+                node: Node::new(node_id, req.endpoint)
+                    .with_grpc_advertise_address(req.grpc_api_advertise_address),
+                overriding: false,
+            },
+        };
+        self.write(ent).await?;
+
+        self.raft
+            .change_membership(
+                ChangeMembers::AddNodes(btreemap! {node_id=>MembershipNode{}}),
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Promote a learner to a voter.
+    ///
+    /// It is refused if the learner is not up to date,
+    /// or if a majority of the new voters are not up to date.
+    ///
+    /// If the node is already a voter, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn promote(&self, req: PromoteRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        let mut voters = membership.voter_ids().collect::<BTreeSet<_>>();
+
+        if voters.contains(&node_id) {
+            return Ok(());
+        }
+
+        if !membership.learner_ids().any(|id| id == node_id) {
+            return Err(refused(format!(
+                "node-{} is not a learner, add it as a learner first",
+                node_id
+            )));
+        }
+
+        if !is_up_to_date(&metrics, node_id) {
+            return Err(refused(format!(
+                "learner node-{} is not up to date, lag: {:?}",
+                node_id,
+                lag(&metrics, node_id)
+            )));
+        }
+
+        voters.insert(node_id);
+        check_quorum(&metrics, &voters, format!("promoting node-{}", node_id))?;
+
+        self.raft
+            .change_membership(ChangeMembers::AddVoterIds(btreeset! {node_id}), false)
+            .await?;
+        Ok(())
+    }
+
+    /// Demote a voter to a learner, which still receives logs.
+    ///
+    /// It is refused if it leaves no voter, or if a majority of the remaining voters are not up to date.
+    /// Demoting the leader is allowed: it steps down once the change is committed.
+    ///
+    /// If the node is already a learner, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn demote(&self, req: DemoteRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        let mut voters = membership.voter_ids().collect::<BTreeSet<_>>();
+
+        if !voters.contains(&node_id) {
+            if membership.learner_ids().any(|id| id == node_id) {
+                return Ok(());
+            }
+            return Err(refused(format!("node-{} is not in the cluster", node_id)));
+        }
+
+        voters.remove(&node_id);
+        check_quorum(&metrics, &voters, format!("demoting node-{}", node_id))?;
+
+        self.raft
+            .change_membership(ChangeMembers::RemoveVoters(btreeset! {node_id}), true)
+            .await?;
+        Ok(())
+    }
+
+    /// Check if the leadership can be transferred to a node.
+    ///
+    /// The target must be a voter and have all the logs of the leader, otherwise it may not win the election.
+    #[minitrace::trace]
+    pub fn check_transfer_leader(
+        &self,
+        req: TransferLeaderRequest,
+    ) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if !membership.voter_ids().any(|id| id == node_id) {
+            return Err(refused(format!("node-{} is not a voter", node_id)));
+        }
+
+        if lag(&metrics, node_id) != Some(0) {
+            return Err(refused(format!(
+                "node-{} does not have all the logs of the leader, lag: {:?}",
+                node_id,
+                lag(&metrics, node_id)
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
@@ -304,3 +466,52 @@ impl<'a> MetaLeader<'a> {
         self.sto.state_machine.read().await
     }
 }
+
+fn refused(msg: impl ToString) -> MetaOperationError {
+    MetaDataError::MembershipRefused(AnyError::error(msg)).into()
+}
+
+/// Return the number of logs a node lags behind the leader, or `None` if the leader does not replicate to it.
+///
+/// The lag is what the leader knows of: a node that has just crashed is still considered up to date.
+fn lag(metrics: &RaftMetrics, node_id: NodeId) -> Option<u64> {
+    let last_log_index = metrics.last_log_index.unwrap_or_default();
+
+    if node_id == metrics.id {
+        return Some(0);
+    }
+
+    let matched = metrics.replication.as_ref()?.get(&node_id)?;
+    Some((last_log_index + 1).saturating_sub(matched.next_index()))
+}
+
+fn is_up_to_date(metrics: &RaftMetrics, node_id: NodeId) -> bool {
+    lag(metrics, node_id).map_or(false, |x| x <= MAX_LAG)
+}
+
+/// Check that a majority of the `voters` are up to date, so that the cluster still works after the change.
+fn check_quorum(
+    metrics: &RaftMetrics,
+    voters: &BTreeSet<NodeId>,
+    action: impl Display,
+) -> Result<(), MetaOperationError> {
+    if voters.is_empty() {
+        return Err(refused(format!("{} leaves no voter", action)));
+    }
+
+    let up_to_date = voters
+        .iter()
+        .filter(|id| is_up_to_date(metrics, **id))
+        .count();
+
+    if up_to_date * 2 <= voters.len() {
+        return Err(refused(format!(
+            "{} leaves only {} of {} voters up to date, less than a quorum",
+            action,
+            up_to_date,
+            voters.len()
+        )));
+    }
+
+    Ok(())
+}
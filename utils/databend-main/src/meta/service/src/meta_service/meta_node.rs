@@ -38,9 +38,13 @@ use databend_common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDa
 use databend_common_meta_sled_store::openraft;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
 use databend_common_meta_stoerr::MetaStorageError;
+use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use databend_common_meta_types::protobuf::raft_service_server::RaftServiceServer;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::Event;
 use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Cmd;
 use databend_common_meta_types::CommittedLeaderId;
@@ -52,6 +56,8 @@ use databend_common_meta_types::LogEntry;
 use databend_common_meta_types::LogId;
 use databend_common_meta_types::MembershipNode;
 use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::MetaDataError;
+use databend_common_meta_types::MetaDataReadError;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::MetaManagementError;
 use databend_common_meta_types::MetaNetworkError;
@@ -60,6 +66,8 @@ use databend_common_meta_types::MetaStartupError;
 use databend_common_meta_types::Node;
 use databend_common_meta_types::NodeId;
 use databend_common_meta_types::RaftMetrics;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
 use databend_common_meta_types::TypeConfig;
 use futures::channel::oneshot;
 use itertools::Itertools;
@@ -74,13 +82,18 @@ use openraft::Config;
 use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
+use tonic::Status;
 
 use crate::configs::Config as MetaConfig;
+use crate::message::AddLearnerRequest;
+use crate::message::DemoteRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
+use crate::message::TransferLeaderRequest;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::forwarder::MetaForwarder;
 use crate::meta_service::meta_leader::MetaLeader;
@@ -1125,6 +1138,89 @@ impl MetaNode {
         Ok(resp)
     }
 
+    /// Add a node to the cluster as a learner, via the leader.
+    #[minitrace::trace]
+    pub async fn add_learner(&self, req: AddLearnerRequest) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest::new(
+            1,
+            ForwardRequestBody::AddLearner(req),
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Promote a learner to a voter, via the leader.
+    #[minitrace::trace]
+    pub async fn promote(&self, node_id: NodeId) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest::new(
+            1,
+            ForwardRequestBody::Promote(PromoteRequest { node_id }),
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Demote a voter to a learner, via the leader.
+    #[minitrace::trace]
+    pub async fn demote(&self, node_id: NodeId) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest::new(
+            1,
+            ForwardRequestBody::Demote(DemoteRequest { node_id }),
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Transfer the leadership to a voter.
+    ///
+    /// The leader checks that the target has all the logs, then the target starts an election.
+    /// It returns when this node sees the target become the leader.
+    #[minitrace::trace]
+    pub async fn transfer_leader(&self, node_id: NodeId) -> Result<(), MetaAPIError> {
+        if self.raft.metrics().borrow().current_leader == Some(node_id) {
+            return Ok(());
+        }
+
+        self.handle_forwardable_request(ForwardRequest::new(
+            1,
+            ForwardRequestBody::TransferLeader(TransferLeaderRequest { node_id }),
+        ))
+        .await?;
+
+        if node_id == self.sto.id {
+            self.elect().await?;
+        } else {
+            MetaForwarder::new(self)
+                .forward(node_id, ForwardRequest::new(0, ForwardRequestBody::Elect))
+                .await?;
+        }
+
+        self.raft
+            .wait(Some(Duration::from_millis(10_000)))
+            .current_leader(node_id, "transfer leader")
+            .await
+            .map_err(|e| {
+                MetaDataError::ReadError(MetaDataReadError::new(
+                    "transfer_leader()",
+                    format!("wait for node-{} to become leader", node_id),
+                    &e,
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    /// Let this node start an election to become the leader.
+    #[minitrace::trace]
+    pub async fn elect(&self) -> Result<(), MetaAPIError> {
+        self.raft
+            .trigger()
+            .elect()
+            .await
+            .map_err(MetaDataError::from)?;
+        Ok(())
+    }
+
     /// Submit a write request to the known leader. Returns the response after applying the request.
     #[minitrace::trace]
     pub async fn write(&self, req: LogEntry) -> Result<AppliedState, MetaAPIError> {
@@ -1190,4 +1286,105 @@ impl MetaNode {
             Err(_e) => Err("dispatcher closed"),
         }
     }
+
+    /// Add a watcher, and return it along with the responses to send before the changes it receives.
+    ///
+    /// With `initial_flush`, these are the key-values in the range, followed by a marker of the end.
+    /// With `since_seq`, these are the retained changes in the range made at or after it.
+    ///
+    /// They are read with the state machine locked, and the watcher is submitted to the dispatcher before unlocking it.
+    /// Since changes are sent to the dispatcher with the state machine locked too,
+    /// the watcher receives exactly the changes made after the returned responses.
+    pub(crate) async fn add_watcher_with_initial(
+        &self,
+        request: WatchRequest,
+        tx: WatcherSender,
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
+        if request.initial_flush && request.since_seq.is_some() {
+            return Err(Status::invalid_argument(
+                "initial_flush and since_seq can not be used together",
+            ));
+        }
+
+        let range = EventDispatcher::build_key_range(request.key.clone(), &request.key_end)
+            .map_err(Status::invalid_argument)?;
+        let in_range = |k: &str| match &request.key_end {
+            Some(_) => range.contains(&k.to_string()),
+            None => k == request.key,
+        };
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+
+        let initial = {
+            let sm = self.sto.state_machine.read().await;
+
+            let initial = if request.initial_flush {
+                let kvs = match &request.key_end {
+                    Some(_) => sm.range_kv(range.clone()).await,
+                    None => sm
+                        .get_maybe_expired_kv(&request.key)
+                        .await
+                        .map(|v| v.map(|v| (request.key.clone(), v)).into_iter().collect()),
+                };
+                let kvs = kvs.map_err(|e| Status::internal(e.to_string()))?;
+
+                let now_ms = SeqV::<()>::now_ms();
+                kvs.into_iter()
+                    .filter(|(_k, v)| !v.is_expired(now_ms))
+                    .map(|(key, v)| WatchResponse {
+                        event: Some(Event {
+                            key,
+                            current: Some(pb::SeqV::from(v)),
+                            prev: None,
+                        }),
+                        is_initialization: true,
+                    })
+                    // Mark the end of the initial key-values.
+                    .chain([WatchResponse {
+                        event: None,
+                        is_initialization: true,
+                    }])
+                    .collect()
+            } else if let Some(since_seq) = request.since_seq {
+                let filter = request.filter_type();
+                let changes = sm
+                    .changes_since(since_seq)
+                    .map_err(|e| Status::out_of_range(e.to_string()))?;
+
+                changes
+                    .filter(|c| in_range(c.ident.as_deref().unwrap_or_default()))
+                    .filter(|c| match filter {
+                        FilterType::All => true,
+                        FilterType::Update => c.result.is_some(),
+                        FilterType::Delete => c.result.is_none(),
+                    })
+                    .map(|c| WatchResponse {
+                        event: Some(Event {
+                            key: c.ident.clone().unwrap_or_default(),
+                            current: c.result.clone().map(pb::SeqV::from),
+                            prev: c.prev.clone().map(pb::SeqV::from),
+                        }),
+                        is_initialization: false,
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            self.dispatcher_handle.request(|d: &mut EventDispatcher| {
+                let add_res = d.add_watcher(request, tx);
+                let _ = resp_tx.send(add_res);
+            });
+
+            initial
+        };
+
+        let watcher = match resp_rx.await {
+            Ok(add_res) => add_res,
+            Err(_e) => Err("dispatcher closed"),
+        };
+        let watcher = watcher.map_err(Status::invalid_argument)?;
+
+        Ok((watcher, initial))
+    }
 }
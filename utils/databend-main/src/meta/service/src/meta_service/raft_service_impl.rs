@@ -39,6 +39,7 @@ use tonic::Status;
 
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
+use crate::message::ForwardResponse;
 use crate::meta_service::MetaNode;
 use crate::metrics::raft_metrics;
 
@@ -136,9 +137,16 @@ impl RaftService for RaftServiceImpl {
         async {
             let forward_req: ForwardRequest<ForwardRequestBody> = GrpcHelper::parse_req(request)?;
 
-            let res = self.meta_node.handle_forwardable_request(forward_req).await;
-
-            let res = res.map(|(_endpoint, forward_resp)| forward_resp);
+            // `Elect` is addressed to this node, not to the leader.
+            let res = if forward_req.body == ForwardRequestBody::Elect {
+                self.meta_node
+                    .elect()
+                    .await
+                    .map(|_| ForwardResponse::Elect(()))
+            } else {
+                let res = self.meta_node.handle_forwardable_request(forward_req).await;
+                res.map(|(_endpoint, forward_resp)| forward_resp)
+            };
 
             let raft_reply: RaftReply = res.into();
 
@@ -388,25 +388,20 @@ impl StoreInner {
 
     /// Export data that can be used to restore a meta-service node.
     ///
+    /// If `since_log_index` is specified, it exports only the logs at or after it, without the state machine.
+    /// Such an export is an increment to a previous export that contains all the logs before `since_log_index`.
+    ///
     /// Returns a `BoxStream<'a, Result<String, io::Error>>` that yields a series of JSON strings.
     #[futures_async_stream::try_stream(boxed, ok = String, error = io::Error)]
-    pub async fn export(self: Arc<StoreInner>) {
+    pub async fn export(self: Arc<StoreInner>, since_log_index: Option<u64>) {
         // Convert an error occurred during export to `io::Error(InvalidData)`.
         fn invalid_data(e: impl std::error::Error + Send + Sync + 'static) -> io::Error {
             io::Error::new(ErrorKind::InvalidData, e)
         }
 
-        // Lock all data components so that we have a consistent view.
-        //
         // Hold the snapshot lock to prevent snapshot from being replaced until exporting finished.
         // Holding this lock prevent logs from being purged.
-        //
-        // Although vote and log must be consistent,
-        // it is OK to export RaftState and logs without transaction protection(i.e. they do not share a lock),
-        // if it guarantees no logs have a greater `vote` than `RaftState.HardState`.
         let current_snapshot = self.current_snapshot.read().await;
-        let raft_state = self.raft_state.read().await;
-        let log = self.log.read().await;
 
         // Export data header first
         {
@@ -420,64 +415,110 @@ impl StoreInner {
             }
         }
 
-        // Export raft state
+        // Raft state and logs are copied while holding their locks,
+        // and are sent after the locks are released,
+        // so that a slow reader does not block appending logs.
+        //
+        // Although vote and log must be consistent,
+        // it is OK to export RaftState and logs without transaction protection(i.e. they do not share a lock),
+        // if it guarantees no logs have a greater `vote` than `RaftState.HardState`.
+        let mut lines = vec![];
         {
-            let tree_name = &raft_state.inner.name;
+            let raft_state = self.raft_state.read().await;
+            let log = self.log.read().await;
 
-            let ks = raft_state.inner.key_space::<RaftStateKV>();
+            // Export raft state
+            {
+                let tree_name = &raft_state.inner.name;
 
-            let id = ks.get(&RaftStateKey::Id)?.map(NodeId::from);
+                let ks = raft_state.inner.key_space::<RaftStateKV>();
 
-            if let Some(id) = id {
-                let ent_id = RaftStoreEntry::RaftStateKV {
-                    key: RaftStateKey::Id,
-                    value: RaftStateValue::NodeId(id),
-                };
+                let id = ks.get(&RaftStateKey::Id)?.map(NodeId::from);
 
-                let s = serde_json::to_string(&(tree_name, ent_id)).map_err(invalid_data)?;
-                yield s;
-            }
+                if let Some(id) = id {
+                    let ent_id = RaftStoreEntry::RaftStateKV {
+                        key: RaftStateKey::Id,
+                        value: RaftStateValue::NodeId(id),
+                    };
 
-            let vote = ks.get(&RaftStateKey::HardState)?.map(Vote::from);
+                    let s = serde_json::to_string(&(tree_name, ent_id)).map_err(invalid_data)?;
+                    lines.push(s);
+                }
 
-            if let Some(vote) = vote {
-                let ent_vote = RaftStoreEntry::RaftStateKV {
-                    key: RaftStateKey::HardState,
-                    value: RaftStateValue::HardState(vote),
-                };
+                let vote = ks.get(&RaftStateKey::HardState)?.map(Vote::from);
 
-                let s = serde_json::to_string(&(tree_name, ent_vote)).map_err(invalid_data)?;
-                yield s;
-            }
+                if let Some(vote) = vote {
+                    let ent_vote = RaftStoreEntry::RaftStateKV {
+                        key: RaftStateKey::HardState,
+                        value: RaftStateValue::HardState(vote),
+                    };
 
-            let committed = ks
-                .get(&RaftStateKey::Committed)?
-                .and_then(Option::<LogId>::from);
+                    let s = serde_json::to_string(&(tree_name, ent_vote)).map_err(invalid_data)?;
+                    lines.push(s);
+                }
+
+                let committed = ks
+                    .get(&RaftStateKey::Committed)?
+                    .and_then(Option::<LogId>::from);
+
+                let ent_committed = RaftStoreEntry::RaftStateKV {
+                    key: RaftStateKey::Committed,
+                    value: RaftStateValue::Committed(committed),
+                };
 
-            let ent_committed = RaftStoreEntry::RaftStateKV {
-                key: RaftStateKey::Committed,
-                value: RaftStateValue::Committed(committed),
+                let s = serde_json::to_string(&(tree_name, ent_committed)).map_err(invalid_data)?;
+                lines.push(s);
             };
 
-            let s = serde_json::to_string(&(tree_name, ent_committed)).map_err(invalid_data)?;
-            yield s;
-        };
+            // Export logs that has smaller or equal leader id as `vote`
+            {
+                if let Some(since) = since_log_index {
+                    let purged = log.get_last_purged()?;
+
+                    if let Some(purged) = purged {
+                        if purged.index >= since {
+                            return Err(io::Error::new(
+                                ErrorKind::NotFound,
+                                format!(
+                                    "can not export logs since index {}: logs upto {} are purged",
+                                    since, purged
+                                ),
+                            ));
+                        }
+                    }
+                }
 
-        // Export logs that has smaller or equal leader id as `vote`
-        {
-            let tree_name = &log.inner.name;
+                let tree_name = &log.inner.name;
 
-            let log_kvs = log.inner.export()?;
+                let log_kvs = log.inner.export()?;
 
-            for kv in log_kvs.iter() {
-                let kv_entry = RaftStoreEntry::deserialize(&kv[0], &kv[1])?;
+                for kv in log_kvs.iter() {
+                    let kv_entry = RaftStoreEntry::deserialize(&kv[0], &kv[1])?;
 
-                let tree_kv = (tree_name, kv_entry);
-                let line = serde_json::to_string(&tree_kv).map_err(invalid_data)?;
-                yield line;
+                    if let (Some(since), RaftStoreEntry::Logs { key, .. }) =
+                        (since_log_index, &kv_entry)
+                    {
+                        if *key < since {
+                            continue;
+                        }
+                    }
+
+                    let tree_kv = (tree_name, kv_entry);
+                    let line = serde_json::to_string(&tree_kv).map_err(invalid_data)?;
+                    lines.push(line);
+                }
             }
         }
 
+        for line in lines {
+            yield line;
+        }
+
+        // An incremental export contains only logs
+        if since_log_index.is_some() {
+            return Ok(());
+        }
+
         // Export snapshot of state machine
         {
             // NOTE:
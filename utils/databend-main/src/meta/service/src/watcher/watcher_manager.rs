@@ -164,6 +164,7 @@ impl EventDispatcher {
                     current: current.clone().map(pb::SeqV::from),
                     prev: prev.clone().map(pb::SeqV::from),
                 }),
+                is_initialization: false,
             };
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
@@ -224,7 +225,7 @@ impl EventDispatcher {
         server_metrics::incr_watchers(-1);
     }
 
-    fn build_key_range(
+    pub(crate) fn build_key_range(
         key: String,
         key_end: &Option<String>,
     ) -> Result<Range<String>, &'static str> {
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_meta::api::http::v1::membership::promote;
+use databend_meta::configs::Config;
+use databend_meta::meta_service::MetaNode;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::service::MetaSrvTestContext;
+
+/// Test the token authentication of http API "/v1/ctrl/promote"
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_membership_api_auth() -> anyhow::Result<()> {
+    let mut tc0 = MetaSrvTestContext::new(0);
+    let mn0 = MetaNode::start(&tc0.config).await?;
+
+    // Disabled if no token is configured.
+    assert_eq!(
+        StatusCode::FORBIDDEN,
+        call_promote(&mn0, &tc0.config, Some("")).await
+    );
+
+    tc0.config.admin_api_token = "secret".to_string();
+
+    assert_eq!(
+        StatusCode::UNAUTHORIZED,
+        call_promote(&mn0, &tc0.config, None).await
+    );
+    assert_eq!(
+        StatusCode::UNAUTHORIZED,
+        call_promote(&mn0, &tc0.config, Some("wrong")).await
+    );
+
+    // node-0 is already a voter.
+    assert_eq!(
+        StatusCode::OK,
+        call_promote(&mn0, &tc0.config, Some("secret")).await
+    );
+
+    Ok(())
+}
+
+async fn call_promote(mn: &Arc<MetaNode>, cfg: &Config, token: Option<&str>) -> StatusCode {
+    let router = Route::new()
+        .at("/v1/ctrl/promote", post(promote))
+        .data(mn.clone())
+        .data(cfg.clone());
+
+    let mut req = Request::builder()
+        .uri_str("/v1/ctrl/promote?node_id=0")
+        .method(Method::POST);
+    if let Some(token) = token {
+        req = req.header("Authorization", format!("Bearer {}", token));
+    }
+
+    router.get_response(req.finish()).await.status()
+}
@@ -14,4 +14,5 @@
 
 pub mod cluster_state_test;
 pub mod config;
+pub mod membership;
 pub mod metrics;
@@ -45,9 +45,12 @@ metric_api_address = "127.0.0.1:8000"
 admin_api_address = "127.0.0.1:9000"
 admin_tls_server_cert = "admin tls cert"
 admin_tls_server_key = "admin tls key"
+admin_api_token = "admin token"
 grpc_api_address = "127.0.0.1:10000"
 grpc_tls_server_cert = "grpc server cert"
 grpc_tls_server_key = "grpc server key"
+etcd_api_address = "127.0.0.1:12000"
+etcd_api_password = "etcd password"
 
 [raft_config]
 config_id = "raft config id"
@@ -75,9 +78,12 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.admin_api_address, "127.0.0.1:9000");
         assert_eq!(cfg.admin_tls_server_cert, "admin tls cert");
         assert_eq!(cfg.admin_tls_server_key, "admin tls key");
+        assert_eq!(cfg.admin_api_token, "admin token");
         assert_eq!(cfg.grpc_api_address, "127.0.0.1:10000");
         assert_eq!(cfg.grpc_tls_server_cert, "grpc server cert");
         assert_eq!(cfg.grpc_tls_server_key, "grpc server key");
+        assert_eq!(cfg.etcd_api_address, "127.0.0.1:12000");
+        assert_eq!(cfg.etcd_api_password, "etcd password");
         assert_eq!(cfg.raft_config.config_id, "raft config id");
         assert_eq!(cfg.raft_config.raft_listen_host, "127.0.0.1");
         assert_eq!(cfg.raft_config.raft_api_port, 11000);
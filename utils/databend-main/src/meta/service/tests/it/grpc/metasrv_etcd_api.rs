@@ -0,0 +1,449 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test the etcd v3 compatible gRPC API.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::Stoppable;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::etcd::etcdserverpb::auth_client::AuthClient;
+use databend_common_meta_types::etcd::etcdserverpb::compare;
+use databend_common_meta_types::etcd::etcdserverpb::kv_client::KvClient;
+use databend_common_meta_types::etcd::etcdserverpb::lease_client::LeaseClient;
+use databend_common_meta_types::etcd::etcdserverpb::request_op;
+use databend_common_meta_types::etcd::etcdserverpb::response_op;
+use databend_common_meta_types::etcd::etcdserverpb::watch_client::WatchClient;
+use databend_common_meta_types::etcd::etcdserverpb::watch_request::RequestUnion;
+use databend_common_meta_types::etcd::etcdserverpb::AuthenticateRequest;
+use databend_common_meta_types::etcd::etcdserverpb::Compare;
+use databend_common_meta_types::etcd::etcdserverpb::DeleteRangeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseGrantRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseLeasesRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseRevokeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::LeaseTimeToLiveRequest;
+use databend_common_meta_types::etcd::etcdserverpb::PutRequest;
+use databend_common_meta_types::etcd::etcdserverpb::RangeRequest;
+use databend_common_meta_types::etcd::etcdserverpb::RequestOp;
+use databend_common_meta_types::etcd::etcdserverpb::TxnRequest;
+use databend_common_meta_types::etcd::etcdserverpb::WatchCancelRequest;
+use databend_common_meta_types::etcd::etcdserverpb::WatchCreateRequest;
+use databend_common_meta_types::etcd::etcdserverpb::WatchRequest;
+use databend_common_meta_types::etcd::mvccpb::event::EventType;
+use databend_meta::api::EtcdServer;
+use test_harness::test;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::codegen::InterceptedService;
+use tonic::metadata::Ascii;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::transport::Channel;
+use tonic::Code;
+use tonic::Request;
+use tonic::Status;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::service::next_port;
+use crate::tests::service::start_metasrv_with_context;
+use crate::tests::service::MetaSrvTestContext;
+
+const ROOT_PASSWORD: &str = "etcd_root_password";
+
+/// Start a metasrv with the etcd API enabled, return the channel to the etcd API.
+async fn start_etcd_api() -> anyhow::Result<(MetaSrvTestContext, EtcdServer, Channel)> {
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.etcd_api_address = format!("127.0.0.1:{}", next_port());
+    tc.config.etcd_api_password = ROOT_PASSWORD.to_string();
+    start_metasrv_with_context(&mut tc).await?;
+
+    let meta_node = tc.grpc_srv.as_ref().map(|x| x.get_meta_node()).unwrap();
+    let mut srv = EtcdServer::create(tc.config.clone(), meta_node);
+    srv.start().await?;
+
+    let channel = Channel::from_shared(format!("http://{}", tc.config.etcd_api_address))?
+        .connect()
+        .await?;
+
+    Ok((tc, srv, channel))
+}
+
+/// Authenticate as root, return the channel that sends the token with each request.
+async fn authenticate(
+    channel: Channel,
+) -> anyhow::Result<InterceptedService<Channel, impl Interceptor + Clone>> {
+    let resp = AuthClient::new(channel.clone())
+        .authenticate(AuthenticateRequest {
+            name: "root".to_string(),
+            password: ROOT_PASSWORD.to_string(),
+        })
+        .await?
+        .into_inner();
+    let token: MetadataValue<Ascii> = resp.token.parse()?;
+
+    Ok(InterceptedService::new(
+        channel,
+        move |mut req: Request<()>| -> Result<Request<()>, Status> {
+            req.metadata_mut().insert("token", token.clone());
+            Ok(req)
+        },
+    ))
+}
+
+fn put(key: &str, value: &str) -> PutRequest {
+    PutRequest {
+        key: key.as_bytes().to_vec(),
+        value: value.as_bytes().to_vec(),
+        ..Default::default()
+    }
+}
+
+fn get(key: &str) -> RangeRequest {
+    RangeRequest {
+        key: key.as_bytes().to_vec(),
+        ..Default::default()
+    }
+}
+
+fn get_prefix(prefix: &str) -> RangeRequest {
+    let mut range_end = prefix.as_bytes().to_vec();
+    *range_end.last_mut().unwrap() += 1;
+    RangeRequest {
+        key: prefix.as_bytes().to_vec(),
+        range_end,
+        ..Default::default()
+    }
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_etcd_auth() -> anyhow::Result<()> {
+    let (_tc, _srv, channel) = start_etcd_api().await?;
+
+    // Without a token.
+    let err = KvClient::new(channel.clone())
+        .range(get("a/1"))
+        .await
+        .unwrap_err();
+    assert_eq!(Code::Unauthenticated, err.code());
+
+    let err = LeaseClient::new(channel.clone())
+        .lease_leases(LeaseLeasesRequest {})
+        .await
+        .unwrap_err();
+    assert_eq!(Code::Unauthenticated, err.code());
+
+    // With a forged token.
+    let mut kv = KvClient::with_interceptor(channel.clone(), |mut req: Request<()>| {
+        req.metadata_mut()
+            .insert("token", MetadataValue::from_static("forged"));
+        Ok(req)
+    });
+    let err = kv.range(get("a/1")).await.unwrap_err();
+    assert_eq!(Code::Unauthenticated, err.code());
+
+    // Unknown user.
+    let err = AuthClient::new(channel.clone())
+        .authenticate(AuthenticateRequest {
+            name: "nobody".to_string(),
+            password: ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(Code::Unauthenticated, err.code());
+
+    // Wrong password.
+    for password in ["", "wrong", "etcd_root_passwor"] {
+        let err = AuthClient::new(channel.clone())
+            .authenticate(AuthenticateRequest {
+                name: "root".to_string(),
+                password: password.to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(Code::Unauthenticated, err.code());
+    }
+
+    // Authenticated.
+    let mut kv = KvClient::new(authenticate(channel).await?);
+    let resp = kv.range(get("a/1")).await?.into_inner();
+    assert_eq!(0, resp.count);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_etcd_kv() -> anyhow::Result<()> {
+    let (tc, _srv, channel) = start_etcd_api().await?;
+    let mut kv = KvClient::new(authenticate(channel).await?);
+
+    kv.put(put("a/1", "v1")).await?;
+    kv.put(put("a/2", "v2")).await?;
+    kv.put(put("b/1", "v3")).await?;
+
+    // Written by etcd, read by a meta-service client.
+    let client = tc.grpc_client().await?;
+    let got = client.get_kv("a/1").await?;
+    assert_eq!(b"v1".to_vec(), got.unwrap().data);
+
+    let resp = kv.range(get("a/1")).await?.into_inner();
+    assert_eq!(1, resp.count);
+    assert_eq!(b"v1".to_vec(), resp.kvs[0].value);
+    assert!(resp.kvs[0].mod_revision > 0);
+    assert!(resp.header.unwrap().revision >= resp.kvs[0].mod_revision);
+
+    let resp = kv.range(get_prefix("a/")).await?.into_inner();
+    let keys = resp.kvs.iter().map(|kv| kv.key.clone()).collect::<Vec<_>>();
+    assert_eq!(vec![b"a/1".to_vec(), b"a/2".to_vec()], keys);
+
+    let resp = kv
+        .range(RangeRequest {
+            limit: 1,
+            ..get_prefix("a/")
+        })
+        .await?
+        .into_inner();
+    assert_eq!(1, resp.kvs.len());
+    assert_eq!(2, resp.count);
+    assert!(resp.more);
+
+    let resp = kv
+        .put(PutRequest {
+            prev_kv: true,
+            ..put("a/1", "v1.1")
+        })
+        .await?
+        .into_inner();
+    assert_eq!(b"v1".to_vec(), resp.prev_kv.unwrap().value);
+
+    // Create if absent: `create_revision(key) == 0`.
+    let create_if_absent = |key: &str| TxnRequest {
+        compare: vec![Compare {
+            result: compare::CompareResult::Equal as i32,
+            target: compare::CompareTarget::Create as i32,
+            key: key.as_bytes().to_vec(),
+            target_union: Some(compare::TargetUnion::CreateRevision(0)),
+            range_end: vec![],
+        }],
+        success: vec![RequestOp {
+            request: Some(request_op::Request::RequestPut(put(key, "created"))),
+        }],
+        failure: vec![RequestOp {
+            request: Some(request_op::Request::RequestRange(get(key))),
+        }],
+    };
+
+    let resp = kv.txn(create_if_absent("c/1")).await?.into_inner();
+    assert!(resp.succeeded);
+
+    let resp = kv.txn(create_if_absent("c/1")).await?.into_inner();
+    assert!(!resp.succeeded);
+    match &resp.responses[0].response {
+        Some(response_op::Response::ResponseRange(r)) => {
+            assert_eq!(b"created".to_vec(), r.kvs[0].value);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let resp = kv
+        .delete_range(DeleteRangeRequest {
+            key: b"a/".to_vec(),
+            range_end: b"a0".to_vec(),
+            prev_kv: true,
+        })
+        .await?
+        .into_inner();
+    assert_eq!(2, resp.deleted);
+    assert_eq!(2, resp.prev_kvs.len());
+
+    let resp = kv.range(get_prefix("a/")).await?.into_inner();
+    assert_eq!(0, resp.count);
+
+    let resp = kv.range(get("b/1")).await?.into_inner();
+    assert_eq!(1, resp.count);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_etcd_watch() -> anyhow::Result<()> {
+    let (_tc, _srv, channel) = start_etcd_api().await?;
+    let channel = authenticate(channel).await?;
+    let mut kv = KvClient::new(channel.clone());
+    let mut watch = WatchClient::new(channel);
+
+    let (req_tx, req_rx) = mpsc::channel(4);
+    let mut events = watch.watch(ReceiverStream::new(req_rx)).await?.into_inner();
+
+    let range = get_prefix("w/");
+    req_tx
+        .send(WatchRequest {
+            request_union: Some(RequestUnion::CreateRequest(WatchCreateRequest {
+                key: range.key,
+                range_end: range.range_end,
+                prev_kv: true,
+                ..Default::default()
+            })),
+        })
+        .await?;
+
+    let resp = events.message().await?.unwrap();
+    assert!(resp.created);
+    let watch_id = resp.watch_id;
+
+    kv.put(put("x/1", "not watched")).await?;
+    kv.put(put("w/1", "v1")).await?;
+    kv.put(put("w/1", "v2")).await?;
+    kv.delete_range(DeleteRangeRequest {
+        key: b"w/1".to_vec(),
+        ..Default::default()
+    })
+    .await?;
+
+    let mut got = vec![];
+    for _ in 0..3 {
+        let resp = events.message().await?.unwrap();
+        assert_eq!(watch_id, resp.watch_id);
+        for ev in resp.events {
+            let kv = ev.kv.unwrap();
+            let prev = ev.prev_kv.map(|p| String::from_utf8(p.value).unwrap());
+            got.push((
+                ev.r#type,
+                String::from_utf8(kv.key)?,
+                String::from_utf8(kv.value)?,
+                prev,
+            ));
+        }
+    }
+
+    assert_eq!(
+        vec![
+            (EventType::Put as i32, s("w/1"), s("v1"), None),
+            (EventType::Put as i32, s("w/1"), s("v2"), Some(s("v1"))),
+            (EventType::Delete as i32, s("w/1"), s(""), Some(s("v2"))),
+        ],
+        got
+    );
+
+    req_tx
+        .send(WatchRequest {
+            request_union: Some(RequestUnion::CancelRequest(WatchCancelRequest { watch_id })),
+        })
+        .await?;
+
+    let resp = events.message().await?.unwrap();
+    assert!(resp.canceled);
+    assert_eq!(watch_id, resp.watch_id);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_etcd_lease() -> anyhow::Result<()> {
+    let (_tc, _srv, channel) = start_etcd_api().await?;
+    let channel = authenticate(channel).await?;
+    let mut kv = KvClient::new(channel.clone());
+    let mut lease = LeaseClient::new(channel);
+
+    let resp = lease
+        .lease_grant(LeaseGrantRequest { ttl: 60, id: 0 })
+        .await?
+        .into_inner();
+    let id = resp.id;
+    assert!(id > 0);
+    assert_eq!(60, resp.ttl);
+
+    kv.put(PutRequest {
+        lease: id,
+        ..put("l/1", "v1")
+    })
+    .await?;
+    kv.put(put("l/2", "not leased")).await?;
+
+    // Internal keys of leases are not visible.
+    let resp = kv
+        .range(RangeRequest {
+            key: vec![0],
+            range_end: vec![0],
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    assert_eq!(2, resp.count);
+
+    let resp = lease
+        .lease_time_to_live(LeaseTimeToLiveRequest { id, keys: true })
+        .await?
+        .into_inner();
+    assert_eq!(60, resp.granted_ttl);
+    assert!(resp.ttl > 0 && resp.ttl <= 60);
+    assert_eq!(vec![b"l/1".to_vec()], resp.keys);
+
+    let resp = lease
+        .lease_leases(LeaseLeasesRequest {})
+        .await?
+        .into_inner();
+    assert_eq!(
+        vec![id],
+        resp.leases.iter().map(|l| l.id).collect::<Vec<_>>()
+    );
+
+    // Revoking deletes the attached keys.
+    lease.lease_revoke(LeaseRevokeRequest { id }).await?;
+
+    let resp = kv.range(get_prefix("l/")).await?.into_inner();
+    let keys = resp.kvs.iter().map(|kv| kv.key.clone()).collect::<Vec<_>>();
+    assert_eq!(vec![b"l/2".to_vec()], keys);
+
+    let resp = lease
+        .lease_time_to_live(LeaseTimeToLiveRequest { id, keys: false })
+        .await?
+        .into_inner();
+    assert_eq!(-1, resp.ttl);
+
+    // Keys expire along with the lease.
+    let resp = lease
+        .lease_grant(LeaseGrantRequest { ttl: 1, id: 0 })
+        .await?
+        .into_inner();
+    kv.put(PutRequest {
+        lease: resp.id,
+        ..put("l/3", "v3")
+    })
+    .await?;
+
+    tokio::time::sleep(Duration::from_millis(3_000)).await;
+
+    let resp = kv.range(get("l/3")).await?.into_inner();
+    assert_eq!(0, resp.count);
+
+    // Putting with a non-existent lease fails.
+    let res = kv
+        .put(PutRequest {
+            lease: 1_000_000,
+            ..put("l/4", "v4")
+        })
+        .await;
+    assert_eq!(tonic::Code::NotFound, res.unwrap_err().code());
+
+    Ok(())
+}
+
+fn s(x: &str) -> String {
+    x.to_string()
+}
@@ -61,6 +61,7 @@ async fn test_export() -> anyhow::Result<()> {
     let exported = grpc_client
         .export_v1(tonic::Request::new(pb::ExportRequest {
             chunk_size: Some(1),
+            since_log_index: None,
         }))
         .await?;
 
@@ -96,9 +97,67 @@ async fn test_export() -> anyhow::Result<()> {
         r#"["state_machine/0",{"GenericKV":{"key":"wow","value":{"seq":3,"meta":null,"data":[119,111,119]}}}]"#,
     ];
 
-    // The addresses are built from random number.
-    // Wash them.
-    let lines = lines
+    let lines = wash(&lines);
+
+    assert_eq!(want, lines);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_export_since_log_index() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Write some data
+    // - Export only the logs since index 5, without the state machine.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    info!("--- upsert kv");
+    {
+        for k in ["foo", "bar", "wow"] {
+            client.upsert_kv(UpsertKVReq::update(k, &b(k))).await?;
+        }
+    }
+
+    let mut grpc_client = client.make_established_client().await?;
+
+    let exported = grpc_client
+        .export_v1(tonic::Request::new(pb::ExportRequest {
+            chunk_size: None,
+            since_log_index: Some(5),
+        }))
+        .await?;
+
+    let mut stream = exported.into_inner();
+
+    let mut lines = vec![];
+    while let Some(chunk_res) = stream.next().await {
+        let chunk = chunk_res?;
+        lines.extend_from_slice(&chunk.data);
+    }
+
+    let want = vec![
+        r#"["test-29000-raft_state",{"RaftStateKV":{"key":"Id","value":{"NodeId":0}}}]"#,
+        r#"["test-29000-raft_state",{"RaftStateKV":{"key":"HardState","value":{"HardState":{"leader_id":{"term":1,"node_id":0},"committed":true}}}}]"#,
+        r#"["test-29000-raft_state",{"RaftStateKV":{"key":"Committed","value":{"Committed":{"leader_id":{"term":1,"node_id":0},"index":6}}}}]"#,
+        r#"["test-29000-raft_log",{"Logs":{"key":5,"value":{"log_id":{"leader_id":{"term":1,"node_id":0},"index":5},"payload":{"Normal":{"txid":null,"time_ms":1111111111111,"cmd":{"UpsertKV":{"key":"bar","seq":{"GE":0},"value":{"Update":[98,97,114]},"value_meta":null}}}}}}}]"#,
+        r#"["test-29000-raft_log",{"Logs":{"key":6,"value":{"log_id":{"leader_id":{"term":1,"node_id":0},"index":6},"payload":{"Normal":{"txid":null,"time_ms":1111111111111,"cmd":{"UpsertKV":{"key":"wow","seq":{"GE":0},"value":{"Update":[119,111,119]},"value_meta":null}}}}}}}]"#,
+    ];
+
+    let lines = wash(&lines);
+
+    assert_eq!(want, lines);
+
+    Ok(())
+}
+
+/// The addresses are built from random number.
+/// Wash them.
+fn wash(lines: &[String]) -> Vec<String> {
+    lines
         .iter()
         .map(|x| {
             Regex::new(r"29\d\d\d")
@@ -118,11 +177,7 @@ async fn test_export() -> anyhow::Result<()> {
                 .replace_all(&x, "1111111111111")
                 .to_string()
         })
-        .collect::<Vec<_>>();
-
-    assert_eq!(want, lines);
-
-    Ok(())
+        .collect::<Vec<_>>()
 }
 
 fn b(s: impl ToString) -> Vec<u8> {
@@ -30,6 +30,7 @@ use databend_common_meta_types::protobuf::KvMeta;
 use databend_common_meta_types::protobuf::SeqV;
 use databend_common_meta_types::protobuf::TxnRequest;
 use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::txn_condition;
 use databend_common_meta_types::txn_op;
 use databend_common_meta_types::ConditionResult;
@@ -131,6 +132,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let key_a = s("a");
@@ -189,6 +192,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let key = s(key_str);
@@ -252,6 +257,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -363,6 +370,8 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
         watch_client.request(watch).await?
     };
@@ -437,6 +446,111 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_watch_initial_flush() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch a range with initial_flush.
+    // - Assert watcher get the key-values in the range, the end marker, then the changes.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client.upsert_kv(UpsertKVReq::update("i_a", b"a")).await?;
+    client.upsert_kv(UpsertKVReq::update("i_b", b"b")).await?;
+    client.upsert_kv(UpsertKVReq::update("j", b"j")).await?;
+
+    let watch = WatchRequest {
+        key: s("i_"),
+        key_end: Some(s("i_z")),
+        filter_type: FilterType::All.into(),
+        initial_flush: true,
+        since_seq: None,
+    };
+    let mut watch_stream = client.request(watch).await?;
+
+    client.upsert_kv(UpsertKVReq::update("i_c", b"c")).await?;
+
+    let want = vec![
+        WatchResponse {
+            event: Some(add_event("i_a", 1, "a", None)),
+            is_initialization: true,
+        },
+        WatchResponse {
+            event: Some(add_event("i_b", 2, "b", None)),
+            is_initialization: true,
+        },
+        WatchResponse {
+            event: None,
+            is_initialization: true,
+        },
+        WatchResponse {
+            event: Some(add_event("i_c", 4, "c", None)),
+            is_initialization: false,
+        },
+    ];
+
+    for w in want {
+        let msg = watch_stream.message().await?.unwrap();
+        assert_eq!(w, msg);
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_watch_since_seq() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch a range since a seq.
+    // - Assert watcher get the retained changes since the seq, then the new changes.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client.upsert_kv(UpsertKVReq::update("r_a", b"a")).await?;
+    client.upsert_kv(UpsertKVReq::update("r_b", b"b")).await?;
+    client.upsert_kv(UpsertKVReq::delete("r_a")).await?;
+    client.upsert_kv(UpsertKVReq::update("s", b"s")).await?;
+
+    let watch = |since_seq| WatchRequest {
+        key: s("r_"),
+        key_end: Some(s("r_z")),
+        filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: Some(since_seq),
+    };
+    let mut watch_stream = client.request(watch(2)).await?;
+
+    client.upsert_kv(UpsertKVReq::update("r_c", b"c")).await?;
+
+    let want = vec![
+        add_event("r_b", 2, "b", None),
+        // A delete is made at the current seq
+        del_event("r_a", 1, "a", None),
+        add_event("r_c", 4, "c", None),
+    ];
+
+    for ev in want {
+        let msg = watch_stream.message().await?.unwrap();
+        assert_eq!(Some(ev), msg.event);
+        assert!(!msg.is_initialization);
+    }
+
+    info!("since_seq can not be used with initial_flush");
+    {
+        let res = client
+            .request(WatchRequest {
+                initial_flush: true,
+                ..watch(2)
+            })
+            .await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[minitrace::trace]
 async fn test_watch_stream_count() -> anyhow::Result<()> {
@@ -448,6 +562,8 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: None,
     };
 
     let client1 = make_client(&addr)?;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod metasrv_connection_error;
+pub mod metasrv_etcd_api;
 pub mod metasrv_grpc_api;
 mod metasrv_grpc_export;
 pub mod metasrv_grpc_get_client_info;
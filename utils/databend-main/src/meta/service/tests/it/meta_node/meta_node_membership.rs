@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::MetaDataError;
+use databend_meta::message::AddLearnerRequest;
+use databend_meta::meta_service::MetaNode;
+use log::info;
+use maplit::btreeset;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::start_meta_node_leader;
+use crate::tests::meta_node::timeout;
+use crate::tests::service::MetaSrvTestContext;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_membership_change() -> anyhow::Result<()> {
+    // - Start a single node cluster.
+    // - Add node-1 as a learner and promote it to a voter.
+    // - Transfer the leadership to node-1.
+    // - Demote node-0 to a learner.
+
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let mn0 = tc0.meta_node();
+
+    let mut tc1 = MetaSrvTestContext::new(1);
+    let mn1 = MetaNode::open_create(&tc1.config.raft_config, None, Some(())).await?;
+    tc1.meta_node = Some(mn1.clone());
+
+    info!("--- add node-1 as a learner");
+    {
+        let req = AddLearnerRequest {
+            node_id: 1,
+            endpoint: tc1.config.raft_config.raft_api_addr().await?,
+            grpc_api_advertise_address: tc1.config.grpc_api_advertise_address(),
+        };
+        mn0.add_learner(req).await?;
+
+        mn1.raft
+            .wait(timeout())
+            .current_leader(0, "node-1 receives logs from node-0")
+            .await?;
+    }
+
+    info!("--- demoting the only voter is refused");
+    {
+        let res = mn0.demote(0).await;
+        assert!(
+            matches!(
+                res,
+                Err(MetaAPIError::DataError(MetaDataError::MembershipRefused(_)))
+            ),
+            "got: {:?}",
+            res
+        );
+    }
+
+    info!("--- transfer the leadership to a learner is refused");
+    {
+        let res = mn0.transfer_leader(1).await;
+        assert!(
+            matches!(
+                res,
+                Err(MetaAPIError::DataError(MetaDataError::MembershipRefused(_)))
+            ),
+            "got: {:?}",
+            res
+        );
+    }
+
+    info!("--- promote node-1");
+    {
+        mn0.promote(1).await?;
+
+        mn0.raft
+            .wait(timeout())
+            .voter_ids(btreeset! {0,1}, "node-1 is a voter")
+            .await?;
+    }
+
+    info!("--- transfer the leadership to node-1");
+    {
+        mn0.transfer_leader(1).await?;
+
+        mn1.raft
+            .wait(timeout())
+            .current_leader(1, "node-1 is the leader")
+            .await?;
+    }
+
+    info!("--- demote node-0 via node-0, forwarded to the leader");
+    {
+        mn0.demote(0).await?;
+
+        mn1.raft
+            .wait(timeout())
+            .voter_ids(btreeset! {1}, "node-0 is demoted")
+            .await?;
+
+        mn1.raft
+            .wait(timeout())
+            .metrics(
+                |m| {
+                    m.membership_config
+                        .membership()
+                        .learner_ids()
+                        .any(|x| x == 0)
+                },
+                "node-0 is a learner",
+            )
+            .await?;
+    }
+
+    Ok(())
+}
@@ -33,7 +33,13 @@ fn build_proto() {
         &Path::new(&proto_dir).join(Path::new("request.proto")),
     ];
 
-    for proto in protos.iter() {
+    let etcd_proto_dir = Path::new(&proto_dir).join("etcd");
+    let etcd_protos = [
+        &Path::new(&etcd_proto_dir).join(Path::new("kv.proto")),
+        &Path::new(&etcd_proto_dir).join(Path::new("rpc.proto")),
+    ];
+
+    for proto in protos.iter().chain(etcd_protos.iter()) {
         println!("cargo:rerun-if-changed={}", proto.to_str().unwrap());
     }
 
@@ -139,4 +145,10 @@ fn build_proto() {
         )
         .compile_with_config(config, &protos, &[&proto_dir])
         .unwrap();
+
+    // The etcd v3 API is built on its own, the derives above are not meant for it.
+    tonic_build::configure()
+        .file_descriptor_set_path(out_dir.join("etcd_descriptor.bin"))
+        .compile(&etcd_protos, &[&etcd_proto_dir])
+        .unwrap();
 }
@@ -72,6 +72,7 @@ impl MetaAPIError {
                 },
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::MembershipRefused(_) => false,
             },
             MetaAPIError::ForwardToLeader(_) => {
                 // Leader is changing, wait a while and retry
@@ -85,6 +86,7 @@ impl MetaAPIError {
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ChangeMembershipError(_) => true,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::MembershipRefused(_) => false,
             },
         }
     }
@@ -134,6 +136,10 @@ pub enum MetaDataError {
     /// Error occurred when reading.
     #[error(transparent)]
     ReadError(#[from] MetaDataReadError),
+
+    /// A membership change is refused because it is not safe, e.g., the cluster may lose its quorum.
+    #[error("refused to change membership: {0}")]
+    MembershipRefused(AnyError),
 }
 
 /// Error occurred when a meta-node reads data.
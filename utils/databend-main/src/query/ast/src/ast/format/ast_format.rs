@@ -1432,6 +1432,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::new();
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1581,6 +1599,43 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::CreateTableRef {
+                kind,
+                name,
+                travel_point,
+                ..
+            } => {
+                let mut children = Vec::new();
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_name = format!("Action Create {} {}", kind, name);
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::DropTableRef { kind, name, .. } => {
+                let action_name = format!("Action Drop {} {}", kind, name);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| column.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let action_name =
+                    format!("Action Add Row Access Policy {} On ({})", policy, columns);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop Row Access Policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1809,6 +1864,61 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            columns_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_inverted_index(&mut self, stmt: &'ast RefreshInvertedIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index_name);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2044,6 +2154,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -2098,6 +2229,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -2673,6 +2825,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2763,6 +2945,42 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
 
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_workload_groups(&mut self) {
+        let ctx = AstFormatContext::new("ShowWorkloadGroups".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -3198,6 +3416,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
+            TimeTravelPoint::Branch(branch) => {
+                let name = format!("Branch {}", branch);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
+            TimeTravelPoint::Version(version) => {
+                let name = format!("Version {}", version);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
@@ -30,7 +30,6 @@ use crate::ast::CreateTableSource;
 use crate::ast::CreateTableStmt;
 use crate::ast::CreateViewStmt;
 use crate::ast::StreamPoint;
-use crate::ast::TimeTravelPoint;
 
 pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
     RcDoc::text("CREATE")
@@ -140,6 +139,30 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -221,10 +244,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             } else {
                 RcDoc::nil()
             }),
-        AlterTableAction::RevertTo { point } => match point {
-            TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
-            TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
-        },
+        AlterTableAction::RevertTo { point } => RcDoc::text(format!(" AT{point}")),
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
             doc = doc.append(RcDoc::text("SET OPTIONS: "));
@@ -233,6 +253,12 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ AlterTableAction::CreateTableRef { .. }
+        | action @ AlterTableAction::DropTableRef { .. }
+        | action @ AlterTableAction::AddRowAccessPolicy { .. }
+        | action @ AlterTableAction::DropRowAccessPolicy { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -261,6 +261,9 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(#[drive(skip)] String),
     Timestamp(Box<Expr>),
+    Tag(#[drive(skip)] String),
+    Branch(#[drive(skip)] String),
+    Version(#[drive(skip)] u64),
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -489,20 +492,12 @@ impl Display for TableReference {
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
 
-                if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-                    write!(f, " AT (SNAPSHOT => {sid})")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
                 }
 
-                if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
-                    write!(f, " AT (TIMESTAMP => {ts})")?;
-                }
-
-                if let Some(TimeTravelPoint::Snapshot(sid)) = since_point {
-                    write!(f, " SINCE (SNAPSHOT => {sid})")?;
-                }
-
-                if let Some(TimeTravelPoint::Timestamp(ts)) = since_point {
-                    write!(f, " SINCE (TIMESTAMP => {ts})")?;
+                if let Some(since_point) = since_point {
+                    write!(f, " SINCE{since_point}")?;
                 }
 
                 if let Some(alias) = alias {
@@ -840,6 +835,15 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, " (TAG => '{name}')")?;
+            }
+            TimeTravelPoint::Branch(name) => {
+                write!(f, " (BRANCH => '{name}')")?;
+            }
+            TimeTravelPoint::Version(version) => {
+                write!(f, " (VERSION => {version})")?;
+            }
         }
 
         Ok(())
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -19,6 +20,9 @@ use databend_common_meta_app::schema::CreateOption;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_dot_separated_list;
+use crate::ast::write_space_separated_string_map;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -93,3 +97,113 @@ impl Display for RefreshIndexStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateInvertedIndexStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+
+    pub index_name: Identifier,
+
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+
+    pub columns: Vec<Identifier>,
+    #[drive(skip)]
+    pub sync_creation: bool,
+    #[drive(skip)]
+    pub index_options: BTreeMap<String, String>,
+}
+
+impl Display for CreateInvertedIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        if !self.sync_creation {
+            write!(f, "ASYNC ")?;
+        }
+        write!(f, "INVERTED INDEX")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, " IF NOT EXISTS")?;
+        }
+
+        write!(f, " {}", self.index_name)?;
+        write!(f, " ON ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ")")?;
+        if !self.index_options.is_empty() {
+            write!(f, " ")?;
+            write_space_separated_string_map(f, &self.index_options)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropInvertedIndexStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub index_name: Identifier,
+
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for DropInvertedIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP INVERTED INDEX")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+
+        write!(f, " {} ON ", self.index_name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct RefreshInvertedIndexStmt {
+    pub index_name: Identifier,
+
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    #[drive(skip)]
+    pub limit: Option<u64>,
+}
+
+impl Display for RefreshInvertedIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REFRESH INVERTED INDEX {} ON ", self.index_name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(limit) = self.limit {
+            write!(f, " LIMIT {limit}")?;
+        }
+        Ok(())
+    }
+}
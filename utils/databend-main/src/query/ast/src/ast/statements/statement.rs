@@ -165,6 +165,9 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
+    RefreshInvertedIndex(RefreshInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -280,6 +283,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -296,6 +304,12 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -553,6 +567,9 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -676,6 +693,9 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -691,6 +711,10 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -19,6 +19,7 @@ use std::format;
 use std::time::Duration;
 
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableRefKind;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
@@ -236,6 +237,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -254,6 +261,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -385,6 +405,28 @@ pub enum AlterTableAction {
         #[drive(skip)]
         set_options: BTreeMap<String, String>,
     },
+    CreateTableRef {
+        #[drive(skip)]
+        create_option: CreateOption,
+        #[drive(skip)]
+        kind: TableRefKind,
+        name: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTableRef {
+        #[drive(skip)]
+        if_exists: bool,
+        #[drive(skip)]
+        kind: TableRefKind,
+        name: Identifier,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -439,6 +481,44 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::CreateTableRef {
+                create_option,
+                kind,
+                name,
+                travel_point,
+            } => {
+                write!(f, "CREATE ")?;
+                if let CreateOption::CreateOrReplace = create_option {
+                    write!(f, "OR REPLACE ")?;
+                }
+                write!(f, "{kind} ")?;
+                if let CreateOption::CreateIfNotExists = create_option {
+                    write!(f, "IF NOT EXISTS ")?;
+                }
+                write!(f, "{name}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+            }
+            AlterTableAction::DropTableRef {
+                if_exists,
+                kind,
+                name,
+            } => {
+                write!(f, "DROP {kind} ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "{name}")?;
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
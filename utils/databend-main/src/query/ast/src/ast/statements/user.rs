@@ -26,6 +26,7 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct CreateUserStmt {
@@ -158,6 +159,13 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    /// Privileges narrowed down to some columns of a table, e.g. `SELECT, UPDATE(c1, c2) ON db.t`.
+    ColumnPrivs {
+        #[drive(skip)]
+        privileges: Vec<UserPrivilegeType>,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -186,6 +194,14 @@ pub enum UserOptionItem {
     UnsetNetworkPolicy,
     SetPasswordPolicy(#[drive(skip)] String),
     UnsetPasswordPolicy,
+    SetWorkloadGroup(#[drive(skip)] String),
+    UnsetWorkloadGroup,
+    SetMaxQueryMemoryUsage(#[drive(skip)] u64),
+    UnsetMaxQueryMemoryUsage,
+    SetPublicKey(#[drive(skip)] String),
+    UnsetPublicKey,
+    SetPublicKey2(#[drive(skip)] String),
+    UnsetPublicKey2,
 }
 
 impl UserOptionItem {
@@ -199,6 +215,14 @@ impl UserOptionItem {
             Self::UnsetNetworkPolicy => option.set_network_policy(None),
             Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
             Self::UnsetPasswordPolicy => option.set_password_policy(None),
+            Self::SetWorkloadGroup(v) => option.set_workload_group(Some(v.clone())),
+            Self::UnsetWorkloadGroup => option.set_workload_group(None),
+            Self::SetMaxQueryMemoryUsage(v) => option.set_max_query_memory_usage(Some(*v)),
+            Self::UnsetMaxQueryMemoryUsage => option.set_max_query_memory_usage(None),
+            Self::SetPublicKey(v) => option.set_public_key(Some(v.clone())),
+            Self::UnsetPublicKey => option.set_public_key(None),
+            Self::SetPublicKey2(v) => option.set_public_key_2(Some(v.clone())),
+            Self::UnsetPublicKey2 => option.set_public_key_2(None),
         }
     }
 }
@@ -231,6 +255,36 @@ impl Display for AccountMgrSource {
                     AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}")?,
                 }
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                write!(f, " ")?;
+                write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                write!(f, "(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON")?;
+                match level {
+                    AccountMgrLevel::Table(database_name, table_name) => {
+                        if let Some(database_name) = database_name {
+                            write!(f, " {database_name}.{table_name}")?;
+                        } else {
+                            write!(f, " {table_name}")?;
+                        }
+                    }
+                    AccountMgrLevel::Global => write!(f, " *.*")?,
+                    AccountMgrLevel::Database(database_name) => {
+                        if let Some(database_name) = database_name {
+                            write!(f, " {database_name}.*")?;
+                        } else {
+                            write!(f, " *")?;
+                        }
+                    }
+                    AccountMgrLevel::UDF(udf) => write!(f, " UDF {udf}")?,
+                    AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}")?,
+                }
+            }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
                 write!(f, " ON")?;
@@ -269,6 +323,16 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
             UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
+            UserOptionItem::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            UserOptionItem::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
+            UserOptionItem::SetMaxQueryMemoryUsage(v) => {
+                write!(f, "SET MAX_QUERY_MEMORY_USAGE = {}", v)
+            }
+            UserOptionItem::UnsetMaxQueryMemoryUsage => write!(f, "UNSET MAX_QUERY_MEMORY_USAGE"),
+            UserOptionItem::SetPublicKey(v) => write!(f, "SET PUBLIC_KEY = '{}'", v),
+            UserOptionItem::UnsetPublicKey => write!(f, "UNSET PUBLIC_KEY"),
+            UserOptionItem::SetPublicKey2(v) => write!(f, "SET PUBLIC_KEY_2 = '{}'", v),
+            UserOptionItem::UnsetPublicKey2 => write!(f, "UNSET PUBLIC_KEY_2"),
         }
     }
 }
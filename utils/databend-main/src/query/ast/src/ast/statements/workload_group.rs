@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateWorkloadGroupStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+    #[drive(skip)]
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "WORKLOAD GROUP ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterWorkloadGroupStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+        write!(f, "{}", self.options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct WorkloadGroupOptions {
+    #[drive(skip)]
+    pub max_concurrency: Option<u64>,
+    #[drive(skip)]
+    pub max_memory_usage: Option<u64>,
+    #[drive(skip)]
+    pub cpu_share: Option<u64>,
+    #[drive(skip)]
+    pub queue_timeout: Option<u64>,
+    #[drive(skip)]
+    pub roles: Option<Vec<String>>,
+    #[drive(skip)]
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {}", max_concurrency)?;
+        }
+        if let Some(max_memory_usage) = self.max_memory_usage {
+            write!(f, " MAX_MEMORY_USAGE = {}", max_memory_usage)?;
+        }
+        if let Some(cpu_share) = self.cpu_share {
+            write!(f, " CPU_SHARE = {}", cpu_share)?;
+        }
+        if let Some(queue_timeout) = self.queue_timeout {
+            write!(f, " QUEUE_TIMEOUT = {}", queue_timeout)?;
+        }
+        if let Some(roles) = &self.roles {
+            write!(f, " ROLES = (")?;
+            for (i, role) in roles.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "'{}'", role)?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropWorkloadGroupStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -583,6 +583,9 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+    fn visit_refresh_inverted_index(&mut self, _stmt: &'ast RefreshInvertedIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
@@ -676,6 +679,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -696,6 +705,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &'ast CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &'ast AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &'ast DropWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
@@ -596,6 +596,9 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+    fn visit_refresh_inverted_index(&mut self, _stmt: &mut RefreshInvertedIndexStmt) {}
 
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
@@ -689,6 +692,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
@@ -709,6 +718,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &mut AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
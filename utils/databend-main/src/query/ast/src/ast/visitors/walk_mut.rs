@@ -329,7 +329,10 @@ pub fn walk_table_reference_mut<V: VisitorMut>(visitor: &mut V, table_ref: &mut
 
 pub fn walk_time_travel_point_mut<V: VisitorMut>(visitor: &mut V, time: &mut TimeTravelPoint) {
     match time {
-        TimeTravelPoint::Snapshot(_) => {}
+        TimeTravelPoint::Snapshot(_)
+        | TimeTravelPoint::Tag(_)
+        | TimeTravelPoint::Branch(_)
+        | TimeTravelPoint::Version(_) => {}
         TimeTravelPoint::Timestamp(expr) => visitor.visit_expr(expr),
     }
 }
@@ -451,6 +454,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::RefreshInvertedIndex(stmt) => visitor.visit_refresh_inverted_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -517,6 +523,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
         Statement::AttachTable(_) => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
@@ -530,6 +539,10 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::ShowPasswordPolicies { show_options } => {
             visitor.visit_show_password_policies(show_options)
         }
+        Statement::CreateWorkloadGroup(stmt) => visitor.visit_create_workload_group(stmt),
+        Statement::AlterWorkloadGroup(stmt) => visitor.visit_alter_workload_group(stmt),
+        Statement::DropWorkloadGroup(stmt) => visitor.visit_drop_workload_group(stmt),
+        Statement::ShowWorkloadGroups => visitor.visit_show_workload_groups(),
 
         Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
         Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
@@ -508,9 +508,21 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #literal_string ~ ")" },
+        |(_, _, _, s, _)| TimeTravelPoint::Tag(s),
+    );
+    let at_branch = map(
+        rule! { "(" ~ BRANCH ~ "=>" ~ #literal_string ~ ")" },
+        |(_, _, _, s, _)| TimeTravelPoint::Branch(s),
+    );
+    let at_version = map(
+        rule! { "(" ~ VERSION ~ "=>" ~ #literal_u64 ~ ")" },
+        |(_, _, _, v, _)| TimeTravelPoint::Version(v),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_tag | #at_branch | #at_version
     )(i)
 }
 
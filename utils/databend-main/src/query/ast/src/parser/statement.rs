@@ -22,6 +22,7 @@ use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableRefKind;
 use databend_common_meta_app::share::ShareGrantObjectName;
 use databend_common_meta_app::share::ShareGrantObjectPrivilege;
 use databend_common_meta_app::share::ShareNameIdent;
@@ -40,6 +41,7 @@ use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::input::Input;
 use crate::parser::query::*;
+use crate::parser::row_access_policy::row_access_policy;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
 use crate::parser::stream::stream_table;
@@ -1021,6 +1023,80 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_inverted_index = map_res(
+        rule! {
+            CREATE
+            ~ ( OR ~ ^REPLACE )?
+            ~ ASYNC?
+            ~ INVERTED ~ ^INDEX
+            ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ( #table_option )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            opt_async,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            opt_index_options,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                create_option,
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                sync_creation: opt_async.is_none(),
+                index_options: opt_index_options.unwrap_or_default(),
+            }))
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ ^INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_inverted_index = map(
+        rule! {
+            REFRESH ~ INVERTED ~ ^INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
     let create_virtual_column = map_res(
         rule! {
             CREATE
@@ -1672,6 +1748,40 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_row_access_policy = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+            ~ ^#row_access_policy
+        },
+        |(_, opt_or_replace, _, _, _, opt_if_not_exists, name, policy)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateRowAccessPolicyStmt {
+                create_option,
+                name,
+                policy,
+            };
+            Ok(Statement::CreateRowAccessPolicy(stmt))
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, name)| Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt { name }),
+    );
+
     let create_network_policy = map_res(
         rule! {
             CREATE ~  ( OR ~ ^REPLACE )? ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -1835,6 +1945,53 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ #workload_group_options
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                options,
+            };
+            Ok(Statement::CreateWorkloadGroup(stmt))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+             ~ ^SET ~ #workload_group_options
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ ^GROUPS },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2002,7 +2159,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / row access policy
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2014,6 +2171,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
+            | #create_row_access_policy: "`CREATE [OR REPLACE] ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type> [, ...]) RETURNS BOOLEAN -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <name>`"
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -2060,6 +2224,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE [OR REPLACE] [ASYNC] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...) [<option>='<value>' ...]`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -2546,6 +2713,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            #comma_separated_list1(column_priv_type) ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privileges, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privileges,
+            columns,
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_all_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -2585,6 +2762,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
         | #privs : "<privileges> ON <privileges_level>"
+        | #column_privs : "<column_privileges>(<column>, ...) ON <database>.<table>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
@@ -2616,6 +2794,14 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
     ))(i)
 }
 
+pub fn column_priv_type(i: Input) -> IResult<UserPrivilegeType> {
+    alt((
+        value(UserPrivilegeType::Select, rule! { SELECT }),
+        value(UserPrivilegeType::Insert, rule! { INSERT }),
+        value(UserPrivilegeType::Update, rule! { UPDATE }),
+    ))(i)
+}
+
 pub fn stage_priv_type(i: Input) -> IResult<UserPrivilegeType> {
     alt((
         value(UserPrivilegeType::Read, rule! { READ }),
@@ -2837,10 +3023,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3046,20 +3244,76 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let create_table_ref = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ #table_ref_kind ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, opt_or_replace, kind, opt_if_not_exists, name, opt_travel_point)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(AlterTableAction::CreateTableRef {
+                create_option,
+                kind,
+                name,
+                travel_point: opt_travel_point.map(|(_, point)| point),
+            })
+        },
+    );
+
+    let drop_table_ref = map(
+        rule! {
+            DROP ~ #table_ref_kind ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, kind, opt_if_exists, name)| AlterTableAction::DropTableRef {
+            if_exists: opt_if_exists.is_some(),
+            kind,
+            name,
+        },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
+        | #add_row_access_policy
         | #add_column
+        | #drop_row_access_policy
+        | #drop_table_ref
         | #drop_column
         | #modify_column
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #create_table_ref
     )(i)
 }
 
+pub fn table_ref_kind(i: Input) -> IResult<TableRefKind> {
+    alt((
+        value(TableRefKind::Tag, rule! { TAG }),
+        value(TableRefKind::Branch, rule! { BRANCH }),
+    ))(i)
+}
+
 pub fn match_clause(i: Input) -> IResult<MergeOption> {
     map(
         rule! {
@@ -3567,6 +3821,54 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| UserOptionItem::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
+    let set_max_query_memory_usage = map(
+        rule! {
+            SET ~ MAX_QUERY_MEMORY_USAGE ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, _, bytes)| UserOptionItem::SetMaxQueryMemoryUsage(bytes),
+    );
+    let unset_max_query_memory_usage = map(
+        rule! {
+            UNSET ~ MAX_QUERY_MEMORY_USAGE
+        },
+        |(_, _)| UserOptionItem::UnsetMaxQueryMemoryUsage,
+    );
+    let set_public_key = map(
+        rule! {
+            SET ~ PUBLIC_KEY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, key)| UserOptionItem::SetPublicKey(key),
+    );
+    let unset_public_key = map(
+        rule! {
+            UNSET ~ PUBLIC_KEY
+        },
+        |(_, _)| UserOptionItem::UnsetPublicKey,
+    );
+    let set_public_key_2 = map(
+        rule! {
+            SET ~ PUBLIC_KEY_2 ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, key)| UserOptionItem::SetPublicKey2(key),
+    );
+    let unset_public_key_2 = map(
+        rule! {
+            UNSET ~ PUBLIC_KEY_2
+        },
+        |(_, _)| UserOptionItem::UnsetPublicKey2,
+    );
 
     rule!(
         #tenant_setting
@@ -3576,6 +3878,14 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_network_policy
         | #set_password_policy
         | #unset_password_policy
+        | #set_workload_group
+        | #unset_workload_group
+        | #set_max_query_memory_usage
+        | #unset_max_query_memory_usage
+        | #set_public_key
+        | #unset_public_key
+        | #set_public_key_2
+        | #unset_public_key_2
     )(i)
 }
 
@@ -3597,6 +3907,8 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::Ldap, rule! { LDAP }),
+        value(AuthType::KeyPair, rule! { KEY_PAIR }),
     ))(i)
 }
 
@@ -3802,6 +4114,36 @@ pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
     )(i)
 }
 
+pub fn workload_group_options(i: Input) -> IResult<WorkloadGroupOptions> {
+    map(
+        rule! {
+             ( MAX_CONCURRENCY ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( MAX_MEMORY_USAGE ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( CPU_SHARE ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( QUEUE_TIMEOUT ~ ^Eq ~ ^#literal_u64 ) ?
+             ~ ( ROLES ~ ^Eq ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" ) ?
+             ~ ( COMMENT ~ ^Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_max_concurrency,
+            opt_max_memory_usage,
+            opt_cpu_share,
+            opt_queue_timeout,
+            opt_roles,
+            opt_comment,
+        )| {
+            WorkloadGroupOptions {
+                max_concurrency: opt_max_concurrency.map(|opt| opt.2),
+                max_memory_usage: opt_max_memory_usage.map(|opt| opt.2),
+                cpu_share: opt_cpu_share.map(|opt| opt.2),
+                queue_timeout: opt_queue_timeout.map(|opt| opt.2),
+                roles: opt_roles.map(|opt| opt.3),
+                comment: opt_comment.map(|opt| opt.2),
+            }
+        },
+    )(i)
+}
+
 pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
     map(
         rule! {
@@ -318,6 +318,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
@@ -392,6 +394,8 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
@@ -408,6 +412,8 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -652,6 +658,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -668,10 +676,14 @@ pub enum TokenKind {
     JWT,
     #[token("KEY", ignore(ascii_case))]
     KEY,
+    #[token("KEY_PAIR", ignore(ascii_case))]
+    KEY_PAIR,
     #[token("KILL", ignore(ascii_case))]
     KILL,
     #[token("LATERAL", ignore(ascii_case))]
     LATERAL,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("LOCKS", ignore(ascii_case))]
@@ -801,6 +813,20 @@ pub enum TokenKind {
     PASSWORD_LOCKOUT_TIME_MINS,
     #[token("PASSWORD_HISTORY", ignore(ascii_case))]
     PASSWORD_HISTORY,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MAX_MEMORY_USAGE", ignore(ascii_case))]
+    MAX_MEMORY_USAGE,
+    #[token("MAX_QUERY_MEMORY_USAGE", ignore(ascii_case))]
+    MAX_QUERY_MEMORY_USAGE,
+    #[token("CPU_SHARE", ignore(ascii_case))]
+    CPU_SHARE,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -815,6 +841,10 @@ pub enum TokenKind {
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
+    #[token("PUBLIC_KEY", ignore(ascii_case))]
+    PUBLIC_KEY,
+    #[token("PUBLIC_KEY_2", ignore(ascii_case))]
+    PUBLIC_KEY_2,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
     #[token("QUARTER", ignore(ascii_case))]
@@ -985,6 +1015,8 @@ pub enum TokenKind {
     SYNC,
     #[token("STORAGE_TYPE", ignore(ascii_case))]
     STORAGE_TYPE,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
@@ -1081,6 +1113,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERSION", ignore(ascii_case))]
+    VERSION,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
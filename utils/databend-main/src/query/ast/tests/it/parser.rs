@@ -113,6 +113,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t2 clone t1 at (tag => 'v1');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -158,8 +159,13 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET MAX_QUERY_MEMORY_USAGE = 2147483648;"#,
+        r#"ALTER USER u1 WITH UNSET MAX_QUERY_MEMORY_USAGE;"#,
+        r#"ALTER USER u1 WITH SET PUBLIC_KEY_2 = 'key2';"#,
+        r#"ALTER USER u1 WITH UNSET PUBLIC_KEY;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
+        r#"CREATE USER u1 IDENTIFIED WITH ldap WITH DEFAULT_ROLE='role1'"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
         r#"select * from t4;"#,
@@ -226,6 +232,8 @@ fn test_statement() {
         r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER TABLE t DROP b;"#,
+        r#"ALTER TABLE t CREATE TAG v1 AT (SNAPSHOT => 'abc');"#,
+        r#"ALTER TABLE t DROP BRANCH IF EXISTS b1;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
         r#"ALTER TABLE t MODIFY COLUMN b UNSET MASKING POLICY;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int DEFAULT 1, COLUMN b float;"#,
@@ -263,6 +271,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT SELECT ON tb1 TO ROLE role1;"#,
+        r#"GRANT SELECT, UPDATE(a, b) ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant';"#,
@@ -271,6 +280,7 @@ fn test_statement() {
         r#"SHOW GRANTS FOR ROLE 'role1';"#,
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE role1;"#,
+        r#"REVOKE INSERT(c1) ON tb1 FROM ROLE role1;"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"drop role 'role1';"#,
         r#"GRANT ROLE test TO ROLE 'test-user';"#,
@@ -523,6 +533,10 @@ fn test_statement() {
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"CREATE OR REPLACE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl MAX_CONCURRENCY=4 CPU_SHARE=50 QUEUE_TIMEOUT=60 ROLES=('etl_role','loader') COMMENT='batch'"#,
+        r#"ALTER WORKLOAD GROUP etl SET MAX_MEMORY_USAGE=1073741824"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl"#,
+        r#"SHOW WORKLOAD GROUPS"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 ERROR_INTEGRATION = 'notification_name' COMMENT = 'This is test task 1' DATABASE = 'target', TIMEZONE = 'America/Los Angeles' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
@@ -63,6 +63,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -264,6 +266,15 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply>;
 
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Err(ErrorCode::Unimplemented(
+            "'set_table_row_access_policy' not implemented",
+        ))
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
@@ -62,6 +62,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -361,6 +363,13 @@ impl Catalog for SessionCatalog {
         self.inner.set_table_column_mask_policy(req).await
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        self.inner.set_table_row_access_policy(req).await
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
         self.inner.count_tables(req).await
     }
@@ -17,12 +17,15 @@ use std::path::Path;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DecimalDataType;
 use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::Float32Type;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::F32;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -36,6 +39,8 @@ use databend_common_expression::BASE_BLOCK_IDS_COLUMN_ID;
 use databend_common_expression::BASE_ROW_ID_COLUMN_ID;
 use databend_common_expression::BLOCK_NAME_COLUMN_ID;
 use databend_common_expression::ROW_ID_COLUMN_ID;
+use databend_common_expression::SEARCH_MATCHED_COLUMN_ID;
+use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
 use databend_common_expression::SEGMENT_NAME_COLUMN_ID;
 use databend_common_expression::SNAPSHOT_NAME_COLUMN_ID;
 use databend_storages_common_table_meta::meta::NUM_BLOCK_ID_BITS;
@@ -98,6 +103,8 @@ pub struct InternalColumnMeta {
     /// The row offsets in the block.
     pub offsets: Option<Vec<usize>>,
     pub base_block_ids: Option<Scalar>,
+    /// The rows matched by the inverted index and their optional scores, ordered by row.
+    pub matched_rows: Option<Vec<(usize, Option<F32>)>>,
 }
 
 #[typetag::serde(name = "internal_column_meta")]
@@ -129,6 +136,10 @@ pub enum InternalColumnType {
     // stream columns
     BaseRowId,
     BaseBlockIds,
+
+    // search columns
+    SearchMatched,
+    SearchScore,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -162,6 +173,8 @@ impl InternalColumn {
                     scale: 0,
                 })),
             )),
+            InternalColumnType::SearchMatched => TableDataType::Boolean,
+            InternalColumnType::SearchScore => TableDataType::Number(NumberDataType::Float32),
         }
     }
 
@@ -182,6 +195,8 @@ impl InternalColumn {
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
             InternalColumnType::BaseRowId => BASE_ROW_ID_COLUMN_ID,
             InternalColumnType::BaseBlockIds => BASE_BLOCK_IDS_COLUMN_ID,
+            InternalColumnType::SearchMatched => SEARCH_MATCHED_COLUMN_ID,
+            InternalColumnType::SearchScore => SEARCH_SCORE_COLUMN_ID,
         }
     }
 
@@ -280,6 +295,46 @@ impl InternalColumn {
                     Value::Scalar(meta.base_block_ids.clone().unwrap()),
                 )
             }
+            InternalColumnType::SearchMatched => {
+                let matched_rows = meta.matched_rows.as_deref().unwrap_or_default();
+                let mut bitmap = Vec::with_capacity(num_rows);
+                if let Some(offsets) = &meta.offsets {
+                    for i in offsets {
+                        let matched = matched_rows.binary_search_by_key(i, |r| r.0).is_ok();
+                        bitmap.push(matched);
+                    }
+                } else {
+                    for i in 0..num_rows {
+                        let matched = matched_rows.binary_search_by_key(&i, |r| r.0).is_ok();
+                        bitmap.push(matched);
+                    }
+                }
+                BlockEntry::new(
+                    DataType::Boolean,
+                    Value::Column(BooleanType::from_data(bitmap)),
+                )
+            }
+            InternalColumnType::SearchScore => {
+                let matched_rows = meta.matched_rows.as_deref().unwrap_or_default();
+                let score = |i: usize| match matched_rows.binary_search_by_key(&i, |r| r.0) {
+                    Ok(pos) => matched_rows[pos].1.unwrap_or_default(),
+                    Err(_) => F32::default(),
+                };
+                let mut scores = Vec::with_capacity(num_rows);
+                if let Some(offsets) = &meta.offsets {
+                    for i in offsets {
+                        scores.push(score(*i));
+                    }
+                } else {
+                    for i in 0..num_rows {
+                        scores.push(score(i));
+                    }
+                }
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::Float32),
+                    Value::Column(Float32Type::from_data(scores)),
+                )
+            }
         }
     }
 }
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchema;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -40,6 +42,25 @@ pub struct VirtualColumnInfo {
     pub data_type: Box<TableDataType>,
 }
 
+/// Information of inverted index used by `match` function.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexInfo {
+    /// The index name.
+    pub index_name: String,
+    /// The index version, used to locate the index files.
+    pub index_version: String,
+    /// The index options, like tokenizer.
+    pub index_options: BTreeMap<String, String>,
+    /// The schema of the indexed columns.
+    pub index_schema: DataSchema,
+    /// The columns to be searched.
+    pub query_fields: Vec<String>,
+    /// The text to be searched in the columns.
+    pub query_text: String,
+    /// If the `score` function is used, scores of the matched rows are needed.
+    pub has_score: bool,
+}
+
 /// Information about prewhere optimization.
 ///
 /// Prewhere steps:
@@ -96,6 +117,11 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// If the scan only feeds `MIN`/`MAX`/`COUNT` aggregates without group by.
+    /// The blocks fully matching the filters can be answered by their statistics without reading data.
+    pub agg_by_statistics: bool,
+    /// Optional inverted index used to search the matched rows.
+    pub inverted_index: Option<InvertedIndexInfo>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -20,6 +20,8 @@ pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
     min_max: Vec<Expr<String>>,
     bloom: Vec<(String, BinaryFuse16)>,
+    // The threshold of a top-n sort, replaced by a tighter one as the sort goes on.
+    top_n: Option<Expr<String>>,
 }
 
 impl RuntimeFilterInfo {
@@ -35,6 +37,10 @@ impl RuntimeFilterInfo {
         self.min_max.push(expr);
     }
 
+    pub fn set_top_n(&mut self, expr: Expr<String>) {
+        self.top_n = Some(expr);
+    }
+
     pub fn get_inlist(&self) -> &Vec<Expr<String>> {
         &self.inlist
     }
@@ -47,6 +53,10 @@ impl RuntimeFilterInfo {
         &self.min_max
     }
 
+    pub fn get_top_n(&self) -> Option<&Expr<String>> {
+        self.top_n.as_ref()
+    }
+
     pub fn blooms(self) -> Vec<(String, BinaryFuse16)> {
         self.bloom
     }
@@ -60,6 +70,9 @@ impl RuntimeFilterInfo {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.inlist.is_empty() && self.bloom.is_empty() && self.min_max.is_empty()
+        self.inlist.is_empty()
+            && self.bloom.is_empty()
+            && self.min_max.is_empty()
+            && self.top_n.is_none()
     }
 }
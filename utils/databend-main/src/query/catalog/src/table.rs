@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::StorageMetrics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
@@ -446,6 +447,10 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    Tag(String),
+    Branch(String),
+    /// Version of tables keeping a sequential log, e.g. delta tables.
+    Version(u64),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -505,6 +510,11 @@ pub trait ColumnStatisticsProvider: Send {
 
     // returns the num rows of the table, if any.
     fn num_rows(&self) -> Option<u64>;
+
+    // returns the histogram of the given column collected by `ANALYZE TABLE`, if any.
+    fn histogram(&self, _column_id: ColumnId) -> Option<&ColumnHistogram> {
+        None
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
@@ -27,6 +27,7 @@ use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -48,6 +49,7 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storage::StorageMetrics;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_txn::TxnManagerRef;
 use parking_lot::RwLock;
 use xorf::BinaryFuse16;
@@ -149,6 +151,10 @@ pub trait TableContext: Send + Sync {
     fn set_can_scan_from_agg_index(&self, enable: bool);
     fn set_need_compact_after_write(&self, enable: bool);
     fn get_need_compact_after_write(&self) -> bool;
+    /// Merge the sketches of the column values written by the query, they are folded into the
+    /// table statistics on commit so that appends do not leave the NDVs stale.
+    fn add_written_column_hlls(&self, rows: u64, hlls: HashMap<ColumnId, MetaHLL>);
+    fn take_written_column_hlls(&self) -> Option<(u64, HashMap<ColumnId, MetaHLL>)>;
 
     fn attach_query_str(&self, kind: QueryKind, query: String);
     fn get_query_str(&self) -> String;
@@ -45,6 +45,7 @@ use databend_common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhd
 use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
+use databend_common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
@@ -54,6 +55,7 @@ use databend_common_tracing::StderrConfig as InnerStderrLogConfig;
 use databend_common_tracing::StructLogConfig as InnerStructLogConfig;
 use databend_common_tracing::TracingConfig as InnerTracingConfig;
 use databend_common_users::idm_config::IDMConfig as InnerIDMConfig;
+use databend_common_users::LdapConfig;
 use serde::Deserialize;
 use serde::Serialize;
 use serfig::collectors::from_env;
@@ -1528,6 +1530,10 @@ pub struct QueryConfig {
     #[clap(skip)]
     quota: Option<TenantQuota>,
 
+    /// LDAP server for users created `IDENTIFIED WITH ldap`.
+    #[clap(skip)]
+    pub ldap: Option<LdapConfig>,
+
     #[clap(long, value_name = "VALUE")]
     pub internal_enable_sandbox_tenant: bool,
 
@@ -1721,6 +1727,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             share_endpoint_address: self.share_endpoint_address,
             share_endpoint_auth_token_file: self.share_endpoint_auth_token_file,
             tenant_quota: self.quota,
+            ldap: self.ldap,
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
             data_retention_time_in_days_max: self.data_retention_time_in_days_max,
@@ -1803,6 +1810,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
             quota: inner.tenant_quota,
+            ldap: inner.ldap,
             internal_enable_sandbox_tenant: inner.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: false,
             data_retention_time_in_days_max: 90,
@@ -1887,6 +1895,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub tracing: TracingConfig,
+
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
 }
 
 impl Default for LogConfig {
@@ -1970,6 +1981,22 @@ impl TryInto<InnerLogConfig> for LogConfig {
 
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
+        let mut audit: InnerAuditLogConfig = self.audit.try_into()?;
+        if audit.on && audit.secret_key.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "`secret_key` must be set when `audit.on` is true".to_string(),
+            ));
+        }
+        if audit.on && audit.dir.is_empty() {
+            if file.dir.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "`dir` or `file.dir` must be set when `audit.on` is true".to_string(),
+                ));
+            } else {
+                audit.dir = format!("{}/audit", &file.dir);
+            }
+        }
+
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
@@ -1978,6 +2005,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             profile,
             structlog,
             tracing,
+            audit,
         })
     }
 }
@@ -1994,6 +2022,7 @@ impl From<InnerLogConfig> for LogConfig {
             profile: inner.profile.into(),
             structlog: inner.structlog.into(),
             tracing: inner.tracing.into(),
+            audit: inner.audit.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -2396,6 +2425,73 @@ impl From<InnerTracingConfig> for TracingConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    #[clap(long = "log-audit-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    #[serde(rename = "on")]
+    pub log_audit_on: bool,
+
+    /// Audit Log file dir
+    #[clap(long = "log-audit-dir", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "dir")]
+    pub log_audit_dir: String,
+
+    /// Security event categories to record: login, grant, ddl, network_policy, privilege.
+    /// All categories are recorded if empty.
+    #[clap(long = "log-audit-categories", value_name = "VALUE")]
+    #[serde(rename = "categories")]
+    pub log_audit_categories: Vec<String>,
+
+    /// The secret key of the HMAC that chains the audit events, required when the audit log is on.
+    #[clap(
+        long = "log-audit-secret-key",
+        value_name = "VALUE",
+        default_value = ""
+    )]
+    #[serde(rename = "secret_key")]
+    pub log_audit_secret_key: String,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        for category in &self.log_audit_categories {
+            if !InnerAuditLogConfig::CATEGORIES.contains(&category.as_str()) {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "unknown audit category `{}`, expected one of: {}",
+                    category,
+                    InnerAuditLogConfig::CATEGORIES.join(", ")
+                )));
+            }
+        }
+        Ok(InnerAuditLogConfig {
+            on: self.log_audit_on,
+            dir: self.log_audit_dir,
+            categories: self.log_audit_categories,
+            secret_key: self.log_audit_secret_key,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            log_audit_on: inner.on,
+            log_audit_dir: inner.dir,
+            log_audit_categories: inner.categories,
+            log_audit_secret_key: inner.secret_key,
+        }
+    }
+}
+
 /// Meta config group.
 /// deny_unknown_fields to check unknown field, like the deprecated `address`.
 /// TODO(xuanwo): All meta_xxx should be rename to xxx.
@@ -2616,6 +2712,10 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::Ldap => check_no_auth_string(self.auth_string, AuthInfo::Ldap),
+            AuthType::KeyPair => Err(ErrorCode::InvalidConfig(
+                "auth_type key_pair is not supported for configured users",
+            )),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
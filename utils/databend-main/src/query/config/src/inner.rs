@@ -32,6 +32,7 @@ use databend_common_meta_types::NonEmptyString;
 use databend_common_storage::StorageConfig;
 use databend_common_tracing::Config as LogConfig;
 use databend_common_users::idm_config::IDMConfig;
+use databend_common_users::LdapConfig;
 
 use super::config::Commands;
 use super::config::Config;
@@ -209,6 +210,7 @@ pub struct QueryConfig {
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
     pub tenant_quota: Option<TenantQuota>,
+    pub ldap: Option<LdapConfig>,
     pub internal_enable_sandbox_tenant: bool,
     pub internal_merge_on_read_mutation: bool,
     /// Disable some system load(For example system.configs) for cloud security.
@@ -285,6 +287,7 @@ impl Default for QueryConfig {
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
             tenant_quota: None,
+            ldap: None,
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
             disable_system_table_load: false,
@@ -320,6 +323,9 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        if let Some(ldap) = sanitized.ldap.as_mut() {
+            ldap.bind_password = mask_string(&ldap.bind_password, 3);
+        }
         sanitized
     }
 }
@@ -46,7 +46,9 @@ use databend_common_meta_store::MetaStore;
 use databend_common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
+use databend_query::sessions::TableContext;
 use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use log::debug;
 use log::error;
 use log::info;
@@ -451,9 +453,42 @@ impl CompactionJob {
             }
         }
 
+        self.do_purge_by_table_retention(session, database, table)
+            .await?;
+
         Ok(true)
     }
 
+    // tables with their own data retention are purged right after compaction,
+    // the others are left to the explicit VACUUM/PURGE.
+    async fn do_purge_by_table_retention(
+        &self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+    ) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let tbl = ctx
+            .get_table(&ctx.get_current_catalog(), &database, &table)
+            .await?;
+        if !tbl
+            .options()
+            .contains_key(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+        {
+            return Ok(());
+        }
+
+        let sql = Self::get_purge_sql(database, table);
+        debug!(
+            job = "compaction",
+            background = true,
+            sql = sql.as_str();
+            "purge"
+        );
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
     pub async fn do_get_target_tables_from_config(
         config: &InnerConfig,
         ctx: Arc<QueryContext>,
@@ -623,6 +658,7 @@ impl CompactionJob {
                     .unwrap()
                     .value(0),
             ),
+            ..Default::default()
         };
 
         Ok((need_segment_compact, need_block_compact, table_statistics))
@@ -710,4 +746,8 @@ impl CompactionJob {
         };
         format!("OPTIMIZE TABLE {}.{} COMPACT{};", database, table, limit)
     }
+
+    pub fn get_purge_sql(database: String, table: String) -> String {
+        format!("OPTIMIZE TABLE {}.{} PURGE;", database, table)
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::Projection;
@@ -22,9 +23,11 @@ use databend_common_expression::DataSchema;
 use databend_common_storages_fuse::io::InvertedIndexWriter;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::ReadSettings;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::Location;
+use log::info;
 
 pub struct Indexer {}
 
@@ -33,28 +36,36 @@ impl Indexer {
         Indexer {}
     }
 
+    // Generate the inverted index data for each block that has no index data yet.
+    // The index data of a block is stored in a single file next to the block,
+    // so the refresh can be done incrementally.
     #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn index(
         &self,
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
-        schema: DataSchema,
+        index_name: String,
+        index_version: String,
+        index_options: &BTreeMap<String, String>,
+        index_schema: DataSchema,
+        limit: Option<usize>,
         segment_locs: Option<Vec<Location>>,
-    ) -> Result<String> {
+    ) -> Result<()> {
         let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
             // no snapshot
-            return Ok("".to_string());
+            return Ok(());
         };
-        if schema.fields.is_empty() {
+        if index_schema.fields.is_empty() {
             // no field for index
-            return Ok("".to_string());
+            return Ok(());
         }
 
         let table_schema = &fuse_table.get_table_info().meta.schema;
 
         // Collect field indices used by inverted index.
         let mut field_indices = Vec::new();
-        for field in &schema.fields {
+        for field in &index_schema.fields {
             let field_index = table_schema.index_of(field.name())?;
             field_indices.push(field_index);
         }
@@ -79,9 +90,8 @@ impl Indexer {
             snapshot.segments.clone()
         };
 
-        let mut index_writer = InvertedIndexWriter::try_create(schema)?;
-
-        for (location, ver) in segment_locs {
+        let mut indexed_blocks = 0;
+        'segments: for (location, ver) in segment_locs {
             let segment_info = segment_reader
                 .read(&LoadParams {
                     location: location.to_string(),
@@ -93,18 +103,40 @@ impl Indexer {
 
             let block_metas = segment_info.block_metas()?;
             for block_meta in block_metas {
+                if limit.is_some_and(|limit| indexed_blocks >= limit) {
+                    break 'segments;
+                }
+
+                let index_location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index_name,
+                        &index_version,
+                    );
+                // The index data of the block has been generated.
+                if operator.is_exist(&index_location).await? {
+                    continue;
+                }
+
                 let block = block_reader
                     .read_by_meta(&settings, &block_meta, &storage_format)
                     .await?;
 
+                let mut index_writer =
+                    InvertedIndexWriter::try_create(index_schema.clone(), index_options)?;
                 index_writer.add_block(block)?;
+                index_writer.finalize(operator, &index_location).await?;
+                indexed_blocks += 1;
             }
         }
 
-        let location_generator = fuse_table.meta_location_generator();
+        info!(
+            "refresh inverted index {} of table {}, {} blocks indexed",
+            index_name,
+            fuse_table.name(),
+            indexed_blocks
+        );
 
-        let index_location = index_writer.finalize(operator, location_generator).await?;
-        // TODO: add index location to meta
-        Ok(index_location)
+        Ok(())
     }
 }
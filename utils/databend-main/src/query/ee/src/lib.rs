@@ -18,6 +18,7 @@ pub mod data_mask;
 pub mod enterprise_services;
 pub mod inverted_index;
 pub mod license;
+pub mod row_access_policy;
 pub mod storage_encryption;
 pub mod storages;
 pub mod stream;
@@ -25,6 +25,10 @@ use opendal::EntryMode;
 use opendal::Metakey;
 use opendal::Operator;
 
+/// Removes the whole directory of a dropped table.
+///
+/// The caller is responsible for keeping the tables whose files are still shared
+/// by their clones, see `VacuumDropTablesInterpreter`.
 #[async_backtrace::framed]
 pub async fn do_vacuum_drop_table(
     table_info: &TableInfo,
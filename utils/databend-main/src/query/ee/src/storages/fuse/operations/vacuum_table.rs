@@ -17,7 +17,6 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono::DateTime;
-use chrono::Duration;
 use chrono::Utc;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -29,6 +28,10 @@ use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_TBL_BLOCK_PREFIX;
+use databend_common_storages_fuse::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use databend_common_storages_fuse::FUSE_TBL_SEGMENT_PREFIX;
+use databend_common_storages_fuse::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 
@@ -41,6 +44,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub deletion_vectors: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -55,11 +59,14 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.deletion_vectors {
+            files.push(file.clone());
+        }
         files
     }
 }
 
-// return all the segment\block\index files referenced by current snapshot.
+// return all the segment\block\index\deletion vector files referenced by current snapshot.
 #[async_backtrace::framed]
 pub async fn get_snapshot_referenced_files(
     fuse_table: &FuseTable,
@@ -121,7 +128,7 @@ pub async fn get_snapshot_referenced_files(
         }
     };
 
-    let locations_referenced = fuse_table
+    let mut locations_referenced = fuse_table
         .get_block_locations(ctx.clone(), &segments_vec, false, false)
         .await?;
 
@@ -129,39 +136,54 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+
+    // 3. The files pinned by tags, branches and clones are referenced as well.
+    let ref_locations = fuse_table.read_ref_locations(ctx).await?;
+    segments.extend(
+        ref_locations
+            .segments
+            .into_iter()
+            .map(|(location, _)| location),
+    );
+    locations_referenced
+        .block_location
+        .extend(ref_locations.locations.block_location);
+    locations_referenced
+        .bloom_location
+        .extend(ref_locations.locations.bloom_location);
+    locations_referenced
+        .deletion_vector_location
+        .extend(ref_locations.locations.deletion_vector_location);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
+        deletion_vectors: locations_referenced.deletion_vector_location,
     }))
 }
 
 // return orphan files to be purged
+//
+// Only the files under the prefix of the given file type in the directory of the table are
+// listed, the files shared by the source of a cloned table are not orphans of the clone.
 #[async_backtrace::framed]
 async fn get_orphan_files_to_be_purged(
     fuse_table: &FuseTable,
+    file_type_prefix: &str,
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
-        Some(location) => {
-            let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
-            if let Some(prefix) = prefix {
-                fuse_table
-                    .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
-                    })
-                    .await?
-            } else {
-                vec![]
-            }
-        }
-        None => {
-            vec![]
-        }
-    };
-
-    Ok(files_to_be_purged)
+    let prefix = format!(
+        "{}/{}/",
+        fuse_table.meta_location_generator().prefix(),
+        file_type_prefix
+    );
+    fuse_table
+        .list_files(prefix, |location, modified| {
+            modified <= retention_time && !referenced_files.contains(&location)
+        })
+        .await
 }
 
 #[async_backtrace::framed]
@@ -177,19 +199,24 @@ pub async fn do_gc_orphan_files(
         None => return Ok(()),
     };
     let status = format!(
-        "gc orphan: read referenced files:{},{},{}, cost:{} sec",
+        "gc orphan: read referenced files:{},{},{},{}, cost:{} sec",
         referenced_files.segments.len(),
         referenced_files.blocks.len(),
         referenced_files.blocks_index.len(),
+        referenced_files.deletion_vectors.len(),
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_SEGMENT_PREFIX,
+        referenced_files.segments,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read segment_locations_to_be_purged:{}, cost:{} sec, retention_time: {}",
         segment_locations_to_be_purged.len(),
@@ -215,8 +242,13 @@ pub async fn do_gc_orphan_files(
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_BLOCK_PREFIX,
+        referenced_files.blocks,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -241,9 +273,13 @@ pub async fn do_gc_orphan_files(
 
     // 4. Purge orphan block index files.
     // 4.1 Get orphan block index files to be purged
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+        referenced_files.blocks_index,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -266,6 +302,37 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan deletion vector files.
+    // 5.1 Get orphan deletion vector files to be purged
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_DELETION_VECTOR_PREFIX,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "gc orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan deletion vector files to be purged
+    let purged_file_num = deletion_vector_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(deletion_vector_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged deletion vector files:{}, cost:{} sec",
+        purged_file_num,
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -284,18 +351,23 @@ pub async fn do_dry_run_orphan_files(
         None => return Ok(()),
     };
     let status = format!(
-        "dry_run orphan: read referenced files:{},{},{}, cost:{} sec",
+        "dry_run orphan: read referenced files:{},{},{},{}, cost:{} sec",
         referenced_files.segments.len(),
         referenced_files.blocks.len(),
         referenced_files.blocks_index.len(),
+        referenced_files.deletion_vectors.len(),
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
 
     // 2. Get purge orphan segment files.
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_SEGMENT_PREFIX,
+        referenced_files.segments,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read segment_locations_to_be_purged:{}, cost:{} sec",
         segment_locations_to_be_purged.len(),
@@ -309,8 +381,13 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 3. Get purge orphan block files.
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_BLOCK_PREFIX,
+        referenced_files.blocks,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -323,9 +400,13 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 4. Get purge orphan block index files.
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+        referenced_files.blocks_index,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -334,6 +415,26 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan deletion vector files.
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_DELETION_VECTOR_PREFIX,
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "dry_run orphan: read deletion_vector_locations_to_be_purged:{}, cost:{} sec",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(deletion_vector_locations_to_be_purged);
 
     Ok(())
 }
@@ -357,7 +458,7 @@ pub async fn do_vacuum(
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
-    let retention = Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64);
+    let retention = fuse_table.get_data_retention_period(ctx.as_ref())?;
     // use min(now - get_retention_period(), retention_time) as gc orphan files retention time
     // to protect files that generated by txn which has not been committed being gc.
     let retention_time = std::cmp::min(chrono::Utc::now() - retention, retention_time);
@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_base::base::tokio;
+use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_storages_fuse::io::read::InvertedIndexReader;
+use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
 use databend_enterprise_query::test_kits::context::EESetup;
 use databend_query::test_kits::append_string_sample_data;
 use databend_query::test_kits::*;
+use databend_storages_common_cache::LoadParams;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
@@ -42,33 +48,101 @@ async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
     let dal = fuse_table.get_operator_ref();
 
     let table_ctx = fixture.new_query_ctx().await?;
-    let schema = DataSchema::from(table_schema);
+    let schema = DataSchema::from(table_schema.clone());
+    let index_name = "idx1".to_string();
+    let index_version = "v1".to_string();
+    let index_options = BTreeMap::new();
+
+    // collect the index locations of all the blocks.
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table_schema.clone());
+    let mut block_metas = vec![];
+    for (location, ver) in &snapshot.segments {
+        let segment_info = segment_reader
+            .read(&LoadParams {
+                location: location.to_string(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            })
+            .await?;
+        block_metas.extend(segment_info.block_metas()?);
+    }
+    assert_eq!(block_metas.len(), number_of_block);
+    let index_locations = block_metas
+        .iter()
+        .map(|block_meta| {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                &block_meta.location.0,
+                &index_name,
+                &index_version,
+            )
+        })
+        .collect::<Vec<_>>();
 
+    // refresh with limit only generates the index of one block.
     let handler = get_inverted_index_handler();
-    let location = handler
-        .do_refresh_index(fuse_table, table_ctx.clone(), schema.clone(), None)
+    handler
+        .do_refresh_index(
+            fuse_table,
+            table_ctx.clone(),
+            index_name.clone(),
+            index_version.clone(),
+            &index_options,
+            schema.clone(),
+            Some(1),
+            None,
+        )
         .await?;
+    let mut exists = 0;
+    for location in &index_locations {
+        if dal.is_exist(location).await? {
+            exists += 1;
+        }
+    }
+    assert_eq!(exists, 1);
 
-    let index_reader = InvertedIndexReader::create(dal.clone(), schema);
-
-    let num = 5;
-    let query = "rust";
-    let docs = index_reader.do_read(location.clone(), query, num)?;
-    assert_eq!(docs.len(), 2);
-    assert_eq!(docs[0].1.doc_id, 0);
-    assert_eq!(docs[1].1.doc_id, 1);
+    // refresh the remaining blocks.
+    handler
+        .do_refresh_index(
+            fuse_table,
+            table_ctx.clone(),
+            index_name.clone(),
+            index_version.clone(),
+            &index_options,
+            schema.clone(),
+            None,
+            None,
+        )
+        .await?;
 
-    let query = "java";
-    let docs = index_reader.do_read(location.clone(), query, num)?;
-    assert_eq!(docs.len(), 1);
-    assert_eq!(docs[0].1.doc_id, 2);
+    let index_reader = InvertedIndexReader::create(dal.clone());
+    let mut indexes = Vec::with_capacity(index_locations.len());
+    for location in &index_locations {
+        let index = index_reader.read_index(location).await?;
+        assert!(index.is_some());
+        indexes.push(index.unwrap());
+    }
 
-    let query = "data";
-    let docs = index_reader.do_read(location, query, num)?;
-    assert_eq!(docs.len(), 3);
-    assert_eq!(docs[0].1.doc_id, 4);
-    assert_eq!(docs[1].1.doc_id, 1);
-    assert_eq!(docs[2].1.doc_id, 5);
+    let query_fields = schema
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect::<Vec<_>>();
+    let num_rows = 3;
+    for (query, expected) in [("rust", 2), ("java", 1), ("data", 3)] {
+        let mut matched = 0;
+        for index in &indexes {
+            let rows = InvertedIndexReader::search(index, &query_fields, query, true, num_rows)?;
+            for (row, score) in &rows {
+                assert!(*row < num_rows);
+                assert!(score.is_some());
+            }
+            matched += rows.len();
+        }
+        assert_eq!(matched, expected, "query: {}", query);
+    }
 
     Ok(())
 }
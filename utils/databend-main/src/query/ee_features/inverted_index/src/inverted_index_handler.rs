@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
@@ -23,13 +24,18 @@ use databend_storages_common_table_meta::meta::Location;
 
 #[async_trait::async_trait]
 pub trait InvertedIndexHandler: Sync + Send {
+    #[allow(clippy::too_many_arguments)]
     async fn do_refresh_index(
         &self,
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
-        schema: DataSchema,
+        index_name: String,
+        index_version: String,
+        index_options: &BTreeMap<String, String>,
+        index_schema: DataSchema,
+        limit: Option<usize>,
         segment_locs: Option<Vec<Location>>,
-    ) -> Result<String>;
+    ) -> Result<()>;
 }
 
 pub struct InvertedIndexHandlerWrapper {
@@ -42,15 +48,29 @@ impl InvertedIndexHandlerWrapper {
     }
 
     #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
     pub async fn do_refresh_index(
         &self,
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
-        schema: DataSchema,
+        index_name: String,
+        index_version: String,
+        index_options: &BTreeMap<String, String>,
+        index_schema: DataSchema,
+        limit: Option<usize>,
         segment_locs: Option<Vec<Location>>,
-    ) -> Result<String> {
+    ) -> Result<()> {
         self.handler
-            .do_refresh_index(fuse_table, ctx, schema, segment_locs)
+            .do_refresh_index(
+                fuse_table,
+                ctx,
+                index_name,
+                index_version,
+                index_options,
+                index_schema,
+                limit,
+                segment_locs,
+            )
             .await
     }
 }
@@ -44,6 +44,9 @@ pub const SNAPSHOT_NAME_COLUMN_ID: u32 = u32::MAX - 3;
 // internal stream column id.
 pub const BASE_ROW_ID_COLUMN_ID: u32 = u32::MAX - 5;
 pub const BASE_BLOCK_IDS_COLUMN_ID: u32 = u32::MAX - 6;
+// internal search column id.
+pub const SEARCH_MATCHED_COLUMN_ID: u32 = u32::MAX - 7;
+pub const SEARCH_SCORE_COLUMN_ID: u32 = u32::MAX - 8;
 
 // internal column name.
 pub const ROW_ID_COL_NAME: &str = "_row_id";
@@ -56,6 +59,9 @@ pub const BASE_BLOCK_IDS_COL_NAME: &str = "_base_block_ids";
 pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
 pub const CHANGE_IS_UPDATE_COL_NAME: &str = "change$is_update";
 pub const CHANGE_ROW_ID_COL_NAME: &str = "change$row_id";
+// internal search column name.
+pub const SEARCH_MATCHED_COL_NAME: &str = "_search_matched";
+pub const SEARCH_SCORE_COL_NAME: &str = "_search_score";
 
 pub const ROW_NUMBER_COL_NAME: &str = "_row_number";
 pub const PREDICATE_COLUMN_NAME: &str = "_predicate";
@@ -73,7 +79,7 @@ pub const ROW_VERSION_COL_NAME: &str = "_row_version";
 
 #[inline]
 pub fn is_internal_column_id(column_id: ColumnId) -> bool {
-    column_id >= BASE_BLOCK_IDS_COLUMN_ID
+    column_id >= SEARCH_SCORE_COLUMN_ID
 }
 
 #[inline]
@@ -94,6 +100,8 @@ pub fn is_internal_column(column_name: &str) -> bool {
             // to the computation of the two following internal columns
             | ORIGIN_BLOCK_ROW_NUM_COL_NAME
             | BASE_ROW_ID_COL_NAME
+            | SEARCH_MATCHED_COL_NAME
+            | SEARCH_SCORE_COL_NAME
     )
 }
 
@@ -26,6 +26,7 @@ mod setting;
 mod stage;
 pub mod udf;
 mod user;
+mod workload_group;
 
 pub mod errors;
 
@@ -52,3 +53,5 @@ pub use stage::StageApi;
 pub use stage::StageMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupApi;
+pub use workload_group::WorkloadGroupMgr;
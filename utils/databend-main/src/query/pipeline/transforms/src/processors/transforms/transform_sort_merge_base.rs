@@ -15,6 +15,7 @@
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -237,7 +238,8 @@ where
 
         let blocks = if self.may_spill
             && (self.inner.num_bytes() >= self.spilling_bytes_threshold
-                || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage)
+                || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage
+                || ThreadTracker::query_memory_should_spill())
         {
             self.prepare_spill()?
         } else {
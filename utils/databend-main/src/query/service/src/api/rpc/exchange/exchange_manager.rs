@@ -718,9 +718,7 @@ impl QueryCoordinator {
             }
         }
 
-        let query_id = info.query_ctx.get_id();
-        let executor_settings =
-            ExecutorSettings::try_create(&info.query_ctx.get_settings(), query_id)?;
+        let executor_settings = ExecutorSettings::try_create(info.query_ctx.clone())?;
 
         let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
 
@@ -0,0 +1,224 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storages_system::AuditLogElement;
+use databend_common_storages_system::AuditLogQueue;
+use log::error;
+use log::info;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::Session;
+
+/// The file in the audit log dir that keeps the head of the audit chain.
+const AUDIT_CHAIN_HEAD_FILE: &str = "audit_chain_head";
+
+static AUDIT_CHAIN_HEAD: Lazy<Mutex<AuditChainHead>> = Lazy::new(|| {
+    let config = GlobalConfig::instance();
+    let dir = &config.log.audit.dir;
+    let head = AuditChainHead::load(dir).unwrap_or_else(|e| {
+        error!("fail to load the audit chain head from {}: {:?}", dir, e);
+        AuditChainHead::default()
+    });
+    Mutex::new(head)
+});
+
+/// Hash of the last recorded event, the head of the audit chain.
+///
+/// The head is persisted in the audit log dir, so the chain of the node goes on
+/// across restarts, instead of starting over from an empty `prev_hash`.
+#[derive(Default)]
+pub struct AuditChainHead {
+    hash: String,
+    path: Option<PathBuf>,
+}
+
+impl AuditChainHead {
+    /// Load the head persisted in `dir`, the head is kept in memory only if `dir` is empty.
+    pub fn load(dir: &str) -> Result<Self> {
+        if dir.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let path = Path::new(dir).join(AUDIT_CHAIN_HEAD_FILE);
+        let hash = match std::fs::read_to_string(&path) {
+            Ok(hash) => hash.trim().to_string(),
+            Err(e) if e.kind() == ErrorKind::NotFound => "".to_string(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            hash,
+            path: Some(path),
+        })
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Move the head to the hash of the event just recorded, and persist it.
+    pub fn advance(&mut self, hash: String) -> Result<()> {
+        self.hash = hash;
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            // Replace the file at once, a crash never leaves a partial head behind.
+            let tmp_path = path.with_extension("tmp");
+            std::fs::write(&tmp_path, &self.hash)?;
+            std::fs::rename(&tmp_path, path)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditCategory {
+    /// Logins and login failures.
+    Login,
+    /// GRANT and REVOKE.
+    Grant,
+    /// User, role and policy DDL.
+    Ddl,
+    /// Logins rejected by a network policy.
+    NetworkPolicy,
+    /// Statements rejected by the privilege check.
+    Privilege,
+}
+
+impl AuditCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditCategory::Login => "login",
+            AuditCategory::Grant => "grant",
+            AuditCategory::Ddl => "ddl",
+            AuditCategory::NetworkPolicy => "network_policy",
+            AuditCategory::Privilege => "privilege",
+        }
+    }
+}
+
+pub struct AuditEvent {
+    pub category: AuditCategory,
+    /// What happened, such as `LOGIN` or `CREATE USER`.
+    pub event_type: String,
+    /// The object the event applies to, such as a user or a role, or the id of
+    /// the query rejected by the privilege check.
+    pub object: String,
+}
+
+impl AuditEvent {
+    pub fn new(
+        category: AuditCategory,
+        event_type: impl Into<String>,
+        object: impl Into<String>,
+    ) -> Self {
+        AuditEvent {
+            category,
+            event_type: event_type.into(),
+            object: object.into(),
+        }
+    }
+}
+
+pub struct AuditLog;
+
+impl AuditLog {
+    /// Record a security event of the session.
+    ///
+    /// `user` is the authenticated user, or the name the client tried to log in with.
+    /// `client_address` overrides the address the session is attached to, for the
+    /// handlers which only know the client ip from the request.
+    pub fn log(
+        session: &Arc<Session>,
+        user: &str,
+        client_address: Option<&str>,
+        event: AuditEvent,
+        err: Option<&ErrorCode>,
+    ) {
+        let config = GlobalConfig::instance();
+        if !config.log.audit.is_enabled(event.category.as_str()) {
+            return;
+        }
+
+        let client_address = match (client_address, session.get_client_host()) {
+            (Some(addr), _) => addr.to_string(),
+            (None, Some(addr)) => format!("{:?}", addr),
+            (None, None) => "".to_string(),
+        };
+        let (error_code, error_message) = match err {
+            None => (0, "".to_string()),
+            Some(e) => (e.code().into(), e.message()),
+        };
+
+        let element = AuditLogElement {
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            tenant_id: session.get_current_tenant().as_str().to_string(),
+            cluster_id: config.query.cluster_id.clone(),
+            node_id: config.query.node_id.clone(),
+            category: event.category.as_str().to_string(),
+            event_type: event.event_type,
+            object: event.object,
+            success: err.is_none(),
+            error_code,
+            error_message,
+            session_id: session.get_id(),
+            client_address,
+            sql_user: user.to_string(),
+            current_role: session
+                .get_current_role()
+                .map(|r| r.name)
+                .unwrap_or_default(),
+            prev_hash: "".to_string(),
+            hash: "".to_string(),
+        };
+        Self::write_log(element).unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    /// Record a security event of the current user of the session.
+    pub fn log_current_user(session: &Arc<Session>, event: AuditEvent, err: Option<&ErrorCode>) {
+        let user = session
+            .get_current_user()
+            .map(|u| u.identity().to_string())
+            .unwrap_or_default();
+        Self::log(session, &user, None, event, err)
+    }
+
+    fn write_log(mut event: AuditLogElement) -> Result<()> {
+        let config = GlobalConfig::instance();
+        // Hold the head while writing, so the sinks see the events in chain order.
+        let mut head = AUDIT_CHAIN_HEAD.lock();
+        event.seal(
+            head.hash().to_string(),
+            config.log.audit.secret_key.as_bytes(),
+        );
+        let hash = event.hash.clone();
+
+        let event_str = serde_json::to_string(&event)?;
+        info!(target: "databend::log::audit", "{}", event_str);
+        let appended = AuditLogQueue::instance().and_then(|queue| queue.append_data(event));
+        head.advance(hash)?;
+        appended
+    }
+}
@@ -22,13 +22,22 @@ use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::NonEmptyString;
+use databend_common_users::unverified_token_subject;
+use databend_common_users::verify_key_pair_token;
 use databend_common_users::JwtAuthenticator;
+use databend_common_users::LdapAuthenticator;
 use databend_common_users::UserApiProvider;
+use log::info;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 pub enum Credential {
@@ -45,7 +54,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -53,23 +62,82 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
             ),
-        })
+            ldap_auth: LdapAuthenticator::create(cfg.query.ldap.clone())?,
+        }))
     }
 
     #[async_backtrace::framed]
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
+        let res = self.do_auth(&session, credential).await;
+        let (user, client_ip, method) = match credential {
+            Credential::Jwt { client_ip, .. } => {
+                let user = session
+                    .get_current_user()
+                    .map(|u| u.identity().to_string())
+                    .unwrap_or_default();
+                (user, client_ip, "jwt")
+            }
+            Credential::Password {
+                name, client_ip, ..
+            } => (name.clone(), client_ip, "password"),
+        };
+        let event = AuditEvent::new(AuditCategory::Login, "LOGIN", method);
+        AuditLog::log(
+            &session,
+            &user,
+            client_ip.as_deref(),
+            event,
+            res.as_ref().err(),
+        );
+        res
+    }
+
+    /// Get the user and check the client ip against the network policy of the user.
+    ///
+    /// Logins rejected by the network policy are recorded in the audit log.
+    #[async_backtrace::framed]
+    pub async fn get_user_with_client_ip(
+        &self,
+        session: &Arc<Session>,
+        identity: UserIdentity,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
+        let user_api = UserApiProvider::instance();
+        let tenant = session.get_current_tenant();
+        let user = user_api.get_user(&tenant, identity).await?;
+        if let Err(e) = user_api.check_client_ip(&tenant, &user, client_ip).await {
+            let policy = user.option.network_policy().cloned().unwrap_or_default();
+            let event = AuditEvent::new(AuditCategory::NetworkPolicy, "LOGIN", policy);
+            AuditLog::log(
+                session,
+                &user.identity().to_string(),
+                client_ip,
+                event,
+                Some(&e),
+            );
+            return Err(e);
+        }
+        Ok(user)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_auth(&self, session: &Arc<Session>, credential: &Credential) -> Result<()> {
         let user_api = UserApiProvider::instance();
         match credential {
             Credential::Jwt {
                 token: t,
                 client_ip,
             } => {
+                if self.auth_key_pair(session, t, client_ip.as_deref()).await? {
+                    return Ok(());
+                }
+
                 let jwt_auth = self
                     .jwt_auth
                     .as_ref()
@@ -90,8 +158,8 @@ impl AuthMgr {
                 let identity = UserIdentity::new(&user_name, "%");
 
                 // create a new user for this identity if not exists
-                let user = match user_api
-                    .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
+                let user = match self
+                    .get_user_with_client_ip(session, identity.clone(), client_ip.as_deref())
                     .await
                 {
                     Ok(user_info) => match user_info.auth_info {
@@ -133,16 +201,36 @@ impl AuthMgr {
             } => {
                 let tenant = session.get_current_tenant();
                 let identity = UserIdentity::new(n, "%");
-                let user = user_api
-                    .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
-                    .await?;
+                let user = match self
+                    .get_user_with_client_ip(session, identity.clone(), client_ip.as_deref())
+                    .await
+                {
+                    Ok(user) => user,
+                    Err(e)
+                        if e.code() == ErrorCode::UNKNOWN_USER && self.ldap_auto_create_user() =>
+                    {
+                        let password = p.as_deref().unwrap_or_default();
+                        let user = self.auth_ldap(&tenant, n, None, password).await?;
+                        session.set_authed_user(user, None).await?;
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e),
+                };
                 // Check password policy for login
                 UserApiProvider::instance()
                     .check_login_password(&tenant, identity.clone(), &user)
                     .await?;
 
+                let mut ldap_user = None;
                 let authed = match &user.auth_info {
                     AuthInfo::None => Ok(()),
+                    AuthInfo::Ldap => match p {
+                        None => Err(ErrorCode::AuthenticateFailure("password required")),
+                        Some(p) => self
+                            .auth_ldap(&tenant, n, Some(user.clone()), p)
+                            .await
+                            .map(|u| ldap_user = Some(u)),
+                    },
                     AuthInfo::Password {
                         hash_value: h,
                         hash_method: t,
@@ -164,9 +252,114 @@ impl AuthMgr {
 
                 authed?;
 
+                let user = ldap_user.unwrap_or(user);
                 session.set_authed_user(user, None).await?;
             }
         };
         Ok(())
     }
+
+    /// Authenticate with a token signed by the user's own private key.
+    ///
+    /// Returns false if the token is not issued for a user `IDENTIFIED WITH key_pair`,
+    /// so the caller can fall back to other token types.
+    #[async_backtrace::framed]
+    pub async fn auth_key_pair(
+        &self,
+        session: &Arc<Session>,
+        token: &str,
+        client_ip: Option<&str>,
+    ) -> Result<bool> {
+        let Some(user_name) = unverified_token_subject(token) else {
+            return Ok(false);
+        };
+        let user_api = UserApiProvider::instance();
+        let tenant = session.get_current_tenant();
+        let identity = UserIdentity::new(&user_name, "%");
+        let user = match self
+            .get_user_with_client_ip(session, identity.clone(), client_ip)
+            .await
+        {
+            Ok(user) if user.auth_info == AuthInfo::KeyPair => user,
+            Ok(_) => return Ok(false),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_USER => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        // Locked out users can't log in with a key pair either.
+        user_api
+            .check_login_password(&tenant, identity.clone(), &user)
+            .await?;
+
+        let authed = verify_key_pair_token(token, &user);
+        user_api
+            .update_user_login_result(tenant, identity, authed.is_ok())
+            .await?;
+        authed?;
+
+        session.set_authed_user(user, None).await?;
+        Ok(true)
+    }
+
+    /// Whether an unknown user may log in with LDAP and be created on the fly.
+    pub fn ldap_auto_create_user(&self) -> bool {
+        self.ldap_auth
+            .as_ref()
+            .map(|ldap| ldap.auto_create_user())
+            .unwrap_or(false)
+    }
+
+    /// Verify the password of `name` against the LDAP server.
+    ///
+    /// The user is created if `user` is None, and the roles mapped from LDAP groups
+    /// are granted or revoked to follow the current group membership. Roles not
+    /// covered by the mapping are left untouched.
+    #[async_backtrace::framed]
+    pub async fn auth_ldap(
+        &self,
+        tenant: &NonEmptyString,
+        name: &str,
+        user: Option<UserInfo>,
+        password: &[u8],
+    ) -> Result<UserInfo> {
+        let ldap_auth = self
+            .ldap_auth
+            .as_ref()
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("ldap auth not configured."))?;
+        let identity = ldap_auth.authenticate(name, password).await?;
+        let mapped_roles = ldap_auth.mapped_roles(&identity.groups);
+        let user_api = UserApiProvider::instance();
+
+        let mut user = match user {
+            Some(user) => user,
+            None => {
+                let mut user_info = UserInfo::new(name, "%", AuthInfo::Ldap);
+                for role in mapped_roles.iter() {
+                    user_info.grants.grant_role(role.clone());
+                }
+                user_api
+                    .add_user(tenant, user_info.clone(), &CreateOption::CreateIfNotExists)
+                    .await?;
+                info!("created ldap user {} ({})", name, identity.dn);
+                return Ok(user_info);
+            }
+        };
+
+        let current_roles = user.grants.roles();
+        for role in ldap_auth.managed_roles() {
+            let has_role = current_roles.contains(&role);
+            if mapped_roles.contains(&role) && !has_role {
+                user_api
+                    .grant_role_to_user(tenant.clone(), user.identity(), role.clone())
+                    .await?;
+                user.grants.grant_role(role);
+            } else if !mapped_roles.contains(&role) && has_role {
+                user_api
+                    .revoke_role_from_user(tenant, user.identity(), role.clone())
+                    .await?;
+                user.grants.revoke_role(&role);
+            }
+        }
+        Ok(user)
+    }
 }
@@ -74,6 +74,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -507,6 +509,13 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Ok(self.ctx.meta.set_table_row_access_policy(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -20,6 +20,7 @@ use databend_common_meta_app::schema::DatabaseIdent;
 use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_storages_system::AuditLogTable;
 use databend_common_storages_system::BackgroundJobTable;
 use databend_common_storages_system::BackgroundTaskTable;
 use databend_common_storages_system::BacktraceTable;
@@ -62,6 +63,7 @@ use databend_common_storages_system::TracingTable;
 use databend_common_storages_system::UserFunctionsTable;
 use databend_common_storages_system::UsersTable;
 use databend_common_storages_system::VirtualColumnsTable;
+use databend_common_storages_system::WorkloadGroupsTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -109,6 +111,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -131,6 +137,7 @@ impl SystemDatabase {
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
+            WorkloadGroupsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
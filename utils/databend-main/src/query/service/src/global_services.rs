@@ -33,6 +33,7 @@ use databend_common_storages_iceberg::IcebergCreator;
 use databend_common_tracing::GlobalLogger;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_common_users::WorkloadGroupManager;
 use databend_storages_common_cache_manager::CacheManager;
 
 use crate::api::DataExchangeManager;
@@ -108,6 +109,7 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        WorkloadGroupManager::init()?;
         ShareEndpointManager::init()?;
 
         DataOperator::init(&config.storage).await?;
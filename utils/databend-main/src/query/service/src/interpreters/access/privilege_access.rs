@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -28,11 +30,23 @@ use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_sql::optimizer::get_udf_names;
+use databend_common_sql::optimizer::ColumnSet;
+use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::PresignAction;
+use databend_common_sql::plans::RelOperator;
 use databend_common_sql::plans::RewriteKind;
+use databend_common_sql::plans::SubqueryDesc;
+use databend_common_sql::BaseTableColumn;
+use databend_common_sql::ColumnEntry;
+use databend_common_sql::IndexType;
+use databend_common_sql::Metadata;
+use databend_common_sql::VirtualColumn;
 use databend_common_users::RoleCacheManager;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -50,13 +64,14 @@ enum ObjectId {
 // some statements like `SELECT 1`, `SHOW USERS`, `SHOW ROLES`, `SHOW TABLES` will be
 // rewritten to the queries on the system tables, we need to skip the privilege check on
 // these tables.
-const SYSTEM_TABLES_ALLOW_LIST: [&str; 15] = [
+const SYSTEM_TABLES_ALLOW_LIST: [&str; 16] = [
     "catalogs",
     "columns",
     "databases",
     "tables",
     "tables_with_history",
     "password_policies",
+    "workload_groups",
     "streams",
     "virtual_columns",
     "users",
@@ -126,11 +141,14 @@ impl PrivilegeAccess {
                 catalog_name: catalog_name.clone(),
                 db_id: *db_id,
             },
-            GrantObject::TableById(catalog_name, db_id, table_id) => OwnershipObject::Table {
-                catalog_name: catalog_name.clone(),
-                db_id: *db_id,
-                table_id: *table_id,
-            },
+            GrantObject::TableById(catalog_name, db_id, table_id)
+            | GrantObject::TableColumnById(catalog_name, db_id, table_id, _) => {
+                OwnershipObject::Table {
+                    catalog_name: catalog_name.clone(),
+                    db_id: *db_id,
+                    table_id: *table_id,
+                }
+            }
             GrantObject::Stage(name) => OwnershipObject::Stage {
                 name: name.to_string(),
             },
@@ -270,6 +288,112 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    /// Validate the privilege on a table, falling back to the privileges granted on its columns
+    /// when the table-level check is denied. All of `columns` must be granted in that case.
+    async fn validate_table_columns_access(
+        &self,
+        catalog_name: &str,
+        db_name: &str,
+        table_name: &str,
+        columns: &BTreeSet<String>,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let err = match self
+            .validate_table_access(catalog_name, db_name, table_name, vec![privilege], false)
+            .await
+        {
+            Ok(_) => return Ok(()),
+            Err(err) if err.code() == ErrorCode::PermissionDenied("").code() => err,
+            Err(err) => return Err(err),
+        };
+        // queries like `SELECT COUNT(*) FROM t` read no column, they need the table privilege.
+        if columns.is_empty() {
+            return Err(err);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let db_id = catalog
+            .get_database(tenant.as_str(), db_name)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let table = catalog
+            .get_table(tenant.as_str(), db_name, table_name)
+            .await?;
+        let schema = table.schema();
+        let session = self.ctx.get_current_session();
+        for column in columns {
+            let Some(field) = schema.fields().iter().find(|f| f.name() == column) else {
+                return Err(err);
+            };
+            let object = GrantObject::TableColumnById(
+                catalog_name.to_string(),
+                db_id,
+                table.get_id(),
+                field.column_id(),
+            );
+            if session
+                .validate_privilege(&object, vec![privilege])
+                .await
+                .is_err()
+            {
+                let roles_name = session
+                    .get_all_effective_roles()
+                    .await?
+                    .iter()
+                    .map(|r| r.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, privilege {:?} is required on '{}'.'{}'.'{}' or on its column '{}' for user {} with roles [{}]",
+                    vec![privilege],
+                    catalog_name,
+                    db_name,
+                    table_name,
+                    column,
+                    &self.ctx.get_current_user()?.identity(),
+                    roles_name,
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate SELECT on the columns read by a DML statement, which can't be used to read
+    /// columns not granted, e.g. `UPDATE t SET granted = not_granted`. `columns` are read by
+    /// the expressions of the statement, `scan_columns` by the tables it scans.
+    async fn validate_read_columns_access(
+        &self,
+        metadata: &Metadata,
+        columns: ColumnSet,
+        scan_columns: HashMap<IndexType, ColumnSet>,
+    ) -> Result<()> {
+        let mut read_columns = scan_columns;
+        for index in columns {
+            if let Some(table_index) = metadata.column(index).table_index() {
+                read_columns.entry(table_index).or_default().insert(index);
+            }
+        }
+        for (table_index, columns) in read_columns {
+            let table = metadata.table(table_index);
+            if table.is_source_of_view() || table.is_source_of_stage() {
+                continue;
+            }
+            let columns = scan_column_names(metadata, &columns);
+            self.validate_table_columns_access(
+                table.catalog(),
+                table.database(),
+                table.name(),
+                &columns,
+                UserPrivilegeType::Select,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
     async fn has_ownership(
         &self,
         session: &Arc<Session>,
@@ -313,7 +437,8 @@ impl PrivilegeAccess {
                 let db_name = catalog.get_db_name_by_id(*db_id).await?;
                 (db_name.to_lowercase(), "".to_string())
             }
-            GrantObject::TableById(catalog_name, db_id, table_id) => {
+            GrantObject::TableById(catalog_name, db_id, table_id)
+            | GrantObject::TableColumnById(catalog_name, db_id, table_id, _) => {
                 let catalog = self.ctx.get_catalog(catalog_name).await?;
                 let db_name = catalog.get_db_name_by_id(*db_id).await?;
                 let table_name = catalog.get_table_name_by_id(*table_id).await?;
@@ -336,7 +461,8 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
-            | GrantObject::TableById(_, _, _) => true,
+            | GrantObject::TableById(_, _, _)
+            | GrantObject::TableColumnById(_, _, _, _) => true,
             GrantObject::Global => false,
         };
 
@@ -363,7 +489,8 @@ impl PrivilegeAccess {
                     .collect::<Vec<_>>()
                     .join(",");
                 match grant_object {
-                    GrantObject::TableById(catalog_name, _, _) => {
+                    GrantObject::TableById(catalog_name, _, _)
+                    | GrantObject::TableColumnById(catalog_name, _, _, _) => {
                         Err(ErrorCode::PermissionDenied(format!(
                             "Permission denied, privilege {:?} is required on '{}'.'{}'.'{}' for user {} with roles [{}]",
                             privileges.clone(),
@@ -472,6 +599,22 @@ impl PrivilegeAccess {
 impl AccessChecker for PrivilegeAccess {
     #[async_backtrace::framed]
     async fn check(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let res = self.check_plan(ctx, plan).await;
+        if let Err(err) = &res {
+            if err.code() == ErrorCode::PERMISSION_DENIED {
+                // The query text may carry secrets, refer to the query by id instead.
+                let event =
+                    AuditEvent::new(AuditCategory::Privilege, "ACCESS DENIED", ctx.get_id());
+                AuditLog::log_current_user(&ctx.get_current_session(), event, Some(err));
+            }
+        }
+        res
+    }
+}
+
+impl PrivilegeAccess {
+    #[async_backtrace::framed]
+    async fn check_plan(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
         let user = self.ctx.get_current_user()?;
         let (identity, grant_set) = (user.identity().to_string(), user.grants);
 
@@ -572,6 +715,7 @@ impl AccessChecker for PrivilegeAccess {
                 }
 
                 let metadata = metadata.read().clone();
+                let scan_columns = s_expr.get_scan_columns();
 
                 for table in metadata.tables() {
                     if enable_experimental_rbac_check && table.is_source_of_stage() {
@@ -592,7 +736,11 @@ impl AccessChecker for PrivilegeAccess {
                     // like this sql: copy into t from (select * from @s3); will bind a mock table with name `system.read_parquet(s3)`
                     // this is no means to check table `system.read_parquet(s3)` privilege
                     if !table.is_source_of_stage() {
-                        self.validate_table_access(catalog_name, table.database(), table.name(), vec![UserPrivilegeType::Select], false).await?
+                        let columns = scan_columns
+                            .get(&table.index())
+                            .map(|columns| scan_column_names(&metadata, columns))
+                            .unwrap_or_default();
+                        self.validate_table_columns_access(catalog_name, table.database(), table.name(), &columns, UserPrivilegeType::Select).await?
                     }
                 }
             }
@@ -654,6 +802,15 @@ impl AccessChecker for PrivilegeAccess {
             Plan::RefreshVirtualColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Super], false).await?
             }
+            Plan::CreateTableIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Create], false).await?
+            }
+            Plan::DropTableIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Drop], plan.if_exists).await?
+            }
+            Plan::RefreshTableIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Super], false).await?
+            }
 
             // Table.
             Plan::ShowCreateTable(plan) => {
@@ -703,6 +860,18 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Drop], false).await?
             }
+            Plan::CreateTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
+            Plan::DropTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
             Plan::ReclusterTable(plan) => {
                 if enable_experimental_rbac_check {
                     if let Some(scalar) = &plan.push_downs {
@@ -732,7 +901,8 @@ impl AccessChecker for PrivilegeAccess {
             }
             // Others.
             Plan::Insert(plan) => {
-                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Insert], false).await?;
+                let columns = plan.schema.fields().iter().map(|f| f.name().clone()).collect();
+                self.validate_table_columns_access(&plan.catalog, &plan.database, &plan.table, &columns, UserPrivilegeType::Insert).await?;
                 match &plan.source {
                     InsertInputSource::SelectPlan(plan) => {
                         self.check(ctx, plan).await?;
@@ -799,6 +969,29 @@ impl AccessChecker for PrivilegeAccess {
                     }
                 }
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete], false).await?;
+                let mut read_columns = ColumnSet::new();
+                collect_join_filter_columns(&plan.input, &mut read_columns)?;
+                for matched_evaluator in &plan.matched_evaluators {
+                    if let Some(condition) = &matched_evaluator.condition {
+                        read_columns.extend(condition.used_columns());
+                    }
+                    for scalar in matched_evaluator.update.iter().flat_map(|updates| updates.values()) {
+                        read_columns.extend(scalar.used_columns());
+                    }
+                }
+                for unmatched_evaluator in &plan.unmatched_evaluators {
+                    if let Some(condition) = &unmatched_evaluator.condition {
+                        read_columns.extend(condition.used_columns());
+                    }
+                    for value in &unmatched_evaluator.values {
+                        read_columns.extend(value.used_columns());
+                    }
+                }
+                // the source is read as a whole, the target is only read by the expressions.
+                let mut scan_columns = plan.input.get_scan_columns();
+                scan_columns.remove(&plan.target_table_idx);
+                let metadata = plan.meta_data.read().clone();
+                self.validate_read_columns_access(&metadata, read_columns, scan_columns).await?;
             }
             Plan::Delete(plan) => {
                 if enable_experimental_rbac_check {
@@ -819,7 +1012,10 @@ impl AccessChecker for PrivilegeAccess {
                         }
                     }
                 }
-                self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, vec![UserPrivilegeType::Delete], false).await?
+                self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, vec![UserPrivilegeType::Delete], false).await?;
+                let read_columns = plan.selection.iter().flat_map(|selection| selection.used_columns()).collect();
+                let metadata = plan.metadata.read().clone();
+                self.validate_read_columns_access(&metadata, read_columns, subquery_scan_columns(&plan.subquery_desc)).await?;
             }
             Plan::Update(plan) => {
                 if enable_experimental_rbac_check {
@@ -844,7 +1040,13 @@ impl AccessChecker for PrivilegeAccess {
                         }
                     }
                 }
-                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Update], false).await?;
+                let table = self.ctx.get_table(&plan.catalog, &plan.database, &plan.table).await?;
+                let schema = table.schema();
+                let columns = plan.update_list.keys().map(|index| schema.field(*index).name().clone()).collect();
+                self.validate_table_columns_access(&plan.catalog, &plan.database, &plan.table, &columns, UserPrivilegeType::Update).await?;
+                let read_columns = plan.update_list.values().chain(plan.selection.iter()).flat_map(|scalar| scalar.used_columns()).collect();
+                let metadata = plan.metadata.read().clone();
+                self.validate_read_columns_access(&metadata, read_columns, subquery_scan_columns(&plan.subquery_desc)).await?;
             }
             Plan::CreateView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?
@@ -953,6 +1155,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -979,6 +1184,10 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateRowAccessPolicy(_) | Plan::DropRowAccessPolicy(_) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
@@ -996,6 +1205,7 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
             Plan::Begin => {}
             Plan::Commit => {}
             Plan::Abort => {}
@@ -1057,3 +1267,44 @@ async fn has_priv(
             }
         }))
 }
+
+fn subquery_scan_columns(subquery_desc: &[SubqueryDesc]) -> HashMap<IndexType, ColumnSet> {
+    let mut scan_columns: HashMap<IndexType, ColumnSet> = HashMap::new();
+    for subquery in subquery_desc {
+        for (table_index, columns) in subquery.input_expr.get_scan_columns() {
+            scan_columns.entry(table_index).or_default().extend(columns);
+        }
+    }
+    scan_columns
+}
+
+// The columns read by the join conditions and the filters, e.g. the `ON` of MERGE.
+fn collect_join_filter_columns(s_expr: &SExpr, columns: &mut ColumnSet) -> Result<()> {
+    match s_expr.plan() {
+        RelOperator::Join(join) => columns.extend(join.used_columns()?),
+        RelOperator::Filter(filter) => columns.extend(filter.used_columns()?),
+        _ => {}
+    }
+    for child in s_expr.children() {
+        collect_join_filter_columns(child, columns)?;
+    }
+    Ok(())
+}
+
+// The names of the table columns read by a scan, inner fields of a tuple and virtual columns
+// are resolved to the top-level column they come from.
+fn scan_column_names(metadata: &Metadata, columns: &ColumnSet) -> BTreeSet<String> {
+    columns
+        .iter()
+        .filter_map(|index| match metadata.column(*index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => column_name
+                .split(':')
+                .next()
+                .map(|column_name| column_name.to_string()),
+            ColumnEntry::VirtualColumn(VirtualColumn {
+                source_column_name, ..
+            }) => Some(source_column_name.clone()),
+            ColumnEntry::DerivedColumn(_) | ColumnEntry::InternalColumn(_) => None,
+        })
+        .collect()
+}
@@ -67,7 +67,8 @@ pub async fn validate_grant_object_exists(
                 ));
             }
         }
-        GrantObject::TableById(catalog_name, db_id, table_id) => {
+        GrantObject::TableById(catalog_name, db_id, table_id)
+        | GrantObject::TableColumnById(catalog_name, db_id, table_id, _) => {
             let catalog = ctx.get_catalog(catalog_name).await?;
 
             if catalog.get_table_name_by_id(*table_id).await.is_err() {
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableStatistics;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::plans::AnalyzeTablePlan;
+use log::info;
+
+use crate::interpreters::AnalyzeTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+pub struct AnalyzeDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Hook analyze action with a on-finished callback.
+/// The analyze job runs in the background with its own query context, so the write returns
+/// without waiting for it. errors (if any) are ignored.
+pub async fn hook_analyze(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: AnalyzeDesc) {
+    if pipeline.is_empty() {
+        return;
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_ok() {
+            let session = ctx.get_current_session();
+            GlobalIORuntime::instance().spawn(ctx.get_id(), async move {
+                match do_analyze(session, desc).await {
+                    Ok(true) => {
+                        info!("execute analyze job successfully.");
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        info!("execute analyze job failed. {:?}", e);
+                    }
+                }
+            });
+        }
+        Ok(())
+    });
+}
+
+/// Re-analyze the table if the rows modified since its last analyze exceed the threshold,
+/// returns true if the table is analyzed.
+async fn do_analyze(session: Arc<Session>, desc: AnalyzeDesc) -> Result<bool> {
+    let ctx = session.create_background_query_context().await?;
+    let stale_percentage = ctx.get_settings().get_auto_analyze_stale_percentage()?;
+    if stale_percentage == 0 {
+        return Ok(false);
+    }
+
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    if !is_stale(&table.get_table_info().meta.statistics, stale_percentage) {
+        return Ok(false);
+    }
+
+    info!(
+        "statistics of table {}.{} are stale, running analyze job.",
+        desc.database, desc.table
+    );
+    let analyze_interpreter = AnalyzeTableInterpreter::try_create(ctx.clone(), AnalyzeTablePlan {
+        catalog: desc.catalog,
+        database: desc.database,
+        table: desc.table,
+    })?;
+    let mut build_res = analyze_interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() {
+        return Ok(false);
+    }
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()?;
+    }
+    Ok(true)
+}
+
+/// Whether the rows modified since the last analyze exceed `stale_percentage` percent of the
+/// rows of the table. Tables without the tracking (e.g. non-fuse tables) are never stale.
+fn is_stale(statistics: &TableStatistics, stale_percentage: u64) -> bool {
+    match statistics.rows_modified_since_analyze {
+        Some(modified) if modified > 0 && statistics.number_of_rows > 0 => {
+            modified as u128 * 100 >= statistics.number_of_rows as u128 * stale_percentage as u128
+        }
+        _ => false,
+    }
+}
@@ -17,6 +17,8 @@ use std::time::Instant;
 
 use databend_common_pipeline_core::Pipeline;
 
+use crate::interpreters::hook::analyze_hook::hook_analyze;
+use crate::interpreters::hook::analyze_hook::AnalyzeDesc;
 use crate::interpreters::hook::compact_hook::hook_compact;
 use crate::interpreters::hook::compact_hook::CompactHookTraceCtx;
 use crate::interpreters::hook::compact_hook::CompactTargetTableDescription;
@@ -56,12 +58,14 @@ impl HookOperator {
     /// Execute the hook operator.
     /// The hook operator will:
     /// 1. Compact if needed.
-    /// 2. Refresh aggregating index if needed.
-    /// 3. Refresh virtual columns if needed.
+    /// 2. Analyze if the statistics are stale.
+    /// 3. Refresh aggregating index if needed.
+    /// 4. Refresh virtual columns if needed.
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn execute(&self, pipeline: &mut Pipeline) {
         self.execute_compact(pipeline).await;
+        self.execute_analyze(pipeline).await;
         self.execute_refresh(pipeline).await;
     }
 
@@ -90,6 +94,19 @@ impl HookOperator {
         .await;
     }
 
+    /// Execute the analyze hook operator.
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    pub async fn execute_analyze(&self, pipeline: &mut Pipeline) {
+        let analyze_desc = AnalyzeDesc {
+            catalog: self.catalog.to_owned(),
+            database: self.database.to_owned(),
+            table: self.table.to_owned(),
+        };
+
+        hook_analyze(self.ctx.clone(), pipeline, analyze_desc).await;
+    }
+
     /// Execute the refresh hook operator.
     // 1. Refresh aggregating index.
     // 2. Refresh virtual columns.
@@ -26,6 +26,7 @@ use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
+use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_sql::plans::RefreshVirtualColumnPlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
@@ -37,6 +38,7 @@ use parking_lot::RwLock;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
+use crate::interpreters::RefreshTableIndexInterpreter;
 use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -89,6 +91,10 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         plans.extend_from_slice(&agg_index_plans);
     }
 
+    // Generate sync inverted indexes.
+    let inverted_index_plans = generate_refresh_inverted_index_plan(ctx.clone(), &desc).await?;
+    plans.extend_from_slice(&inverted_index_plans);
+
     // Generate virtual columns.
     if ctx
         .get_settings()
@@ -118,9 +124,8 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                     }
 
                     let settings = ctx_cloned.get_settings();
-                    let query_id = ctx_cloned.get_id();
                     build_res.set_max_threads(settings.get_max_threads()? as usize);
-                    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+                    let settings = ExecutorSettings::try_create(ctx_cloned.clone())?;
 
                     if build_res.main_pipeline.is_complete_pipeline()? {
                         let mut pipelines = build_res.sources_pipelines;
@@ -148,6 +153,20 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                     }
                     Ok(())
                 }
+                Plan::RefreshTableIndex(inverted_index_plan) => {
+                    let refresh_inverted_index_interpreter =
+                        RefreshTableIndexInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *inverted_index_plan,
+                        )?;
+                    let build_res = refresh_inverted_index_interpreter.execute2().await?;
+                    if !build_res.main_pipeline.is_empty() {
+                        return Err(ErrorCode::Internal(
+                            "Logical error, refresh inverted index is an empty pipeline.",
+                        ));
+                    }
+                    Ok(())
+                }
                 _ => unreachable!(),
             }
         });
@@ -223,6 +242,32 @@ async fn build_refresh_index_plan(
         .await
 }
 
+async fn generate_refresh_inverted_index_plan(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+) -> Result<Vec<Plan>> {
+    let segment_locs = ctx.get_segment_locations()?;
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+
+    let mut plans = vec![];
+    let indexes = &table.get_table_info().meta.indexes;
+    for index in indexes.values().filter(|index| index.sync_creation) {
+        let plan = RefreshTableIndexPlan {
+            catalog: desc.catalog.clone(),
+            database: desc.database.clone(),
+            table: desc.table.clone(),
+            index_name: index.name.clone(),
+            limit: None,
+            segment_locs: Some(segment_locs.clone()),
+        };
+        plans.push(Plan::RefreshTableIndex(Box::new(plan)));
+    }
+
+    Ok(plans)
+}
+
 async fn generate_refresh_virtual_column_plan(
     ctx: Arc<QueryContext>,
     desc: &RefreshDesc,
@@ -28,6 +28,8 @@ use databend_common_pipeline_core::SourcePipeBuilder;
 use log::error;
 use log::info;
 
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
@@ -53,6 +55,11 @@ pub trait Interpreter: Sync + Send {
 
     fn is_ddl(&self) -> bool;
 
+    /// Return the security event to record in the audit log, such as "GRANT"
+    fn audit_event(&self) -> Option<AuditEvent> {
+        None
+    }
+
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
     #[async_backtrace::framed]
     #[minitrace::trace]
@@ -62,25 +69,47 @@ pub trait Interpreter: Sync + Send {
         log_query_start(&ctx);
 
         if let Err(err) = ctx.check_aborting() {
+            ctx.release_workload_group_permit();
             log_query_finished(&ctx, Some(err.clone()), false);
             return Err(err);
         }
         if self.is_ddl() {
-            CommitInterpreter::try_create(ctx.clone())?
-                .execute2()
-                .await?;
+            let commit_res = match CommitInterpreter::try_create(ctx.clone()) {
+                Ok(commit) => commit.execute2().await,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = commit_res {
+                ctx.release_workload_group_permit();
+                return Err(err);
+            }
             ctx.clear_tables_cache();
         }
         if !self.is_txn_command() && ctx.txn_mgr().lock().is_fail() {
             let error = ErrorCode::CurrentTransactionIsAborted(
                 "current transaction is aborted, commands ignored until end of transaction block",
             );
+            ctx.release_workload_group_permit();
             log_query_finished(&ctx, Some(error.clone()), false);
             return Err(error);
         }
-        let mut build_res = match self.execute2().await {
+        // Queries are usually admitted when their context is created,
+        // this admits the ones whose context is created otherwise.
+        if ctx.get_current_session().get_type().is_user_session() {
+            if let Err(err) = ctx.acquire_workload_group_permit().await {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()), false);
+                return Err(err);
+            }
+        }
+        let build_res = self.execute2().await;
+        if let Some(event) = self.audit_event() {
+            let session = ctx.get_current_session();
+            AuditLog::log_current_user(&session, event, build_res.as_ref().err());
+        }
+        let mut build_res = match build_res {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.release_workload_group_permit();
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()), false);
                 return Err(build_error);
@@ -88,6 +117,7 @@ pub trait Interpreter: Sync + Send {
         };
 
         if build_res.main_pipeline.is_empty() {
+            ctx.release_workload_group_permit();
             InterpreterMetrics::record_query_finished(&ctx, None);
             log_query_finished(&ctx, None, false);
 
@@ -134,6 +164,8 @@ pub trait Interpreter: Sync + Send {
                 Err(e) => Some(e.clone()),
             };
 
+            query_ctx.release_workload_group_permit();
+
             InterpreterMetrics::record_query_finished(&query_ctx, err_opt.clone());
             log_query_finished(&query_ctx, err_opt, has_profiles);
 
@@ -146,9 +178,8 @@ pub trait Interpreter: Sync + Send {
         ctx.set_status_info("executing pipeline");
 
         let settings = ctx.get_settings();
-        let query_id = ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let settings = ExecutorSettings::try_create(ctx.clone())?;
 
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
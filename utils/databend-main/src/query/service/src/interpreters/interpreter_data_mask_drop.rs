@@ -21,6 +21,8 @@ use databend_common_sql::plans::DropDatamaskPolicyPlan;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_data_mask_feature::get_datamask_handler;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -43,6 +45,14 @@ impl Interpreter for DropDataMaskInterpreter {
         "DropDataMaskInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "DROP MASKING POLICY",
+            self.plan.name.clone(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
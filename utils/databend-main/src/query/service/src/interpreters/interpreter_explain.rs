@@ -393,9 +393,8 @@ impl ExplainInterpreter {
         mut build_res: PipelineBuildResult,
     ) -> Result<HashMap<u32, PlanProfile>> {
         let settings = self.ctx.get_settings();
-        let query_id = self.ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id.clone())?;
+        let settings = ExecutorSettings::try_create(self.ctx.clone())?;
 
         match build_res.main_pipeline.is_complete_pipeline()? {
             true => {
@@ -277,6 +277,17 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateTableIndex(index) => Ok(Arc::new(CreateTableIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::DropTableIndex(index) => Ok(Arc::new(DropTableIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::RefreshTableIndex(index) => Ok(Arc::new(
+                RefreshTableIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -455,6 +466,20 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTableRef(p) => Ok(Arc::new(CreateTableRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTableRef(p) => Ok(Arc::new(DropTableRefInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -467,6 +492,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -500,6 +534,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -21,6 +21,8 @@ use databend_common_sql::plans::CreateNetworkPolicyPlan;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -44,6 +46,14 @@ impl Interpreter for CreateNetworkPolicyInterpreter {
         "CreateNetworkPolicyInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "CREATE NETWORK POLICY",
+            self.plan.name.clone(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
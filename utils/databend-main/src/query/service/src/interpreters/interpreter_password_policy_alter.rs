@@ -29,6 +29,8 @@ use databend_common_users::DEFAULT_PASSWORD_MIN_LENGTH;
 use databend_common_users::DEFAULT_PASSWORD_MIN_SPECIAL_CHARS;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -52,6 +54,14 @@ impl Interpreter for AlterPasswordPolicyInterpreter {
         "AlterPasswordPolicyInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "ALTER PASSWORD POLICY",
+            self.plan.name.clone(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
@@ -29,6 +29,8 @@ use log::debug;
 use log::error;
 use log::info;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::common::validate_grant_object_exists;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -101,9 +103,11 @@ impl GrantPrivilegeInterpreter {
             GrantObject::UDF(name) => Ok(OwnershipObject::UDF {
                 name: name.to_string(),
             }),
-            GrantObject::Global => Err(ErrorCode::IllegalGrant(
-                "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
-            )),
+            GrantObject::Global | GrantObject::TableColumnById(_, _, _, _) => {
+                Err(ErrorCode::IllegalGrant(
+                    "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
+                ))
+            }
         }
     }
 
@@ -168,6 +172,21 @@ impl Interpreter for GrantPrivilegeInterpreter {
         "GrantPrivilegeInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        let object = format!(
+            "{} ON {} TO{}",
+            self.plan.priv_types,
+            self.plan
+                .on
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.plan.principal
+        );
+        Some(AuditEvent::new(AuditCategory::Grant, "GRANT", object))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
@@ -179,8 +198,10 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         let plan = self.plan.clone();
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in &plan.on {
+            validate_grant_privileges(object, plan.priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -190,14 +211,22 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         match plan.principal {
             PrincipalIdentity::User(user) => {
-                user_mgr
-                    .grant_privileges_to_user(tenant.clone(), user, plan.on, plan.priv_types)
-                    .await?;
+                for object in plan.on {
+                    user_mgr
+                        .grant_privileges_to_user(
+                            tenant.clone(),
+                            user.clone(),
+                            object,
+                            plan.priv_types,
+                        )
+                        .await?;
+                }
             }
             PrincipalIdentity::Role(role) => {
                 if plan.priv_types.has_privilege(Ownership) && plan.priv_types.len() == 1 {
+                    let object = &plan.on[0];
                     let owner_object = self
-                        .convert_to_ownerobject(tenant.as_str(), &plan.on, plan.on.catalog())
+                        .convert_to_ownerobject(tenant.as_str(), object, object.catalog())
                         .await?;
                     if self.ctx.get_current_role().is_some() {
                         self.grant_ownership(&self.ctx, &tenant, &owner_object, &role)
@@ -208,9 +237,11 @@ impl Interpreter for GrantPrivilegeInterpreter {
                         ));
                     }
                 } else {
-                    user_mgr
-                        .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
-                        .await?;
+                    for object in plan.on {
+                        user_mgr
+                            .grant_privileges_to_role(&tenant, &role, object, plan.priv_types)
+                            .await?;
+                    }
                     RoleCacheManager::instance().invalidate_cache(&tenant);
                 }
             }
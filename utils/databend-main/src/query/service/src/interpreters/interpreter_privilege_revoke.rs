@@ -22,6 +22,8 @@ use databend_common_users::UserApiProvider;
 use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::common::validate_grant_object_exists;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -46,6 +48,21 @@ impl Interpreter for RevokePrivilegeInterpreter {
         "RevokePrivilegeInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        let object = format!(
+            "{} ON {} FROM{}",
+            self.plan.priv_types,
+            self.plan
+                .on
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.plan.principal
+        );
+        Some(AuditEvent::new(AuditCategory::Grant, "REVOKE", object))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
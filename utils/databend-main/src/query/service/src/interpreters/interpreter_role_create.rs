@@ -24,6 +24,8 @@ use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use databend_common_users::BUILTIN_ROLE_PUBLIC;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -47,6 +49,14 @@ impl Interpreter for CreateRoleInterpreter {
         "CreateRoleInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "CREATE ROLE",
+            self.plan.role_name.clone(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
@@ -21,6 +21,8 @@ use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -44,6 +46,11 @@ impl Interpreter for RevokeRoleInterpreter {
         "RevokeRoleInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        let object = format!("ROLE {} FROM{}", self.plan.role, self.plan.principal);
+        Some(AuditEvent::new(AuditCategory::Grant, "REVOKE", object))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
@@ -104,6 +104,33 @@ impl Interpreter for ShowGrantsInterpreter {
                         &privileges_str, catalog_name, db_name, table_name, identity
                     ));
                 }
+                GrantObject::TableColumnById(catalog_name, db_id, table_id, column_id) => {
+                    let privileges: UserPrivilegeSet = (*grant_entry.privileges()).into();
+                    let catalog = self.ctx.get_catalog(catalog_name).await?;
+                    let db_name = catalog.get_db_name_by_id(*db_id).await?;
+                    let table_name = catalog.get_table_name_by_id(*table_id).await?;
+                    let (_, table_meta) = catalog.get_table_meta_by_id(*table_id).await?;
+                    // the column might have been dropped after the grant, skip it.
+                    let Some(column_name) = table_meta
+                        .schema
+                        .fields()
+                        .iter()
+                        .find(|f| f.column_id() == *column_id)
+                        .map(|f| f.name().clone())
+                    else {
+                        continue;
+                    };
+                    // each privilege is rendered with its column list, as it is granted.
+                    let privileges_str = privileges
+                        .iter()
+                        .map(|privilege| format!("{}({})", privilege, column_name))
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    grant_list.push(format!(
+                        "GRANT {} ON '{}'.'{}'.'{}' TO {}",
+                        privileges_str, catalog_name, db_name, table_name, identity
+                    ));
+                }
                 GrantObject::DatabaseById(catalog_name, db_id) => {
                     let privileges_str = if grant_entry.has_all_available_privileges() {
                         "ALL".to_string()
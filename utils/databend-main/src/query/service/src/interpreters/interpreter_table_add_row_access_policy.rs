@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableRowAccessPolicyPlan;
+use databend_common_storages_share::save_share_table_info;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    fn audit_event(&self) -> Option<AuditEvent> {
+        let object = format!(
+            "{} ON {}.{}",
+            self.plan.policy, self.plan.database, self.plan.table
+        );
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "ADD ROW ACCESS POLICY",
+            object,
+        ))
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        if let Some(attached) = &table_info.meta.row_access_policy {
+            return Err(ErrorCode::RowAccessPolicyAlreadyExists(format!(
+                "Table {} already has row access policy {}",
+                self.plan.table, attached.policy
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_row_access_policy_handler();
+        let policy = handler
+            .get_row_access_policy(meta_api, tenant.to_string(), self.plan.policy.clone())
+            .await?;
+
+        // The columns are bound to the args of the policy by position.
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Row access policy {} expects {} columns, but got {}",
+                self.plan.policy,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+        let schema = table.schema();
+        for (column, (_, arg_type)) in self.plan.columns.iter().zip(policy.args.iter()) {
+            let field = schema
+                .field_with_name(column)
+                .map_err(|_| ErrorCode::UnknownColumn(format!("Cannot find column {}", column)))?;
+            let data_type = field.data_type().remove_nullable().to_string();
+            if !data_type.eq_ignore_ascii_case(arg_type) {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Column '{}' data type {} does not match to the row access policy arg type {}",
+                    column, data_type, arg_type,
+                )));
+            }
+        }
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: tenant.to_string(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action: SetTableRowAccessPolicyAction::Add(
+                self.plan.policy.clone(),
+                self.plan.columns.clone(),
+            ),
+        };
+        let res = catalog.set_table_row_access_policy(req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                tenant.as_str(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
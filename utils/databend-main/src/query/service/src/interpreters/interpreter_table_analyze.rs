@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::parser::quote::quote_ident;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::BLOCK_NAME_COL_NAME;
 use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::plans::AnalyzeTablePlan;
 use databend_common_sql::plans::Plan;
@@ -25,13 +32,25 @@ use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket;
+use databend_storages_common_table_meta::meta::MostCommonValue;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures_util::TryStreamExt;
 use itertools::Itertools;
 
 use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::stream::PullingExecutorStream;
+
+/// The upper limit of the rows sampled by ANALYZE TABLE to build histograms.
+const ANALYZE_MAX_SAMPLE_ROWS: u64 = 10_000_000;
 
 pub struct AnalyzeTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -42,6 +61,171 @@ impl AnalyzeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: AnalyzeTablePlan) -> Result<Self> {
         Ok(AnalyzeTableInterpreter { ctx, plan })
     }
+
+    /// Build the equi-height histogram and the most common values of the columns.
+    ///
+    /// Unlike the NDV sketches, histograms can't be merged, so they are always built
+    /// from the whole snapshot, or from randomly picked blocks of it if the table has more
+    /// rows than `analyze_sample_rows`. Only the picked blocks are read, the others are
+    /// pruned by their `_block_name`. The counts of a sample are scaled to the table.
+    /// The values of all the columns are counted in one scan of the sample.
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        table: &FuseTable,
+        snapshot: &TableSnapshot,
+        table_at_snapshot: &str,
+        columns: &[(ColumnId, String)],
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let settings = self.ctx.get_settings();
+        let num_buckets = settings.get_analyze_histogram_buckets()?;
+        let num_mcv = settings.get_analyze_most_common_values()?;
+        let sample_rows = match settings.get_analyze_sample_rows()? {
+            0 => ANALYZE_MAX_SAMPLE_ROWS,
+            sample_rows => sample_rows.min(ANALYZE_MAX_SAMPLE_ROWS),
+        };
+        let num_rows = snapshot.summary.row_count;
+        if num_buckets == 0 || num_rows == 0 || columns.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let (sample, scale_factor) = if num_rows > sample_rows {
+            let (blocks, sampled_rows) = table
+                .sample_blocks(self.ctx.clone(), snapshot, sample_rows)
+                .await?;
+            let block_names = blocks
+                .iter()
+                .map(|location| quote_ident(location, '\'', true))
+                .join(", ");
+            (
+                format!(" WHERE {BLOCK_NAME_COL_NAME} IN ({block_names})"),
+                num_rows as f64 / sampled_rows.max(1) as f64,
+            )
+        } else {
+            ("".to_string(), 1.0)
+        };
+
+        let select_list = columns
+            .iter()
+            .map(|(_, name)| quote_ident(name, '`', true))
+            .join(", ");
+        let sql = format!("SELECT {select_list} FROM {table_at_snapshot}{sample}");
+
+        let mut value_counts = (0..columns.len())
+            .map(|_| BTreeMap::<Scalar, u64>::new())
+            .collect::<Vec<_>>();
+        let mut stream = self.execute_sql_stream(&sql).await?;
+        while let Some(block) = stream.try_next().await? {
+            for (offset, counts) in value_counts.iter_mut().enumerate() {
+                let entry = block.get_by_offset(offset);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                for value in column.iter() {
+                    if !matches!(value, ScalarRef::Null) {
+                        *counts.entry(value.to_owned()).or_default() += 1;
+                    }
+                }
+            }
+        }
+
+        let mut histograms = HashMap::with_capacity(columns.len());
+        for ((column_id, _), counts) in columns.iter().zip(value_counts) {
+            let buckets = build_buckets(&counts, num_buckets, scale_factor);
+            if buckets.is_empty() {
+                continue;
+            }
+            histograms.insert(*column_id, ColumnHistogram {
+                buckets,
+                most_common_values: most_common_values(&counts, num_mcv, scale_factor),
+                num_rows,
+            });
+        }
+        Ok(histograms)
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_sql_stream(&self, sql: &str) -> Result<PullingExecutorStream> {
+        log::info!("Analyze via sql {:?}", sql);
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = &plan
+        else {
+            unreachable!()
+        };
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+        let physical_plan = builder.build(s_expr, bind_context.column_set()).await?;
+        let build_res =
+            build_query_pipeline(&self.ctx, &bind_context.columns, &physical_plan, false).await?;
+
+        let settings = ExecutorSettings::try_create(self.ctx.clone())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        self.ctx.set_executor(pulling_executor.get_inner())?;
+        PullingExecutorStream::create(pulling_executor)
+    }
+}
+
+fn scale(count: u64, scale_factor: f64) -> u64 {
+    (count as f64 * scale_factor).round() as u64
+}
+
+/// The values are assigned to the buckets by their accumulated number of rows,
+/// a value never spans two buckets.
+fn build_buckets(
+    counts: &BTreeMap<Scalar, u64>,
+    num_buckets: usize,
+    scale_factor: f64,
+) -> Vec<HistogramBucket> {
+    let total = counts.values().sum::<u64>();
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let mut current: Option<(u64, HistogramBucket)> = None;
+    let mut accumulated = 0;
+    for (value, count) in counts {
+        accumulated += count;
+        let id = (accumulated * num_buckets as u64).div_ceil(total);
+        if let Some((_, bucket)) = current.as_mut().filter(|(current_id, _)| *current_id == id) {
+            bucket.upper_bound = value.clone();
+            bucket.num_values += count;
+            bucket.num_distinct += 1;
+            continue;
+        }
+        buckets.extend(current.take().map(|(_, bucket)| bucket));
+        current = Some((id, HistogramBucket {
+            upper_bound: value.clone(),
+            num_values: *count,
+            num_distinct: 1,
+        }));
+    }
+    buckets.extend(current.map(|(_, bucket)| bucket));
+
+    for bucket in buckets.iter_mut() {
+        bucket.num_values = scale(bucket.num_values, scale_factor);
+    }
+    buckets
+}
+
+fn most_common_values(
+    counts: &BTreeMap<Scalar, u64>,
+    num_mcv: usize,
+    scale_factor: f64,
+) -> Vec<MostCommonValue> {
+    // The sort is stable, so the values of the same count are kept in order.
+    counts
+        .iter()
+        .filter(|(_, count)| **count > 1)
+        .sorted_by(|a, b| b.1.cmp(a.1))
+        .take(num_mcv)
+        .map(|(value, count)| MostCommonValue {
+            value: value.clone(),
+            count: scale(*count, scale_factor),
+        })
+        .collect()
 }
 
 #[async_trait::async_trait]
@@ -121,21 +305,29 @@ impl Interpreter for AnalyzeTableInterpreter {
                 .map(|c| {
                     format!(
                         "approx_count_distinct_state({DISTINCT_ERROR_RATE})({}) as ndv_{}",
-                        c.1, c.0
+                        quote_ident(&c.1, '`', true),
+                        c.0
                     )
                 })
                 .join(", ");
 
+            let table_at_snapshot = format!(
+                "{}.{} AT (snapshot => '{}')",
+                quote_ident(&plan.database, '`', true),
+                quote_ident(&plan.table, '`', true),
+                snapshot.snapshot_id.simple()
+            );
             let sql = format!(
-                "SELECT {select_expr}, {} as is_full from {}.{} AT (snapshot => '{}') {since_str} ",
+                "SELECT {select_expr}, {} as is_full from {table_at_snapshot} {since_str} ",
                 since_str.is_empty(),
-                plan.database,
-                plan.table,
-                snapshot.snapshot_id.simple(),
             );
 
             log::info!("Analyze via sql {:?}", sql);
 
+            let histograms = self
+                .build_histograms(table, &snapshot, &table_at_snapshot, &index_cols)
+                .await?;
+
             let mut planner = Planner::new(self.ctx.clone());
             let (plan, _) = planner.plan_sql(&sql).await?;
             let (select_plan, schema) = match &plan {
@@ -165,6 +357,7 @@ impl Interpreter for AnalyzeTableInterpreter {
                 &self.plan.database,
                 &self.plan.table,
                 snapshot.snapshot_id,
+                histograms,
                 &mut build_res.main_pipeline,
             )?;
             return Ok(build_res);
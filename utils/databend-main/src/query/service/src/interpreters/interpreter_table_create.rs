@@ -17,10 +17,13 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use chrono::Utc;
+use databend_common_ast::ast::Engine;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -30,15 +33,22 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRef;
+use databend_common_meta_app::schema::TableRefKind;
 use databend_common_meta_app::schema::TableStatistics;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaId;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_sql::field_default_value;
+use databend_common_sql::plans::CloneTableSource;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::io::check_iceberg_schema;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
@@ -46,6 +56,7 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use databend_common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -58,14 +69,20 @@ use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use databend_storages_common_table_meta::table::OPT_KEY_ENABLE_DELETION_VECTORS;
+use databend_storages_common_table_meta::table::OPT_KEY_ENABLE_ICEBERG_METADATA;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use log::error;
+use log::warn;
 
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
@@ -77,6 +94,9 @@ use crate::sql::plans::insert::InsertInputSource;
 use crate::sql::plans::Plan;
 use crate::storages::StorageDescription;
 
+/// Retries on the version conflicts of the source table when registering a clone.
+const MAX_CLONE_REF_RETRIES: usize = 10;
+
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: CreateTablePlan,
@@ -154,6 +174,15 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        // the metadata of a new iceberg table is kept in its location, initialize it
+        // before the table is registered. Existing iceberg tables are left as they are.
+        if engine == Engine::Iceberg {
+            if let Some(sp) = &self.plan.storage_params {
+                let dop = DataOperator::try_create(sp).await?;
+                IcebergTable::initialize(&dop, &self.plan.schema).await?;
+            }
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -241,10 +270,70 @@ impl CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
+        let Some((clone_source, snapshot_location)) =
+            self.plan.clone_source.as_ref().and_then(|clone_source| {
+                let location = clone_source.snapshot_location.as_ref()?;
+                Some((clone_source, location))
+            })
+        else {
+            self.do_create_table(None).await?;
+            return Ok(PipelineBuildResult::create());
+        };
+
+        // Pin the snapshot in the refs of the source table before the clone is created,
+        // so that the purge of the source table can't remove the files shared by the clone.
+        let pending_ref_name = TableRef::pending_clone_ref_name(&self.ctx.get_id());
+        self.update_clone_source_refs(clone_source, |refs| {
+            let now = Utc::now();
+            // the pending refs left by the clones that crashed are expired here.
+            refs.retain(|_, table_ref| !table_ref.is_expired_pending_clone(now));
+            refs.insert(pending_ref_name.clone(), TableRef {
+                kind: TableRefKind::Clone,
+                snapshot_location: snapshot_location.clone(),
+                cloned_table_id: None,
+                created_on: now,
+            });
+            true
+        })
+        .await?;
+
+        let res = self
+            .do_create_table(Some((clone_source, snapshot_location, &pending_ref_name)))
+            .await;
+
+        // The pending ref is renamed after the clone once it is created, it is left only if
+        // the clone is not created, e.g. the table exists or the creation fails.
+        let released = self
+            .update_clone_source_refs(clone_source, |refs| {
+                refs.remove(&pending_ref_name).is_some()
+            })
+            .await;
+        if let Err(e) = released {
+            warn!(
+                "release the ref {} of table {}.{} failed: {:?}",
+                pending_ref_name, clone_source.database, clone_source.table, e
+            );
+        }
+
+        res?;
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Creates the table, the snapshot shared by a clone is pinned by the pending ref,
+    /// which is renamed after the cloned table once it is created.
+    #[async_backtrace::framed]
+    async fn do_create_table(
+        &self,
+        pinned_clone_source: Option<(&CloneTableSource, &String, &String)>,
+    ) -> Result<()> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let mut stat = None;
         if !GlobalConfig::instance().query.management_mode {
-            if let Some(snapshot_loc) = self.plan.options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+            let snapshot_loc = match &self.plan.clone_source {
+                Some(clone_source) => clone_source.snapshot_location.as_ref(),
+                None => self.plan.options.get(OPT_KEY_SNAPSHOT_LOCATION),
+            };
+            if let Some(snapshot_loc) = snapshot_loc {
                 let operator = self.ctx.get_data_operator()?.operator();
                 let reader = MetaReaders::table_snapshot_reader(operator);
 
@@ -263,6 +352,8 @@ impl CreateTableInterpreter {
                     index_data_bytes: snapshot.summary.index_size,
                     number_of_segments: Some(snapshot.segments.len() as u64),
                     number_of_blocks: Some(snapshot.summary.block_count),
+                    rows_modified_since_analyze: Some(snapshot.rows_modified_since_analyze),
+                    analyzed_on: snapshot.analyzed_on,
                 });
             }
         }
@@ -274,6 +365,29 @@ impl CreateTableInterpreter {
 
         let reply = catalog.create_table(req.clone()).await?;
 
+        if let Some((clone_source, snapshot_location, pending_ref_name)) = pinned_clone_source {
+            if reply.new_table {
+                let renamed = self
+                    .update_clone_source_refs(clone_source, |refs| {
+                        refs.remove(pending_ref_name);
+                        refs.insert(TableRef::clone_ref_name(reply.table_id), TableRef {
+                            kind: TableRefKind::Clone,
+                            snapshot_location: snapshot_location.clone(),
+                            cloned_table_id: Some(reply.table_id),
+                            created_on: Utc::now(),
+                        });
+                        true
+                    })
+                    .await;
+                if let Err(e) = renamed {
+                    // The ref of the clone can't be registered, the clone is dropped instead
+                    // of being left with files that the purge of the source table may remove.
+                    self.drop_clone(reply.table_id).await?;
+                    return Err(e);
+                }
+            }
+        }
+
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
             let tenant = self.ctx.get_tenant();
@@ -307,7 +421,74 @@ impl CreateTableInterpreter {
             .await?;
         }
 
-        Ok(PipelineBuildResult::create())
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_clone(&self, table_id: MetaId) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: true,
+                tenant: self.plan.tenant.clone(),
+                table_name: self.plan.table.clone(),
+                tb_id: table_id,
+                db_id: db.get_db_info().ident.db_id,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Updates the refs of the source table of a clone, the refs pin the snapshots shared by
+    /// the clones, so that the purge of the source table keeps the files the clones refer to.
+    /// `update` returns false if the refs are not changed.
+    #[async_backtrace::framed]
+    async fn update_clone_source_refs(
+        &self,
+        clone_source: &CloneTableSource,
+        update: impl Fn(&mut BTreeMap<String, TableRef>) -> bool,
+    ) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&clone_source.catalog).await?;
+        let tenant = self.ctx.get_tenant();
+        let mut retries = 0;
+        loop {
+            let table = catalog
+                .get_table(tenant.as_str(), &clone_source.database, &clone_source.table)
+                .await?;
+            let table_info = table.get_table_info();
+            if table.get_id() != clone_source.table_id {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "table {}.{} was dropped or replaced while being cloned",
+                    clone_source.database, clone_source.table
+                )));
+            }
+
+            let mut new_table_meta = table_info.meta.clone();
+            if !update(&mut new_table_meta.refs) {
+                return Ok(());
+            }
+            let req = UpdateTableMetaReq {
+                table_id: table.get_id(),
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+            match catalog.update_table_meta(table_info, req).await {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && retries < MAX_CLONE_REF_RETRIES =>
+                {
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(()),
+            }
+        }
     }
 
     /// Build CreateTableReq from CreateTablePlanV2.
@@ -353,8 +534,17 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&table_meta.options, schema.clone())?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_deletion_vectors(&table_meta.options)?;
+        is_valid_data_retention(&table_meta.options)?;
+        is_valid_iceberg_metadata(
+            &table_meta.options,
+            table_meta.options.get(OPT_KEY_STORAGE_FORMAT),
+            &schema,
+        )?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -366,6 +556,18 @@ impl CreateTableInterpreter {
             }
         }
 
+        // the cloned table starts from the snapshot of the source table.
+        if let Some(snapshot_loc) = self
+            .plan
+            .clone_source
+            .as_ref()
+            .and_then(|source| source.snapshot_location.as_ref())
+        {
+            table_meta
+                .options
+                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc.clone());
+        }
+
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
@@ -421,6 +623,8 @@ impl CreateTableInterpreter {
             index_data_bytes: snapshot.summary.index_size,
             number_of_segments: Some(snapshot.segments.len() as u64),
             number_of_blocks: Some(snapshot.summary.block_count),
+            rows_modified_since_analyze: Some(snapshot.rows_modified_since_analyze),
+            analyzed_on: snapshot.analyzed_on,
         };
 
         let field_comments = vec!["".to_string(); snapshot.schema.num_fields()];
@@ -460,11 +664,16 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_ENABLE_DELETION_VECTORS);
+    r.insert(OPT_KEY_ENABLE_ICEBERG_METADATA);
+    r.insert(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS);
+    r.insert(OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -529,9 +738,69 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
         value.to_lowercase().parse::<bool>()?;
     }
     Ok(())
 }
+
+pub fn is_valid_deletion_vectors(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ENABLE_DELETION_VECTORS) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_iceberg_metadata(
+    options: &BTreeMap<String, String>,
+    storage_format: Option<&String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ENABLE_ICEBERG_METADATA) {
+        if !value.to_lowercase().parse::<bool>()? {
+            return Ok(());
+        }
+        // only parquet blocks can be read by iceberg readers.
+        if storage_format.is_some_and(|v| v.to_lowercase() == "native") {
+            let error_str =
+                "invalid enable_iceberg_metadata option, storage_format must be parquet";
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+        check_iceberg_schema(schema).map_err(|e| ErrorCode::TableOptionInvalid(e.message()))?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_data_retention(options: &BTreeMap<String, String>) -> Result<()> {
+    // check data_retention_time_in_days is not over data_retention_time_in_days_max.
+    if let Some(value) = options.get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+        let days = value.parse::<u64>()?;
+        let max = GlobalConfig::instance()
+            .query
+            .data_retention_time_in_days_max;
+        if days > max {
+            let error_str = format!(
+                "invalid data_retention_time_in_days option, can't be over {}",
+                max
+            );
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    if let Some(value) = options.get(OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP) {
+        value.parse::<u64>()?;
+    }
+    Ok(())
+}
@@ -27,6 +27,7 @@ use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
@@ -104,13 +105,15 @@ impl Interpreter for DropTableColumnInterpreter {
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the bloom index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropTableRowAccessPolicyPlan;
+use databend_common_storages_share::save_share_table_info;
+
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableRowAccessPolicyPlan,
+}
+
+impl DropTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DropTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DropTableRowAccessPolicyInterpreter"
+    }
+
+    fn audit_event(&self) -> Option<AuditEvent> {
+        let object = format!(
+            "{} ON {}.{}",
+            self.plan.policy, self.plan.database, self.plan.table
+        );
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "DROP ROW ACCESS POLICY",
+            object,
+        ))
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        match &table_info.meta.row_access_policy {
+            Some(attached) if attached.policy == self.plan.policy => {}
+            _ => {
+                return Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                    "Row access policy {} is not attached to table {}",
+                    self.plan.policy, self.plan.table
+                )));
+            }
+        }
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: tenant.to_string(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action: SetTableRowAccessPolicyAction::Drop(self.plan.policy.clone()),
+        };
+        let res = catalog.set_table_row_access_policy(req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                tenant.as_str(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateTableIndexPlan;
+use uuid::Uuid;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableIndexPlan,
+}
+
+impl CreateTableIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableIndexPlan) -> Result<Self> {
+        Ok(CreateTableIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableIndexInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+        let index_name = self.plan.index_name.clone();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+
+        if new_table_meta.indexes.contains_key(&index_name) {
+            match self.plan.create_option {
+                CreateOption::None => {
+                    return Err(ErrorCode::IndexAlreadyExists(format!(
+                        "index {} already exists on table {}",
+                        index_name, self.plan.table
+                    )));
+                }
+                CreateOption::CreateIfNotExists => {
+                    return Ok(PipelineBuildResult::create());
+                }
+                CreateOption::CreateOrReplace => {}
+            }
+        }
+
+        // A column can only be indexed by one inverted index,
+        // so that `match` can find the index by the column.
+        for (name, index) in new_table_meta.indexes.iter() {
+            if name == &index_name {
+                continue;
+            }
+            if let Some(field) = new_table_meta.schema.fields().iter().find(|field| {
+                self.plan.column_ids.contains(&field.column_id())
+                    && index.column_ids.contains(&field.column_id())
+            }) {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "column {} is already indexed by inverted index {}",
+                    field.name(),
+                    name
+                )));
+            }
+        }
+
+        // The version is a part of the index location,
+        // a new version makes the index data of the replaced index invisible.
+        let index = TableIndex {
+            name: index_name.clone(),
+            column_ids: self.plan.column_ids.clone(),
+            sync_creation: self.plan.sync_creation,
+            version: Uuid::new_v4().simple().to_string(),
+            options: self.plan.index_options.clone(),
+        };
+        new_table_meta.indexes.insert(index_name, index);
+
+        let req = UpdateTableMetaReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropTableIndexPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableIndexPlan,
+}
+
+impl DropTableIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableIndexPlan) -> Result<Self> {
+        Ok(DropTableIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropTableIndexInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+        let index_name = self.plan.index_name.clone();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        if new_table_meta.indexes.remove(&index_name).is_none() {
+            if self.plan.if_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::UnknownIndex(format!(
+                "index {} does not exist on table {}",
+                index_name, self.plan.table
+            )));
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
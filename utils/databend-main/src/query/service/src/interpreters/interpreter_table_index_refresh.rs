@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::RefreshTableIndexPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_enterprise_inverted_index::get_inverted_index_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshTableIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshTableIndexPlan,
+}
+
+impl RefreshTableIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshTableIndexPlan) -> Result<Self> {
+        Ok(RefreshTableIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshTableIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshTableIndexInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let table_meta = &table.get_table_info().meta;
+        let Some(index) = table_meta.indexes.get(&self.plan.index_name) else {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "index {} does not exist on table {}",
+                self.plan.index_name, self.plan.table
+            )));
+        };
+
+        let index_fields = table_meta
+            .schema
+            .fields()
+            .iter()
+            .filter(|field| index.column_ids.contains(&field.column_id()))
+            .cloned()
+            .collect::<Vec<_>>();
+        let index_schema = DataSchema::from(Arc::new(TableSchema::new(index_fields)));
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let handler = get_inverted_index_handler();
+        handler
+            .do_refresh_index(
+                fuse_table,
+                self.ctx.clone(),
+                index.name.clone(),
+                index.version.clone(),
+                &index.options,
+                index_schema,
+                self.plan.limit.map(|limit| limit as usize),
+                self.plan.segment_locs.clone(),
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableRef;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateTableRefPlan;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableRefPlan,
+}
+
+impl CreateTableRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableRefPlan) -> Result<Self> {
+        Ok(CreateTableRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableRefInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableRefInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        table.check_mutable()?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{} is only supported by FUSE tables, but table {} uses the {} engine",
+                self.plan.kind,
+                self.plan.table,
+                table.engine()
+            )));
+        }
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        if let Some(table_ref) = new_table_meta.refs.get(&self.plan.name) {
+            if table_ref.kind != self.plan.kind {
+                return Err(ErrorCode::TableRefAlreadyExists(format!(
+                    "{} {} already exists on table {}",
+                    table_ref.kind, self.plan.name, self.plan.table
+                )));
+            }
+            match self.plan.create_option {
+                CreateOption::None => {
+                    return Err(ErrorCode::TableRefAlreadyExists(format!(
+                        "{} {} already exists on table {}",
+                        self.plan.kind, self.plan.name, self.plan.table
+                    )));
+                }
+                CreateOption::CreateIfNotExists => {
+                    return Ok(PipelineBuildResult::create());
+                }
+                CreateOption::CreateOrReplace => {}
+            }
+        }
+
+        let snapshot_table = match &self.plan.point {
+            Some(point) => table.navigate_since_to(&None, &Some(point.clone())).await?,
+            None => table.clone(),
+        };
+        let options = snapshot_table.get_table_info().options();
+        let snapshot_location = options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Empty table {} has no snapshot to create {} {} on",
+                    self.plan.table, self.plan.kind, self.plan.name
+                ))
+            })?;
+
+        new_table_meta
+            .refs
+            .insert(self.plan.name.clone(), TableRef {
+                kind: self.plan.kind,
+                snapshot_location,
+                cloned_table_id: None,
+                created_on: Utc::now(),
+            });
+
+        let req = UpdateTableMetaReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
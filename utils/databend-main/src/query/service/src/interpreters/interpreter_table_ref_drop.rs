@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropTableRefPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableRefPlan,
+}
+
+impl DropTableRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableRefPlan) -> Result<Self> {
+        Ok(DropTableRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableRefInterpreter {
+    fn name(&self) -> &str {
+        "DropTableRefInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        match new_table_meta.refs.get(&self.plan.name) {
+            Some(table_ref) if table_ref.kind == self.plan.kind => {
+                new_table_meta.refs.remove(&self.plan.name);
+            }
+            _ => {
+                if self.plan.if_exists {
+                    return Ok(PipelineBuildResult::create());
+                }
+                return Err(ErrorCode::UnknownTableRef(format!(
+                    "{} {} does not exist on table {}",
+                    self.plan.kind, self.plan.name, self.plan.table
+                )));
+            }
+        }
+
+        // The files only referenced by the dropped ref are reclaimed by the next purge.
+        let req = UpdateTableMetaReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -30,6 +30,10 @@ use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_data_retention;
+use super::interpreter_table_create::is_valid_deletion_vectors;
+use super::interpreter_table_create::is_valid_iceberg_metadata;
+use super::interpreter_table_create::is_valid_ngram_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -65,6 +69,8 @@ impl Interpreter for SetOptionsInterpreter {
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
+        is_valid_deletion_vectors(&self.plan.set_options)?;
+        is_valid_data_retention(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -103,6 +109,14 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
+        // check enable_iceberg_metadata.
+        is_valid_iceberg_metadata(
+            &self.plan.set_options,
+            table.options().get(OPT_KEY_STORAGE_FORMAT),
+            &table.schema(),
+        )?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
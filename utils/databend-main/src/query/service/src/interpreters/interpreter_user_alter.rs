@@ -19,6 +19,8 @@ use databend_common_sql::plans::AlterUserPlan;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -42,6 +44,14 @@ impl Interpreter for AlterUserInterpreter {
         "AlterUserInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "ALTER USER",
+            self.plan.user.to_string(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
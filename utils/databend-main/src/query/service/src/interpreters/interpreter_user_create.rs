@@ -24,6 +24,8 @@ use databend_common_sql::plans::CreateUserPlan;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -47,6 +49,14 @@ impl Interpreter for CreateUserInterpreter {
         "CreateUserInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent::new(
+            AuditCategory::Ddl,
+            "CREATE USER",
+            self.plan.user.to_string(),
+        ))
+    }
+
     fn is_ddl(&self) -> bool {
         true
     }
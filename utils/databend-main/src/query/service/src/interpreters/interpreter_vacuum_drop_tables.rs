@@ -13,10 +13,15 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::DateTime;
 use chrono::Duration;
+use chrono::Utc;
 use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
@@ -27,9 +32,13 @@ use databend_common_meta_app::schema::DatabaseNameIdent;
 use databend_common_meta_app::schema::DroppedId;
 use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
+use databend_common_meta_app::schema::TableRefKind;
 use databend_common_sql::plans::VacuumDropTablePlan;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use log::info;
 
 use crate::interpreters::Interpreter;
@@ -98,6 +107,176 @@ impl VacuumDropTablesInterpreter {
     }
 }
 
+/// Returns the retention period set by the `data_retention_time_in_days` option of the table.
+fn table_retention(table_info: &TableInfo) -> Option<Duration> {
+    table_info
+        .options()
+        .get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+        .and_then(|days| days.parse::<i64>().ok())
+        .map(Duration::days)
+}
+
+fn is_retention_expired(table_info: &TableInfo, retention: Duration, now: DateTime<Utc>) -> bool {
+    let dropped_on = table_info
+        .meta
+        .drop_on
+        .unwrap_or(table_info.meta.updated_on);
+    dropped_on + retention <= now
+}
+
+/// Applies the `data_retention_time_in_days` option of the dropped tables, which takes
+/// precedence over the `data_retention_time_in_days` setting.
+///
+/// `tables` and `drop_ids` are the tables expired by the setting, the ones whose own
+/// retention period has not expired yet are kept, together with the dropped databases
+/// holding them. `dropped_tables` are all the dropped tables, the ones whose own retention
+/// period is shorter than the setting and has expired are vacuumed as well.
+fn apply_table_retention(
+    tables: Vec<Arc<dyn Table>>,
+    drop_ids: Vec<DroppedId>,
+    dropped_tables: Vec<Arc<dyn Table>>,
+    limit: Option<usize>,
+) -> (Vec<Arc<dyn Table>>, Vec<DroppedId>) {
+    let now = Utc::now();
+    let listed_table_ids = tables
+        .iter()
+        .map(|table| table.get_table_info().ident.table_id)
+        .collect::<HashSet<_>>();
+
+    let (mut tables, mut drop_ids) = exclude_kept_tables(tables, drop_ids, |table_info| {
+        let Some(retention) = table_retention(table_info) else {
+            return false;
+        };
+        if is_retention_expired(table_info, retention, now) {
+            return false;
+        }
+        info!(
+            "vacuum drop table {}, kept by its data retention period ({} days)",
+            table_info.desc,
+            retention.num_days()
+        );
+        true
+    });
+
+    for table in dropped_tables {
+        if limit.is_some_and(|limit| tables.len() >= limit) {
+            break;
+        }
+        let table_info = table.get_table_info();
+        if listed_table_ids.contains(&table_info.ident.table_id) {
+            continue;
+        }
+        let Some(retention) = table_retention(table_info) else {
+            continue;
+        };
+        if !is_retention_expired(table_info, retention, now) {
+            continue;
+        }
+        let Some(db_id) = table_info
+            .options()
+            .get(OPT_KEY_DATABASE_ID)
+            .and_then(|db_id| db_id.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        info!(
+            "vacuum drop table {}, expired by its data retention period ({} days)",
+            table_info.desc,
+            retention.num_days()
+        );
+        drop_ids.push(DroppedId::Table(
+            db_id,
+            table_info.ident.table_id,
+            table_info.name.clone(),
+        ));
+        tables.push(table);
+    }
+    (tables, drop_ids)
+}
+
+/// Keeps the dropped tables whose files are shared by a clone that still exists,
+/// or by a clone that is being created, together with the dropped databases holding them.
+///
+/// The refs of the clones that are vacuumed and the expired pending refs are ignored,
+/// the table is vacuumed once none of its clones is left.
+async fn retain_by_live_clones(
+    catalog: &Arc<dyn Catalog>,
+    tables: Vec<Arc<dyn Table>>,
+    drop_ids: Vec<DroppedId>,
+) -> Result<(Vec<Arc<dyn Table>>, Vec<DroppedId>)> {
+    let now = Utc::now();
+    let mut shared_table_ids = HashSet::new();
+    for table in &tables {
+        let table_info = table.get_table_info();
+        for table_ref in table_info.meta.refs.values() {
+            if table_ref.kind != TableRefKind::Clone {
+                continue;
+            }
+            let live = match table_ref.cloned_table_id {
+                None => !table_ref.is_expired_pending_clone(now),
+                Some(table_id) => match catalog.get_table_meta_by_id(table_id).await {
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => false,
+                    Err(e) => return Err(e),
+                    Ok(_) => true,
+                },
+            };
+            if live {
+                shared_table_ids.insert(table_info.ident.table_id);
+                break;
+            }
+        }
+    }
+
+    Ok(exclude_kept_tables(tables, drop_ids, |table_info| {
+        if !shared_table_ids.contains(&table_info.ident.table_id) {
+            return false;
+        }
+        info!(
+            "vacuum drop table {}, kept by the cloned tables sharing its files",
+            table_info.desc
+        );
+        true
+    }))
+}
+
+/// Removes the kept tables from the vacuum, together with their drop ids and the
+/// drop ids of the databases holding them, so that a later vacuum picks them up again.
+fn exclude_kept_tables(
+    tables: Vec<Arc<dyn Table>>,
+    drop_ids: Vec<DroppedId>,
+    is_kept: impl Fn(&TableInfo) -> bool,
+) -> (Vec<Arc<dyn Table>>, Vec<DroppedId>) {
+    let mut kept_table_ids = HashSet::new();
+    let mut kept_db_ids = HashSet::new();
+    let tables = tables
+        .into_iter()
+        .filter(|table| {
+            let table_info = table.get_table_info();
+            if !is_kept(table_info) {
+                return true;
+            }
+            kept_table_ids.insert(table_info.ident.table_id);
+            if let Some(db_id) = table_info
+                .options()
+                .get(OPT_KEY_DATABASE_ID)
+                .and_then(|db_id| db_id.parse::<u64>().ok())
+            {
+                kept_db_ids.insert(db_id);
+            }
+            false
+        })
+        .collect::<Vec<_>>();
+
+    let drop_ids = drop_ids
+        .into_iter()
+        .filter(|drop_id| match drop_id {
+            DroppedId::Db(db_id, _) => !kept_db_ids.contains(db_id),
+            DroppedId::Table(_, table_id, _) => !kept_table_ids.contains(table_id),
+        })
+        .collect::<Vec<_>>();
+    (tables, drop_ids)
+}
+
 #[async_trait::async_trait]
 impl Interpreter for VacuumDropTablesInterpreter {
     fn name(&self) -> &str {
@@ -150,6 +329,27 @@ impl Interpreter for VacuumDropTablesInterpreter {
             drop_ids.len()
         );
 
+        // the tables whose own retention period is shorter than the setting
+        let filter = if self.plan.database.is_empty() {
+            TableInfoFilter::AllDroppedTables(None)
+        } else {
+            TableInfoFilter::Dropped(None)
+        };
+        let (dropped_tables, _) = catalog
+            .get_drop_table_infos(ListDroppedTableReq {
+                inner: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: self.plan.database.clone(),
+                },
+                filter,
+                limit: None,
+            })
+            .await?;
+
+        let (tables, drop_ids) =
+            apply_table_retention(tables, drop_ids, dropped_tables, self.plan.option.limit);
+        let (tables, drop_ids) = retain_by_live_clones(&catalog, tables, drop_ids).await?;
+
         // TODO buggy, table as catalog obj should be allowed to drop
         // also drop ids
         // filter out read-only tables
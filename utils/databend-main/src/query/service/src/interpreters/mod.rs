@@ -73,6 +73,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -91,15 +94,22 @@ mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
+mod interpreter_table_index_create;
+mod interpreter_table_index_drop;
+mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
+mod interpreter_table_ref_create;
+mod interpreter_table_ref_drop;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
@@ -138,6 +148,9 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
@@ -180,6 +193,9 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -197,15 +213,22 @@ pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInter
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
+pub use interpreter_table_index_create::CreateTableIndexInterpreter;
+pub use interpreter_table_index_drop::DropTableIndexInterpreter;
+pub use interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
+pub use interpreter_table_ref_create::CreateTableRefInterpreter;
+pub use interpreter_table_ref_drop::DropTableRefInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
@@ -233,3 +256,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
@@ -43,6 +43,7 @@
 extern crate core;
 
 pub mod api;
+pub mod audit;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
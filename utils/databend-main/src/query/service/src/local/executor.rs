@@ -129,7 +129,7 @@ impl SessionExecutor {
         session: &Arc<Session>,
         sql: &str,
     ) -> Result<(SendableDataBlockStream, Arc<QueryContext>, Plan, Statement)> {
-        let context = session.create_query_context().await?;
+        let context = session.create_admitted_query_context().await?;
         let mut planner = Planner::new(context.clone());
         let (plan, extras) = planner.plan_sql(sql).await?;
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SortColumnDescription;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_pipeline_core::Pipeline;
@@ -31,6 +32,8 @@ use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::create_transform_sort_spill;
+use crate::pipelines::processors::transforms::TopNRuntimeFilter;
+use crate::pipelines::processors::transforms::TransformSortTopN;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::spillers::Spiller;
@@ -87,7 +90,24 @@ impl PipelineBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+        let top_n_runtime_filter = match (&sort.top_n_runtime_filter, sort.limit) {
+            (Some((column, table_index)), Some(limit)) => Some(TopNRuntimeFilter::create(
+                self.ctx.clone(),
+                *table_index,
+                column.as_expr(&BUILTIN_FUNCTIONS),
+                sort.order_by[0].asc,
+                limit,
+            )),
+            _ => None,
+        };
+
+        self.build_sort_pipeline_with_top_n(
+            plan_schema,
+            sort_desc,
+            sort.limit,
+            sort.after_exchange,
+            top_n_runtime_filter,
+        )
     }
 
     pub(crate) fn build_sort_pipeline(
@@ -96,6 +116,17 @@ impl PipelineBuilder {
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
         after_exchange: Option<bool>,
+    ) -> Result<()> {
+        self.build_sort_pipeline_with_top_n(plan_schema, sort_desc, limit, after_exchange, None)
+    }
+
+    fn build_sort_pipeline_with_top_n(
+        &mut self,
+        plan_schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        limit: Option<usize>,
+        after_exchange: Option<bool>,
+        top_n_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
     ) -> Result<()> {
         let block_size = self.settings.get_max_block_size()? as usize;
         let max_threads = self.settings.get_max_threads()? as usize;
@@ -110,7 +141,8 @@ impl PipelineBuilder {
             SortPipelineBuilder::create(self.ctx.clone(), plan_schema.clone(), sort_desc.clone())
                 .with_partial_block_size(block_size)
                 .with_final_block_size(block_size)
-                .with_limit(limit);
+                .with_limit(limit)
+                .with_top_n_runtime_filter(top_n_runtime_filter);
 
         match after_exchange {
             Some(true) => {
@@ -156,6 +188,7 @@ pub struct SortPipelineBuilder {
     partial_block_size: usize,
     final_block_size: usize,
     remove_order_col_at_last: bool,
+    top_n_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
 }
 
 impl SortPipelineBuilder {
@@ -172,6 +205,7 @@ impl SortPipelineBuilder {
             partial_block_size: 0,
             final_block_size: 0,
             remove_order_col_at_last: false,
+            top_n_runtime_filter: None,
         }
     }
 
@@ -190,6 +224,14 @@ impl SortPipelineBuilder {
         self
     }
 
+    pub fn with_top_n_runtime_filter(
+        mut self,
+        top_n_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
+    ) -> Self {
+        self.top_n_runtime_filter = top_n_runtime_filter;
+        self
+    }
+
     pub fn remove_order_col_at_last(mut self) -> Self {
         self.remove_order_col_at_last = true;
         self
@@ -206,6 +248,19 @@ impl SortPipelineBuilder {
             )?))
         })?;
 
+        // Top-N, publish the threshold to the table scan as soon as possible.
+        if let Some(top_n_runtime_filter) = &self.top_n_runtime_filter {
+            let offset = self.sort_desc[0].offset;
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(TransformSortTopN::try_create(
+                    input,
+                    output,
+                    offset,
+                    top_n_runtime_filter.clone(),
+                )?))
+            })?;
+        }
+
         self.build_merge_sort_pipeline(pipeline, false)
     }
 
@@ -214,9 +269,13 @@ impl SortPipelineBuilder {
         let memory_ratio = settings.get_sort_spilling_memory_ratio()?;
         let bytes_limit_per_proc = settings.get_sort_spilling_bytes_threshold_per_proc()?;
         if memory_ratio == 0 && bytes_limit_per_proc == 0 {
-            // If these two settings are not set, do not enable sort spill.
+            // If these two settings are not set, the sort (and the partitions buffered for
+            // window functions) only spills when the query goes near its memory budget.
             // TODO(spill): enable sort spill by default like aggregate.
-            return Ok((0, 0));
+            return match self.ctx.get_query_memory_budget()? {
+                Some(budget) if budget.spilling_enabled() => Ok((usize::MAX, usize::MAX)),
+                _ => Ok((0, 0)),
+            };
         }
         let memory_ratio = (memory_ratio as f64 / 100_f64).min(1_f64);
         let max_memory_usage = match settings.get_max_memory_usage()? {
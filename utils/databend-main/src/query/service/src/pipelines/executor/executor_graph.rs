@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -20,9 +21,12 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use byte_unit::Byte;
+use byte_unit::ByteUnit;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::QueryMemoryBudget;
 use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrackingPayload;
 use databend_common_base::runtime::TrySpawn;
@@ -691,6 +695,48 @@ impl RunningGraph {
             .collect::<Vec<_>>()
     }
 
+    /// The memory held by the processors of the graph, grouped by processor name and sorted
+    /// from the largest to the smallest.
+    pub fn get_memory_holders(&self) -> Vec<(String, usize)> {
+        let mut holders = HashMap::<String, usize>::new();
+        for node in self.0.graph.node_weights() {
+            if let Some(mem_stat) = &node.tracking_payload.mem_stat {
+                let memory_usage = mem_stat.get_memory_usage();
+                if memory_usage > 0 {
+                    let name = unsafe { node.processor.name() };
+                    *holders.entry(name).or_default() += memory_usage as usize;
+                }
+            }
+        }
+
+        let mut holders = holders.into_iter().collect::<Vec<_>>();
+        holders.sort_by(|left, right| right.1.cmp(&left.1));
+        holders
+    }
+
+    /// The error to cancel the query with once it goes over its memory budget.
+    pub fn query_memory_limit_exceeded(&self, budget: &QueryMemoryBudget) -> ErrorCode {
+        let format_bytes = |bytes: usize| {
+            Byte::from_unit(bytes as f64, ByteUnit::B)
+                .unwrap()
+                .get_appropriate_unit(true)
+                .format(2)
+        };
+
+        let holders = self
+            .get_memory_holders()
+            .into_iter()
+            .map(|(name, bytes)| format!("{}: {}", name, format_bytes(bytes)))
+            .collect::<Vec<_>>();
+
+        ErrorCode::QueryMemoryLimitExceeded(format!(
+            "Aborted query, because its memory usage {} exceeds the limit {} of max_query_memory_usage, memory held by operators: [{}]",
+            format_bytes(std::cmp::max(0, budget.get_memory_usage()) as usize),
+            format_bytes(budget.get_limit() as usize),
+            holders.join(", "),
+        ))
+    }
+
     pub fn interrupt_running_nodes(&self) {
         unsafe {
             for node_index in self.0.graph.node_indices() {
@@ -15,23 +15,45 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::QueryMemoryBudget;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
-use databend_common_settings::Settings;
+
+use crate::sessions::QueryContext;
+
+/// How often the executor checks whether the query goes over its memory budget.
+pub const QUERY_MEMORY_LIMIT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
 
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub enable_new_executor: bool,
     pub query_id: Arc<String>,
     pub max_execute_time_in_seconds: Duration,
+    /// Memory trackers the query execution memory is also accounted to, e.g. its workload group.
+    pub parent_mem_stats: Vec<Arc<MemStat>>,
+    /// The hard memory budget of the query, the query is cancelled once it goes over the budget.
+    pub query_memory_budget: Option<Arc<QueryMemoryBudget>>,
 }
 
 impl ExecutorSettings {
-    pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
+    /// Create the settings to execute a pipeline of the query,
+    /// which also carry the memory trackers and the memory budget of the query.
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<ExecutorSettings> {
+        let settings = ctx.get_settings();
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+
+        let mut parent_mem_stats = vec![];
+        if let Some(mem_stat) = ctx.get_workload_group_mem_stat() {
+            parent_mem_stats.push(mem_stat);
+        }
+
         Ok(ExecutorSettings {
             enable_new_executor: settings.get_enable_experimental_queries_executor()?,
-            query_id: Arc::new(query_id),
+            query_id: Arc::new(ctx.get_id()),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            parent_mem_stats,
+            query_memory_budget: ctx.get_query_memory_budget()?,
         })
     }
 }
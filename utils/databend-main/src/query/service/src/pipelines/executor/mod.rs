@@ -32,6 +32,7 @@ pub use executor_condvar::WorkersCondvar;
 pub use executor_condvar::WorkersWaitingStatus;
 pub use executor_graph::RunningGraph;
 pub use executor_settings::ExecutorSettings;
+pub use executor_settings::QUERY_MEMORY_LIMIT_CHECK_INTERVAL;
 pub use executor_worker_context::CompletedAsyncTask;
 pub use executor_worker_context::ExecutorTask;
 pub use executor_worker_context::ExecutorWorkerContext;
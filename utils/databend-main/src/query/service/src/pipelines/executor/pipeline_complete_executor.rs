@@ -37,12 +37,18 @@ pub struct PipelineCompleteExecutor {
 
 // Use this executor when the pipeline is complete pipeline (has source and sink)
 impl PipelineCompleteExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
+        let mut parent_mem_stats = settings.parent_mem_stats.clone();
+        if let Some(budget) = &settings.query_memory_budget {
+            parent_mem_stats.push(budget.mem_stat().clone());
+        }
+
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        tracking_payload.mem_stat = Some(MemStat::create_child(
+            format!("QueryExecutionMemStat-{}", settings.query_id),
+            parent_mem_stats,
+        ));
+        tracking_payload.query_memory_budget = settings.query_memory_budget.clone();
         tracking_payload
     }
 
@@ -50,7 +56,7 @@ impl PipelineCompleteExecutor {
         pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelineCompleteExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         if !pipeline.is_complete_pipeline()? {
@@ -78,7 +84,7 @@ impl PipelineCompleteExecutor {
         pipelines: Vec<Pipeline>,
         settings: ExecutorSettings,
     ) -> Result<Arc<PipelineCompleteExecutor>> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         for pipeline in &pipelines {
@@ -103,12 +103,18 @@ pub struct PipelinePullingExecutor {
 }
 
 impl PipelinePullingExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
+        let mut parent_mem_stats = settings.parent_mem_stats.clone();
+        if let Some(budget) = &settings.query_memory_budget {
+            parent_mem_stats.push(budget.mem_stat().clone());
+        }
+
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        tracking_payload.mem_stat = Some(MemStat::create_child(
+            format!("QueryExecutionMemStat-{}", settings.query_id),
+            parent_mem_stats,
+        ));
+        tracking_payload.query_memory_budget = settings.query_memory_budget.clone();
         tracking_payload
     }
 
@@ -143,7 +149,7 @@ impl PipelinePullingExecutor {
         mut pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let (sender, receiver) = std::sync::mpsc::sync_channel(pipeline.output_len());
@@ -175,7 +181,7 @@ impl PipelinePullingExecutor {
         build_res: PipelineBuildResult,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let mut main_pipeline = build_res.main_pipeline;
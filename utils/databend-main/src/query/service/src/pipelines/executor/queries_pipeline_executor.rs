@@ -50,6 +50,7 @@ use crate::pipelines::executor::QueriesExecutorTasksQueue;
 use crate::pipelines::executor::RunningGraph;
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::executor::WorkersCondvar;
+use crate::pipelines::executor::QUERY_MEMORY_LIMIT_CHECK_INTERVAL;
 
 pub type InitCallback = Box<dyn FnOnce() -> Result<()> + Send + Sync + 'static>;
 
@@ -241,6 +242,7 @@ impl QueriesPipelineExecutor {
         self.init(self.graph.clone())?;
 
         self.start_time_limit_daemon()?;
+        self.start_memory_limit_daemon()?;
 
         let mut thread_join_handles = self.execute_threads(self.threads_num);
 
@@ -344,6 +346,35 @@ impl QueriesPipelineExecutor {
         Ok(())
     }
 
+    /// Used to abort the query when its memory usage goes over the `max_query_memory_usage` budget
+    fn start_memory_limit_daemon(self: &Arc<Self>) -> Result<()> {
+        if let Some(budget) = self.settings.query_memory_budget.clone() {
+            // NOTE(wake ref): When runtime scheduling is blocked, holding executor strong ref may cause the executor can not stop.
+            let this = Arc::downgrade(self);
+            let finished_notify = self.finished_notify.clone();
+            self.async_runtime.spawn(GLOBAL_TASK, async move {
+                let mut finished_future = Box::pin(finished_notify.notified());
+                loop {
+                    let check_future = Box::pin(time::sleep(QUERY_MEMORY_LIMIT_CHECK_INTERVAL));
+                    match select(check_future, finished_future).await {
+                        Either::Left((_, unfinished_future)) => finished_future = unfinished_future,
+                        Either::Right(_) => break,
+                    }
+
+                    if budget.is_exceeded() {
+                        if let Some(executor) = this.upgrade() {
+                            let cause = executor.graph.query_memory_limit_exceeded(&budget);
+                            executor.finish(Some(cause));
+                        }
+                        break;
+                    }
+                }
+            });
+        }
+
+        Ok(())
+    }
+
     fn execute_threads(self: &Arc<Self>, threads: usize) -> Vec<ThreadJoinHandle<Result<()>>> {
         let mut thread_join_handles = Vec::with_capacity(threads);
 
@@ -18,6 +18,7 @@ use std::vec;
 use bumpalo::Bump;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::plan::AggIndexMeta;
 use databend_common_catalog::table_context::TableContext;
@@ -344,7 +345,8 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
             if matches!(&self.hash_table, HashTable::HashTable(cell)
                 if cell.len() >= self.settings.convert_threshold ||
                     cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc ||
-                    GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage
+                    GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage ||
+                    ThreadTracker::query_memory_should_spill()
             ) {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                     self.hash_table = HashTable::PartitionedHashTable(
@@ -355,6 +357,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
 
             if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
                 || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage
+                || ThreadTracker::query_memory_should_spill()
             {
                 if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table) {
                     // perf
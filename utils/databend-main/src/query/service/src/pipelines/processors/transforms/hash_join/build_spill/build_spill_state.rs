@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use byte_unit::Byte;
 use byte_unit::ByteUnit;
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -90,6 +91,14 @@ impl BuildSpillState {
             return Ok(true);
         }
 
+        // Check if the query goes near its memory budget.
+        if ThreadTracker::query_memory_should_spill() {
+            info!(
+                "need to spill because the query memory usage is close to max_query_memory_usage"
+            );
+            return Ok(true);
+        }
+
         // Check if global memory usage exceeds the threshold.
         let mut global_used = GLOBAL_MEM_STAT.get_memory_usage();
         // `global_used` may be negative at the beginning of starting query.
@@ -33,6 +33,7 @@ mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
 mod transform_sort_spill;
+mod transform_sort_top_n;
 mod transform_srf;
 mod transform_udf_script;
 mod transform_udf_server;
@@ -58,6 +59,8 @@ pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_sort_spill::create_transform_sort_spill;
+pub use transform_sort_top_n::TopNRuntimeFilter;
+pub use transform_sort_top_n::TransformSortTopN;
 pub use transform_srf::TransformSRF;
 pub use transform_udf_script::TransformUdfScript;
 pub use transform_udf_server::TransformUdfServer;
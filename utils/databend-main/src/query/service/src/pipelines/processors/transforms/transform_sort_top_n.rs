@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::RawExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_sql::IndexType;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
+
+/// A value of the first sort key, ordered by how far it is from the top of the sort,
+/// so the peek of a max-heap of the values is the threshold of the top-n.
+struct TopNValue {
+    value: Scalar,
+    asc: bool,
+}
+
+impl Ord for TopNValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.asc {
+            true => self.value.cmp(&other.value),
+            false => other.value.cmp(&self.value),
+        }
+    }
+}
+
+impl PartialOrd for TopNValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TopNValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TopNValue {}
+
+struct TopNHeap {
+    heap: BinaryHeap<TopNValue>,
+    published: Option<Scalar>,
+}
+
+/// The top-n threshold shared by all the sort processors of a top-n sort in this node.
+///
+/// It keeps the best `limit` values of the first sort key in a bounded heap.
+/// Once the heap is full, any row whose first sort key is worse than the top of the heap
+/// can't be in the result, so the threshold is published to the table scan as a runtime filter,
+/// and the remaining blocks whose min/max statistics can't beat it are skipped.
+/// NULLs are not kept in the heap, the threshold is always a non-NULL value.
+pub struct TopNRuntimeFilter {
+    ctx: Arc<QueryContext>,
+    table_index: IndexType,
+    // The first sort key as a column of the table.
+    column: Expr<String>,
+    asc: bool,
+    limit: usize,
+    heap: Mutex<TopNHeap>,
+}
+
+impl TopNRuntimeFilter {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        table_index: IndexType,
+        column: Expr<String>,
+        asc: bool,
+        limit: usize,
+    ) -> Arc<Self> {
+        Arc::new(TopNRuntimeFilter {
+            ctx,
+            table_index,
+            column,
+            asc,
+            limit,
+            heap: Mutex::new(TopNHeap {
+                heap: BinaryHeap::with_capacity(limit + 1),
+                published: None,
+            }),
+        })
+    }
+
+    /// Push the values of the first sort key into the heap, returns the current threshold.
+    fn update(&self, column: &Column) -> Result<Option<Scalar>> {
+        let mut state = self.heap.lock();
+        for value in column.iter() {
+            if matches!(value, ScalarRef::Null) {
+                continue;
+            }
+            let value = TopNValue {
+                value: value.to_owned(),
+                asc: self.asc,
+            };
+            if state.heap.len() == self.limit {
+                match state.heap.peek() {
+                    Some(top) if value < *top => {
+                        state.heap.pop();
+                    }
+                    _ => continue,
+                }
+            }
+            state.heap.push(value);
+        }
+
+        if self.limit == 0 || state.heap.len() < self.limit {
+            return Ok(None);
+        }
+
+        let threshold = state.heap.peek().unwrap().value.clone();
+        if state.published.as_ref() != Some(&threshold) {
+            self.publish(threshold.clone())?;
+            state.published = Some(threshold.clone());
+        }
+        Ok(Some(threshold))
+    }
+
+    fn publish(&self, threshold: Scalar) -> Result<()> {
+        let Expr::ColumnRef {
+            span,
+            id,
+            data_type,
+            display_name,
+        } = &self.column
+        else {
+            return Ok(());
+        };
+
+        let column = RawExpr::ColumnRef {
+            span: *span,
+            id: id.to_string(),
+            data_type: data_type.clone(),
+            display_name: display_name.clone(),
+        };
+        let threshold = RawExpr::Constant {
+            span: None,
+            scalar: threshold,
+        };
+        // Keep the values equal to the threshold, they may win by the other sort keys.
+        let func = RawExpr::FunctionCall {
+            span: None,
+            name: if self.asc { "lte" } else { "gte" }.to_string(),
+            params: vec![],
+            args: vec![column, threshold],
+        };
+        let expr = type_check::check(&func, &BUILTIN_FUNCTIONS)?;
+
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        runtime_filter.set_top_n(expr);
+        self.ctx
+            .set_runtime_filter((self.table_index, runtime_filter));
+        Ok(())
+    }
+}
+
+/// Top-N operator placed after the partial sort of `ORDER BY ... LIMIT`.
+///
+/// It maintains the shared [`TopNRuntimeFilter`] and drops the rows that are already
+/// beaten by the threshold, so less data goes into the merge sort.
+pub struct TransformSortTopN {
+    // The offset of the first sort key in the input block.
+    offset: usize,
+    asc: bool,
+    runtime_filter: Arc<TopNRuntimeFilter>,
+}
+
+impl TransformSortTopN {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        offset: usize,
+        runtime_filter: Arc<TopNRuntimeFilter>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(Transformer::create(input, output, TransformSortTopN {
+            offset,
+            asc: runtime_filter.asc,
+            runtime_filter,
+        }))
+    }
+}
+
+impl Transform for TransformSortTopN {
+    const NAME: &'static str = "SortTopNTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if block.is_empty() {
+            return Ok(block);
+        }
+
+        let entry = block.get_by_offset(self.offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let Some(threshold) = self.runtime_filter.update(&column)? else {
+            return Ok(block);
+        };
+
+        let threshold = threshold.as_ref();
+        let mut bitmap = MutableBitmap::with_capacity(column.len());
+        let mut num_kept = 0;
+        for value in column.iter() {
+            let keep = match value {
+                ScalarRef::Null => true,
+                value => match value.cmp(&threshold) {
+                    Ordering::Greater => !self.asc,
+                    Ordering::Less => self.asc,
+                    Ordering::Equal => true,
+                },
+            };
+            num_kept += keep as usize;
+            bitmap.push(keep);
+        }
+        if num_kept == column.len() {
+            return Ok(block);
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
@@ -91,7 +91,7 @@ impl FlightSqlServiceImpl {
         plan_extras: &PlanExtras,
     ) -> Result<i64> {
         let context = session
-            .create_query_context()
+            .create_admitted_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
@@ -116,7 +116,7 @@ impl FlightSqlServiceImpl {
         let is_native_client = session.get_status().read().is_native_client;
 
         let context = session
-            .create_query_context()
+            .create_admitted_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
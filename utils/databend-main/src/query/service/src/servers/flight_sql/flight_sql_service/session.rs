@@ -16,14 +16,20 @@ use std::sync::Arc;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use databend_common_exception::ErrorCode;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
+use databend_common_users::unverified_token_subject;
 use databend_common_users::UserApiProvider;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
+use crate::auth::AuthMgr;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -83,6 +89,38 @@ impl FlightSqlServiceImpl {
         Ok((user.to_string(), pass.to_string()))
     }
 
+    pub(super) fn get_bearer_token(metadata: &MetadataMap) -> Option<String> {
+        Self::get_header_value(metadata, "authorization")
+            .and_then(|v| v.strip_prefix("Bearer ").map(|t| t.to_string()))
+    }
+
+    /// Handshake with a token signed by the user's private key, see `ALTER USER ... SET PUBLIC_KEY`.
+    #[async_backtrace::framed]
+    pub(super) async fn auth_key_pair_token(
+        token: String,
+        client_ip: Option<&str>,
+    ) -> Result<Arc<Session>, Status> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::FlightSQL)
+            .await
+            .map_err(|e| status!("Could not create session", e))?;
+        let authed = match AuthMgr::instance()
+            .auth_key_pair(&session, &token, client_ip)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(ErrorCode::AuthenticateFailure(
+                "token is not issued for a key_pair user",
+            )),
+            Err(e) => Err(e),
+        };
+        let user = unverified_token_subject(&token).unwrap_or_default();
+        let event = AuditEvent::new(AuditCategory::Login, "LOGIN", "key_pair");
+        AuditLog::log(&session, &user, client_ip, event, authed.as_ref().err());
+        authed.map_err(|e| Status::unauthenticated(e.message()))?;
+        Ok(session)
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn auth_user_password(
         user: String,
@@ -93,13 +131,48 @@ impl FlightSqlServiceImpl {
             .create_session(SessionType::FlightSQL)
             .await
             .map_err(|e| status!("Could not create session", e))?;
+        let authed = Self::do_auth_user_password(&session, &user, password, client_ip).await;
+        let err = authed
+            .as_ref()
+            .err()
+            .map(|e| ErrorCode::AuthenticateFailure(e.message().to_string()));
+        let event = AuditEvent::new(AuditCategory::Login, "LOGIN", "password");
+        AuditLog::log(&session, &user, client_ip, event, err.as_ref());
+        authed?;
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_auth_user_password(
+        session: &Arc<Session>,
+        user_name: &str,
+        password: String,
+        client_ip: Option<&str>,
+    ) -> Result<(), Status> {
         let tenant = session.get_current_tenant();
 
-        let identity = UserIdentity::new(&user, "%");
-        let user = UserApiProvider::instance()
-            .get_user_with_client_ip(&tenant, identity.clone(), client_ip)
+        let identity = UserIdentity::new(user_name, "%");
+        let user = match AuthMgr::instance()
+            .get_user_with_client_ip(session, identity.clone(), client_ip)
             .await
-            .map_err(|e| status!("get_user fail {}", e))?;
+        {
+            Ok(user) => user,
+            Err(e)
+                if e.code() == ErrorCode::UNKNOWN_USER
+                    && AuthMgr::instance().ldap_auto_create_user() =>
+            {
+                let user = AuthMgr::instance()
+                    .auth_ldap(&tenant, user_name, None, password.as_bytes())
+                    .await
+                    .map_err(|e| Status::unauthenticated(e.message()))?;
+                session
+                    .set_authed_user(user, None)
+                    .await
+                    .map_err(|e| status!("set_authed_user fail {}", e))?;
+                return Ok(());
+            }
+            Err(e) => return Err(status!("get_user fail {}", e)),
+        };
         // Check password policy for login
         UserApiProvider::instance()
             .check_login_password(&tenant, identity.clone(), &user)
@@ -109,8 +182,17 @@ impl FlightSqlServiceImpl {
         let password = password.as_bytes().to_vec();
         let password = (!password.is_empty()).then_some(password);
 
+        let mut ldap_user = None;
         let authed = match &user.auth_info {
             AuthInfo::None => Ok(()),
+            AuthInfo::Ldap => match password {
+                None => Err(Status::unauthenticated("password required")),
+                Some(p) => AuthMgr::instance()
+                    .auth_ldap(&tenant, user_name, Some(user.clone()), &p)
+                    .await
+                    .map(|u| ldap_user = Some(u))
+                    .map_err(|e| Status::unauthenticated(e.message())),
+            },
             AuthInfo::Password {
                 hash_value: h,
                 hash_method: t,
@@ -132,10 +214,11 @@ impl FlightSqlServiceImpl {
             .await?;
         authed?;
 
+        let user = ldap_user.unwrap_or(user);
         session
             .set_authed_user(user, None)
             .await
             .map_err(|e| status!("set_authed_user fail {}", e))?;
-        Ok(session)
+        Ok(())
     }
 }
@@ -238,7 +238,7 @@ pub async fn clickhouse_handler_get(
             session.set_current_database(db.clone());
         }
         let context = session
-            .create_query_context()
+            .create_admitted_query_context()
             .await
             .map_err(InternalServerError)?;
 
@@ -302,7 +302,7 @@ pub async fn clickhouse_handler_post(
             session.set_current_database(db.clone());
         }
         let ctx = session
-            .create_query_context()
+            .create_admitted_query_context()
             .await
             .map_err(InternalServerError)?;
 
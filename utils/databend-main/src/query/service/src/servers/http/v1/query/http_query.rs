@@ -392,7 +392,7 @@ impl HttpQuery {
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
         let http_ctx = ctx;
-        let ctx = session.create_query_context().await?;
+        let ctx = session.create_admitted_query_context().await?;
 
         // Deduplicate label is used on the DML queries which may be retried by the client.
         // It can be used to avoid the duplicated execution of the DML queries.
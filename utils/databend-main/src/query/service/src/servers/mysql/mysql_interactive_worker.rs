@@ -27,6 +27,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
 use databend_common_sql::Planner;
@@ -45,6 +46,11 @@ use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::audit::AuditLog;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
@@ -59,6 +65,9 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+const NATIVE_PASSWORD_PLUGIN: &str = "mysql_native_password";
+const CLEAR_PASSWORD_PLUGIN: &str = "mysql_clear_password";
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
 }
@@ -68,6 +77,9 @@ pub struct InteractiveWorker {
     version: String,
     salt: [u8; 20],
     client_addr: String,
+    // Whether the connection is over TLS, the cleartext password of LDAP users
+    // is only accepted on secure connections.
+    secure: bool,
 }
 
 #[async_trait::async_trait]
@@ -89,12 +101,17 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     }
 
     fn default_auth_plugin(&self) -> &str {
-        "mysql_native_password"
+        NATIVE_PASSWORD_PLUGIN
     }
 
     #[async_backtrace::framed]
-    async fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    async fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        let username = String::from_utf8_lossy(user);
+        if self.secure && self.base.use_clear_password(&username).await {
+            CLEAR_PASSWORD_PLUGIN
+        } else {
+            NATIVE_PASSWORD_PLUGIN
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -104,7 +121,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     #[async_backtrace::framed]
     async fn authenticate(
         &self,
-        _auth_plugin: &str,
+        auth_plugin: &str,
         username: &[u8],
         salt: &[u8],
         auth_data: &[u8],
@@ -113,8 +130,22 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let client_addr = self.client_addr.clone();
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
-        let authenticate = self.base.authenticate(salt, info);
-        match authenticate.await {
+        let authenticate = match auth_plugin {
+            CLEAR_PASSWORD_PLUGIN if self.secure => {
+                self.base.authenticate_clear_password(info).await
+            }
+            _ if !self.secure && self.base.use_clear_password(&username).await => {
+                Err(ErrorCode::AuthenticateFailure(format!(
+                    "LDAP user {} must connect over TLS, the password is sent in cleartext",
+                    username
+                )))
+            }
+            CLEAR_PASSWORD_PLUGIN => Err(ErrorCode::AuthenticateFailure(
+                "mysql_clear_password is only allowed over TLS",
+            )),
+            _ => self.base.authenticate(salt, info).await,
+        };
+        match authenticate {
             Ok(res) => res,
             Err(failure) => {
                 error!(
@@ -257,11 +288,37 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
+        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let authed = self
+            .authenticate_native_password(salt, &info, client_ip)
+            .await;
+        let err = match &authed {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(e) => Some(e.clone()),
+        };
+        let event = AuditEvent::new(AuditCategory::Login, "LOGIN", "password");
+        AuditLog::log(
+            &self.session,
+            &info.user_name,
+            Some(client_ip),
+            event,
+            err.as_ref(),
+        );
+        authed
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate_native_password(
+        &self,
+        salt: &[u8],
+        info: &CertifiedInfo,
+        client_ip: &str,
+    ) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
-        let user_info = UserApiProvider::instance()
-            .get_user_with_client_ip(&ctx.get_tenant(), identity.clone(), Some(client_ip))
+        let user_info = AuthMgr::instance()
+            .get_user_with_client_ip(&self.session, identity.clone(), Some(client_ip))
             .await?;
 
         // Check password policy for login
@@ -279,6 +336,39 @@ impl InteractiveWorkerBase {
         Ok(authed)
     }
 
+    /// LDAP users send the cleartext password, which is verified by the LDAP server.
+    #[async_backtrace::framed]
+    async fn use_clear_password(&self, user_name: &str) -> bool {
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(user_name, "%");
+        match UserApiProvider::instance()
+            .get_user(&tenant, identity)
+            .await
+        {
+            Ok(user_info) => user_info.auth_info == AuthInfo::Ldap,
+            Err(e) => {
+                e.code() == ErrorCode::UNKNOWN_USER && AuthMgr::instance().ldap_auto_create_user()
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate_clear_password(&self, info: CertifiedInfo) -> Result<bool> {
+        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        // mysql_clear_password sends the password with a trailing NUL.
+        let password = info.user_password.as_slice();
+        let password = password.strip_suffix(&[0]).unwrap_or(password);
+        let credential = Credential::Password {
+            name: info.user_name.clone(),
+            password: Some(password.to_vec()),
+            client_ip: Some(client_ip.to_string()),
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await?;
+        Ok(true)
+    }
+
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
@@ -352,7 +442,7 @@ impl InteractiveWorkerBase {
             }
             None => {
                 info!("Normal query: {}", query);
-                let context = self.session.create_query_context().await?;
+                let context = self.session.create_admitted_query_context().await?;
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
@@ -461,8 +551,13 @@ impl InteractiveWorker {
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
+            secure: false,
         }
     }
+
+    pub fn set_secure(&mut self, secure: bool) {
+        self.secure = secure;
+    }
 }
 
 struct ContextProgressReporter {
@@ -80,6 +80,7 @@ impl MySQLConnection {
                     &tls,
                 )
                 .await?;
+                interactive_worker.set_secure(use_ssl && tls.is_some());
 
                 match tls {
                     Some(config) if use_ssl => {
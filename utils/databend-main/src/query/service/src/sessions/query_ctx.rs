@@ -37,6 +37,8 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::QueryMemoryBudget;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::plan::DataSourceInfo;
@@ -55,6 +57,7 @@ use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::date_helper::TzFactory;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -90,7 +93,9 @@ use databend_common_storages_result_cache::ResultScan;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
+use databend_common_users::WorkloadGroupManager;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use log::info;
@@ -249,6 +254,85 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// Admit the query into the workload group of the current user, waiting in the group's
+    /// queue if the group is full. Queries without a workload group are admitted at once.
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_group_permit(&self) -> Result<()> {
+        if self.shared.workload_group_permit.lock().is_some() {
+            return Ok(());
+        }
+
+        let tenant = self.get_tenant();
+        let user = self.get_current_user()?;
+        let current_role = self.get_current_role().map(|role| role.name);
+        let Some(group) = UserApiProvider::instance()
+            .resolve_workload_group(tenant.as_str(), &user, current_role.as_deref())
+            .await?
+        else {
+            return Ok(());
+        };
+
+        self.set_status_info(&format!("queued in workload group {}", group.name));
+        let permit = WorkloadGroupManager::instance()
+            .acquire(tenant.as_str(), &group)
+            .await?;
+
+        if group.cpu_share > 0 {
+            let cpus = std::thread::available_parallelism().map_or(1, |n| n.get() as u64);
+            let max_threads = (cpus * group.cpu_share).div_ceil(100).max(1);
+            let settings = self.get_settings();
+            if max_threads < settings.get_max_threads()? {
+                settings.set_max_threads(max_threads)?;
+            }
+        }
+
+        *self.shared.workload_group_permit.lock() = Some(permit);
+        Ok(())
+    }
+
+    pub fn release_workload_group_permit(&self) {
+        self.shared.workload_group_permit.lock().take();
+    }
+
+    pub fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.shared
+            .workload_group_permit
+            .lock()
+            .as_ref()
+            .map(|permit| permit.mem_stat())
+    }
+
+    /// Get the memory budget of the query, created on first use from `max_query_memory_usage`
+    /// and the `MAX_QUERY_MEMORY_USAGE` option of the current user, the lower one wins.
+    /// Returns None if neither of them limits the query.
+    pub fn get_query_memory_budget(&self) -> Result<Option<Arc<QueryMemoryBudget>>> {
+        let mut guard = self.shared.query_memory_budget.lock();
+        if let Some(budget) = guard.as_ref() {
+            return Ok(Some(budget.clone()));
+        }
+
+        let settings = self.get_settings();
+        let user_limit = self
+            .get_current_user()
+            .ok()
+            .and_then(|user| user.option.max_query_memory_usage())
+            .unwrap_or(0);
+        let limit = match (settings.get_max_query_memory_usage()?, user_limit) {
+            (0, limit) | (limit, 0) => limit,
+            (query_limit, user_limit) => std::cmp::min(query_limit, user_limit),
+        };
+
+        if limit == 0 {
+            return Ok(None);
+        }
+
+        let mem_stat = MemStat::create(format!("QueryMemStat-{}", self.get_id()));
+        let budget =
+            QueryMemoryBudget::create(mem_stat, limit, settings.get_query_spilling_memory_ratio()?);
+        *guard = Some(budget.clone());
+        Ok(Some(budget))
+    }
+
     pub fn get_affect(self: &Arc<Self>) -> Option<QueryAffect> {
         self.shared.get_affect()
     }
@@ -495,6 +579,26 @@ impl TableContext for QueryContext {
             .store(enable, Ordering::Release);
     }
 
+    fn add_written_column_hlls(&self, rows: u64, hlls: HashMap<ColumnId, MetaHLL>) {
+        let mut guard = self.shared.written_column_hlls.lock();
+        match guard.as_mut() {
+            Some((written_rows, written_hlls)) => {
+                *written_rows += rows;
+                for (column_id, hll) in hlls {
+                    written_hlls
+                        .entry(column_id)
+                        .and_modify(|v| v.merge(&hll))
+                        .or_insert(hll);
+                }
+            }
+            None => *guard = Some((rows, hlls)),
+        }
+    }
+
+    fn take_written_column_hlls(&self) -> Option<(u64, HashMap<ColumnId, MetaHLL>)> {
+        self.shared.written_column_hlls.lock().take()
+    }
+
     fn attach_query_str(&self, kind: QueryKind, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -958,6 +1062,9 @@ impl TableContext for QueryContext {
                 for filter in filters.1.get_min_max() {
                     v.get_mut().add_min_max(filter.clone());
                 }
+                if let Some(filter) = filters.1.get_top_n() {
+                    v.get_mut().set_top_n(filter.clone());
+                }
                 for filter in filters.1.blooms() {
                     v.get_mut().add_bloom(filter);
                 }
@@ -993,7 +1100,11 @@ impl TableContext for QueryContext {
     fn get_min_max_runtime_filter_with_id(&self, id: IndexType) -> Vec<Expr<String>> {
         let runtime_filters = self.shared.runtime_filters.read();
         match runtime_filters.get(&id) {
-            Some(v) => (v.get_min_max()).clone(),
+            Some(v) => {
+                let mut filters = v.get_min_max().clone();
+                filters.extend(v.get_top_n().cloned());
+                filters
+            }
             None => vec![],
         }
     }
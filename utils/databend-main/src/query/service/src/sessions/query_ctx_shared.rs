@@ -23,6 +23,7 @@ use std::time::SystemTime;
 use dashmap::DashMap;
 use databend_common_base::base::Progress;
 use databend_common_base::runtime::drop_guard;
+use databend_common_base::runtime::QueryMemoryBudget;
 use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
@@ -33,6 +34,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -47,6 +49,8 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::MergeStatus;
 use databend_common_storage::StorageMetrics;
 use databend_common_users::UserApiProvider;
+use databend_common_users::WorkloadGroupPermit;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
@@ -106,6 +110,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     pub(in crate::sessions) auto_compact_after_write: Arc<AtomicBool>,
+    /// Rows and column sketches of the blocks written by the query.
+    #[allow(clippy::type_complexity)]
+    pub(in crate::sessions) written_column_hlls:
+        Arc<Mutex<Option<(u64, HashMap<ColumnId, MetaHLL>)>>>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
 
@@ -122,6 +130,12 @@ pub struct QueryContextShared {
 
     // Records query level data cache metrics
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
+
+    /// The slot of the query in its workload group, released when the query finishes.
+    pub(in crate::sessions) workload_group_permit: Arc<Mutex<Option<WorkloadGroupPermit>>>,
+
+    /// The hard memory budget of the query, shared by all of its pipeline executors.
+    pub(in crate::sessions) query_memory_budget: Arc<Mutex<Option<Arc<QueryMemoryBudget>>>>,
 }
 
 impl QueryContextShared {
@@ -159,6 +173,7 @@ impl QueryContextShared {
             cacheable: Arc::new(AtomicBool::new(true)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             auto_compact_after_write: Arc::new(AtomicBool::new(true)),
+            written_column_hlls: Arc::new(Mutex::new(None)),
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
@@ -169,6 +184,8 @@ impl QueryContextShared {
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
             merge_into_join: Default::default(),
+            workload_group_permit: Arc::new(Mutex::new(None)),
+            query_memory_budget: Arc::new(Mutex::new(None)),
         }))
     }
 
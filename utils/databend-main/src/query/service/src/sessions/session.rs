@@ -157,6 +157,30 @@ impl Session {
         Ok(QueryContext::create_from_shared(shared))
     }
 
+    /// Create a query context to run a user query.
+    ///
+    /// The query is admitted into the workload group of the current user,
+    /// it waits in the queue of the group if the group is full.
+    #[async_backtrace::framed]
+    pub async fn create_admitted_query_context(self: &Arc<Self>) -> Result<Arc<QueryContext>> {
+        let ctx = self.create_query_context().await?;
+        if self.get_type().is_user_session() {
+            ctx.acquire_workload_group_permit().await?;
+        }
+        Ok(ctx)
+    }
+
+    /// Create a query context for a job running in the background of the session,
+    /// e.g. the analyze after a write. It doesn't replace the query context of the session,
+    /// so the current query is still the one shown and killed by the session.
+    #[async_backtrace::framed]
+    pub async fn create_background_query_context(self: &Arc<Self>) -> Result<Arc<QueryContext>> {
+        let config = GlobalConfig::instance();
+        let cluster = ClusterDiscovery::instance().discover(&config).await?;
+        let shared = QueryContextShared::try_create(self.clone(), cluster)?;
+        Ok(QueryContext::create_from_shared(shared))
+    }
+
     // only used for values and mysql output
     pub fn set_format_settings(&mut self, other: FormatSettings) {
         self.format_settings = other
@@ -176,6 +200,10 @@ impl Session {
         self.session_ctx.set_io_shutdown_tx(io_shutdown);
     }
 
+    pub fn get_client_host(self: &Arc<Self>) -> Option<SocketAddr> {
+        self.session_ctx.get_client_host()
+    }
+
     pub fn set_current_database(self: &Arc<Self>, database_name: String) {
         self.session_ctx.set_current_database(database_name);
     }
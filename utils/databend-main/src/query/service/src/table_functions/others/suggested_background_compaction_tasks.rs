@@ -169,6 +169,7 @@ impl SuggestedBackgroundTasksSource {
                         number_of_segments: Some(number_of_segments),
                         number_of_blocks: Some(number_of_blocks),
                         index_data_bytes,
+                        ..Default::default()
                     },
                 };
                 suggestions.push(suggestion);
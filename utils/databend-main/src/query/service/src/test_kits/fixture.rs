@@ -320,6 +320,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -345,6 +346,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -381,6 +383,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -417,6 +420,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -462,6 +466,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_storages_system::AuditLogElement;
+use databend_query::audit::AuditChainHead;
+use databend_query::auth::AuthMgr;
+use databend_query::auth::Credential;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::*;
+
+fn new_element(event_type: &str) -> AuditLogElement {
+    AuditLogElement {
+        event_time: 0,
+        tenant_id: "test".to_string(),
+        cluster_id: "".to_string(),
+        node_id: "".to_string(),
+        category: "ddl".to_string(),
+        event_type: event_type.to_string(),
+        object: "u1".to_string(),
+        success: true,
+        error_code: 0,
+        error_message: "".to_string(),
+        session_id: "".to_string(),
+        client_address: "".to_string(),
+        sql_user: "root".to_string(),
+        current_role: "".to_string(),
+        prev_hash: "".to_string(),
+        hash: "".to_string(),
+    }
+}
+
+#[test]
+fn test_audit_log_element_chain() {
+    let key = b"audit-key";
+    let mut first = new_element("CREATE USER");
+    first.seal("".to_string(), key);
+    let mut second = new_element("DROP USER");
+    second.seal(first.hash.clone(), key);
+
+    assert!(first.verify(key));
+    assert!(second.verify(key));
+    assert_eq!(second.prev_hash, first.hash);
+    assert_ne!(first.hash, second.hash);
+
+    // Editing a sealed event breaks it.
+    let mut edited = second.clone();
+    edited.object = "u2".to_string();
+    assert!(!edited.verify(key));
+
+    // So does relinking it to another predecessor.
+    let mut relinked = second.clone();
+    relinked.prev_hash = "".to_string();
+    assert!(!relinked.verify(key));
+
+    // And an event can't be resealed without the key.
+    let mut forged = edited.clone();
+    forged.seal(edited.prev_hash.clone(), b"");
+    assert!(!forged.verify(key));
+}
+
+#[test]
+fn test_audit_chain_head_survives_restart() -> Result<()> {
+    let root = tempfile::tempdir()?;
+    let dir = root.path().join("audit");
+    let dir = dir.to_str().unwrap();
+
+    let mut head = AuditChainHead::load(dir)?;
+    assert_eq!(head.hash(), "");
+    head.advance("h1".to_string())?;
+    head.advance("h2".to_string())?;
+
+    // The chain goes on from the last event recorded before the restart.
+    let head = AuditChainHead::load(dir)?;
+    assert_eq!(head.hash(), "h2");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_log_login_and_ddl() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.log.audit.on = true;
+    conf.log.audit.secret_key = "audit-key".to_string();
+    let fixture = TestFixture::setup_with_config(&conf).await?;
+
+    fixture
+        .execute_command("CREATE USER u1 IDENTIFIED BY 'p1'")
+        .await?;
+
+    let auth_mgr = AuthMgr::instance();
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let res = auth_mgr
+        .auth(session.clone(), &Credential::Password {
+            name: "u1".to_string(),
+            password: Some(b"wrong".to_vec()),
+            client_ip: None,
+        })
+        .await;
+    assert!(res.is_err());
+    let res = auth_mgr
+        .auth(session, &Credential::Password {
+            name: "u1".to_string(),
+            password: Some(b"p1".to_vec()),
+            client_ip: None,
+        })
+        .await;
+    assert!(res.is_ok());
+
+    expects_ok(
+        "audit_log_events",
+        fixture
+            .execute_query("SELECT category, event_type, error_code FROM system.audit_log")
+            .await,
+        vec![
+            "+----------+---------------+----------+",
+            "| Column 0 | Column 1      | Column 2 |",
+            "+----------+---------------+----------+",
+            "| 'ddl'    | 'CREATE USER' | 0        |",
+            "| 'login'  | 'LOGIN'       | 0        |",
+            "| 'login'  | 'LOGIN'       | 1051     |",
+            "+----------+---------------+----------+",
+        ],
+    )
+    .await?;
+
+    // Every event but the first one is linked to its predecessor.
+    expects_ok(
+        "audit_log_chain",
+        fixture
+            .execute_query(
+                "SELECT count(*) FROM system.audit_log a, system.audit_log b \
+                WHERE a.hash = b.prev_hash",
+            )
+            .await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 2        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
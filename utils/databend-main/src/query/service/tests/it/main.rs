@@ -21,6 +21,7 @@
 extern crate core;
 
 mod api;
+mod audit;
 mod auth;
 mod catalogs;
 mod clusters;
@@ -44,6 +44,8 @@ async fn test_always_call_on_finished() -> Result<()> {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
         enable_new_executor: false,
+        parent_mem_stats: vec![],
+        query_memory_budget: None,
     };
 
     {
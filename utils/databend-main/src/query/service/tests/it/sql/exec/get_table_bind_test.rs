@@ -39,6 +39,7 @@ use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -123,6 +124,7 @@ use databend_common_users::GrantObjectVisibilityChecker;
 use databend_query::sessions::QueryContext;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_txn::TxnManagerRef;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -729,6 +731,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_written_column_hlls(&self, _rows: u64, _hlls: HashMap<ColumnId, MetaHLL>) {
+        todo!()
+    }
+
+    fn take_written_column_hlls(&self) -> Option<(u64, HashMap<ColumnId, MetaHLL>)> {
+        todo!()
+    }
+
     fn add_file_status(&self, _file_path: &str, _file_status: FileStatus) -> Result<()> {
         todo!()
     }
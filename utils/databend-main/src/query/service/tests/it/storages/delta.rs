@@ -0,0 +1,84 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::path::Path;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::test_kits::*;
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+        } else {
+            std::fs::copy(entry.path(), to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delta_concurrent_appends() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // The table has 5 rows at version 0.
+    let root = tempfile::tempdir()?;
+    copy_dir(Path::new("../../../tests/data/delta/simple"), root.path())?;
+
+    fixture
+        .execute_command(&format!(
+            "create table default.t engine = delta location = 'fs://{}/'",
+            root.path().display()
+        ))
+        .await?;
+
+    // Both appends are written against version 0.
+    let (r1, r2) = futures::join!(
+        fixture.execute_command("insert into default.t values (5), (6)"),
+        fixture.execute_command("insert into default.t values (7)"),
+    );
+    r1?;
+    r2?;
+
+    let mut log_entries = std::fs::read_dir(root.path().join("_delta_log"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    log_entries.sort();
+    assert_eq!(log_entries, vec![
+        "00000000000000000000.json",
+        "00000000000000000001.json",
+        "00000000000000000002.json",
+    ]);
+
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 8        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "count_after_concurrent_appends",
+        fixture
+            .execute_query("select count(*) from default.t")
+            .await,
+        expected,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -342,6 +342,7 @@ fn build_test_segment_info(
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -51,6 +51,7 @@ fn test_unresolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics: Statistics::default(),
         merged_statistics: Statistics::default(),
+        modified_rows: 0,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -139,6 +140,7 @@ fn test_resolvable_delete_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        modified_rows: 0,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
@@ -242,6 +244,7 @@ fn test_resolvable_replace_conflict() {
         removed_segment_indexes: vec![1],
         removed_statistics,
         merged_statistics,
+        modified_rows: 0,
     });
 
     let mut generator = MutationGenerator::new(Arc::new(base_snapshot));
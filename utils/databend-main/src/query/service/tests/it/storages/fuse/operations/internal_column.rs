@@ -52,6 +52,7 @@ fn expected_data_block(
             snapshot_location: block_meta.snapshot_location.clone(),
             offsets: None,
             base_block_ids: None,
+            matched_rows: block_meta.matched_rows.clone(),
         };
         for internal_column in internal_columns {
             let column = internal_column.generate_column_values(&internal_column_meta, num_rows);
@@ -16,6 +16,7 @@ use std::ops::Sub;
 use std::time::Duration;
 
 use databend_common_base::base::tokio;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -226,3 +227,69 @@ async fn test_navigate_for_purge() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_navigate_within_data_retention() -> Result<()> {
+    // - perform two insertions, which will left 2 snapshots
+    // - with a retention of 0 days, only the kept snapshots can be navigated to
+
+    // 1. Setup
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    for qry in [
+        format!("insert into {}.{} values (1, (2, 3))", db, tbl),
+        format!("insert into {}.{} values (3, (6, 9))", db, tbl),
+        format!(
+            "alter table {}.{} set options(data_retention_time_in_days = 0, data_retention_num_snapshots_to_keep = 1)",
+            db, tbl
+        ),
+    ] {
+        fixture
+            .execute_query(qry.as_str())
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+    }
+
+    // 2. grab the history
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let reader = MetaReaders::table_snapshot_reader(fuse_table.get_operator());
+    let loc = fuse_table.snapshot_loc().await?.unwrap();
+    let version = TableMetaLocationGenerator::snapshot_version(loc.as_str());
+    let snapshots: Vec<_> = reader
+        .snapshot_history(loc, version, fuse_table.meta_location_generator().clone())
+        .try_collect()
+        .await?;
+    assert_eq!(2, snapshots.len());
+    let latest = NavigationPoint::SnapshotID(snapshots[0].0.snapshot_id.simple().to_string());
+    let first = NavigationPoint::SnapshotID(snapshots[1].0.snapshot_id.simple().to_string());
+
+    // 3. the latest snapshot is kept, the first one is past the retention
+    assert!(fuse_table.navigate_to(&latest).await.is_ok());
+    match fuse_table.navigate_to(&first).await {
+        Ok(_) => panic!("the snapshot should be past the data retention"),
+        Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND),
+    };
+
+    // 4. keep both of the snapshots
+    let qry = format!(
+        "alter table {}.{} set options(data_retention_num_snapshots_to_keep = 2)",
+        db, tbl
+    );
+    fixture
+        .execute_query(qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(fuse_table.navigate_to(&first).await.is_ok());
+
+    Ok(())
+}
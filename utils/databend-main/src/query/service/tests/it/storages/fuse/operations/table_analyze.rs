@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::base::tokio;
 use databend_common_catalog::table::Table;
@@ -29,6 +30,9 @@ use databend_query::sql::plans::Plan;
 use databend_query::sql::Planner;
 use databend_query::test_kits::*;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket;
+use databend_storages_common_table_meta::meta::MostCommonValue;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
 
@@ -164,6 +168,160 @@ async fn test_table_update_analyze_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histograms() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.execute_command("create table t(c int)").await?;
+    fixture
+        .execute_command("insert into t values(1),(1),(1),(2),(3),(4)")
+        .await?;
+    fixture
+        .execute_command("set analyze_histogram_buckets = 100")
+        .await?;
+    fixture.execute_command("analyze table default.t").await?;
+
+    ctx.evict_table_from_cache("default", "default", "t")?;
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider(ctx.clone()).await?;
+
+    let bucket = |v: i32, num_values: u64| HistogramBucket {
+        upper_bound: Scalar::Number(NumberScalar::Int32(v)),
+        num_values,
+        num_distinct: 1,
+    };
+    let expected = ColumnHistogram {
+        buckets: vec![bucket(1, 3), bucket(2, 1), bucket(3, 1), bucket(4, 1)],
+        most_common_values: vec![MostCommonValue {
+            value: Scalar::Number(NumberScalar::Int32(1)),
+            count: 3,
+        }],
+        num_rows: 6,
+    };
+    assert_eq!(provider.histogram(0), Some(&expected));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histograms_of_quoted_columns() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture
+        .execute_command("create table `t-1`(`select` int, `b c` int)")
+        .await?;
+    fixture
+        .execute_command("insert into `t-1` values(1, 5),(1, 5),(2, 6)")
+        .await?;
+    fixture
+        .execute_command("set analyze_histogram_buckets = 2")
+        .await?;
+    fixture
+        .execute_command("analyze table default.`t-1`")
+        .await?;
+
+    ctx.evict_table_from_cache("default", "default", "t-1")?;
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t-1")
+        .await?;
+    let provider = table.column_statistics_provider(ctx.clone()).await?;
+
+    let histogram = |common: i32, upper_bound: i32| ColumnHistogram {
+        buckets: vec![HistogramBucket {
+            upper_bound: Scalar::Number(NumberScalar::Int32(upper_bound)),
+            num_values: 3,
+            num_distinct: 2,
+        }],
+        most_common_values: vec![MostCommonValue {
+            value: Scalar::Number(NumberScalar::Int32(common)),
+            count: 2,
+        }],
+        num_rows: 3,
+    };
+    assert_eq!(provider.histogram(0), Some(&histogram(1, 2)));
+    assert_eq!(provider.histogram(1), Some(&histogram(5, 6)));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histograms_of_sampled_blocks() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.execute_command("create table t(c int)").await?;
+    for v in 1..=3 {
+        fixture
+            .execute_command(&format!("insert into t values({v}),({v})"))
+            .await?;
+    }
+    fixture
+        .execute_command("set analyze_sample_rows = 1")
+        .await?;
+    fixture.execute_command("analyze table default.t").await?;
+
+    ctx.evict_table_from_cache("default", "default", "t")?;
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider(ctx.clone()).await?;
+
+    // Only one of the blocks is read, its counts are scaled to the 6 rows of the table.
+    let histogram = provider.histogram(0).unwrap();
+    assert_eq!(histogram.num_rows, 6);
+    assert_eq!(histogram.buckets.len(), 1);
+    assert_eq!(histogram.buckets[0].num_values, 6);
+    assert_eq!(histogram.buckets[0].num_distinct, 1);
+    assert_eq!(histogram.most_common_values.len(), 1);
+    assert_eq!(histogram.most_common_values[0].count, 6);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_auto_analyze_after_write() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.execute_command("create table t(c int)").await?;
+    fixture
+        .execute_command("insert into t values(1),(2),(3)")
+        .await?;
+    fixture.execute_command("analyze table default.t").await?;
+    fixture
+        .execute_command("set auto_analyze_stale_percentage = 10")
+        .await?;
+    fixture.execute_command("insert into t values(4)").await?;
+
+    // The analyze job runs in the background after the insert returns.
+    let catalog = ctx.get_catalog("default").await?;
+    let mut rows_modified_since_analyze = None;
+    for _ in 0..100 {
+        let table = catalog
+            .get_table(ctx.get_tenant().as_str(), "default", "t")
+            .await?;
+        rows_modified_since_analyze = table
+            .get_table_info()
+            .meta
+            .statistics
+            .rows_modified_since_analyze;
+        if rows_modified_since_analyze == Some(0) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(rows_modified_since_analyze, Some(0));
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     ctx: Arc<dyn TableContext>,
     table: Arc<dyn Table>,
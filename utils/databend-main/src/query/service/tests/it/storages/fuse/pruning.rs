@@ -63,10 +63,17 @@ async fn apply_block_pruning(
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
-    FusePruner::create(&ctx, op, schema, push_down, bloom_index_cols)?
-        .read_pruning(segment_locs)
-        .await
-        .map(|v| v.into_iter().map(|(_, v)| v).collect())
+    FusePruner::create(
+        &ctx,
+        op,
+        schema,
+        push_down,
+        bloom_index_cols,
+        BloomIndexColumns::None,
+    )?
+    .read_pruning(segment_locs)
+    .await
+    .map(|v| v.into_iter().map(|(_, v)| v).collect())
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -107,6 +114,7 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_source: None,
         cluster_key: None,
     };
 
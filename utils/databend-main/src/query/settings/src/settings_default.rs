@@ -151,6 +151,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("max_query_memory_usage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the hard limit of memory in bytes a single query can use, the query is cancelled once it exceeds the limit, 0 is unlimited.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("query_spilling_memory_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(80),
+                    desc: "Sets the ratio of max_query_memory_usage a query can use before its joins, sorts and aggregators start spilling data to storage, 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("data_retention_time_in_days", DefaultSettingValue {
                     // unit of retention_period is day
                     value: UserSettingValue::UInt64(1),
@@ -289,6 +301,42 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_aggregate_by_statistics", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables answering MIN/MAX/COUNT from the block statistics of fuse tables.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_top_n_runtime_filter", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables pruning the blocks of table scan with the threshold of ORDER BY ... LIMIT.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("analyze_histogram_buckets", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(128),
+                    desc: "Sets the number of histogram buckets built by ANALYZE TABLE for each column, 0 disables histograms and most common values.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1024)),
+                }),
+                ("analyze_most_common_values", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(20),
+                    desc: "Sets the maximum number of most common values collected by ANALYZE TABLE for each column.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1024)),
+                }),
+                ("analyze_sample_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1_000_000),
+                    desc: "Sets the number of rows sampled by ANALYZE TABLE to build histograms, 0 means the maximum of 10,000,000 rows. Whole blocks are sampled.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=10_000_000)),
+                }),
+                ("auto_analyze_stale_percentage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Re-analyzes a table after write(copy/insert/replace-into/merge-into) once the rows modified since its last ANALYZE TABLE exceed this percentage of its rows, 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -174,6 +174,18 @@ impl Settings {
         self.try_set_u64("max_memory_usage", val)
     }
 
+    pub fn get_max_query_memory_usage(&self) -> Result<u64> {
+        self.try_get_u64("max_query_memory_usage")
+    }
+
+    pub fn set_max_query_memory_usage(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_query_memory_usage", val)
+    }
+
+    pub fn get_query_spilling_memory_ratio(&self) -> Result<u64> {
+        self.try_get_u64("query_spilling_memory_ratio")
+    }
+
     pub fn set_data_retention_time_in_days(&self, days: u64) -> Result<()> {
         self.try_set_u64("data_retention_time_in_days", days)
     }
@@ -285,6 +297,30 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_aggregate_by_statistics(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_aggregate_by_statistics")? != 0)
+    }
+
+    pub fn get_top_n_runtime_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_top_n_runtime_filter")? != 0)
+    }
+
+    pub fn get_analyze_histogram_buckets(&self) -> Result<usize> {
+        Ok(self.try_get_u64("analyze_histogram_buckets")? as usize)
+    }
+
+    pub fn get_analyze_most_common_values(&self) -> Result<usize> {
+        Ok(self.try_get_u64("analyze_most_common_values")? as usize)
+    }
+
+    pub fn get_analyze_sample_rows(&self) -> Result<u64> {
+        self.try_get_u64("analyze_sample_rows")
+    }
+
+    pub fn get_auto_analyze_stale_percentage(&self) -> Result<u64> {
+        self.try_get_u64("auto_analyze_stale_percentage")
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
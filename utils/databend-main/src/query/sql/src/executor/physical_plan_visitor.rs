@@ -283,6 +283,7 @@ pub trait PhysicalPlanReplacer {
             limit: plan.limit,
             after_exchange: plan.after_exchange,
             pre_projection: plan.pre_projection.clone(),
+            top_n_runtime_filter: plan.top_n_runtime_filter.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -18,6 +18,8 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Expr;
+use databend_common_expression::RemoteExpr;
 use databend_common_pipeline_transforms::processors::sort::utils::ORDER_COL_NAME;
 use itertools::Itertools;
 
@@ -26,6 +28,8 @@ use crate::executor::physical_plans::common::SortDesc;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
 
@@ -41,6 +45,9 @@ pub struct Sort {
     /// It's [None] if the sorting plan is in single node mode.
     pub after_exchange: Option<bool>,
     pub pre_projection: Option<Vec<IndexType>>,
+    /// The first sort key as a base table column, and the index of the table it comes from.
+    /// If it's set, the top-n threshold of the sort is published to the table scan as a runtime filter.
+    pub top_n_runtime_filter: Option<(RemoteExpr<String>, IndexType)>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -119,6 +126,8 @@ impl PhysicalPlanBuilder {
             None
         };
 
+        let top_n_runtime_filter = self.build_top_n_runtime_filter(s_expr, sort)?;
+
         // 2. Build physical plan.
         Ok(PhysicalPlan::Sort(Sort {
             plan_id: 0,
@@ -136,7 +145,70 @@ impl PhysicalPlanBuilder {
             limit: sort.limit,
             after_exchange: sort.after_exchange,
             pre_projection,
+            top_n_runtime_filter,
             stat_info: Some(stat_info),
         }))
     }
+
+    // Only a top-n sort whose first key is a column read directly from a table scan
+    // can prune the blocks of the scan with its threshold.
+    // Joins, aggregates and others are not supported, because the rows they output may be
+    // generated from the pruned blocks, e.g. the rows padded with NULL by outer join.
+    fn build_top_n_runtime_filter(
+        &self,
+        s_expr: &SExpr,
+        sort: &crate::plans::Sort,
+    ) -> Result<Option<(RemoteExpr<String>, IndexType)>> {
+        if sort.limit.is_none()
+            || matches!(sort.after_exchange, Some(true))
+            || !self.ctx.get_settings().get_top_n_runtime_filter()?
+        {
+            return Ok(None);
+        }
+
+        let Some(first) = sort.items.first() else {
+            return Ok(None);
+        };
+
+        let mut child = s_expr.child(0)?;
+        let scan_table_index = loop {
+            match child.plan() {
+                RelOperator::Filter(_) | RelOperator::EvalScalar(_) => {
+                    child = child.child(0)?;
+                }
+                RelOperator::Scan(scan) => break scan.table_index,
+                _ => return Ok(None),
+            }
+        };
+
+        let metadata = self.metadata.read();
+        match metadata.column(first.index) {
+            ColumnEntry::BaseTableColumn(column)
+                if column.table_index == scan_table_index
+                    && column.path_indices.is_none()
+                    && column.virtual_computed_expr.is_none() =>
+            {
+                let data_type = DataType::from(&column.data_type);
+                // Only the types whose block statistics keep the same order as sorting.
+                if !matches!(
+                    data_type.remove_nullable(),
+                    DataType::Number(_)
+                        | DataType::Decimal(_)
+                        | DataType::Date
+                        | DataType::Timestamp
+                        | DataType::String
+                ) {
+                    return Ok(None);
+                }
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: column.column_name.clone(),
+                    data_type,
+                    display_name: column.column_name.clone(),
+                };
+                Ok(Some((expr.as_remote_expr(), scan_table_index)))
+            }
+            _ => Ok(None),
+        }
+    }
 }
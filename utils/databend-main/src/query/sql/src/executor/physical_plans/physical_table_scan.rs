@@ -22,6 +22,8 @@ use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::plan::InternalColumn;
+use databend_common_catalog::plan::InternalColumnType;
+use databend_common_catalog::plan::InvertedIndexInfo;
 use databend_common_catalog::plan::PrewhereInfo;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
@@ -473,6 +475,27 @@ impl PhysicalPlanBuilder {
 
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
+        // The `_search_score` column is generated from the scores of the matched rows,
+        // so the score is only calculated while searching if the column is read.
+        let has_score = scan.columns.iter().any(|index| {
+            matches!(metadata.column(*index), ColumnEntry::InternalColumn(TableInternalColumn {
+                internal_column,
+                ..
+            }) if internal_column.column_type == InternalColumnType::SearchScore)
+        });
+        let inverted_index = match metadata.get_inverted_index(scan.table_index) {
+            Some(info) => Some(InvertedIndexInfo {
+                has_score,
+                ..info.clone()
+            }),
+            None if has_score => {
+                return Err(ErrorCode::SemanticError(
+                    "score function must be used with match function".to_string(),
+                ));
+            }
+            None => None,
+        };
+
         Ok(PushDownInfo {
             projection: Some(projection),
             output_columns,
@@ -485,6 +508,12 @@ impl PhysicalPlanBuilder {
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
+            inverted_index,
+            agg_by_statistics: scan.agg_by_statistics
+                && self
+                    .ctx
+                    .get_settings()
+                    .get_enable_aggregate_by_statistics()?,
         })
     }
 
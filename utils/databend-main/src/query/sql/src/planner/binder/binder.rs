@@ -315,6 +315,9 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+            Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -542,6 +545,15 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -570,6 +582,16 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => self.bind_show_workload_groups(bind_context).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -18,7 +18,9 @@ use databend_common_ast::ast::AccountMgrSource;
 use databend_common_ast::ast::AlterUserStmt;
 use databend_common_ast::ast::CreateUserStmt;
 use databend_common_ast::ast::GrantStmt;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::RevokeStmt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::GrantObject;
@@ -26,7 +28,9 @@ use databend_common_meta_app::principal::PrincipalIdentity;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_users::UserApiProvider;
+use databend_common_users::UserPublicKey;
 
+use crate::normalize_identifier;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::GrantPrivilegePlan;
@@ -59,7 +63,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges(false);
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -70,6 +74,23 @@ impl Binder {
                 for x in privileges {
                     priv_types.set_privilege(*x);
                 }
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_object = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(*x);
+                }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
                     on: grant_object,
@@ -125,6 +146,23 @@ impl Binder {
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_object = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(*x);
+                }
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on: grant_object,
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
         }
     }
 
@@ -222,6 +260,62 @@ impl Binder {
         }
     }
 
+    // Column privileges are stored by column id, so that they are not carried over to
+    // a new column which reuses the name of a dropped one.
+    pub(in crate::planner::binder) async fn convert_to_column_grant_objects(
+        &self,
+        source: &AccountMgrLevel,
+        columns: &[Identifier],
+    ) -> Result<Vec<GrantObject>> {
+        let AccountMgrLevel::Table(database_name, table_name) = source else {
+            return Err(ErrorCode::IllegalGrant(
+                "Illegal GRANT/REVOKE command; column privileges can only be granted on a table",
+            ));
+        };
+        // TODO fetch real catalog
+        let catalog_name = self.ctx.get_current_catalog();
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let database_name = database_name
+            .clone()
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let db_id = catalog
+            .get_database(tenant.as_str(), &database_name)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        let table = catalog
+            .get_table(tenant.as_str(), &database_name, table_name)
+            .await?;
+        let schema = table.schema();
+
+        let mut objects = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column_name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema
+                .fields()
+                .iter()
+                .find(|f| f.name() == &column_name)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "column {} not exists in table {}.{}",
+                        column_name, database_name, table_name
+                    ))
+                })?;
+            let object = GrantObject::TableColumnById(
+                catalog_name.clone(),
+                db_id,
+                table.get_id(),
+                field.column_id(),
+            );
+            if !objects.contains(&object) {
+                objects.push(object);
+            }
+        }
+        Ok(objects)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_user(
         &mut self,
@@ -237,6 +331,9 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        for public_key in user_option.public_keys() {
+            UserPublicKey::from_pem(public_key)?;
+        }
         UserApiProvider::instance()
             .verify_password(
                 self.ctx.get_tenant().as_str(),
@@ -280,6 +377,9 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        for public_key in user_option.public_keys() {
+            UserPublicKey::from_pem(public_key)?;
+        }
 
         // None means no change to make
         let new_auth_info = if let Some(auth_option) = &auth_option {
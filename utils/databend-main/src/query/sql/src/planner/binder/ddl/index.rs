@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
 use databend_common_ast::ast::walk_statement_mut;
 use databend_common_ast::ast::CreateIndexStmt;
+use databend_common_ast::ast::CreateInvertedIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
+use databend_common_ast::ast::DropInvertedIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
+use databend_common_ast::ast::RefreshInvertedIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
@@ -28,6 +34,7 @@ use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::GetIndexReq;
@@ -38,12 +45,16 @@ use databend_common_meta_types::NonEmptyString;
 use databend_storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateTableIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropTableIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshTableIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -343,6 +354,163 @@ impl Binder {
         Ok(plan)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            create_option,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            sync_creation,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create inverted index",
+                table_info.engine()
+            )));
+        }
+        let schema = table_info.schema();
+
+        let mut column_set = HashSet::with_capacity(columns.len());
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column_name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column_name)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index currently only support String type, \
+                    but the type of column {} is {}",
+                    column_name,
+                    field.data_type()
+                )));
+            }
+            if !column_set.insert(column_name.clone()) {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index column {} is duplicated",
+                    column_name
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let index_options = Self::validate_inverted_index_options(index_options)?;
+
+        let plan = CreateTableIndexPlan {
+            create_option: *create_option,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_ids,
+            sync_creation: *sync_creation,
+            index_options,
+        };
+        Ok(Plan::CreateTableIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropTableIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropTableIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+        &mut self,
+        stmt: &RefreshInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshInvertedIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        if limit.is_some() && limit.unwrap() < 1 {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Invalid 'limit' value: {}. 'limit' must be greater than or equal to 1.",
+                limit.unwrap()
+            )));
+        }
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = RefreshTableIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+            limit: *limit,
+            segment_locs: None,
+        };
+        Ok(Plan::RefreshTableIndex(Box::new(plan)))
+    }
+
+    fn validate_inverted_index_options(
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        for (key, value) in index_options {
+            let value = value.to_lowercase();
+            match key.as_str() {
+                "tokenizer" => match value.as_str() {
+                    "english" | "simple" => {
+                        options.insert(key.clone(), value);
+                    }
+                    _ => {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "tokenizer `{}` is invalid, only support `english` and `simple`",
+                            value
+                        )));
+                    }
+                },
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `{}` is invalid, only support `tokenizer`",
+                        key
+                    )));
+                }
+            }
+        }
+        Ok(options)
+    }
+
     fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
@@ -23,6 +23,7 @@ mod network_policy;
 mod notification;
 mod password_policy;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod stream;
@@ -30,3 +31,4 @@ mod table;
 mod task;
 mod view;
 mod virtual_column;
+mod workload_group;
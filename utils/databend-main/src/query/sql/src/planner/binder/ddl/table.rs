@@ -44,6 +44,7 @@ use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
@@ -53,6 +54,7 @@ use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::ast::VacuumTemporaryFiles;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -68,6 +70,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TABLE_CLONE_REF_PREFIX;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_delta::DeltaTable;
@@ -77,6 +80,8 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -99,13 +104,18 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -471,6 +481,43 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... CLONE ...` shares the snapshot of the source table, the
+        // options of the source table are inherited unless they are overridden.
+        let mut clone_source = None;
+        let mut default_cluster_key = None;
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = &source
+        {
+            if engine != Engine::Fuse || storage_params.is_some() || as_query.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: CLONE is only supported for FUSE engine tables without external location or AS SELECT",
+                ));
+            }
+            let (source, source_table) = self
+                .resolve_clone_source(source_catalog, source_database, source_table, travel_point)
+                .await?;
+            let source_info = source_table.get_table_info();
+            for (key, value) in source_info.options() {
+                if key == OPT_KEY_DATABASE_ID
+                    || key == OPT_KEY_SNAPSHOT_LOCATION
+                    || key == OPT_KEY_LEGACY_SNAPSHOT_LOC
+                {
+                    continue;
+                }
+                options.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+            // blocks are shared with the source table, so the storage format can not be changed.
+            if let Some(storage_format) = source_info.options().get(OPT_KEY_STORAGE_FORMAT) {
+                options.insert(OPT_KEY_STORAGE_FORMAT.to_owned(), storage_format.clone());
+            }
+            default_cluster_key = source_info.meta.default_cluster_key.clone();
+            clone_source = Some(source);
+        }
+
         // todo(geometry): remove this when geometry stable.
         if let Some(CreateTableSource::Columns(cols)) = &source {
             if cols
@@ -565,6 +612,13 @@ impl Binder {
             }
         };
 
+        // iceberg table created with column definitions or `AS SELECT` is written
+        // to the location given by the table options.
+        if engine == Engine::Iceberg && storage_params.is_none() {
+            storage_params =
+                Some(get_storage_params_from_options(self.ctx.as_ref(), &options).await?);
+        }
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -638,7 +692,7 @@ impl Binder {
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                default_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
@@ -670,6 +724,7 @@ impl Binder {
             } else {
                 None
             },
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -742,6 +797,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            clone_source: None,
         })))
     }
 
@@ -1001,6 +1057,69 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::CreateTableRef {
+                create_option,
+                kind,
+                name,
+                travel_point,
+            } => {
+                let name = self.normalize_table_ref_name(name)?;
+                let point = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point).await?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableRef(Box::new(CreateTableRefPlan {
+                    create_option: *create_option,
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    kind: *kind,
+                    name,
+                    point,
+                })))
+            }
+            AlterTableAction::DropTableRef {
+                if_exists,
+                kind,
+                name,
+            } => {
+                let name = self.normalize_table_ref_name(name)?;
+                Ok(Plan::DropTableRef(Box::new(DropTableRefPlan {
+                    if_exists: *if_exists,
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    kind: *kind,
+                    name,
+                })))
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant: tenant.to_string(),
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
             AlterTableAction::SetOptions { set_options } => {
                 Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
                     set_options: set_options.clone(),
@@ -1012,6 +1131,19 @@ impl Binder {
         }
     }
 
+    /// Tags and branches share the namespace with the refs registered by clones,
+    /// which are managed by `CREATE TABLE ... CLONE ...` and `DROP TABLE`.
+    fn normalize_table_ref_name(&self, name: &Identifier) -> Result<String> {
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        if name.starts_with(TABLE_CLONE_REF_PREFIX) {
+            return Err(ErrorCode::BadArguments(format!(
+                "tag or branch name can not start with '{}'",
+                TABLE_CLONE_REF_PREFIX
+            )));
+        }
+        Ok(name)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_rename_table(
         &mut self,
@@ -1420,9 +1552,79 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let (_, table) = self
+                    .resolve_clone_source(catalog, database, table, travel_point)
+                    .await?;
+                let schema = table.schema();
+                // the comments are kept by the latest table meta, which may not
+                // match the schema of a historical snapshot.
+                let field_comments = if table.field_comments().len() == schema.num_fields() {
+                    table.field_comments().clone()
+                } else {
+                    vec![]
+                };
+                Ok((schema, field_comments))
+            }
         }
     }
 
+    /// Resolves the source table of `CREATE TABLE ... CLONE ...`, navigated to
+    /// the given time travel point if any.
+    #[async_backtrace::framed]
+    async fn resolve_clone_source(
+        &self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<(CloneTableSource, Arc<dyn Table>)> {
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let source_table = self.ctx.get_table(&catalog, &database, &table).await?;
+        let table_id = source_table.get_id();
+        if source_table.engine() != "FUSE"
+            || source_table.get_table_info().meta.storage_params.is_some()
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table {}.{} can not be cloned, only FUSE tables located in the default storage can be cloned",
+                database, table
+            )));
+        }
+        let source_table = match travel_point {
+            Some(travel_point) => {
+                let mut bind_context = BindContext::new();
+                let navigation_point = self
+                    .resolve_data_travel_point(&mut bind_context, travel_point)
+                    .await?;
+                source_table
+                    .navigate_since_to(&None, &Some(navigation_point))
+                    .await?
+            }
+            None => source_table,
+        };
+        let options = source_table.get_table_info().options();
+        let snapshot_location = options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+            .cloned();
+        Ok((
+            CloneTableSource {
+                catalog,
+                database,
+                table,
+                table_id,
+                snapshot_location,
+            },
+            source_table,
+        ))
+    }
+
     /// Validate the schema of the table to be created.
     fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
         // Check if there are duplicated column names
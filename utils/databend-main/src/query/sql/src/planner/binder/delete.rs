@@ -29,9 +29,11 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
+use crate::plans::RelOperator;
 use crate::plans::RelOperator::Scan;
 use crate::plans::SubqueryDesc;
 use crate::plans::SubqueryExpr;
@@ -46,15 +48,33 @@ impl<'a> Binder {
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
+        // The row access policy of the table is bound as a filter over the scan,
+        // take it as a part of the selection instead.
+        let (table_expr, mut predicates) = match &*table_expr.plan {
+            RelOperator::Filter(policy) => {
+                (table_expr.child(0)?.clone(), policy.predicates.clone())
+            }
+            _ => (table_expr, vec![]),
+        };
+        if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
-            let mut subquery_desc = vec![];
-            self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
-                .await?;
-            (Some(scalar), subquery_desc)
-        } else {
-            (None, vec![])
-        })
+            predicates.push(scalar);
+        }
+
+        let Some(scalar) = predicates.into_iter().reduce(|lhs, rhs| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![lhs, rhs],
+            })
+        }) else {
+            return Ok((None, vec![]));
+        };
+        let mut subquery_desc = vec![];
+        self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
+            .await?;
+        Ok((Some(scalar), subquery_desc))
     }
 
     #[async_backtrace::framed]
@@ -39,6 +39,7 @@ mod project;
 mod project_set;
 mod qualify;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod select;
@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::VisitorMut;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_license::license::Feature::RowAccessPolicy;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
+
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::normalize_identifier;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+impl Binder {
+    /// Bind the row access policy attached to the table as a filter over the scan of the table.
+    ///
+    /// The columns of the table are substituted for the args of the policy by position,
+    /// so the policy can be bound as a `WHERE` clause of the table.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &mut BindContext,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let table_info = table.get_table_info();
+        let attached = if table.engine() == "STREAM" {
+            // Streams are filtered by the policy of the source table.
+            let source_table_id = table_info
+                .options()
+                .get(OPT_KEY_TABLE_ID)
+                .ok_or_else(|| ErrorCode::Internal("table id must be set in stream"))?
+                .parse::<u64>()?;
+            let catalog = self.ctx.get_catalog(catalog).await?;
+            let (_, source_meta) = catalog.get_table_meta_by_id(source_table_id).await?;
+            source_meta.row_access_policy.clone()
+        } else {
+            table_info.meta.row_access_policy.clone()
+        };
+        let Some(attached) = attached else {
+            return Ok(s_expr);
+        };
+
+        // Refuse to read the table instead of reading it unfiltered.
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), RowAccessPolicy)?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_row_access_policy_handler();
+        let policy = handler
+            .get_row_access_policy(
+                meta_api,
+                self.ctx.get_tenant().to_string(),
+                attached.policy.clone(),
+            )
+            .await?;
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let mut expr = parse_expr(&tokens, self.dialect)?;
+        let mut rewriter = ArgRewriter {
+            args: policy
+                .args
+                .iter()
+                .map(|(arg_name, _)| arg_name.to_lowercase())
+                .zip(attached.columns.iter().cloned())
+                .collect(),
+            name_resolution_ctx: &self.name_resolution_ctx,
+        };
+        rewriter.visit_expr(&mut expr);
+
+        let (s_expr, _) = self.bind_where(bind_context, &[], &expr, s_expr).await?;
+        Ok(s_expr)
+    }
+}
+
+/// Rewrite the args of the policy body to the columns of the table.
+struct ArgRewriter<'a> {
+    args: HashMap<String, String>,
+    name_resolution_ctx: &'a NameResolutionContext,
+}
+
+impl<'a> VisitorMut for ArgRewriter<'a> {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        database: &mut Option<Identifier>,
+        table: &mut Option<Identifier>,
+        column: &mut ColumnID,
+    ) {
+        if database.is_some() || table.is_some() {
+            return;
+        }
+        if let ColumnID::Name(ident) = column {
+            let name = normalize_identifier(ident, self.name_resolution_ctx).name;
+            if let Some(column_name) = self.args.get(&name) {
+                // Quote the column name to keep its case.
+                *ident = Identifier {
+                    span: ident.span,
+                    name: column_name.clone(),
+                    quote: Some('"'),
+                };
+            }
+        }
+    }
+}
@@ -1333,10 +1333,13 @@ impl Binder {
 
         let table = self.metadata.read().table(table_index).clone();
         let table_name = table.name();
+        let catalog = table.catalog().to_string();
+        let source_of_index = table.is_source_of_index();
         let table = table.table();
         let statistics_provider = table.column_statistics_provider(self.ctx.clone()).await?;
 
         let mut col_stats = HashMap::new();
+        let mut histograms = HashMap::new();
         let columns = self.metadata.read().columns_by_table_index(table_index);
         for column in columns.iter() {
             match column {
@@ -1374,6 +1377,11 @@ impl Binder {
                             let col_stat =
                                 statistics_provider.column_statistics(col_id as ColumnId);
                             col_stats.insert(*column_index, col_stat.cloned());
+                            if let Some(histogram) =
+                                statistics_provider.histogram(col_id as ColumnId)
+                            {
+                                histograms.insert(*column_index, histogram.clone());
+                            }
                         }
                     }
                 }
@@ -1391,22 +1399,28 @@ impl Binder {
             .table_statistics(self.ctx.clone(), change_type.clone())
             .await?;
 
-        Ok((
-            SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: columns.into_iter().map(|col| col.index()).collect(),
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                    },
-                    change_type,
-                    ..Default::default()
-                }
-                .into(),
-            )),
-            bind_context,
-        ))
+        let mut s_expr = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index,
+                columns: columns.into_iter().map(|col| col.index()).collect(),
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                    histograms,
+                },
+                change_type,
+                ..Default::default()
+            }
+            .into(),
+        ));
+        // Aggregating indexes are built over all the rows of the table.
+        if !source_of_index {
+            s_expr = self
+                .bind_row_access_policy(&mut bind_context, &catalog, &table, s_expr)
+                .await?;
+        }
+
+        Ok((s_expr, bind_context))
     }
 
     #[async_backtrace::framed]
@@ -1444,6 +1458,9 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(name) => Ok(NavigationPoint::Tag(name.to_owned())),
+            TimeTravelPoint::Branch(name) => Ok(NavigationPoint::Branch(name.to_owned())),
+            TimeTravelPoint::Version(version) => Ok(NavigationPoint::Version(*version)),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::try_create(
                     bind_context,
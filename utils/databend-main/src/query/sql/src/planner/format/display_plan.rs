@@ -107,6 +107,9 @@ impl Plan {
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::CreateTableIndex(_) => Ok("CreateTableIndex".to_string()),
+            Plan::DropTableIndex(_) => Ok("DropTableIndex".to_string()),
+            Plan::RefreshTableIndex(_) => Ok("RefreshTableIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -168,12 +171,21 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(_) => Ok("ShowObjectGrantPrivileges".to_string()),
             Plan::ShowGrantTenantsOfShare(_) => Ok("ShowGrantTenantsOfShare".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::CreateTableRef(_) => Ok("CreateTableRef".to_string()),
+            Plan::DropTableRef(_) => Ok("DropTableRef".to_string()),
+            Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
+            Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
             Plan::DropDatamaskPolicy(_) => Ok("DropDatamaskPolicy".to_string()),
             Plan::DescDatamaskPolicy(_) => Ok("DescDatamaskPolicy".to_string()),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+
             // network policy
             Plan::CreateNetworkPolicy(_) => Ok("CreateNetworkPolicy".to_string()),
             Plan::AlterNetworkPolicy(_) => Ok("AlterNetworkPolicy".to_string()),
@@ -187,6 +199,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // workload group
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -255,6 +272,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             prewhere: None,
             agg_index: None,
             change_type: None,
+            agg_by_statistics: false,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
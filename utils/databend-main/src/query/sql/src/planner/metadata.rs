@@ -22,6 +22,7 @@ use ahash::HashMap;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
 use databend_common_catalog::plan::InternalColumn;
+use databend_common_catalog::plan::InvertedIndexInfo;
 use databend_common_catalog::table::Table;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
@@ -61,6 +62,8 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Mappings from table index to the inverted index used by `match`.
+    inverted_indexes: HashMap<IndexType, InvertedIndexInfo>,
     max_column_position: usize, // for CSV
 }
 
@@ -274,6 +277,14 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_inverted_index(&mut self, table_index: IndexType, info: InvertedIndexInfo) {
+        self.inverted_indexes.insert(table_index, info);
+    }
+
+    pub fn get_inverted_index(&self, table_index: IndexType) -> Option<&InvertedIndexInfo> {
+        self.inverted_indexes.get(&table_index)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -38,6 +38,10 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// Most common values of column collected by `ANALYZE TABLE`,
+    /// with the fraction of rows having the value.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 #[derive(Debug, Clone)]
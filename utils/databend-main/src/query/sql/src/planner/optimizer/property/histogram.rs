@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_exception::Result;
 use databend_common_expression::arithmetics_type::ResultTypeOfUnary;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,9 +34,9 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
+/// If the table has been analyzed, the histogram is built from the equi-height
+/// histogram persisted by `ANALYZE TABLE`. Otherwise it's constructed from
+/// NDV(number of distinct values) and the total number of rows,
 /// which brings the assumption that the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
@@ -151,6 +152,44 @@ pub fn histogram_from_ndv(
     Ok(Histogram { buckets })
 }
 
+/// Construct a histogram from the histogram collected by `ANALYZE TABLE`, the numbers
+/// of values are scaled to the current number of rows of the table.
+///
+/// Returns the histogram and the most common values with their frequencies,
+/// or `None` if the values can't be converted to `Datum`.
+pub fn histogram_from_analyzed(
+    analyzed: &ColumnHistogram,
+    min: Datum,
+    num_rows: u64,
+) -> Option<(Histogram, Vec<(Datum, f64)>)> {
+    if analyzed.num_rows == 0 {
+        return None;
+    }
+    let scale = num_rows as f64 / analyzed.num_rows as f64;
+
+    let mut buckets = Vec::with_capacity(analyzed.buckets.len() + 1);
+    // The first bucket is a dummy bucket of the min value, same as `histogram_from_ndv`.
+    buckets.push(HistogramBucket::new(min, 0.0, 0.0));
+    for bucket in analyzed.buckets.iter() {
+        buckets.push(HistogramBucket::new(
+            Datum::from_scalar(bucket.upper_bound.clone())?,
+            bucket.num_values as f64 * scale,
+            bucket.num_distinct as f64,
+        ));
+    }
+
+    let most_common_values = analyzed
+        .most_common_values
+        .iter()
+        .map(|mcv| {
+            let frequency = mcv.count as f64 / analyzed.num_rows as f64;
+            Some((Datum::from_scalar(mcv.value.clone())?, frequency.min(1.0)))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((Histogram { buckets }, most_common_values))
+}
+
 #[derive(Debug, Clone)]
 pub struct HistogramBucket {
     /// Upper bound value of the bucket.
@@ -33,6 +33,7 @@ pub use constraint::ConstraintSet;
 pub use enforcer::require_property;
 pub use enforcer::DistributionEnforcer;
 pub use enforcer::Enforcer;
+pub use histogram::histogram_from_analyzed;
 pub use histogram::histogram_from_ndv;
 pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
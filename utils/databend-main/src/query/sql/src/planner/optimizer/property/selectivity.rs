@@ -191,9 +191,10 @@ impl<'a> SelectivityEstimator<'a> {
 
                 return match op {
                     ComparisonOp::Equal => {
-                        // For equal predicate, we just use cardinality of a single
-                        // value to estimate the selectivity. This assumes that
-                        // the column is in a uniform distribution.
+                        // For equal predicate, we use the frequency of the value if it's one of
+                        // the most common values, otherwise we just use cardinality of a single
+                        // value to estimate the selectivity. This assumes that the column
+                        // is in a uniform distribution.
                         let selectivity = evaluate_equal(column_stat, constant);
                         if update {
                             update_statistic(
@@ -210,6 +211,9 @@ impl<'a> SelectivityEstimator<'a> {
                         // For not equal predicate, we treat it as opposite of equal predicate.
                         let selectivity = 1.0 - evaluate_equal(column_stat, constant);
                         if update {
+                            column_stat.most_common_values.retain(|(value, _)| {
+                                !matches!(value.compare(&const_datum), Ok(Ordering::Equal))
+                            });
                             update_statistic(
                                 column_stat,
                                 column_stat.min.clone(),
@@ -437,6 +441,9 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+        if !column_stat.most_common_values.is_empty() {
+            return equal_by_most_common_values(constant_datum, column_stat);
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -446,6 +453,19 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
     }
 }
 
+// The values other than the most common values are assumed to be uniformly distributed.
+fn equal_by_most_common_values(datum: &Datum, column_stat: &ColumnStat) -> f64 {
+    let mut total_frequency = 0.0;
+    for (value, frequency) in column_stat.most_common_values.iter() {
+        if value.type_comparable(datum) && matches!(value.compare(datum), Ok(Ordering::Equal)) {
+            return *frequency;
+        }
+        total_frequency += frequency;
+    }
+    let other_ndv = column_stat.ndv - column_stat.most_common_values.len() as f64;
+    (1.0 - total_frequency).max(0.0) / other_ndv.max(1.0)
+}
+
 fn update_statistic(
     column_stat: &mut ColumnStat,
     mut new_min: Datum,
@@ -463,6 +483,20 @@ fn update_statistic(
     }
     column_stat.min = new_min.clone();
     column_stat.max = new_max.clone();
+    // Keep the most common values in the new range, their frequencies are
+    // relative to the filtered rows.
+    column_stat.most_common_values.retain(|(value, _)| {
+        value.type_comparable(&new_min)
+            && !matches!(value.compare(&new_min), Ok(Ordering::Less) | Err(_))
+            && !matches!(value.compare(&new_max), Ok(Ordering::Greater) | Err(_))
+    });
+    if selectivity > 0.0 {
+        for (_, frequency) in column_stat.most_common_values.iter_mut() {
+            *frequency = (*frequency / selectivity).min(1.0);
+        }
+    } else {
+        column_stat.most_common_values.clear();
+    }
     if let Some(histogram) = &column_stat.histogram {
         let num_values = histogram.num_values();
         let new_num_values = (num_values * selectivity).ceil() as u64;
@@ -471,12 +505,14 @@ fn update_statistic(
             column_stat.histogram = None;
             return Ok(());
         }
-        column_stat.histogram = Some(histogram_from_ndv(
+        // The histogram of strings collected by `ANALYZE TABLE` can't be rebuilt from NDV.
+        column_stat.histogram = histogram_from_ndv(
             new_ndv,
             max(new_num_values, new_ndv),
             Some((new_min, new_max)),
             DEFAULT_HISTOGRAM_BUCKETS,
-        )?);
+        )
+        .ok();
     }
     Ok(())
 }
@@ -14,6 +14,7 @@
 
 pub mod agg_index;
 mod filter_join;
+mod rule_aggregate_by_statistics;
 mod rule_commute_join;
 mod rule_eliminate_eval_scalar;
 mod rule_eliminate_filter;
@@ -44,6 +45,7 @@ mod rule_semi_to_inner_join;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
 
+pub use rule_aggregate_by_statistics::RuleAggregateByStatistics;
 pub use rule_commute_join::RuleCommuteJoin;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Mark the table scan under `MIN`/`MAX`/`COUNT` aggregates without group by,
+/// so that the storage can answer the blocks fully matching the filters with their statistics.
+///
+/// Only the following plans are supported:
+///
+///  Aggregate
+///  \
+///   EvalScalar (optional, only column references)
+///   \
+///    Filter (optional)
+///    \
+///     Scan
+pub struct RuleAggregateByStatistics {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleAggregateByStatistics {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::AggregateByStatistics,
+            metadata,
+            //  Aggregate
+            //  \
+            //   *
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Aggregate,
+                children: vec![Matcher::Leaf],
+            }],
+        }
+    }
+
+    // Check if the column is a column of the scanned table,
+    // the min/max statistics are required to be exact if `need_min_max` is true.
+    fn is_scan_column(&self, table_index: IndexType, index: IndexType, need_min_max: bool) -> bool {
+        let metadata = self.metadata.read();
+        match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(column) => {
+                if column.table_index != table_index
+                    || column.path_indices.is_some()
+                    || column.virtual_computed_expr.is_some()
+                {
+                    return false;
+                }
+                // The min/max statistics of strings are truncated.
+                !need_min_max
+                    || matches!(
+                        DataType::from(&column.data_type).remove_nullable(),
+                        DataType::Number(_)
+                            | DataType::Decimal(_)
+                            | DataType::Date
+                            | DataType::Timestamp
+                            | DataType::Boolean
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    fn replace_scan(s_expr: &SExpr, scan: &Scan) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Scan(_) => Ok(s_expr.replace_plan(Arc::new(scan.clone().into()))),
+            _ => {
+                let child = Self::replace_scan(s_expr.child(0)?, scan)?;
+                Ok(s_expr.replace_children(vec![Arc::new(child)]))
+            }
+        }
+    }
+}
+
+impl Rule for RuleAggregateByStatistics {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        if agg.mode != AggregateMode::Initial
+            || !agg.group_items.is_empty()
+            || agg.grouping_sets.is_some()
+            || agg.aggregate_functions.is_empty()
+        {
+            return Ok(());
+        }
+
+        // Find the scan, and the columns projected by the expressions above it.
+        let mut projections = HashMap::new();
+        let mut predicates = vec![];
+        let mut child = s_expr.child(0)?;
+        let scan = loop {
+            match child.plan() {
+                RelOperator::EvalScalar(eval) => {
+                    for item in eval.items.iter() {
+                        let ScalarExpr::BoundColumnRef(column) = &item.scalar else {
+                            return Ok(());
+                        };
+                        projections.insert(item.index, column.column.index);
+                    }
+                }
+                RelOperator::Filter(filter) => {
+                    predicates.extend(filter.predicates.iter());
+                }
+                RelOperator::Scan(scan) => break scan,
+                _ => return Ok(()),
+            }
+            child = child.child(0)?;
+        };
+
+        if scan.agg_by_statistics
+            || scan.agg_index.is_some()
+            || scan.change_type.is_some()
+            || scan.limit.is_some()
+        {
+            return Ok(());
+        }
+
+        // All the predicates should be pushed down to the scan.
+        if predicates.iter().any(|predicate| {
+            predicate
+                .used_columns()
+                .iter()
+                .any(|index| !self.is_scan_column(scan.table_index, *index, false))
+        }) {
+            return Ok(());
+        }
+
+        for item in agg.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                return Ok(());
+            };
+            if func.distinct || !func.params.is_empty() {
+                return Ok(());
+            }
+            let supported = match (func.func_name.as_str(), func.args.as_slice()) {
+                ("count", []) => true,
+                (name @ ("count" | "min" | "max"), [ScalarExpr::BoundColumnRef(column)]) => {
+                    let index = projections
+                        .get(&column.column.index)
+                        .copied()
+                        .unwrap_or(column.column.index);
+                    self.is_scan_column(scan.table_index, index, name != "count")
+                }
+                _ => false,
+            };
+            if !supported {
+                return Ok(());
+            }
+        }
+
+        let mut scan = scan.clone();
+        scan.agg_by_statistics = true;
+        let child = Self::replace_scan(s_expr.child(0)?, &scan)?;
+        state.add_result(s_expr.replace_children(vec![Arc::new(child)]));
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
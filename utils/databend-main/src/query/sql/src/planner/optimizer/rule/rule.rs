@@ -48,6 +48,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownFilterProjectSet,
         RuleID::SemiToInnerJoin,
         RuleID::FoldCountAggregate,
+        RuleID::AggregateByStatistics,
         RuleID::TryApplyAggIndex,
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
@@ -101,6 +102,7 @@ pub enum RuleID {
     MergeFilter,
     SplitAggregate,
     FoldCountAggregate,
+    AggregateByStatistics,
     PushDownPrewhere,
     TryApplyAggIndex,
     CommuteJoin,
@@ -139,6 +141,7 @@ impl Display for RuleID {
             RuleID::NormalizeAggregate => write!(f, "NormalizeAggregate"),
             RuleID::SplitAggregate => write!(f, "SplitAggregate"),
             RuleID::FoldCountAggregate => write!(f, "FoldCountAggregate"),
+            RuleID::AggregateByStatistics => write!(f, "AggregateByStatistics"),
             RuleID::PushDownPrewhere => write!(f, "PushDownPrewhere"),
 
             RuleID::CommuteJoin => write!(f, "CommuteJoin"),
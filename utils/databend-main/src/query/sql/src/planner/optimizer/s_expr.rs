@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -23,6 +24,7 @@ use educe::Educe;
 use super::RelationalProperty;
 use crate::optimizer::rule::AppliedRules;
 use crate::optimizer::rule::RuleID;
+use crate::optimizer::ColumnSet;
 use crate::optimizer::StatInfo;
 use crate::plans::Exchange;
 use crate::plans::RelOperator;
@@ -329,6 +331,23 @@ impl SExpr {
         Ok(udfs)
     }
 
+    /// Collect the columns read by every `Scan` in the expression, grouped by table index.
+    pub fn get_scan_columns(&self) -> HashMap<IndexType, ColumnSet> {
+        let mut scan_columns: HashMap<IndexType, ColumnSet> = HashMap::new();
+        if let RelOperator::Scan(scan) = self.plan.as_ref() {
+            scan_columns
+                .entry(scan.table_index)
+                .or_default()
+                .extend(scan.columns.iter().copied());
+        }
+        for child in &self.children {
+            for (table_index, columns) in child.get_scan_columns() {
+                scan_columns.entry(table_index).or_default().extend(columns);
+            }
+        }
+        scan_columns
+    }
+
     // Add (table_index, column_index) into `Scan` node recursively.
     pub fn add_internal_column_index(
         expr: &SExpr,
@@ -16,6 +16,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use databend_common_ast::ast::AlterPasswordAction;
 use databend_common_ast::ast::PasswordSetOptions;
+use databend_common_ast::ast::WorkloadGroupOptions;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -117,7 +118,7 @@ impl ShowRolesPlan {
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -258,3 +259,44 @@ impl DescPasswordPolicyPlan {
         ])
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateWorkloadGroupPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl CreateWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl AlterWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_ast::ast::TableIndexType;
+use databend_common_expression::ColumnId;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::TableInfo;
@@ -50,3 +53,34 @@ pub struct RefreshIndexPlan {
     pub segment_locs: Option<Vec<Location>>,
     pub user_defined_block_name: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableIndexPlan {
+    pub create_option: CreateOption,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub column_ids: Vec<ColumnId>,
+    pub sync_creation: bool,
+    pub index_options: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshTableIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub limit: Option<u64>,
+    pub segment_locs: Option<Vec<Location>>,
+}
@@ -29,8 +29,10 @@ use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRefKind;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_types::MetaId;
 use databend_common_meta_types::NonEmptyString;
 
 use crate::plans::Plan;
@@ -55,6 +57,18 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_source: Option<CloneTableSource>,
+}
+
+/// The source table of `CREATE TABLE ... CLONE ...`.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    /// The snapshot shared with the source table, `None` if the source table is empty.
+    pub snapshot_location: Option<String>,
 }
 
 impl CreateTablePlan {
@@ -443,3 +457,29 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Create a tag or branch of a table.
+#[derive(Clone, Debug)]
+pub struct CreateTableRefPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+    /// The snapshot to pin, the current snapshot if `None`.
+    pub point: Option<NavigationPoint>,
+}
+
+/// Drop a tag or branch of a table.
+#[derive(Clone, Debug)]
+pub struct DropTableRefPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::max;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -244,45 +245,39 @@ impl Join {
                     let mut new_ndv = None;
                     let (new_min, new_max) = left_interval.intersection(&right_interval)?;
 
-                    if let Datum::Bytes(_) | Datum::Bool(_) = left_col_stat.min {
-                        let card = evaluate_by_ndv(
+                    let card = if !left_col_stat.most_common_values.is_empty()
+                        && !right_col_stat.most_common_values.is_empty()
+                    {
+                        // Evaluate join cardinality by the most common values of both sides.
+                        evaluate_by_most_common_values(
                             left_col_stat,
                             right_col_stat,
                             *left_cardinality,
                             *right_cardinality,
                             &mut new_ndv,
-                        );
-                        let (left_index, right_index) = update_statistic(
-                            left_statistics,
-                            right_statistics,
-                            left_condition,
-                            right_condition,
-                            NewStatistic {
-                                min: new_min,
-                                max: new_max,
-                                ndv: new_ndv,
-                            },
-                        );
-                        if card < join_card {
-                            join_card = card;
-                            join_card_updated = true;
-                            left_column_index = left_index;
-                            right_column_index = right_index;
-                        }
-                        continue;
-                    }
-                    let card = match (&left_col_stat.histogram, &right_col_stat.histogram) {
-                        (Some(left_hist), Some(right_hist)) => {
-                            // Evaluate join cardinality by histogram.
-                            evaluate_by_histogram(left_hist, right_hist, &mut new_ndv)?
-                        }
-                        _ => evaluate_by_ndv(
+                        )?
+                    } else if let Datum::Bytes(_) | Datum::Bool(_) = left_col_stat.min {
+                        evaluate_by_ndv(
                             left_col_stat,
                             right_col_stat,
                             *left_cardinality,
                             *right_cardinality,
                             &mut new_ndv,
-                        ),
+                        )
+                    } else {
+                        match (&left_col_stat.histogram, &right_col_stat.histogram) {
+                            (Some(left_hist), Some(right_hist)) => {
+                                // Evaluate join cardinality by histogram.
+                                evaluate_by_histogram(left_hist, right_hist, &mut new_ndv)?
+                            }
+                            _ => evaluate_by_ndv(
+                                left_col_stat,
+                                right_col_stat,
+                                *left_cardinality,
+                                *right_cardinality,
+                                &mut new_ndv,
+                            ),
+                        }
                     };
                     let (left_index, right_index) = update_statistic(
                         left_statistics,
@@ -309,6 +304,7 @@ impl Join {
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
+                    left.most_common_values.clear();
                     if left.histogram.is_some() {
                         left.histogram = if left.ndv as u64 <= 2 {
                             None
@@ -333,6 +329,7 @@ impl Join {
             }
             for (idx, right) in right_statistics.column_stats.iter_mut() {
                 if *idx == right_column_index {
+                    right.most_common_values.clear();
                     if right.histogram.is_some() {
                         right.histogram = if right.ndv as u64 <= 2 {
                             None
@@ -746,6 +743,38 @@ fn evaluate_by_histogram(
     Ok(card)
 }
 
+// The matched most common values of both sides are joined exactly,
+// the other values are assumed to be uniformly distributed.
+fn evaluate_by_most_common_values(
+    left_stat: &ColumnStat,
+    right_stat: &ColumnStat,
+    left_cardinality: f64,
+    right_cardinality: f64,
+    new_ndv: &mut Option<f64>,
+) -> Result<f64> {
+    let mut selectivity = 0.0;
+    let mut left_matched = 0.0;
+    let mut right_matched = 0.0;
+    let mut num_matched = 0.0;
+    for (left_value, left_frequency) in left_stat.most_common_values.iter() {
+        for (right_value, right_frequency) in right_stat.most_common_values.iter() {
+            if left_value.compare(right_value)? == Ordering::Equal {
+                selectivity += left_frequency * right_frequency;
+                left_matched += left_frequency;
+                right_matched += right_frequency;
+                num_matched += 1.0;
+                break;
+            }
+        }
+    }
+    let other_ndv = f64::max(left_stat.ndv, right_stat.ndv) - num_matched;
+    selectivity +=
+        f64::max(1.0 - left_matched, 0.0) * f64::max(1.0 - right_matched, 0.0) / other_ndv.max(1.0);
+
+    *new_ndv = Some(left_stat.ndv.min(right_stat.ndv));
+    Ok(selectivity.min(1.0) * left_cardinality * right_cardinality)
+}
+
 fn evaluate_by_ndv(
     left_stat: &ColumnStat,
     right_stat: &ColumnStat,
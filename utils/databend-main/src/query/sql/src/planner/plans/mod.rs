@@ -41,6 +41,7 @@ mod project_set;
 mod recluster_table;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod setting;
@@ -84,6 +85,7 @@ pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use setting::*;
@@ -29,6 +29,7 @@ use crate::binder::ExplainConfig;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
@@ -39,6 +40,7 @@ use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
@@ -52,22 +54,27 @@ use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
+use crate::plans::CreateTableIndexPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -81,18 +88,23 @@ use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableIndexPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
@@ -106,6 +118,7 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -206,6 +219,10 @@ pub enum Plan {
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
+    CreateTableRef(Box<CreateTableRefPlan>),
+    DropTableRef(Box<DropTableRefPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
@@ -234,6 +251,9 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateTableIndex(Box<CreateTableIndexPlan>),
+    DropTableIndex(Box<DropTableIndexPlan>),
+    RefreshTableIndex(Box<RefreshTableIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -306,6 +326,11 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -319,6 +344,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -368,6 +398,7 @@ pub enum RewriteKind {
     ListStage,
     ShowRoles,
     ShowPasswordPolicies,
+    ShowWorkloadGroups,
 
     Call,
 }
@@ -439,6 +470,9 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
@@ -484,6 +518,7 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
                 | Plan::DescPasswordPolicy(_)
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::RowAccessPolicy;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::schema::CreateOption;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateRowAccessPolicyPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub name: String,
+    pub policy: RowAccessPolicy,
+}
+
+impl CreateRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl From<CreateRowAccessPolicyPlan> for CreateRowAccessPolicyReq {
+    fn from(p: CreateRowAccessPolicyPlan) -> Self {
+        CreateRowAccessPolicyReq {
+            create_option: p.create_option,
+            name: RowAccessPolicyNameIdent {
+                tenant: p.tenant.clone(),
+                name: p.name.clone(),
+            },
+            args: p
+                .policy
+                .args
+                .iter()
+                .map(|arg| (arg.arg_name.to_string(), arg.arg_type.to_string()))
+                .collect(),
+            body: p.policy.body.to_string(),
+            comment: p.policy.comment,
+            create_on: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropRowAccessPolicyPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl From<DropRowAccessPolicyPlan> for DropRowAccessPolicyReq {
+    fn from(p: DropRowAccessPolicyPlan) -> Self {
+        DropRowAccessPolicyReq {
+            if_exists: p.if_exists,
+            name: RowAccessPolicyNameIdent {
+                tenant: p.tenant.clone(),
+                name: p.name,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescRowAccessPolicyPlan {
+    pub name: String,
+}
+
+impl DescRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Created On", DataType::String),
+            DataField::new("Signature", DataType::String),
+            DataField::new("Body", DataType::String),
+            DataField::new("Comment", DataType::String),
+        ]))
+    }
+}
+
+/// Attach a row access policy to the columns of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Detach the row access policy from a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
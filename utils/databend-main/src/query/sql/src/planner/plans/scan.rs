@@ -22,10 +22,12 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::table::ChangeType;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_analyzed;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -85,6 +87,8 @@ pub struct Statistics {
     pub statistics: Option<TableStatistics>,
     // statistics will be ignored in comparison and hashing
     pub col_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
+    // histograms collected by `ANALYZE TABLE`, will be ignored in comparison and hashing
+    pub histograms: HashMap<IndexType, ColumnHistogram>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -97,6 +101,8 @@ pub struct Scan {
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
     pub change_type: Option<ChangeType>,
+    /// Set by `RuleAggregateByStatistics`, see `PushDownInfo::agg_by_statistics`.
+    pub agg_by_statistics: bool,
 
     pub statistics: Statistics,
 }
@@ -110,6 +116,13 @@ impl Scan {
             .filter(|(col, _)| columns.contains(*col))
             .map(|(col, stat)| (*col, stat.clone()))
             .collect();
+        let histograms = self
+            .statistics
+            .histograms
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, histogram)| (*col, histogram.clone()))
+            .collect();
 
         Scan {
             table_index: self.table_index,
@@ -120,10 +133,12 @@ impl Scan {
             statistics: Statistics {
                 statistics: self.statistics.statistics,
                 col_stats,
+                histograms,
             },
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
+            agg_by_statistics: self.agg_by_statistics,
         }
     }
 
@@ -208,19 +223,30 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                let analyzed =
+                    self.statistics.histograms.get(k).and_then(|analyzed| {
+                        histogram_from_analyzed(analyzed, min.clone(), num_rows)
+                    });
+                let (histogram, most_common_values) = match analyzed {
+                    Some((histogram, most_common_values)) => (Some(histogram), most_common_values),
+                    None => {
+                        let histogram = histogram_from_ndv(
+                            ndv,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok();
+                        (histogram, vec![])
+                    }
+                };
                 let column_stat = ColumnStat {
                     min,
                     max,
                     ndv: ndv as f64,
                     null_count: col_stat.null_count,
                     histogram,
+                    most_common_values,
                 };
                 column_stats.insert(*k as IndexType, column_stat);
             }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::vec;
@@ -43,6 +44,9 @@ use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::InternalColumn;
+use databend_common_catalog::plan::InternalColumnType;
+use databend_common_catalog::plan::InvertedIndexInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
@@ -70,11 +74,16 @@ use databend_common_expression::FunctionKind;
 use databend_common_expression::RawExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::SEARCH_MATCHED_COL_NAME;
+use databend_common_expression::SEARCH_SCORE_COL_NAME;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::is_builtin_function;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
@@ -93,6 +102,7 @@ use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::InternalColumnBinding;
 use crate::binder::NameResolutionResult;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
@@ -2425,6 +2435,8 @@ impl<'a> TypeChecker<'a> {
             "greatest",
             "least",
             "stream_has_data",
+            "match",
+            "score",
         ]
     }
 
@@ -2782,10 +2794,192 @@ impl<'a> TypeChecker<'a> {
                     .ok()?;
                 Some(self.resolve_scalar_function_call(span, "array_min", vec![], vec![array]))
             }
+            ("match", &[column, query]) => {
+                Some(self.resolve_match_search(span, column, query).await)
+            }
+            ("score", &[]) => Some(self.resolve_score_search(span)),
             _ => None,
         }
     }
 
+    // Resolve `match(column, 'query')` function, the query text is searched with
+    // the inverted index of the column, and the function is rewritten to the internal
+    // column `_search_matched`, which is generated from the matched rows while reading.
+    #[async_backtrace::framed]
+    async fn resolve_match_search(
+        &mut self,
+        span: Span,
+        column: &Expr,
+        query: &Expr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let box (column_expr, _) = self.resolve(column).await?;
+        let column = match column_expr {
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. })
+                if column.table_index.is_some() =>
+            {
+                column
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "the first argument of match function must be a table column".to_string(),
+                )
+                .set_span(span));
+            }
+        };
+        let table_index = column.table_index.unwrap();
+
+        let box (query_expr, _) = self.resolve(query).await?;
+        let query_text = match ConstantExpr::try_from(query_expr) {
+            Ok(ConstantExpr {
+                value: Scalar::String(query_text),
+                ..
+            }) => query_text,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "the second argument of match function must be a constant string".to_string(),
+                )
+                .set_span(span));
+            }
+        };
+
+        let (database_name, table_name, inverted_index_info) = {
+            let metadata = self.metadata.read();
+            let table_entry = metadata.table(table_index);
+            let table = table_entry.table();
+            let table_schema = table.schema();
+            let column_id = table_schema
+                .field_with_name(&column.column_name)?
+                .column_id();
+
+            let table_meta = &table.get_table_info().meta;
+            let Some(index) = table_meta
+                .indexes
+                .values()
+                .find(|index| index.column_ids.contains(&column_id))
+            else {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {} has no inverted index",
+                    column.column_name
+                ))
+                .set_span(span));
+            };
+
+            let index_fields = table_schema
+                .fields()
+                .iter()
+                .filter(|field| index.column_ids.contains(&field.column_id()))
+                .cloned()
+                .collect::<Vec<_>>();
+            let index_schema = DataSchema::from(Arc::new(TableSchema::new(index_fields)));
+
+            let inverted_index_info = InvertedIndexInfo {
+                index_name: index.name.clone(),
+                index_version: index.version.clone(),
+                index_options: index.options.clone(),
+                index_schema,
+                query_fields: vec![column.column_name.clone()],
+                query_text,
+                has_score: false,
+            };
+            let table_name = table_entry
+                .alias_name()
+                .clone()
+                .unwrap_or_else(|| table_entry.name().to_string());
+            (
+                table_entry.database().to_string(),
+                table_name,
+                inverted_index_info,
+            )
+        };
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+        {
+            let mut metadata = self.metadata.write();
+            if let Some(info) = metadata.get_inverted_index(table_index) {
+                if info != &inverted_index_info {
+                    return Err(ErrorCode::SemanticError(
+                        "only one match function is supported for each table".to_string(),
+                    )
+                    .set_span(span));
+                }
+            }
+            metadata.add_inverted_index(table_index, inverted_index_info);
+        }
+
+        let internal_column_binding = InternalColumnBinding {
+            database_name: Some(database_name),
+            table_name: Some(table_name),
+            internal_column: InternalColumn::new(
+                SEARCH_MATCHED_COL_NAME,
+                InternalColumnType::SearchMatched,
+            ),
+        };
+        let column = self.bind_context.add_internal_column_binding(
+            &internal_column_binding,
+            self.metadata.clone(),
+            false,
+        )?;
+        let data_type = *column.data_type.clone();
+        Ok(Box::new((
+            BoundColumnRef { span, column }.into(),
+            data_type,
+        )))
+    }
+
+    // Resolve `score()` function, returns the BM25 score of the rows matched by the
+    // `match` function, it is rewritten to the internal column `_search_score`.
+    fn resolve_score_search(&mut self, span: Span) -> Result<Box<(ScalarExpr, DataType)>> {
+        let (database_name, table_name) = {
+            let metadata = self.metadata.read();
+            let table_indexes = self
+                .bind_context
+                .columns
+                .iter()
+                .filter_map(|column| column.table_index)
+                .filter(|table_index| {
+                    let table = metadata.table(*table_index).table();
+                    !table.get_table_info().meta.indexes.is_empty()
+                })
+                .collect::<HashSet<_>>();
+            if table_indexes.len() != 1 {
+                return Err(ErrorCode::SemanticError(
+                    "score function requires exactly one table with inverted index".to_string(),
+                )
+                .set_span(span));
+            }
+            let table_index = table_indexes.into_iter().next().unwrap();
+            let table_entry = metadata.table(table_index);
+            let table_name = table_entry
+                .alias_name()
+                .clone()
+                .unwrap_or_else(|| table_entry.name().to_string());
+            (table_entry.database().to_string(), table_name)
+        };
+
+        let internal_column_binding = InternalColumnBinding {
+            database_name: Some(database_name),
+            table_name: Some(table_name),
+            internal_column: InternalColumn::new(
+                SEARCH_SCORE_COL_NAME,
+                InternalColumnType::SearchScore,
+            ),
+        };
+        let column = self.bind_context.add_internal_column_binding(
+            &internal_column_binding,
+            self.metadata.clone(),
+            false,
+        )?;
+        let data_type = *column.data_type.clone();
+        Ok(Box::new((
+            BoundColumnRef { span, column }.into(),
+            data_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_trim_function(
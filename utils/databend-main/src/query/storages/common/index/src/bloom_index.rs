@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -31,6 +35,7 @@ use databend_common_expression::types::MapType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::BlockEntry;
@@ -58,6 +63,9 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
+/// The length in bytes of the substrings indexed by the n-gram filter.
+pub const NGRAM_SIZE: usize = 3;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -110,6 +118,10 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 /// are not applicable for a filter, we skip the creation.
 /// That is to say, it is legal to have a BlockFilter with zero columns.
 ///
+/// String columns can optionally have an n-gram filter, which is built from all the
+/// substrings of length [`NGRAM_SIZE`] of the column values, and is used to prune
+/// substring predicates like `LIKE '%foo%'`, `position` and `regexp`.
+///
 /// For example, for the source data block as follows:
 /// ```
 ///         +---name--+--age--+
@@ -178,6 +190,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -267,6 +280,36 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (index, field) in ngram_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !Self::supported_ngram_data_type(field_type) {
+                continue;
+            }
+
+            let mut digests = HashSet::new();
+            for block in data_blocks_tobe_indexed {
+                let value = &block.get_by_offset(index).value;
+                let column = value.convert_to_full_column(field_type, block.num_rows());
+                if let Column::String(column) = column.remove_nullable() {
+                    for s in column.iter() {
+                        Self::calculate_ngram_digests(s.as_bytes(), &mut digests);
+                    }
+                }
+            }
+            // All the values are shorter than the n-gram, no substring can be pruned.
+            if digests.is_empty() {
+                continue;
+            }
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.iter());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_filter_column_name(version, &field)?;
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -327,6 +370,16 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_substring(&mut expr, &mut |col_name, substrings| {
+            let field = data_schema.field_with_name(col_name)?;
+            // The n-gram filter is not available in the old index versions.
+            let Ok(filter_column) = Self::build_ngram_filter_column_name(self.version, field)
+            else {
+                return Ok(false);
+            };
+            Ok(self.find_substrings(&filter_column, substrings)? == FilterEvalResult::MustFalse)
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -423,6 +476,44 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the substring patterns in the expression,
+    /// like `col LIKE '%foo%'`, `position('foo' IN col)` and `col REGEXP 'foo.*bar'`.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<TableField>> {
+        let mut cols: Vec<TableField> = Vec::new();
+        visit_expr_column_substring(&mut expr.clone(), &mut |col_name, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                if !cols.iter().any(|c| c.name() == col_name) {
+                    cols.push(v.clone());
+                }
+            }
+            Ok(false)
+        })?;
+        Ok(cols)
+    }
+
+    /// Calculate the digests of all the n-grams of the value.
+    pub fn calculate_ngram_digests(value: &[u8], digests: &mut HashSet<u64>) {
+        for gram in value.windows(NGRAM_SIZE) {
+            let mut hasher = DefaultHasher::default();
+            gram.hash(&mut hasher);
+            digests.insert(hasher.finish());
+        }
+    }
+
+    /// The n-gram filter will be stored with field name 'Ngram(column_id)'.
+    pub fn build_ngram_filter_column_name(version: u64, field: &TableField) -> Result<String> {
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V4(_) => Ok(format!("Ngram({})", field.column_id())),
+            _ => Err(ErrorCode::DeprecatedIndexFormat(
+                "ngram filter requires bloom filter index version(v4)",
+            )),
+        }
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -472,6 +563,44 @@ impl BloomIndex {
         }
     }
 
+    // Returns `MustFalse` if any n-gram of the substrings doesn't show up in the filter,
+    // which means that no value of the column contains the substrings.
+    fn find_substrings(
+        &self,
+        filter_column: &str,
+        substrings: &[String],
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        let mut digests = HashSet::new();
+        for substring in substrings {
+            Self::calculate_ngram_digests(substring.as_bytes(), &mut digests);
+        }
+        if digests
+            .iter()
+            .any(|digest| !filter.contains_digest(*digest))
+        {
+            Ok(FilterEvalResult::MustFalse)
+        } else {
+            Ok(FilterEvalResult::Uncertain)
+        }
+    }
+
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        Self::supported_ngram_data_type(&data_type)
+    }
+
+    pub fn supported_ngram_data_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::String
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
@@ -612,3 +741,188 @@ fn visit_map_column(
     }
     Ok(None)
 }
+
+// Find patterns like `Column LIKE <constant>`, `Column REGEXP <constant>`,
+// `position(<constant> IN Column)`, `locate(<constant>, Column)` and `instr(Column, <constant>)`,
+// and collect the literal substrings that every matched value must contain.
+// If the visitor returns true, which means that no value contains the substrings,
+// the pattern is rewritten to the result of no matched value.
+fn visit_expr_column_substring(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[String]) -> Result<bool>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        let matched = match (name, args.as_slice()) {
+            (
+                "like" | "regexp",
+                [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(pattern),
+                        ..
+                    },
+                ],
+            ) => {
+                let substrings = if name == "like" {
+                    like_pattern_substrings(pattern)
+                } else {
+                    regexp_pattern_substrings(pattern)
+                };
+                Some((id.clone(), substrings, Scalar::Boolean(false)))
+            }
+            (
+                "position" | "locate",
+                [
+                    Expr::Constant {
+                        scalar: Scalar::String(substring),
+                        ..
+                    },
+                    Expr::ColumnRef { id, .. },
+                ],
+            )
+            | (
+                "instr",
+                [
+                    Expr::ColumnRef { id, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(substring),
+                        ..
+                    },
+                ],
+            ) => Some((
+                id.clone(),
+                vec![substring.clone()],
+                Scalar::Number(NumberScalar::UInt64(0)),
+            )),
+            _ => None,
+        };
+
+        if let Some((col_name, substrings, scalar)) = matched {
+            let substrings = substrings
+                .into_iter()
+                .filter(|s| s.len() >= NGRAM_SIZE)
+                .collect::<Vec<_>>();
+            if !substrings.is_empty() && visitor(&col_name, &substrings)? {
+                *expr = Expr::Constant {
+                    span: *span,
+                    scalar,
+                    data_type: return_type.clone(),
+                };
+            }
+            return Ok(());
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_substring(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_substring(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+// The literal parts of a LIKE pattern, split by the wildcards `%` and `_`.
+fn like_pattern_substrings(pattern: &str) -> Vec<String> {
+    let mut substrings = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    current.push(c);
+                }
+            }
+            '%' | '_' => {
+                substrings.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+    substrings.push(current);
+    substrings
+}
+
+// The literal parts of a regular expression that every matched value must contain.
+// Only simple patterns are handled, the patterns with alternations or flags have no literals.
+fn regexp_pattern_substrings(pattern: &str) -> Vec<String> {
+    if pattern.contains('|') || pattern.contains("(?") {
+        return vec![];
+    }
+
+    let mut substrings = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // Escaped special characters are literals.
+                Some(c) if !c.is_ascii_alphanumeric() => current.push(c),
+                // Character classes and assertions, like `\d` and `\b`.
+                _ => substrings.push(std::mem::take(&mut current)),
+            },
+            // The previous character is optional.
+            '*' | '?' | '{' => {
+                current.pop();
+                substrings.push(std::mem::take(&mut current));
+                if c == '{' {
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            break;
+                        }
+                    }
+                }
+            }
+            '[' => {
+                substrings.push(std::mem::take(&mut current));
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            // The groups might be optional, skip them.
+            '(' => {
+                substrings.push(std::mem::take(&mut current));
+                let mut depth = 1;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            '.' | '^' | '$' | '+' | ')' => {
+                substrings.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+    substrings.push(current);
+    substrings
+}
@@ -118,6 +118,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -251,6 +252,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -291,6 +293,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -309,6 +312,105 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["databend cloud", "a*b"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_columns = BTreeMap::from([(1, schema.field(1).clone())]);
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        ngram_columns,
+    )?
+    .unwrap();
+
+    let cases = [
+        ("like", "%bend%", FilterEvalResult::Uncertain),
+        ("like", "data%cloud", FilterEvalResult::Uncertain),
+        ("like", "%bend\\_c%", FilterEvalResult::MustFalse),
+        ("like", "%query%", FilterEvalResult::MustFalse),
+        // Too short to be checked by the n-gram filter.
+        ("like", "%xy%", FilterEvalResult::Uncertain),
+        ("regexp", "^data.*cloud$", FilterEvalResult::Uncertain),
+        ("regexp", "a\\*b", FilterEvalResult::Uncertain),
+        ("regexp", "query|cloud", FilterEvalResult::Uncertain),
+        ("regexp", "[dD]atabase", FilterEvalResult::MustFalse),
+        ("regexp", "cloud(query)?", FilterEvalResult::Uncertain),
+    ];
+    for (func, pattern, expected) in cases {
+        let expr = substring_expr(func, "1", pattern, false);
+        assert_eq!(
+            expected,
+            index.apply(expr, &HashMap::new(), schema.clone())?
+        );
+    }
+
+    let expr = substring_expr("position", "1", "cloud", true);
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        index.apply(expr, &HashMap::new(), schema.clone())?
+    );
+    let expr = substring_expr("position", "1", "warehouse", true);
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        index.apply(expr, &HashMap::new(), schema.clone())?
+    );
+
+    let fields = schema.fields.clone();
+    let expr = substring_expr("like", "1", "%bend%", false);
+    let cols = BloomIndex::find_ngram_columns(&expr, fields.clone())?;
+    assert_eq!(1, cols.len());
+    let expr = substring_expr("like", "1", "bend", false);
+    let cols = BloomIndex::find_ngram_columns(&expr, fields)?;
+    assert_eq!(1, cols.len());
+
+    Ok(())
+}
+
+// Build `<func>(col, pattern)`, or `<func>(pattern, col) > 0` if the substring goes first,
+// like `position`.
+fn substring_expr(func: &str, col_name: &str, pattern: &str, substr_first: bool) -> Expr<String> {
+    let col = Expr::ColumnRef {
+        span: None,
+        id: col_name.to_string(),
+        data_type: DataType::String,
+        display_name: col_name.to_string(),
+    };
+    let pattern = Expr::Constant {
+        span: None,
+        scalar: Scalar::String(pattern.to_string()),
+        data_type: DataType::String,
+    };
+    if substr_first {
+        let expr = check_function(None, func, &[], &[pattern, col], &BUILTIN_FUNCTIONS).unwrap();
+        check_function(
+            None,
+            "gt",
+            &[],
+            &[expr, Expr::Constant {
+                span: None,
+                scalar: Scalar::Number(NumberScalar::UInt64(0)),
+                data_type: DataType::Number(NumberDataType::UInt64),
+            }],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    } else {
+        check_function(None, func, &[], &[col, pattern], &BUILTIN_FUNCTIONS).unwrap()
+    }
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -20,6 +20,8 @@ use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchemaRef;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::BlockMetaIndex;
 
@@ -46,34 +48,69 @@ impl TopNPrunner {
 }
 
 impl TopNPrunner {
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
     pub fn prune(
         &self,
         metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        self.prune_by_stats(
+            metas,
+            |(_, meta)| &meta.col_stats,
+            |(_, meta)| Ok(meta.deletion_vector.is_some()),
+        )
+    }
+
+    /// Pruning the segments by their summary before reading the block metas.
+    /// Every segment has at least one row, so the top N rows are in the first N segments
+    /// ordered by the min/max of the sort column.
+    pub fn prune_segments<T>(
+        &self,
+        segments: Vec<(T, Arc<CompactSegmentInfo>)>,
+    ) -> Result<Vec<(T, Arc<CompactSegmentInfo>)>> {
+        self.prune_by_stats(
+            segments,
+            |(_, segment)| &segment.summary.col_stats,
+            |(_, segment)| {
+                Ok(segment
+                    .block_metas()?
+                    .iter()
+                    .any(|meta| meta.deletion_vector.is_some()))
+            },
+        )
+    }
+
+    // The stats of the items with deleted rows still include the deleted rows, so their min/max
+    // may not be found in any row. They are kept, but not counted in the first N items.
+    fn prune_by_stats<T>(
+        &self,
+        items: Vec<T>,
+        col_stats: impl Fn(&T) -> &StatisticsOfColumns,
+        has_deleted_rows: impl Fn(&T) -> Result<bool>,
+    ) -> Result<Vec<T>> {
         if self.sort.len() != 1 {
-            return Ok(metas);
+            return Ok(items);
         }
 
-        if self.limit >= metas.len() {
-            return Ok(metas);
+        if self.limit >= items.len() {
+            return Ok(items);
         }
 
         let (sort, asc, nulls_first) = &self.sort[0];
-        // Currently, we only support topn on single-column sort.
-        // TODO: support monadic + multi expression + order by cluster key sort.
-
         // Currently, we only support topn on single-column sort.
         // TODO: support monadic + multi expression + order by cluster key sort.
         let column = if let RemoteExpr::ColumnRef { id, .. } = sort {
             id
         } else {
-            return Ok(metas);
+            return Ok(items);
         };
 
         let sort_column_id = if let Ok(index) = self.schema.column_id_of(column.as_str()) {
             index
         } else {
-            return Ok(metas);
+            return Ok(items);
         };
 
         // String Type min/max is truncated
@@ -81,37 +118,48 @@ impl TopNPrunner {
             self.schema.field_with_name(column)?.data_type(),
             TableDataType::String
         ) {
-            return Ok(metas);
+            return Ok(items);
         }
 
-        let mut id_stats = metas
-            .iter()
-            .map(|(id, meta)| {
-                let stat = meta.col_stats.get(&sort_column_id).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        sort_column_id
-                    ))
-                })?;
-                Ok((id.clone(), stat.clone(), meta.clone()))
+        let mut item_stats = items
+            .into_iter()
+            .map(|item| {
+                let stat = col_stats(&item)
+                    .get(&sort_column_id)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownException(format!(
+                            "Unable to get the colStats by ColumnId: {}",
+                            sort_column_id
+                        ))
+                    })?;
+                Ok((stat, item))
             })
             .collect::<Result<Vec<_>>>()?;
 
-        id_stats.sort_by(|a, b| {
-            if a.1.null_count + b.1.null_count != 0 && *nulls_first {
-                return a.1.null_count.cmp(&b.1.null_count).reverse();
+        item_stats.sort_by(|a, b| {
+            if a.0.null_count + b.0.null_count != 0 && *nulls_first {
+                return a.0.null_count.cmp(&b.0.null_count).reverse();
             }
             // no nulls
             if *asc {
-                a.1.min().cmp(b.1.min())
+                a.0.min().cmp(b.0.min())
             } else {
-                a.1.max().cmp(b.1.max()).reverse()
+                a.0.max().cmp(b.0.max()).reverse()
             }
         });
-        Ok(id_stats
-            .iter()
-            .map(|s| (s.0.clone(), s.2.clone()))
-            .take(self.limit)
-            .collect())
+
+        let mut items = Vec::with_capacity(self.limit);
+        let mut counted = 0;
+        for (_, item) in item_stats {
+            if counted >= self.limit {
+                break;
+            }
+            if !has_deleted_rows(&item)? {
+                counted += 1;
+            }
+            items.push(item);
+        }
+        Ok(items)
     }
 }
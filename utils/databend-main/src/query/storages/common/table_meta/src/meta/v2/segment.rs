@@ -81,6 +81,25 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// the rows deleted from the block, they are filtered out while reading the block
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+}
+
+/// Meta information of the deletion vector of a block
+///
+/// The deletion vector is a bitmap of the offsets of the deleted rows, which is
+/// persisted in its own file, so that deleting a few rows does not need to rewrite
+/// the whole block.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// location of the deletion vector
+    pub location: Location,
+    /// size of the deletion vector file
+    pub size: u64,
+    /// number of the deleted rows
+    pub deleted_rows: u64,
 }
 
 impl BlockMeta {
@@ -110,9 +129,15 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
         }
     }
 
+    /// The number of rows that are not deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deletion_vector.as_ref().map_or(0, |v| v.deleted_rows)
+    }
+
     pub fn compression(&self) -> Compression {
         self.compression
     }
@@ -256,6 +281,7 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
         }
     }
 
@@ -288,6 +314,7 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
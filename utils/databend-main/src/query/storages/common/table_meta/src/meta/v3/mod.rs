@@ -17,6 +17,11 @@
 mod frozen;
 mod segment;
 mod snapshot;
+mod table_snapshot_statistics;
 
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
+pub use table_snapshot_statistics::ColumnHistogram;
+pub use table_snapshot_statistics::HistogramBucket;
+pub use table_snapshot_statistics::MostCommonValue;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
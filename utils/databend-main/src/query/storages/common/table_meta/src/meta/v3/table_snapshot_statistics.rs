@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::v1;
+use crate::meta::v2;
+use crate::meta::FormatVersion;
+use crate::meta::MetaHLL;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+    pub hll: HashMap<ColumnId, MetaHLL>,
+    /// histograms of columns, built by `ANALYZE TABLE` from the whole table or a sample of it
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// Distribution of the non-NULL values of a column.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColumnHistogram {
+    /// Equi-height buckets ordered by their upper bounds. The lower bound of the first bucket
+    /// is the min value of the column.
+    pub buckets: Vec<HistogramBucket>,
+    /// The most common values, ordered by their number of rows descending.
+    pub most_common_values: Vec<MostCommonValue>,
+    /// Number of rows of the table when the histogram was built.
+    pub num_rows: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// Upper bound value of the bucket, inclusive.
+    pub upper_bound: Scalar,
+    /// Number of values in the bucket.
+    pub num_values: u64,
+    /// Number of distinct values in the bucket.
+    pub num_distinct: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MostCommonValue {
+    pub value: Scalar,
+    /// Number of rows having the value.
+    pub count: u64,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        hll: HashMap<ColumnId, MetaHLL>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+        snapshot_id: SnapshotId,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id,
+            hll,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn column_distinct_values(&self) -> HashMap<ColumnId, u64> {
+        self.hll
+            .iter()
+            .map(|hll| (*hll.0, hll.1.count() as u64))
+            .collect()
+    }
+}
+
+impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v2::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: value.snapshot_id,
+            hll: value.hll,
+            histograms: HashMap::new(),
+        }
+    }
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v1::TableSnapshotStatistics) -> Self {
+        v2::TableSnapshotStatistics::from(value).into()
+    }
+}
@@ -83,6 +83,15 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// Number of rows inserted, updated or deleted since the table was last analyzed.
+    //  for backward compatibility, missing values are treated as 0
+    #[serde(default)]
+    pub rows_modified_since_analyze: u64,
+
+    /// Time of the last `ANALYZE TABLE`, none if the table has never been analyzed.
+    #[serde(default)]
+    pub analyzed_on: Option<DateTime<Utc>>,
 }
 
 impl TableSnapshot {
@@ -114,6 +123,8 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            rows_modified_since_analyze: 0,
+            analyzed_on: None,
         }
     }
 
@@ -134,7 +145,7 @@ impl TableSnapshot {
         let id = Uuid::new_v4();
         let clone = previous.clone();
         // the timestamp of the new snapshot will be adjusted by the `new` method
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -143,7 +154,10 @@ impl TableSnapshot {
             clone.segments,
             clone.cluster_key_meta,
             clone.table_statistics_location,
-        )
+        );
+        snapshot.rows_modified_since_analyze = clone.rows_modified_since_analyze;
+        snapshot.analyzed_on = clone.analyzed_on;
+        snapshot
     }
 
     /// Serializes the struct to a byte vector.
@@ -221,6 +235,8 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            rows_modified_since_analyze: 0,
+            analyzed_on: None,
         }
     }
 }
@@ -242,6 +258,8 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            rows_modified_since_analyze: 0,
+            analyzed_on: None,
         }
     }
 }
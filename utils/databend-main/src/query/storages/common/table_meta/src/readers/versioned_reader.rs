@@ -40,7 +40,11 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
                 let ts = load_json(&buffer, v).await?;
                 TableSnapshotStatistics::from(ts)
             }
-            TableSnapshotStatisticsVersion::V2(v) => load_json(&buffer, v).await?,
+            TableSnapshotStatisticsVersion::V2(v) => {
+                let ts = load_json(&buffer, v).await?;
+                TableSnapshotStatistics::from(ts)
+            }
+            TableSnapshotStatisticsVersion::V3(v) => load_json(&buffer, v).await?,
         };
         Ok(r)
     }
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+
+const ICEBERG_MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// Returns the iceberg primitive type of the (non-nullable) `ty`, in the form it is
+/// written in the schema of iceberg metadata.
+pub fn iceberg_type(ty: &TableDataType) -> Result<String> {
+    let ty = match ty {
+        TableDataType::Boolean => "boolean".to_string(),
+        TableDataType::Number(num) => match num {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => "int".to_string(),
+            NumberDataType::Int64 | NumberDataType::UInt32 => "long".to_string(),
+            NumberDataType::Float32 => "float".to_string(),
+            NumberDataType::Float64 => "double".to_string(),
+            NumberDataType::UInt64 => {
+                return Err(ErrorCode::Unimplemented(
+                    "Data type UInt64 is not supported by iceberg",
+                ));
+            }
+        },
+        TableDataType::Decimal(decimal) => {
+            let size = decimal.size();
+            if size.precision > ICEBERG_MAX_DECIMAL_PRECISION {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Data type {} is not supported by iceberg, the max precision of decimal is {}",
+                    ty, ICEBERG_MAX_DECIMAL_PRECISION
+                )));
+            }
+            format!("decimal({},{})", size.precision, size.scale)
+        }
+        TableDataType::String => "string".to_string(),
+        TableDataType::Binary => "binary".to_string(),
+        TableDataType::Date => "date".to_string(),
+        TableDataType::Timestamp => "timestamp".to_string(),
+        ty => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Data type {} is not supported by iceberg",
+                ty
+            )));
+        }
+    };
+    Ok(ty)
+}
@@ -22,7 +22,16 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+pub const OPT_KEY_ENABLE_DELETION_VECTORS: &str = "enable_deletion_vectors";
+// Write iceberg metadata of the table on commit, for the iceberg readers.
+pub const OPT_KEY_ENABLE_ICEBERG_METADATA: &str = "enable_iceberg_metadata";
+// Time travel retention of the table, overrides the setting of the same name.
+pub const OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+// The number of the latest snapshots that are never purged.
+pub const OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP: &str =
+    "data_retention_num_snapshots_to_keep";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -19,6 +19,7 @@
 mod dal;
 mod partition;
 mod partition_columns;
+mod sink;
 mod table;
 mod table_source;
 
@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
+use deltalake::kernel::Add;
+use deltalake::writer::DeltaWriter;
+use deltalake::writer::RecordBatchWriter;
+use log::info;
+
+use crate::DeltaTable;
+
+// Buffered data is flushed into parquet files once it exceeds this size.
+const MAX_BUFFER_SIZE: usize = 128 * 1024 * 1024;
+
+/// Add actions of the files written by one [`DeltaDataFileWriter`], not visible until committed.
+#[derive(Debug)]
+pub struct DeltaAddActions {
+    /// The version of the table the files are written against.
+    pub read_version: i64,
+    pub adds: Vec<Add>,
+}
+
+impl DeltaAddActions {
+    pub fn create_block(read_version: i64, adds: Vec<Add>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(DeltaAddActions { read_version, adds }))
+    }
+}
+
+impl Clone for DeltaAddActions {
+    fn clone(&self) -> Self {
+        unreachable!("DeltaAddActions should not be cloned")
+    }
+}
+
+impl serde::Serialize for DeltaAddActions {
+    fn serialize<S>(&self, _: S) -> std::result::Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unreachable!("DeltaAddActions should not be serialized")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DeltaAddActions {
+    fn deserialize<D>(_: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unreachable!("DeltaAddActions should not be deserialized")
+    }
+}
+
+#[typetag::serde(name = "delta_add_actions")]
+impl BlockMetaInfo for DeltaAddActions {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("DeltaAddActions should not be compared")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("DeltaAddActions should not be cloned")
+    }
+}
+
+/// Writes the incoming blocks as parquet files of the table.
+///
+/// Rows are split by the partition columns of the table, which are kept in
+/// the file path instead of the file, the add actions are emitted on finish.
+pub struct DeltaDataFileWriter {
+    table: Arc<DeltaTable>,
+    schema: TableSchemaRef,
+    arrow_schema: Option<ArrowSchemaRef>,
+    read_version: i64,
+    writer: Option<RecordBatchWriter>,
+    adds: Vec<Add>,
+}
+
+impl DeltaDataFileWriter {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        table: Arc<DeltaTable>,
+        schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+            input,
+            output,
+            DeltaDataFileWriter {
+                table,
+                schema,
+                arrow_schema: None,
+                read_version: -1,
+                writer: None,
+                adds: vec![],
+            },
+        )))
+    }
+
+    // Convert the block to the arrow types of the delta schema, e.g.
+    // strings are written as `Utf8` instead of `LargeUtf8`.
+    fn to_record_batch(
+        &self,
+        block: DataBlock,
+        arrow_schema: &ArrowSchemaRef,
+    ) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.schema)?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(arrow_schema.fields())
+            .map(|(column, field)| arrow_cast::cast(column, field.data_type()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(RecordBatch::try_new(arrow_schema.clone(), columns)?)
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            let adds = writer.flush().await.map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot write delta data files: {e:?}"))
+            })?;
+            self.adds.extend(adds);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for DeltaDataFileWriter {
+    const NAME: &'static str = "DeltaDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        if self.writer.is_none() {
+            let table = self.table.table().await?;
+            self.arrow_schema = Some(Arc::new(DeltaTable::get_arrow_schema(table)?));
+            self.read_version = table.version();
+            self.writer = Some(RecordBatchWriter::for_table(table).map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot create delta writer: {e:?}"))
+            })?);
+        }
+
+        let arrow_schema = self.arrow_schema.clone().unwrap();
+        let batch = self.to_record_batch(data, &arrow_schema)?;
+        let writer = self.writer.as_mut().unwrap();
+        writer
+            .write(batch)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot write delta data: {e:?}")))?;
+
+        if writer.buffer_len() >= MAX_BUFFER_SIZE {
+            self.flush().await?;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.flush().await?;
+        if self.adds.is_empty() {
+            return Ok(None);
+        }
+        let adds = std::mem::take(&mut self.adds);
+        Ok(Some(DeltaAddActions::create_block(self.read_version, adds)))
+    }
+}
+
+/// Commits the files of all the writers as one new version of the table.
+pub struct DeltaCommitSink {
+    table: Arc<DeltaTable>,
+    read_version: Option<i64>,
+    adds: Vec<Add>,
+}
+
+impl DeltaCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table: Arc<DeltaTable>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            ctx,
+            DeltaCommitSink {
+                table,
+                read_version: None,
+                adds: vec![],
+            },
+        )))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for DeltaCommitSink {
+    const NAME: &'static str = "DeltaCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let adds = std::mem::take(&mut self.adds);
+        let Some(read_version) = self.read_version else {
+            return Ok(());
+        };
+        info!(
+            "commit {} data files to delta table {}",
+            adds.len(),
+            self.table.get_table_info().desc
+        );
+        self.table.commit_add_actions(read_version, adds).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block.take_meta() {
+            if let Some(actions) = DeltaAddActions::downcast_from(meta) {
+                // Conflicts are checked since the earliest version read by the writers.
+                let read_version = self
+                    .read_version
+                    .map_or(actions.read_version, |v| v.min(actions.read_version));
+                self.read_version = Some(read_version);
+                self.adds.extend(actions.adds);
+            }
+        }
+        Ok(false)
+    }
+}
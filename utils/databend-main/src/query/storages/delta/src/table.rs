@@ -14,9 +14,11 @@
 
 use std::any::Any;
 use std::sync::Arc;
+use std::time::Duration;
 
 use arrow_schema::Schema as ArrowSchema;
 use async_trait::async_trait;
+use chrono::Utc;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::ParquetReadOptions;
@@ -25,6 +27,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -35,38 +39,61 @@ use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::UpsertKV;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
 use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use deltalake::kernel::Action;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
+use log::warn;
 use opendal::Metakey;
+use opendal::Operator;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
 use tokio::sync::OnceCell;
 use url::Url;
+use uuid::Uuid;
 
 // use object_store_opendal::OpendalStore;
 use crate::dal::OpendalStore;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
 use crate::partition_columns::get_pushdown_without_partition_columns;
+use crate::sink::DeltaCommitSink;
+use crate::sink::DeltaDataFileWriter;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
 
+const MAX_COMMIT_RETRIES: usize = 10;
+
+// The versions of the delta log claimed by the writers, see `commit_add_actions`.
+const DELTA_COMMIT_CLAIM_PREFIX: &str = "__fd_delta_commit";
+const DELTA_COMMIT_CLAIM_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
 pub struct DeltaTable {
     info: TableInfo,
     table: OnceCell<deltalake::table::DeltaTable>,
     meta: DeltaTableMeta,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
 }
@@ -102,6 +129,15 @@ impl DeltaTable {
         }))
     }
 
+    // The delta table is loaded again by the writers, to see the latest version.
+    fn clone_without_table(&self) -> Self {
+        Self {
+            info: self.info.clone(),
+            table: OnceCell::new(),
+            meta: self.meta.clone(),
+        }
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: DELTA_ENGINE.to_string(),
@@ -128,16 +164,20 @@ impl DeltaTable {
             .collect()
     }
 
-    #[async_backtrace::framed]
-    pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+    /// Build arrow schema from delta metadata.
+    pub fn get_arrow_schema(table: &deltalake::table::DeltaTable) -> Result<ArrowSchema> {
         let delta_meta = table.get_schema().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
 
-        // Build arrow schema from delta metadata.
-        let arrow_schema: ArrowSchema = delta_meta.try_into().map_err(|e| {
+        delta_meta.try_into().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-        })?;
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
 
         let state = table.metadata().map_err(|_| {
             ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
@@ -153,18 +193,22 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    // Build the table without loading any version of it.
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op).with_metakey(Metakey::Version));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
@@ -172,7 +216,7 @@ impl DeltaTable {
     }
 
     #[async_backtrace::framed]
-    async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
+    pub(crate) async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
@@ -181,6 +225,172 @@ impl DeltaTable {
             .await
     }
 
+    /// Load the table as of the version or the timestamp, by replaying the
+    /// delta log up to it.
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<DeltaTable>> {
+        let mut table = Self::build(self.get_storage_params()?)?;
+        let res = match point {
+            NavigationPoint::Version(version) => table.load_version(*version as i64).await,
+            NavigationPoint::TimePoint(time_point) => table.load_with_datetime(*time_point).await,
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "Delta table can only be navigated by VERSION or TIMESTAMP",
+                ));
+            }
+        };
+        res.map_err(|e| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point {:?}: {e:?}",
+                point
+            ))
+        })?;
+
+        // The schema may have been changed since then.
+        let (schema, meta_string) = Self::get_meta(&table).await?;
+        let meta: DeltaTableMeta = serde_json::from_str(&meta_string).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "fail to deserialize DeltaTableMeta({meta_string}): {e:?}"
+            ))
+        })?;
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        info.meta
+            .engine_options
+            .insert(OPT_KEY_ENGINE_META.to_string(), meta_string);
+
+        Ok(Arc::new(DeltaTable {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
+    /// Commits the add actions as a new version of the table.
+    ///
+    /// Appends do not conflict with each other, so if the next version has been
+    /// committed by another writer since `read_version`, the commit moves on to
+    /// the version after it, unless that writer changed the metadata or the
+    /// protocol of the table.
+    ///
+    /// The object stores have no put-if-absent, so a version is claimed in the
+    /// meta service before its log entry is written, and only the writer that
+    /// claimed it writes it. The entry is written to a temporary file ahead of
+    /// the claim, so that the other writers can finish the commit of a claimed
+    /// version whose writer is gone.
+    #[async_backtrace::framed]
+    pub async fn commit_add_actions(&self, read_version: i64, adds: Vec<Add>) -> Result<()> {
+        let sp = self.get_storage_params()?;
+        let op = init_operator(sp)?;
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let entry = Self::commit_entry(read_version, adds)?;
+
+        let tmp_path = format!("_delta_log/_commit_{}.json.tmp", Uuid::new_v4());
+        op.write(&tmp_path, entry.clone()).await?;
+
+        let mut version = read_version + 1;
+        let mut retries = 0;
+        loop {
+            let path = format!("_delta_log/{version:020}.json");
+            if op.is_exist(&path).await? {
+                // Committed by a writer outside of databend.
+                Self::check_conflict(&op, &path).await?;
+            } else {
+                let claim = meta
+                    .upsert_kv(UpsertKV {
+                        key: format!("{DELTA_COMMIT_CLAIM_PREFIX}/{sp}/{version:020}"),
+                        seq: MatchSeq::Exact(0),
+                        value: Operation::Update(tmp_path.clone().into_bytes()),
+                        value_meta: Some(MetaSpec::new_ttl(DELTA_COMMIT_CLAIM_TTL)),
+                    })
+                    .await?;
+                match claim.prev {
+                    None => {
+                        // If this fails, the temporary file is kept, and the commit is
+                        // finished by the next writer.
+                        op.write(&path, entry).await?;
+                        op.delete(&tmp_path).await?;
+                        return Ok(());
+                    }
+                    // Claimed by another databend writer, which only appends.
+                    Some(claimed) => {
+                        let claimed_tmp_path = String::from_utf8(claimed.data)?;
+                        Self::finish_claimed_commit(&op, &claimed_tmp_path, &path).await?;
+                    }
+                }
+            }
+
+            if retries >= MAX_COMMIT_RETRIES {
+                op.delete(&tmp_path).await?;
+                return Err(ErrorCode::StorageOther(format!(
+                    "Delta commit failed after {} retries",
+                    retries
+                )));
+            }
+            retries += 1;
+            warn!(
+                "version {} of delta table {} already exists, retry {}",
+                version, self.info.desc, retries
+            );
+            version += 1;
+        }
+    }
+
+    // Writes the log entry of a version claimed by another writer, in case the
+    // writer is gone. The temporary file is only removed after the entry is written.
+    async fn finish_claimed_commit(op: &Operator, tmp_path: &str, path: &str) -> Result<()> {
+        match op.read(tmp_path).await {
+            Ok(entry) => {
+                if !op.is_exist(path).await? {
+                    op.write(path, entry).await?;
+                }
+                Ok(())
+            }
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                if op.is_exist(path).await? {
+                    Ok(())
+                } else {
+                    Err(ErrorCode::StorageOther(format!(
+                        "Delta log entry {path} is claimed, but neither it nor {tmp_path} exists"
+                    )))
+                }
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn commit_entry(read_version: i64, adds: Vec<Add>) -> Result<String> {
+        let commit_info = json!({
+            "commitInfo": {
+                "timestamp": Utc::now().timestamp_millis(),
+                "operation": "WRITE",
+                "operationParameters": { "mode": "Append" },
+                "readVersion": read_version,
+                "isBlindAppend": true,
+                "engineInfo": "Databend",
+            }
+        });
+
+        let mut lines = vec![commit_info.to_string()];
+        for add in adds {
+            lines.push(serde_json::to_string(&Action::Add(add))?);
+        }
+        Ok(lines.join("\n"))
+    }
+
+    async fn check_conflict(op: &Operator, path: &str) -> Result<()> {
+        let entry = op.read(path).await?;
+        for line in entry.split(|b| *b == b'\n').filter(|l| !l.is_empty()) {
+            let action: Value = serde_json::from_slice(line)?;
+            if action.get("metaData").is_some() || action.get("protocol").is_some() {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Delta commit conflicts with the metadata change in {path}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -370,6 +580,59 @@ impl Table for DeltaTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_since_to(
+        &self,
+        since_point: &Option<NavigationPoint>,
+        to_point: &Option<NavigationPoint>,
+    ) -> Result<Arc<dyn Table>> {
+        if since_point.is_some() {
+            return Err(ErrorCode::Unimplemented(
+                "CHANGES is not supported for delta table",
+            ));
+        }
+        match to_point {
+            Some(point) => Ok(self.navigate_to(point).await?),
+            None => Ok(Arc::new(self.clone_without_table())),
+        }
+    }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let table = Arc::new(self.clone_without_table());
+        let schema = self.schema();
+        pipeline.add_transform(|input, output| {
+            DeltaDataFileWriter::try_create(input, output, table.clone(), schema.clone())
+        })
+    }
+
+    // Copied files are not recorded for delta tables, like the other
+    // non-fuse tables.
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(
+                "INSERT OVERWRITE is not supported for delta table",
+            ));
+        }
+
+        let table = Arc::new(self.clone_without_table());
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| DeltaCommitSink::try_create(input, ctx.clone(), table.clone()))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -28,6 +28,8 @@ pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
 pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_f";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
+pub const FUSE_TBL_ICEBERG_METADATA_PREFIX: &str = "metadata";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
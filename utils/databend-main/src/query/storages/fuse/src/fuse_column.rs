@@ -18,12 +18,14 @@ use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
 #[derive(Default)]
 pub struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
+    histograms: HashMap<ColumnId, ColumnHistogram>,
 }
 
 impl FuseTableColumnStatisticsProvider {
@@ -47,7 +49,15 @@ impl FuseTableColumnStatisticsProvider {
                 (column_id, stat.get_useful_stat(row_count))
             })
             .collect();
-        Self { column_stats }
+        Self {
+            column_stats,
+            histograms: HashMap::new(),
+        }
+    }
+
+    pub fn with_histograms(mut self, histograms: HashMap<ColumnId, ColumnHistogram>) -> Self {
+        self.histograms = histograms;
+        self
     }
 }
 
@@ -59,4 +69,8 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn num_rows(&self) -> Option<u64> {
         None
     }
+
+    fn histogram(&self, column_id: ColumnId) -> Option<&ColumnHistogram> {
+        self.histograms.get(&column_id)
+    }
 }
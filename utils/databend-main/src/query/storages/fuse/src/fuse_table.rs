@@ -18,6 +18,7 @@ use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::Duration;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::lock::Lock;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -38,6 +39,8 @@ use databend_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
 use databend_common_expression::ROW_VERSION_COL_NAME;
+use databend_common_expression::SEARCH_MATCHED_COLUMN_ID;
+use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
 use databend_common_expression::SNAPSHOT_NAME_COLUMN_ID;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -68,7 +71,11 @@ use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP;
+use databend_storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use databend_storages_common_table_meta::table::OPT_KEY_ENABLE_DELETION_VECTORS;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -82,6 +89,7 @@ use uuid::Uuid;
 
 use crate::fuse_column::FuseTableColumnStatisticsProvider;
 use crate::fuse_type::FuseTableType;
+use crate::io::iceberg_metadata_enabled;
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
@@ -108,6 +116,7 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -215,6 +224,13 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        // The n-gram filters are only built for the columns that explicitly specified.
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -225,6 +241,7 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -246,6 +263,43 @@ impl FuseTable {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
 
+    /// Whether DELETE, UPDATE and MERGE should mark the removed rows in deletion vectors
+    /// instead of rewriting the blocks.
+    ///
+    /// Deletion vectors are only supported by parquet tables, and are not used while change
+    /// tracking is enabled, since streams detect the changes by the block locations. Neither
+    /// are they used while iceberg metadata is written, iceberg readers do not apply them.
+    pub fn deletion_vectors_enabled(&self) -> bool {
+        !self.is_native()
+            && !self.change_tracking_enabled()
+            && !iceberg_metadata_enabled(self.table_info.options())
+            && self.get_option(OPT_KEY_ENABLE_DELETION_VECTORS, false)
+    }
+
+    /// The time travel retention period of the table.
+    ///
+    /// The table option overrides the `data_retention_time_in_days` setting.
+    pub fn get_data_retention_period(&self, ctx: &dyn TableContext) -> Result<Duration> {
+        let days = match self.data_retention_time_in_days() {
+            Some(days) => days,
+            None => ctx.get_settings().get_data_retention_time_in_days()?,
+        };
+        Ok(Duration::days(days as i64))
+    }
+
+    /// The retention specified by the table option, if any.
+    pub fn data_retention_time_in_days(&self) -> Option<u64> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+            .and_then(|days| days.parse::<u64>().ok())
+    }
+
+    /// The number of the latest snapshots kept by purge, even if they are out of retention.
+    pub fn get_data_retention_num_snapshots_to_keep(&self) -> usize {
+        self.get_option(OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP, 0)
+    }
+
     pub fn meta_location_generator(&self) -> &TableMetaLocationGenerator {
         &self.meta_location_generator
     }
@@ -307,17 +361,7 @@ impl FuseTable {
         match snapshot {
             Some(snapshot) => {
                 if let Some(loc) = &snapshot.table_statistics_location {
-                    let reader = MetaReaders::table_snapshot_statistics_reader(self.get_operator());
-
-                    let ver = TableMetaLocationGenerator::table_statistics_version(loc);
-                    let load_params = LoadParams {
-                        location: loc.clone(),
-                        len_hint: None,
-                        ver,
-                        put_cache: true,
-                    };
-
-                    Ok(Some(reader.read(&load_params).await?))
+                    Ok(Some(self.read_table_statistics(loc).await?))
                 } else {
                     Ok(None)
                 }
@@ -326,6 +370,21 @@ impl FuseTable {
         }
     }
 
+    #[async_backtrace::framed]
+    pub async fn read_table_statistics(&self, loc: &str) -> Result<Arc<TableSnapshotStatistics>> {
+        let reader = MetaReaders::table_snapshot_statistics_reader(self.get_operator());
+
+        let ver = TableMetaLocationGenerator::table_statistics_version(loc);
+        let load_params = LoadParams {
+            location: loc.to_string(),
+            len_hint: None,
+            ver,
+            put_cache: true,
+        };
+
+        reader.read(&load_params).await
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn read_table_snapshot(&self) -> Result<Option<Arc<TableSnapshot>>> {
@@ -436,6 +495,10 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -471,6 +534,8 @@ impl Table for FuseTable {
 
     fn supported_internal_column(&self, column_id: ColumnId) -> bool {
         column_id >= SNAPSHOT_NAME_COLUMN_ID
+            || column_id == SEARCH_MATCHED_COLUMN_ID
+            || column_id == SEARCH_SCORE_COLUMN_ID
     }
 
     fn support_column_projection(&self) -> bool {
@@ -780,6 +845,7 @@ impl Table for FuseTable {
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
+                .with_histograms(table_statistics.histograms.clone())
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotVersion;
@@ -24,6 +25,7 @@ use databend_storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -44,6 +46,8 @@ static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
 static SNAPSHOT_STATISTICS_V2: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V2(PhantomData);
+static SNAPSHOT_STATISTICS_V3: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V3(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -99,6 +103,20 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let deletion_vector_uuid = Uuid::new_v4().simple().to_string();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                deletion_vector_uuid,
+                DeletionVectorMeta::VERSION,
+            ),
+            DeletionVectorMeta::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -152,8 +170,13 @@ impl TableMetaLocationGenerator {
             .ends_with(SNAPSHOT_STATISTICS_V0.suffix().as_str())
         {
             SNAPSHOT_STATISTICS_V0.version()
-        } else {
+        } else if table_statistics_location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V2.suffix().as_str())
+        {
             SNAPSHOT_STATISTICS_V2.version()
+        } else {
+            SNAPSHOT_STATISTICS_V3.version()
         }
     }
 
@@ -165,11 +188,20 @@ impl TableMetaLocationGenerator {
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
 
-    pub fn gen_inverted_index_location(&self, id: String) -> String {
-        format!(
-            "{}/{}/{}.index",
-            &self.prefix, FUSE_TBL_INVERTED_INDEX_PREFIX, id
-        )
+    pub fn gen_inverted_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        let id = block_name
+            .rsplit_once('.')
+            .map(|(id, _)| id)
+            .unwrap_or(block_name);
+        format!("{prefix}/{FUSE_TBL_INVERTED_INDEX_PREFIX}/{index_name}-{index_version}/{id}.index")
     }
 }
 
@@ -215,6 +247,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
             TableSnapshotStatisticsVersion::V2(_) => "_ts_v2.json".to_string(),
+            TableSnapshotStatisticsVersion::V3(_) => "_ts_v3.json".to_string(),
         }
     }
 }
@@ -21,6 +21,9 @@ mod write;
 
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::deletion_vector_filter;
+pub use read::read_deletion_vector;
+pub use read::read_deletion_vector_sync;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
@@ -39,8 +42,13 @@ pub use segments::SegmentsIO;
 pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
+pub use write::check_iceberg_schema;
+pub use write::iceberg_metadata_enabled;
+pub use write::segment_manifest_location;
 pub use write::serialize_block;
 pub use write::write_data;
+pub use write::write_deletion_vector;
+pub use write::write_iceberg_metadata;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
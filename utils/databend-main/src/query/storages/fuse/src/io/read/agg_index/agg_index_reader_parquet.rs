@@ -52,6 +52,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -102,6 +103,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 Some((part, res))
             }
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Read the offsets of the deleted rows of a block.
+#[async_backtrace::framed]
+pub async fn read_deletion_vector(
+    dal: &Operator,
+    deletion_vector: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.read(&deletion_vector.location.0).await?;
+    deserialize_deletion_vector(&deletion_vector.location.0, &data)
+}
+
+/// Blocking version of [`read_deletion_vector`].
+pub fn read_deletion_vector_sync(
+    dal: &Operator,
+    deletion_vector: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.blocking().read(&deletion_vector.location.0)?;
+    deserialize_deletion_vector(&deletion_vector.location.0, &data)
+}
+
+/// Build the filter which keeps the rows that are not deleted.
+pub fn deletion_vector_filter(deleted_rows: &RoaringBitmap, num_rows: usize) -> Bitmap {
+    let mut filter = MutableBitmap::from_len_set(num_rows);
+    for offset in deleted_rows.iter() {
+        filter.set(offset as usize, false);
+    }
+    filter.into()
+}
+
+fn deserialize_deletion_vector(location: &str, data: &[u8]) -> Result<RoaringBitmap> {
+    RoaringBitmap::deserialize_from(data).map_err(|e| {
+        ErrorCode::StorageOther(format!(
+            "failed to deserialize deletion vector {}: {}",
+            location, e
+        ))
+    })
+}
@@ -12,70 +12,85 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use databend_common_expression::DataSchema;
+use databend_common_expression::types::F32;
 use opendal::Operator;
+use tantivy::collector::DocSetCollector;
 use tantivy::collector::TopDocs;
 use tantivy::query::QueryParser;
-use tantivy::schema::Field;
-use tantivy::tokenizer::Language;
-use tantivy::tokenizer::LowerCaser;
-use tantivy::tokenizer::RemoveLongFilter;
-use tantivy::tokenizer::SimpleTokenizer;
-use tantivy::tokenizer::Stemmer;
-use tantivy::tokenizer::TextAnalyzer;
-use tantivy::tokenizer::TokenizerManager;
-use tantivy::DocAddress;
 use tantivy::Index;
-use tantivy::Score;
 
 use crate::io::read::inverted_index::cache_directory::CacheDirectory;
+use crate::io::write::create_tokenizer_manager;
 
 #[derive(Clone)]
 pub struct InvertedIndexReader {
     dal: Operator,
-    schema: DataSchema,
 }
 
 impl InvertedIndexReader {
-    pub fn create(dal: Operator, schema: DataSchema) -> Self {
-        Self { dal, schema }
+    pub fn create(dal: Operator) -> Self {
+        Self { dal }
     }
 
-    pub fn do_read(
-        &self,
-        path: String,
-        query: &str,
-        num: usize,
-    ) -> Result<Vec<(Score, DocAddress)>> {
-        let data = self.dal.blocking().read_with(&path).call()?;
+    // Read the index data of a block, returns `None` if the index of the block
+    // has not been generated yet, which happens before the index is refreshed.
+    #[async_backtrace::framed]
+    pub async fn read_index(&self, location: &str) -> Result<Option<Index>> {
+        let data = match self.dal.read(location).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
 
         let directory = CacheDirectory::try_create(data)?;
         let mut index = Index::open(directory)?;
-        let tokenizer_manager = TokenizerManager::new();
-        tokenizer_manager.register(
-            "en",
-            TextAnalyzer::builder(SimpleTokenizer::default())
-                .filter(RemoveLongFilter::limit(40))
-                .filter(LowerCaser)
-                .filter(Stemmer::new(Language::English))
-                .build(),
-        );
-        index.set_tokenizers(tokenizer_manager);
+        index.set_tokenizers(create_tokenizer_manager());
+        Ok(Some(index))
+    }
+
+    // Search the query text in the index, returns the matched row offsets
+    // in ascending order, along with the BM25 score if `has_score` is true.
+    pub fn search(
+        index: &Index,
+        query_fields: &[String],
+        query_text: &str,
+        has_score: bool,
+        num_rows: usize,
+    ) -> Result<Vec<(usize, Option<F32>)>> {
+        let schema = index.schema();
+        let fields = query_fields
+            .iter()
+            .map(|name| {
+                schema.get_field(name).map_err(|_| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "field `{}` is not found in the inverted index",
+                        name
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let reader = index.reader()?;
         let searcher = reader.searcher();
+        let query_parser = QueryParser::for_index(index, fields);
+        let query = query_parser.parse_query(query_text)?;
 
-        let fields = (0..self.schema.fields.len())
-            .map(|i| Field::from_field_id(i as u32))
-            .collect::<Vec<_>>();
-
-        let query_parser = QueryParser::for_index(&index, fields);
-
-        let query = query_parser.parse_query(query)?;
-        let collector = TopDocs::with_limit(num);
-        let docs = searcher.search(&query, &collector)?;
+        let mut matched_rows = if has_score {
+            let collector = TopDocs::with_limit(num_rows.max(1));
+            let docs = searcher.search(&query, &collector)?;
+            docs.into_iter()
+                .map(|(score, doc_addr)| (doc_addr.doc_id as usize, Some(F32::from(score))))
+                .collect::<Vec<_>>()
+        } else {
+            let docs = searcher.search(&query, &DocSetCollector)?;
+            docs.into_iter()
+                .map(|doc_addr| (doc_addr.doc_id as usize, None))
+                .collect::<Vec<_>>()
+        };
+        matched_rows.sort_by_key(|(row, _)| *row);
 
-        Ok(docs)
+        Ok(matched_rows)
     }
 }
@@ -15,6 +15,7 @@
 mod agg_index;
 mod block;
 pub mod bloom;
+mod deletion_vector_reader;
 mod inverted_index;
 pub mod meta;
 mod read_settings;
@@ -29,6 +30,9 @@ pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
 pub use block::UncompressedBuffer;
 pub use bloom::BloomBlockFilterReader;
+pub use deletion_vector_reader::deletion_vector_filter;
+pub use deletion_vector_reader::read_deletion_vector;
+pub use deletion_vector_reader::read_deletion_vector_sync;
 pub use inverted_index::InvertedIndexReader;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
@@ -40,6 +40,7 @@ use opendal::Operator;
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
+use crate::statistics::gen_columns_hll;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseStorageFormat;
@@ -124,6 +125,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -131,6 +133,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -173,6 +176,9 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    /// Whether to collect the column sketches of the written blocks into the context.
+    pub collect_column_hlls: bool,
 }
 
 impl BlockBuilder {
@@ -188,6 +194,7 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -197,6 +204,10 @@ impl BlockBuilder {
         let block_size = data_block.memory_size() as u64;
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        if self.collect_column_hlls {
+            let hlls = gen_columns_hll(&data_block, &self.source_schema)?;
+            self.ctx.add_written_column_hlls(row_count, hlls);
+        }
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let col_metas = serialize_block(
@@ -222,6 +233,7 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.into(),
             create_on: Some(Utc::now()),
+            deletion_vector: None,
         };
 
         let serialized = BlockSerialization {
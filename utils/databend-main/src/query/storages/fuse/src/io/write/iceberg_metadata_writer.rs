@@ -0,0 +1,530 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Iceberg metadata of fuse tables.
+//!
+//! If `enable_iceberg_metadata` is set, each commit of the table also writes an
+//! iceberg (format v2) view of the committed snapshot, which points at the
+//! parquet blocks of the table, so that iceberg readers can read the table
+//! without copying data. The layout is the one of the filesystem catalog:
+//! ```text
+//! {table_prefix}/metadata/version-hint.text
+//! {table_prefix}/metadata/v{n}.metadata.json
+//! {table_prefix}/metadata/snap-{snapshot_id}.avro   manifest list
+//! {table_prefix}/metadata/{segment}-m0.avro         manifest of a segment
+//! ```
+//! Segments are immutable, the manifest of a segment is written once and
+//! shared by all the following snapshots, it is purged along with the segment.
+//! The manifest list of a snapshot is built upon the one of its previous
+//! snapshot, only the segments added by the commit are read.
+//!
+//! The version of the metadata is derived from the version of the table meta the
+//! snapshot is committed on, which is unique for each commit, so that concurrent
+//! commits never write the same metadata file.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use chrono::Utc;
+use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::schema::TableInfo;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::iceberg_type;
+use databend_storages_common_table_meta::table::OPT_KEY_ENABLE_ICEBERG_METADATA;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use opendal::Operator;
+use opendal::Scheme;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+use crate::FUSE_TBL_ICEBERG_METADATA_PREFIX;
+
+const VERSION_HINT: &str = "version-hint.text";
+
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+        {"name": "data_file", "field-id": 2, "type": {
+            "type": "record",
+            "name": "r2",
+            "fields": [
+                {"name": "content", "type": "int", "field-id": 134},
+                {"name": "file_path", "type": "string", "field-id": 100},
+                {"name": "file_format", "type": "string", "field-id": 101},
+                {"name": "partition", "field-id": 102, "type": {"type": "record", "name": "r102", "fields": []}},
+                {"name": "record_count", "type": "long", "field-id": 103},
+                {"name": "file_size_in_bytes", "type": "long", "field-id": 104}
+            ]
+        }}
+    ]
+}"#;
+
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+    ]
+}"#;
+
+/// Whether the iceberg metadata should be written on commit.
+///
+/// Only parquet blocks can be read by iceberg readers.
+pub fn iceberg_metadata_enabled(options: &BTreeMap<String, String>) -> bool {
+    let is_parquet = options
+        .get(OPT_KEY_STORAGE_FORMAT)
+        .map_or(Ok(FuseStorageFormat::Parquet), |v| v.parse())
+        .is_ok_and(|format| matches!(format, FuseStorageFormat::Parquet));
+    is_parquet
+        && options
+            .get(OPT_KEY_ENABLE_ICEBERG_METADATA)
+            .and_then(|v| v.to_lowercase().parse::<bool>().ok())
+            .unwrap_or(false)
+}
+
+/// Check that all the columns of the table can be described by iceberg types.
+pub fn check_iceberg_schema(schema: &TableSchema) -> Result<()> {
+    iceberg_schema(schema).map(|_| ())
+}
+
+/// The location of the iceberg manifest of the segment.
+pub fn segment_manifest_location(
+    location_gen: &TableMetaLocationGenerator,
+    segment_location: &str,
+) -> String {
+    let segment_name = Path::new(segment_location)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(segment_location);
+    format!("{}/{segment_name}-m0.avro", metadata_dir(location_gen))
+}
+
+fn metadata_dir(location_gen: &TableMetaLocationGenerator) -> String {
+    format!(
+        "{}/{}",
+        location_gen.prefix(),
+        FUSE_TBL_ICEBERG_METADATA_PREFIX
+    )
+}
+
+/// Writes the iceberg metadata of the snapshot, which has been committed.
+///
+/// `table_info` is the table the snapshot is committed on, its version is the base
+/// of the commit.
+#[async_backtrace::framed]
+pub async fn write_iceberg_metadata(
+    operator: &Operator,
+    location_gen: &TableMetaLocationGenerator,
+    table_info: &TableInfo,
+    snapshot: &TableSnapshot,
+) -> Result<()> {
+    let storage_root = storage_location(operator)?;
+    let table_location = format!("{storage_root}{}", location_gen.prefix());
+    let metadata_dir = metadata_dir(location_gen);
+    let schema = table_info.schema();
+    let (iceberg_schema, last_column_id, name_mapping) = iceberg_schema(&schema)?;
+
+    // Each commit matches the exact version of the table meta, so the versions of
+    // the committed snapshots are distinct and increasing.
+    let version = table_info.ident.seq as i64 + 1;
+    let snapshot_id = iceberg_snapshot_id(&snapshot.snapshot_id);
+    let timestamp_ms = snapshot
+        .timestamp
+        .unwrap_or_else(Utc::now)
+        .timestamp_millis();
+
+    // 1. manifests of the segments, the ones of the segments inherited from the
+    // previous snapshot are taken from its manifest list.
+    let mut inherited_manifest_files = match snapshot.prev_snapshot_id {
+        Some((prev_snapshot_id, _)) => {
+            let prev_manifest_list_path = format!(
+                "{metadata_dir}/snap-{}.avro",
+                iceberg_snapshot_id(&prev_snapshot_id)
+            );
+            read_manifest_list(operator, &prev_manifest_list_path).await?
+        }
+        None => HashMap::new(),
+    };
+    let mut manifest_files = Vec::with_capacity(snapshot.segments.len());
+    for segment_location in &snapshot.segments {
+        let manifest_path = segment_manifest_location(location_gen, &segment_location.0);
+        let manifest_file =
+            match inherited_manifest_files.remove(&format!("{storage_root}{manifest_path}")) {
+                Some(manifest_file) => manifest_file,
+                None => {
+                    write_segment_manifest(
+                        operator,
+                        &storage_root,
+                        manifest_path,
+                        segment_location,
+                        schema.clone(),
+                        &iceberg_schema,
+                        version,
+                        snapshot_id,
+                    )
+                    .await?
+                }
+            };
+        manifest_files.push(manifest_file);
+    }
+
+    // 2. manifest list of the snapshot
+    let manifest_list_path = format!("{metadata_dir}/snap-{snapshot_id}.avro");
+    let manifest_list = write_avro(
+        MANIFEST_FILE_SCHEMA,
+        vec![
+            ("snapshot-id".to_string(), snapshot_id.to_string()),
+            ("parent-snapshot-id".to_string(), "null".to_string()),
+            ("sequence-number".to_string(), version.to_string()),
+            ("format-version".to_string(), "2".to_string()),
+        ],
+        manifest_files,
+    )?;
+    operator.write(&manifest_list_path, manifest_list).await?;
+
+    // 3. table metadata, only the current snapshot is kept
+    let metadata = json!({
+        "format-version": 2,
+        "table-uuid": Uuid::from_u128(table_info.ident.table_id as u128).to_string(),
+        "location": table_location,
+        "last-sequence-number": version,
+        "last-updated-ms": Utc::now().timestamp_millis(),
+        "last-column-id": last_column_id,
+        "current-schema-id": 0,
+        "schemas": [iceberg_schema],
+        "default-spec-id": 0,
+        "partition-specs": [{ "spec-id": 0, "fields": [] }],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{ "order-id": 0, "fields": [] }],
+        // The parquet blocks carry no field ids, they are resolved by column names.
+        "properties": { "schema.name-mapping.default": name_mapping.to_string() },
+        "current-snapshot-id": snapshot_id,
+        "snapshots": [{
+            "snapshot-id": snapshot_id,
+            "sequence-number": version,
+            "timestamp-ms": timestamp_ms,
+            "summary": {
+                "operation": "overwrite",
+                "total-records": snapshot.summary.row_count.to_string(),
+                "total-data-files": snapshot.summary.block_count.to_string(),
+            },
+            "manifest-list": format!("{storage_root}{manifest_list_path}"),
+            "schema-id": 0,
+        }],
+        "snapshot-log": [{ "timestamp-ms": timestamp_ms, "snapshot-id": snapshot_id }],
+        "metadata-log": [],
+        "refs": { "main": { "snapshot-id": snapshot_id, "type": "branch" } },
+    });
+    operator
+        .write(
+            &format!("{metadata_dir}/v{version}.metadata.json"),
+            metadata.to_string(),
+        )
+        .await?;
+
+    write_version_hint(operator, &metadata_dir).await
+}
+
+// The iceberg snapshot id is derived from the fuse snapshot id.
+fn iceberg_snapshot_id(snapshot_id: &Uuid) -> i64 {
+    (snapshot_id.as_u128() as i64) & i64::MAX
+}
+
+// Points the version hint at the latest version of the metadata.
+//
+// The storage offers no conditional write, so the hint of a concurrent commit may
+// overwrite this one with an older version. The hint is checked again after each
+// write, and the commit that writes the last hint leaves it at the latest version.
+async fn write_version_hint(operator: &Operator, metadata_dir: &str) -> Result<()> {
+    let hint_path = format!("{metadata_dir}/{VERSION_HINT}");
+    loop {
+        let latest_version = operator
+            .list(&format!("{metadata_dir}/"))
+            .await?
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .name()
+                    .strip_prefix('v')?
+                    .strip_suffix(".metadata.json")?
+                    .parse::<i64>()
+                    .ok()
+            })
+            .max();
+        let Some(latest_version) = latest_version else {
+            return Ok(());
+        };
+
+        let hinted_version = match operator.read(&hint_path).await {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).trim().parse::<i64>().ok(),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        if hinted_version.is_some_and(|hinted| hinted >= latest_version) {
+            return Ok(());
+        }
+        operator
+            .write(&hint_path, latest_version.to_string())
+            .await?;
+    }
+}
+
+// Returns the entries of the manifest list by the location of the manifests, the
+// manifest list does not exist if the metadata of the snapshot was not written.
+async fn read_manifest_list(operator: &Operator, path: &str) -> Result<HashMap<String, Value>> {
+    let bytes = match operator.read(path).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let avro_error =
+        |e: apache_avro::Error| ErrorCode::Internal(format!("Cannot read iceberg manifest: {e}"));
+    let reader = Reader::new(bytes.as_slice()).map_err(avro_error)?;
+    let mut manifest_files = HashMap::new();
+    for value in reader {
+        let value = value.map_err(avro_error)?;
+        let manifest_path = match &value {
+            Value::Record(fields) => fields.iter().find_map(|(name, field)| match field {
+                Value::String(path) if name == "manifest_path" => Some(path.clone()),
+                _ => None,
+            }),
+            _ => None,
+        };
+        if let Some(manifest_path) = manifest_path {
+            manifest_files.insert(manifest_path, value);
+        }
+    }
+    Ok(manifest_files)
+}
+
+// Write the manifest of the segment if it does not exist yet, returns the
+// entry of the manifest in the manifest list.
+#[allow(clippy::too_many_arguments)]
+async fn write_segment_manifest(
+    operator: &Operator,
+    storage_root: &str,
+    manifest_path: String,
+    segment_location: &Location,
+    schema: TableSchemaRef,
+    iceberg_schema: &JsonValue,
+    sequence_number: i64,
+    snapshot_id: i64,
+) -> Result<Value> {
+    let segment =
+        SegmentsIO::read_compact_segment(operator.clone(), segment_location.clone(), schema, true)
+            .await?;
+
+    let manifest_length = match operator.stat(&manifest_path).await {
+        Ok(meta) => meta.content_length(),
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+            let block_metas = segment.block_metas()?;
+            let mut entries = Vec::with_capacity(block_metas.len());
+            for block_meta in block_metas {
+                if block_meta.deletion_vector.is_some() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Block {} has rows deleted by deletion vector, which can not be exported as iceberg metadata",
+                        block_meta.location.0
+                    )));
+                }
+                // Snapshot id and sequence numbers are inherited from the manifest list.
+                let data_file = Value::Record(vec![
+                    ("content".to_string(), Value::Int(0)),
+                    (
+                        "file_path".to_string(),
+                        Value::String(format!("{storage_root}{}", block_meta.location.0)),
+                    ),
+                    (
+                        "file_format".to_string(),
+                        Value::String("PARQUET".to_string()),
+                    ),
+                    ("partition".to_string(), Value::Record(vec![])),
+                    (
+                        "record_count".to_string(),
+                        Value::Long(block_meta.row_count as i64),
+                    ),
+                    (
+                        "file_size_in_bytes".to_string(),
+                        Value::Long(block_meta.file_size as i64),
+                    ),
+                ]);
+                entries.push(Value::Record(vec![
+                    ("status".to_string(), Value::Int(1)),
+                    (
+                        "snapshot_id".to_string(),
+                        Value::Union(0, Box::new(Value::Null)),
+                    ),
+                    (
+                        "sequence_number".to_string(),
+                        Value::Union(0, Box::new(Value::Null)),
+                    ),
+                    (
+                        "file_sequence_number".to_string(),
+                        Value::Union(0, Box::new(Value::Null)),
+                    ),
+                    ("data_file".to_string(), data_file),
+                ]));
+            }
+
+            let manifest = write_avro(
+                MANIFEST_ENTRY_SCHEMA,
+                vec![
+                    ("schema".to_string(), iceberg_schema.to_string()),
+                    ("schema-id".to_string(), "0".to_string()),
+                    ("partition-spec".to_string(), "[]".to_string()),
+                    ("partition-spec-id".to_string(), "0".to_string()),
+                    ("format-version".to_string(), "2".to_string()),
+                    ("content".to_string(), "data".to_string()),
+                ],
+                entries,
+            )?;
+            let length = manifest.len() as u64;
+            operator.write(&manifest_path, manifest).await?;
+            length
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let summary = &segment.summary;
+    Ok(Value::Record(vec![
+        (
+            "manifest_path".to_string(),
+            Value::String(format!("{storage_root}{manifest_path}")),
+        ),
+        (
+            "manifest_length".to_string(),
+            Value::Long(manifest_length as i64),
+        ),
+        ("partition_spec_id".to_string(), Value::Int(0)),
+        ("content".to_string(), Value::Int(0)),
+        ("sequence_number".to_string(), Value::Long(sequence_number)),
+        (
+            "min_sequence_number".to_string(),
+            Value::Long(sequence_number),
+        ),
+        ("added_snapshot_id".to_string(), Value::Long(snapshot_id)),
+        (
+            "added_files_count".to_string(),
+            Value::Int(summary.block_count as i32),
+        ),
+        ("existing_files_count".to_string(), Value::Int(0)),
+        ("deleted_files_count".to_string(), Value::Int(0)),
+        (
+            "added_rows_count".to_string(),
+            Value::Long(summary.row_count as i64),
+        ),
+        ("existing_rows_count".to_string(), Value::Long(0)),
+        ("deleted_rows_count".to_string(), Value::Long(0)),
+    ]))
+}
+
+fn write_avro(
+    schema: &str,
+    metadata: Vec<(String, String)>,
+    values: Vec<Value>,
+) -> Result<Vec<u8>> {
+    let avro_error =
+        |e: apache_avro::Error| ErrorCode::Internal(format!("Cannot write iceberg manifest: {e}"));
+    let schema = Schema::parse_str(schema).map_err(avro_error)?;
+    let mut writer = Writer::new(&schema, Vec::new());
+    for (key, value) in metadata {
+        writer.add_user_metadata(key, value).map_err(avro_error)?;
+    }
+    for value in values {
+        writer.append(value).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+/// The uri of the root of the storage, iceberg readers locate the files by absolute uri.
+fn storage_location(operator: &Operator) -> Result<String> {
+    let info = operator.info();
+    let location = match info.scheme() {
+        Scheme::S3 => format!("s3://{}{}", info.name(), info.root()),
+        Scheme::Gcs => format!("gs://{}{}", info.name(), info.root()),
+        Scheme::Oss => format!("oss://{}{}", info.name(), info.root()),
+        Scheme::Azblob => format!("azblob://{}{}", info.name(), info.root()),
+        Scheme::Hdfs => format!("{}{}", info.name(), info.root()),
+        Scheme::Fs => format!("file://{}", info.root()),
+        scheme => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Iceberg metadata is not supported on storage {}",
+                scheme
+            )));
+        }
+    };
+    Ok(location)
+}
+
+// Returns the iceberg schema, the last column id and the name mapping of the columns.
+fn iceberg_schema(schema: &TableSchema) -> Result<(JsonValue, u32, JsonValue)> {
+    let mut fields = Vec::with_capacity(schema.num_fields());
+    let mut name_mapping = Vec::with_capacity(schema.num_fields());
+    let mut last_column_id = 0;
+    for field in schema.fields() {
+        // The column id in iceberg is 1-based while the column id in Databend is 0-based.
+        let id = field.column_id + 1;
+        last_column_id = last_column_id.max(id);
+        let (ty, required) = match field.data_type() {
+            TableDataType::Nullable(inner) => (iceberg_type(inner)?, false),
+            ty => (iceberg_type(ty)?, true),
+        };
+        fields.push(json!({
+            "id": id,
+            "name": field.name(),
+            "required": required,
+            "type": ty,
+        }));
+        name_mapping.push(json!({ "field-id": id, "names": [field.name()] }));
+    }
+
+    let schema = json!({
+        "type": "struct",
+        "schema-id": 0,
+        "fields": fields,
+    });
+    Ok((schema, last_column_id, JsonValue::Array(name_mapping)))
+}
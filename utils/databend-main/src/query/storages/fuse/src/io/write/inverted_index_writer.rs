@@ -33,6 +33,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
 // IN THE SOFTWARE.
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::Path;
 
@@ -66,12 +67,10 @@ use tantivy::Index;
 use tantivy::IndexBuilder;
 use tantivy::IndexSettings;
 use tantivy::SegmentComponent;
-use tantivy::SegmentId;
 use tantivy::SingleSegmentIndexWriter;
 use tantivy_common::BinarySerializable;
 
 use crate::io::write_data;
-use crate::io::TableMetaLocationGenerator;
 
 // tantivy version is used to generate the footer data
 
@@ -125,15 +124,56 @@ impl Footer {
     }
 }
 
+// The default tokenizer, splits text into words and stems them as English.
+pub const DEFAULT_TOKENIZER: &str = "english";
+
+/// Create a tokenizer manager with all the supported tokenizers registered.
+/// The tokenizer of a field is recorded by name in the index meta,
+/// so both the writer and the reader use the same manager.
+pub fn create_tokenizer_manager() -> TokenizerManager {
+    let tokenizer_manager = TokenizerManager::new();
+    tokenizer_manager.register(
+        "english",
+        TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .filter(Stemmer::new(Language::English))
+            .build(),
+    );
+    tokenizer_manager.register(
+        "simple",
+        TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .build(),
+    );
+    tokenizer_manager
+}
+
 pub struct InvertedIndexWriter {
     schema: DataSchema,
     index_writer: SingleSegmentIndexWriter,
 }
 
 impl InvertedIndexWriter {
-    pub fn try_create(schema: DataSchema) -> Result<InvertedIndexWriter> {
+    pub fn try_create(
+        schema: DataSchema,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<InvertedIndexWriter> {
+        let tokenizer_manager = create_tokenizer_manager();
+        let tokenizer_name = index_options
+            .get("tokenizer")
+            .map(|v| v.as_str())
+            .unwrap_or(DEFAULT_TOKENIZER);
+        if tokenizer_manager.get(tokenizer_name).is_none() {
+            return Err(ErrorCode::IndexOptionInvalid(format!(
+                "tokenizer `{}` is not supported",
+                tokenizer_name
+            )));
+        }
+
         let text_field_indexing = TextFieldIndexing::default()
-            .set_tokenizer("en")
+            .set_tokenizer(tokenizer_name)
             .set_index_option(IndexRecordOption::WithFreqsAndPositions);
         let text_options = TextOptions::default().set_indexing_options(text_field_indexing);
 
@@ -155,16 +195,6 @@ impl InvertedIndexWriter {
             sort_by_field: None,
             ..Default::default()
         };
-        let tokenizer_manager = TokenizerManager::new();
-        tokenizer_manager.register(
-            "en",
-            TextAnalyzer::builder(SimpleTokenizer::default())
-                .filter(RemoveLongFilter::limit(40))
-                .filter(LowerCaser)
-                .filter(Stemmer::new(Language::English))
-                .build(),
-        );
-
         let index_builder = IndexBuilder::new()
             .settings(index_settings)
             .schema(index_schema.clone())
@@ -215,22 +245,22 @@ impl InvertedIndexWriter {
     }
 
     #[async_backtrace::framed]
-    pub async fn finalize(
-        self,
-        operator: &Operator,
-        location_generator: &TableMetaLocationGenerator,
-    ) -> Result<String> {
+    pub async fn finalize(self, operator: &Operator, index_location: &str) -> Result<()> {
         let index = self.index_writer.finalize()?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
-        let segment_id = Self::write_index(&mut buffer, index).await?;
+        Self::write_index(&mut buffer, index).await?;
 
-        let index_location =
-            location_generator.gen_inverted_index_location(segment_id.uuid_string());
+        write_data(buffer, operator, index_location).await?;
 
-        write_data(buffer, operator, &index_location).await?;
+        Ok(())
+    }
 
-        Ok(index_location)
+    // Build the index in memory without writing it,
+    // used to search the blocks whose index data has not been generated yet.
+    pub fn finalize_in_memory(self) -> Result<Index> {
+        let index = self.index_writer.finalize()?;
+        Ok(index)
     }
 
     // The tantivy index data consists of eight files.
@@ -263,7 +293,7 @@ impl InvertedIndexWriter {
     //       "indexing": {
     //         "record": "position",
     //         "fieldnorms": true,
-    //         "tokenizer": "en"
+    //         "tokenizer": "english"
     //       },
     //       "stored": false,
     //       "fast": false
@@ -275,7 +305,7 @@ impl InvertedIndexWriter {
     //       "indexing": {
     //         "record": "position",
     //         "fieldnorms": true,
-    //         "tokenizer": "en"
+    //         "tokenizer": "english"
     //       },
     //       "stored": false,
     //       "fast": false
@@ -298,7 +328,7 @@ impl InvertedIndexWriter {
     // We merge the data from these files into one file and
     // record the offset to read each part of the data.
     #[async_backtrace::framed]
-    async fn write_index<W: Write>(mut writer: &mut W, index: Index) -> Result<SegmentId> {
+    async fn write_index<W: Write>(mut writer: &mut W, index: Index) -> Result<()> {
         let directory = index.directory();
 
         let managed_filepath = Path::new(".managed.json");
@@ -388,9 +418,7 @@ impl InvertedIndexWriter {
 
         writer.flush()?;
 
-        let segment_id = segment.id();
-
-        Ok(segment_id)
+        Ok(())
     }
 
     fn build_footer<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<usize> {
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod block_writer;
+mod deletion_vector_writer;
+mod iceberg_metadata_writer;
 mod inverted_index_writer;
 mod meta_writer;
 mod segment_writer;
@@ -22,6 +24,12 @@ pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
+pub use deletion_vector_writer::write_deletion_vector;
+pub use iceberg_metadata_writer::check_iceberg_schema;
+pub use iceberg_metadata_writer::iceberg_metadata_enabled;
+pub use iceberg_metadata_writer::segment_manifest_location;
+pub use iceberg_metadata_writer::write_iceberg_metadata;
+pub(crate) use inverted_index_writer::create_tokenizer_manager;
 pub use inverted_index_writer::InvertedIndexWriter;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
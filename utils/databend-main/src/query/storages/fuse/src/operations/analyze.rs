@@ -18,11 +18,13 @@ use std::time::Instant;
 
 use async_trait::async_trait;
 use async_trait::unboxed_simple;
+use chrono::Utc;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_io::prelude::borsh_deserialize_from_slice;
@@ -32,12 +34,15 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sinks::AsyncSink;
 use databend_common_pipeline_sinks::AsyncSinker;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
 
 use crate::io::SegmentsIO;
 use crate::statistics::reduce_block_statistics;
@@ -53,6 +58,7 @@ impl FuseTable {
         database: &str,
         table: &str,
         snapshot_id: SnapshotId,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         pipeline.add_sink(|input| {
@@ -63,11 +69,52 @@ impl FuseTable {
                 database,
                 table,
                 snapshot_id,
+                histograms.clone(),
                 input,
             )
         })?;
         Ok(())
     }
+
+    /// Randomly pick whole blocks of the snapshot until they hold at least `sample_rows` rows.
+    /// Returns the locations of the picked blocks and their number of rows.
+    #[async_backtrace::framed]
+    pub async fn sample_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+        sample_rows: u64,
+    ) -> Result<(Vec<String>, u64)> {
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut blocks = Vec::with_capacity(snapshot.summary.block_count as usize);
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, true)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                blocks.extend(
+                    segment
+                        .blocks
+                        .iter()
+                        .map(|block| (block.location.0.clone(), block.row_count)),
+                );
+            }
+        }
+
+        blocks.shuffle(&mut thread_rng());
+        let mut num_rows = 0;
+        let mut locations = vec![];
+        for (location, row_count) in blocks {
+            if num_rows >= sample_rows {
+                break;
+            }
+            num_rows += row_count;
+            locations.push(location);
+        }
+        Ok((locations, num_rows))
+    }
 }
 
 struct SinkAnalyzeState {
@@ -78,6 +125,8 @@ struct SinkAnalyzeState {
     database: String,
     table: String,
     snapshot_id: SnapshotId,
+    // Histograms are built from the whole snapshot, they are not merged incrementally.
+    histograms: HashMap<ColumnId, ColumnHistogram>,
 }
 
 impl SinkAnalyzeState {
@@ -89,6 +138,7 @@ impl SinkAnalyzeState {
         database: &str,
         table: &str,
         snapshot_id: SnapshotId,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
         input: Arc<InputPort>,
     ) -> Result<ProcessorPtr> {
         let sinker = AsyncSinker::create(input, ctx.clone(), SinkAnalyzeState {
@@ -98,6 +148,7 @@ impl SinkAnalyzeState {
             database: database.to_string(),
             table: table.to_string(),
             snapshot_id,
+            histograms,
         });
         Ok(ProcessorPtr::create(sinker))
     }
@@ -164,7 +215,11 @@ impl SinkAnalyzeState {
 
         let snapshot = snapshot.unwrap();
         // 3. Generate new table statistics
-        let table_statistics = TableSnapshotStatistics::new(ndv_states, self.snapshot_id);
+        let table_statistics = TableSnapshotStatistics::new(
+            ndv_states,
+            std::mem::take(&mut self.histograms),
+            self.snapshot_id,
+        );
         let table_statistics_location = table
             .meta_location_generator
             .snapshot_statistics_location_from_uuid(
@@ -179,6 +234,8 @@ impl SinkAnalyzeState {
         new_snapshot.summary.col_stats = col_stats;
         new_snapshot.summary.cluster_stats = cluster_stats;
         new_snapshot.table_statistics_location = Some(table_statistics_location);
+        new_snapshot.rows_modified_since_analyze = 0;
+        new_snapshot.analyzed_on = Some(Utc::now());
 
         FuseTable::commit_to_meta_server(
             self.ctx.as_ref(),
@@ -51,6 +51,8 @@ use log::info;
 use log::warn;
 use opendal::Operator;
 
+use crate::io::iceberg_metadata_enabled;
+use crate::io::write_iceberg_metadata;
 use crate::io::MetaWriter;
 use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
@@ -206,6 +208,8 @@ impl FuseTable {
             index_data_bytes: stats.index_size,
             number_of_segments: Some(snapshot.segments.len() as u64),
             number_of_blocks: Some(stats.block_count),
+            rows_modified_since_analyze: Some(snapshot.rows_modified_since_analyze),
+            analyzed_on: snapshot.analyzed_on,
         };
         new_table_meta.updated_on = Utc::now();
 
@@ -227,10 +231,19 @@ impl FuseTable {
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
-                TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
+                let snapshot = Arc::new(snapshot);
+                TableSnapshot::cache().put(snapshot_location.clone(), snapshot.clone());
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
                     .await;
+                Self::try_write_iceberg_metadata(
+                    ctx,
+                    operator,
+                    location_generator,
+                    table_info,
+                    &snapshot,
+                )
+                .await;
                 Ok(())
             }
             Err(e) => {
@@ -281,6 +294,37 @@ impl FuseTable {
             });
     }
 
+    // Keep the iceberg metadata of the table up to date, if it is enabled.
+    //
+    // The commit has succeeded already, so errors are reported to the client as a
+    // warning of the query, the metadata is brought up to date by the next commit.
+    #[async_backtrace::framed]
+    pub async fn try_write_iceberg_metadata(
+        ctx: &dyn TableContext,
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+    ) {
+        if !iceberg_metadata_enabled(table_info.options()) {
+            return;
+        }
+
+        if let Err(e) =
+            write_iceberg_metadata(operator, location_generator, table_info, snapshot).await
+        {
+            warn!(
+                "write iceberg metadata of table {} failure. {}",
+                table_info.desc, e
+            );
+            ctx.push_warning(format!(
+                "The iceberg metadata of table {} is stale, writing it failed: {}",
+                table_info.desc,
+                e.message()
+            ));
+        }
+    }
+
     // TODO refactor, it is called by segment compaction
     #[async_backtrace::framed]
     pub async fn commit_mutation(
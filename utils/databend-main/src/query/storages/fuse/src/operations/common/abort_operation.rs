@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache_manager::CacheManager;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use opendal::Operator;
 
 use crate::io::Files;
@@ -28,6 +29,7 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    pub deletion_vectors: Vec<String>,
 }
 
 impl AbortOperation {
@@ -35,6 +37,7 @@ impl AbortOperation {
         self.segments.extend(rhs.segments);
         self.blocks.extend(rhs.blocks);
         self.bloom_filter_indexes.extend(rhs.bloom_filter_indexes);
+        self.deletion_vectors.extend(rhs.deletion_vectors);
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -45,6 +48,11 @@ impl AbortOperation {
         }
     }
 
+    pub fn add_deletion_vector(&mut self, deletion_vector: &DeletionVectorMeta) {
+        self.deletion_vectors
+            .push(deletion_vector.location.0.clone());
+    }
+
     pub fn add_segment(&mut self, segment: String) {
         self.segments.push(segment);
     }
@@ -63,6 +71,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.deletion_vectors.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
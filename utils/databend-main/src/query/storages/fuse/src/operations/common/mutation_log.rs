@@ -22,6 +22,7 @@ use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::FormatVersion;
 use databend_storages_common_table_meta::meta::Statistics;
 
@@ -57,6 +58,18 @@ pub enum MutationLogEntry {
         index: BlockMetaIndex,
         block_meta: Arc<BlockMeta>,
     },
+    // The deleted rows of the block are marked by a new deletion vector,
+    // the block itself is left untouched.
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVectorMeta,
+    },
+    // A new block appended to the segment, e.g. the updated rows of a block
+    // whose original rows are marked by a deletion vector.
+    AppendedBlock {
+        segment_idx: SegmentIndex,
+        block_meta: Arc<BlockMeta>,
+    },
     CompactExtras {
         extras: CompactExtraInfo,
     },
@@ -170,6 +183,7 @@ fn merge_conflict_resolve_context(
                     &r.merged_statistics,
                     default_cluster_key_id,
                 ),
+                modified_rows: l.modified_rows + r.modified_rows,
             })
         }
         _ => unreachable!(
@@ -208,6 +222,12 @@ fn merge_commit_meta(
                 .into_iter()
                 .chain(r.abort_operation.bloom_filter_indexes)
                 .collect(),
+            deletion_vectors: l
+                .abort_operation
+                .deletion_vectors
+                .into_iter()
+                .chain(r.abort_operation.deletion_vectors)
+                .collect(),
         },
     }
 }
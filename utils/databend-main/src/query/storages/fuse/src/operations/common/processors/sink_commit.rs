@@ -39,6 +39,7 @@ use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
 use log::error;
@@ -46,6 +47,7 @@ use log::info;
 use log::warn;
 use opendal::Operator;
 
+use crate::io::MetaWriter;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::CommitMeta;
@@ -183,6 +185,42 @@ where F: SnapshotGenerator + Send + 'static
 
         Ok(Event::Async)
     }
+
+    /// Merge the sketches of the written column values into the table statistics, so that the
+    /// NDVs of an analyzed table keep up with the appends. Returns true if the table statistics
+    /// location of the snapshot is updated.
+    ///
+    /// The sketches are merged by every append, however small: the sketches of the written
+    /// values are not kept anywhere else, skipping a merge would lose them for good.
+    async fn merge_table_statistics(&mut self, snapshot: &mut TableSnapshot) -> Result<bool> {
+        let (Some(hlls), Some(loc)) = (
+            self.snapshot_gen.written_column_hlls(),
+            &snapshot.table_statistics_location,
+        ) else {
+            return Ok(false);
+        };
+
+        let fuse_table = FuseTable::try_from_table(self.table.as_ref())?;
+        let previous = fuse_table.read_table_statistics(loc).await?;
+        let mut merged = previous.hll.clone();
+        for (column_id, hll) in hlls {
+            merged
+                .entry(*column_id)
+                .and_modify(|v| v.merge(hll))
+                .or_insert_with(|| hll.clone());
+        }
+
+        // Histograms are not mergeable, they are kept until the table is analyzed again.
+        let table_statistics =
+            TableSnapshotStatistics::new(merged, previous.histograms.clone(), snapshot.snapshot_id);
+        let location = self.location_gen.snapshot_statistics_location_from_uuid(
+            &table_statistics.snapshot_id,
+            table_statistics.format_version(),
+        )?;
+        table_statistics.write_meta(&self.dal, &location).await?;
+        snapshot.table_statistics_location = Some(location);
+        Ok(true)
+    }
 }
 
 #[async_trait::async_trait]
@@ -318,10 +356,19 @@ where F: SnapshotGenerator + Send + 'static
                 }
             },
             State::TryCommit {
-                data,
-                snapshot,
+                mut data,
+                mut snapshot,
                 table_info,
             } => {
+                match self.merge_table_statistics(&mut snapshot).await {
+                    Ok(true) => data = snapshot.to_bytes()?,
+                    Ok(false) => {}
+                    Err(e) => {
+                        // the table statistics will be caught up by the next `ANALYZE TABLE`
+                        warn!("merge the sketches of the written columns failed: {:?}", e);
+                    }
+                }
+
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
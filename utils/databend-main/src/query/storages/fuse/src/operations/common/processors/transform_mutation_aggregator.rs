@@ -29,6 +29,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
@@ -156,6 +157,29 @@ impl TableMutationAggregator {
                     }
                 }
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                // only the deletion vector is new, the block itself must be kept on abort.
+                self.abort_operation.add_deletion_vector(&deletion_vector);
+                self.mutations
+                    .entry(index.segment_idx)
+                    .or_default()
+                    .deletion_vectors
+                    .push((index.block_idx, deletion_vector));
+            }
+            MutationLogEntry::AppendedBlock {
+                segment_idx,
+                block_meta,
+            } => {
+                self.abort_operation.add_block(&block_meta);
+                self.mutations
+                    .entry(segment_idx)
+                    .or_default()
+                    .appended_blocks
+                    .push(block_meta);
+            }
             MutationLogEntry::DeletedBlock { index } => {
                 self.mutations
                     .entry(index.segment_idx)
@@ -195,7 +219,7 @@ impl TableMutationAggregator {
                     Entry::Vacant(v) => {
                         v.insert(BlockMutations {
                             replaced_blocks: extras.unchanged_blocks,
-                            deleted_blocks: vec![],
+                            ..Default::default()
                         });
                     }
                 }
@@ -278,12 +302,23 @@ impl TableMutationAggregator {
                     self.default_cluster_key_id,
                 );
 
+                // Compaction and re-clustering only move rows around. For the other mutations,
+                // all the rows of the rewritten blocks are regarded as modified.
+                let modified_rows = match self.kind {
+                    MutationKind::Compact | MutationKind::Recluster => 0,
+                    _ => std::cmp::max(
+                        merged_statistics.row_count,
+                        self.removed_statistics.row_count,
+                    ),
+                };
+
                 ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
                     appended_segments,
                     replaced_segments,
                     removed_segment_indexes: std::mem::take(&mut self.removed_segment_indexes),
                     merged_statistics,
                     removed_statistics: std::mem::take(&mut self.removed_statistics),
+                    modified_rows,
                 })
             }
         };
@@ -326,8 +361,15 @@ impl TableMutationAggregator {
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
+                    for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                        if let Some(block_meta) = block_editor.get_mut(&idx) {
+                            let mut new_meta = block_meta.as_ref().clone();
+                            new_meta.deletion_vector = Some(deletion_vector);
+                            *block_meta = Arc::new(new_meta);
+                        }
+                    }
 
-                    if block_editor.is_empty() {
+                    if block_editor.is_empty() && segment_mutation.appended_blocks.is_empty() {
                         return Ok(SegmentLite {
                             index,
                             new_segment_info: None,
@@ -336,11 +378,13 @@ impl TableMutationAggregator {
                     }
 
                     // assign back the mutated blocks to segment
-                    let new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    let mut new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    new_blocks.extend(segment_mutation.appended_blocks);
                     (new_blocks, Some(segment_info.summary))
                 } else {
                     // use by compact.
                     assert!(segment_mutation.deleted_blocks.is_empty());
+                    assert!(segment_mutation.deletion_vectors.is_empty());
                     // There are more than 1 blocks, means that the blocks can no longer be compacted.
                     // They can be marked as perfect blocks.
                     all_perfect = segment_mutation.replaced_blocks.len() > 1;
@@ -403,20 +447,22 @@ impl TableMutationAggregator {
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVectorMeta)>,
+    appended_blocks: Vec<Arc<BlockMeta>>,
 }
 
 impl BlockMutations {
     fn new_replacement(block_idx: BlockIndex, block_meta: Arc<BlockMeta>) -> Self {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
-            deleted_blocks: vec![],
+            ..Default::default()
         }
     }
 
     fn new_deletion(block_idx: BlockIndex) -> Self {
         BlockMutations {
-            replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            ..Default::default()
         }
     }
 
@@ -35,8 +35,10 @@ use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
+use roaring::RoaringBitmap;
 
 use crate::io::write_data;
+use crate::io::write_deletion_vector;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::operations::common::BlockMetaIndex;
@@ -54,10 +56,16 @@ enum State {
         block: DataBlock,
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
+        deleted_rows: Option<RoaringBitmap>,
     },
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        deleted_rows: Option<RoaringBitmap>,
+    },
+    NeedWriteDeletionVector {
+        index: BlockMetaIndex,
+        deleted_rows: RoaringBitmap,
     },
 }
 
@@ -102,6 +110,9 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_ngram_type)?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -109,6 +120,10 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            // Only appends to analyzed tables keep their table statistics up to date on commit.
+            collect_column_hlls: matches!(kind, MutationKind::Insert)
+                && table.table_info.meta.statistics.analyzed_on.is_some(),
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -141,6 +156,22 @@ impl TransformSerializeBlock {
         };
         DataBlock::empty_with_meta(Box::new(meta))
     }
+
+    #[async_backtrace::framed]
+    async fn write_deletion_vector(
+        &self,
+        index: BlockMetaIndex,
+        deleted_rows: &RoaringBitmap,
+    ) -> Result<MutationLogEntry> {
+        let deletion_vector =
+            write_deletion_vector(&self.dal, &self.block_builder.meta_locations, deleted_rows)
+                .await?;
+        metrics_inc_deletion_vector_write_nums(1);
+        Ok(MutationLogEntry::DeletionVector {
+            index,
+            deletion_vector,
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -158,7 +189,10 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(
+            self.state,
+            State::Serialized { .. } | State::NeedWriteDeletionVector { .. }
+        ) {
             return Ok(Event::Async);
         }
 
@@ -212,6 +246,26 @@ impl Processor for TransformSerializeBlock {
                             block: input_data,
                             stats_type: serialize_block.stats_type,
                             index: Some(serialize_block.index),
+                            deleted_rows: None,
+                        };
+                        Ok(Event::Sync)
+                    }
+                }
+                SerializeDataMeta::SerializeDeletionVector(serialize_deletion_vector) => {
+                    if input_data.is_empty() {
+                        // only delete rows
+                        self.state = State::NeedWriteDeletionVector {
+                            index: serialize_deletion_vector.index,
+                            deleted_rows: serialize_deletion_vector.deleted_rows,
+                        };
+                        Ok(Event::Async)
+                    } else {
+                        // the updated rows are appended as a new block
+                        self.state = State::NeedSerialize {
+                            block: input_data,
+                            stats_type: serialize_deletion_vector.stats_type,
+                            index: Some(serialize_deletion_vector.index),
+                            deleted_rows: Some(serialize_deletion_vector.deleted_rows),
                         };
                         Ok(Event::Sync)
                     }
@@ -236,6 +290,7 @@ impl Processor for TransformSerializeBlock {
                 block: input_data,
                 stats_type: ClusterStatsGenType::Generally,
                 index: None,
+                deleted_rows: None,
             };
             Ok(Event::Sync)
         }
@@ -247,6 +302,7 @@ impl Processor for TransformSerializeBlock {
                 block,
                 stats_type,
                 index,
+                deleted_rows,
             } => {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
@@ -262,7 +318,11 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    deleted_rows,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -272,7 +332,11 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                deleted_rows,
+            } => {
                 let start = Instant::now();
                 // write block data.
                 let raw_block_data = serialized.block_raw_data;
@@ -307,25 +371,45 @@ impl Processor for TransformSerializeBlock {
                     }
                 }
 
-                let data_block = if let Some(index) = index {
-                    Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                let data_block = match (index, deleted_rows) {
+                    (Some(index), Some(deleted_rows)) => {
+                        let segment_idx = index.segment_idx;
+                        let deletion_vector =
+                            self.write_deletion_vector(index, &deleted_rows).await?;
+                        let meta = MutationLogs {
+                            entries: vec![deletion_vector, MutationLogEntry::AppendedBlock {
+                                segment_idx,
+                                block_meta: Arc::new(serialized.block_meta),
+                            }],
+                        };
+                        DataBlock::empty_with_meta(Box::new(meta))
+                    }
+                    (Some(index), None) => Self::mutation_logs(MutationLogEntry::ReplacedBlock {
                         index,
                         block_meta: Arc::new(serialized.block_meta),
-                    })
-                } else {
-                    let progress_values = ProgressValues {
-                        rows: serialized.block_meta.row_count as usize,
-                        bytes: serialized.block_meta.block_size as usize,
-                    };
-                    self.block_builder
-                        .ctx
-                        .get_write_progress()
-                        .incr(&progress_values);
+                    }),
+                    (None, _) => {
+                        let progress_values = ProgressValues {
+                            rows: serialized.block_meta.row_count as usize,
+                            bytes: serialized.block_meta.block_size as usize,
+                        };
+                        self.block_builder
+                            .ctx
+                            .get_write_progress()
+                            .incr(&progress_values);
 
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
+                        DataBlock::empty_with_meta(Box::new(serialized.block_meta))
+                    }
                 };
                 self.output_data = Some(data_block);
             }
+            State::NeedWriteDeletionVector {
+                index,
+                deleted_rows,
+            } => {
+                let entry = self.write_deletion_vector(index, &deleted_rows).await?;
+                self.output_data = Some(Self::mutation_logs(entry));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -31,6 +31,7 @@ use databend_common_sql::field_default_value;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::info;
@@ -59,6 +60,12 @@ pub trait SnapshotGenerator {
         cluster_key_meta: Option<ClusterKey>,
         previous: Option<Arc<TableSnapshot>>,
     ) -> Result<TableSnapshot>;
+
+    /// The sketches of the column values written by the commit, to be merged into the
+    /// table statistics of the new snapshot.
+    fn written_column_hlls(&self) -> Option<&HashMap<ColumnId, MetaHLL>> {
+        None
+    }
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug, PartialEq, Default)]
@@ -69,6 +76,9 @@ pub struct SnapshotChanges {
 
     pub merged_statistics: Statistics,
     pub removed_statistics: Statistics,
+
+    /// Number of rows inserted, updated or deleted by the mutation.
+    pub modified_rows: u64,
 }
 
 impl SnapshotChanges {
@@ -261,7 +271,7 @@ impl SnapshotGenerator for MutationGenerator {
                         default_cluster_key_id,
                     );
                     deduct_statistics_mut(&mut new_summary, &ctx.removed_statistics);
-                    let new_snapshot = TableSnapshot::new(
+                    let mut new_snapshot = TableSnapshot::new(
                         Uuid::new_v4(),
                         &previous.timestamp,
                         Some((previous.snapshot_id, previous.format_version)),
@@ -271,6 +281,9 @@ impl SnapshotGenerator for MutationGenerator {
                         cluster_key_meta,
                         previous.table_statistics_location.clone(),
                     );
+                    new_snapshot.rows_modified_since_analyze =
+                        previous.rows_modified_since_analyze + ctx.modified_rows;
+                    new_snapshot.analyzed_on = previous.analyzed_on;
                     return Ok(new_snapshot);
                 }
             }
@@ -293,6 +306,7 @@ pub struct AppendGenerator {
     leaf_default_values: HashMap<ColumnId, Scalar>,
     overwrite: bool,
     conflict_resolve_ctx: Option<ConflictResolveContext>,
+    written_column_hlls: Option<HashMap<ColumnId, MetaHLL>>,
 }
 
 impl AppendGenerator {
@@ -302,6 +316,7 @@ impl AppendGenerator {
             leaf_default_values: HashMap::new(),
             overwrite,
             conflict_resolve_ctx: None,
+            written_column_hlls: None,
         }
     }
 
@@ -342,6 +357,16 @@ impl AppendGenerator {
 #[async_trait::async_trait]
 impl SnapshotGenerator for AppendGenerator {
     fn set_conflict_resolve_context(&mut self, ctx: ConflictResolveContext) {
+        if let ConflictResolveContext::AppendOnly((merged, _)) = &ctx {
+            // The sketches of the blocks written by other nodes of the cluster are not
+            // collected here, partial sketches are dropped instead of being merged.
+            let appended_rows = merged.merged_statistics.row_count;
+            self.written_column_hlls = self
+                .ctx
+                .take_written_column_hlls()
+                .filter(|(rows, _)| !self.overwrite && *rows == appended_rows)
+                .map(|(_, hlls)| hlls);
+        }
         self.conflict_resolve_ctx = Some(ctx);
     }
 
@@ -378,6 +403,8 @@ impl SnapshotGenerator for AppendGenerator {
         let mut prev_timestamp = None;
         let mut prev_snapshot_id = None;
         let mut table_statistics_location = None;
+        let mut rows_modified_since_analyze = snapshot_merged.merged_statistics.row_count;
+        let mut analyzed_on = None;
         let mut new_segments = snapshot_merged.merged_segments.clone();
         let mut new_summary = snapshot_merged.merged_statistics.clone();
 
@@ -385,8 +412,10 @@ impl SnapshotGenerator for AppendGenerator {
             prev_timestamp = snapshot.timestamp;
             prev_snapshot_id = Some((snapshot.snapshot_id, snapshot.format_version));
             table_statistics_location = snapshot.table_statistics_location.clone();
+            analyzed_on = snapshot.analyzed_on;
 
             if !self.overwrite {
+                rows_modified_since_analyze += snapshot.rows_modified_since_analyze;
                 let mut summary = snapshot.summary.clone();
 
                 let leaf_fields = schema.leaf_fields();
@@ -459,7 +488,7 @@ impl SnapshotGenerator for AppendGenerator {
         let auto_compact = imperfect_count >= auto_compaction_imperfect_blocks_threshold;
         self.ctx.set_need_compact_after_write(auto_compact);
 
-        Ok(TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -468,7 +497,14 @@ impl SnapshotGenerator for AppendGenerator {
             new_segments,
             cluster_key_meta,
             table_statistics_location,
-        ))
+        );
+        new_snapshot.rows_modified_since_analyze = rows_modified_since_analyze;
+        new_snapshot.analyzed_on = analyzed_on;
+        Ok(new_snapshot)
+    }
+
+    fn written_column_hlls(&self) -> Option<&HashMap<ColumnId, MetaHLL>> {
+        self.written_column_hlls.as_ref()
     }
 }
 
@@ -236,6 +236,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    self.deletion_vectors_enabled(),
                 )
             },
             max_threads,
@@ -315,6 +316,7 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
@@ -17,6 +17,7 @@ use std::hash::BuildHasher;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::Utc;
 use databend_common_cache::CountableMeter;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -34,6 +35,8 @@ use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use log::error;
 use log::warn;
 
+use crate::io::iceberg_metadata_enabled;
+use crate::io::segment_manifest_location;
 use crate::io::Files;
 use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
@@ -62,7 +65,7 @@ impl FuseTable {
                 return Ok(None);
             }
         }
-        let root_snapshot_info = root_snapshot_info_op.unwrap();
+        let mut root_snapshot_info = root_snapshot_info_op.unwrap();
         if root_snapshot_info.snapshot_lite.timestamp.is_none() {
             return Err(ErrorCode::StorageOther(format!(
                 "gc: snapshot timestamp is none, snapshot location: {}",
@@ -70,6 +73,20 @@ impl FuseTable {
             )));
         }
 
+        // The files referenced by tags, branches and clones are kept as if they
+        // were referenced by the root snapshot.
+        let ref_locations = self.read_ref_locations(ctx).await?;
+        let referenced_locations = &mut root_snapshot_info.referenced_locations;
+        referenced_locations
+            .block_location
+            .extend(ref_locations.locations.block_location);
+        referenced_locations
+            .bloom_location
+            .extend(ref_locations.locations.bloom_location);
+        referenced_locations
+            .deletion_vector_location
+            .extend(ref_locations.locations.deletion_vector_location);
+
         let snapshots_io = SnapshotsIO::create(ctx.clone(), self.operator.clone());
         let location_gen = self.meta_location_generator();
         let purged_snapshot_limit = limit.unwrap_or(snapshot_files.len());
@@ -133,17 +150,26 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
+                    if !ref_locations.snapshots.contains(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                    }
                     purged_snapshot_count += 1;
                 }
 
-                let diff: HashSet<_> = s.segments.difference(&base_segments).cloned().collect();
+                let diff: HashSet<_> = s
+                    .segments
+                    .difference(&base_segments)
+                    .filter(|loc| !ref_locations.segments.contains(loc))
+                    .cloned()
+                    .collect();
                 segments_to_be_purged.extend(diff);
 
-                if s.table_statistics_location.is_some()
-                    && s.table_statistics_location != base_ts_location_opt
-                {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if Some(&ts) != base_ts_location_opt.as_ref()
+                        && !ref_locations.table_statistics.contains(&ts)
+                    {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
 
@@ -206,14 +232,22 @@ impl FuseTable {
                     if purged_snapshot_count >= purged_snapshot_limit {
                         break;
                     }
-                    snapshots_to_be_purged.insert(loc);
+                    if !ref_locations.snapshots.contains(&loc) {
+                        snapshots_to_be_purged.insert(loc);
+                    }
                     purged_snapshot_count += 1;
                 }
 
-                segments_to_be_purged.extend(s.segments);
+                segments_to_be_purged.extend(
+                    s.segments
+                        .into_iter()
+                        .filter(|loc| !ref_locations.segments.contains(loc)),
+                );
 
-                if s.table_statistics_location.is_some() {
-                    ts_to_be_purged.insert(s.table_statistics_location.unwrap());
+                if let Some(ts) = s.table_statistics_location {
+                    if !ref_locations.table_statistics.contains(&ts) {
+                        ts_to_be_purged.insert(ts);
+                    }
                 }
             }
             if dry_run {
@@ -253,6 +287,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &ref_locations,
                 &table_agg_index_ids,
             )
             .await?;
@@ -260,6 +295,83 @@ impl FuseTable {
         Ok(None)
     }
 
+    /// Collects the files referenced by the tags, branches and clones of the table.
+    ///
+    /// The ref of a clone is ignored once the cloned table is vacuumed, or once it is
+    /// an expired pending ref, the files it shares are then purged with the rest of the history.
+    pub async fn read_ref_locations(&self, ctx: &Arc<dyn TableContext>) -> Result<RefLocations> {
+        let mut ref_locations = RefLocations::default();
+        if self.table_info.meta.refs.is_empty() {
+            return Ok(ref_locations);
+        }
+
+        let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let now = Utc::now();
+        for (name, table_ref) in self.table_info.meta.refs.iter() {
+            if table_ref.is_expired_pending_clone(now) {
+                continue;
+            }
+            if let Some(table_id) = table_ref.cloned_table_id {
+                match catalog.get_table_meta_by_id(table_id).await {
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                    Err(e) => return Err(e),
+                    Ok(_) => {}
+                }
+            }
+
+            let location = table_ref.snapshot_location.clone();
+            let ver = TableMetaLocationGenerator::snapshot_version(location.as_str());
+            let params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver,
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "gc: snapshot {} of {} {} not found. table: {}, ident {}",
+                        location, table_ref.kind, name, self.table_info.desc, self.table_info.ident,
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+                .await?;
+            ref_locations.snapshots.insert(location);
+            ref_locations
+                .segments
+                .extend(snapshot.segments.iter().cloned());
+            if let Some(ts) = &snapshot.table_statistics_location {
+                ref_locations.table_statistics.insert(ts.clone());
+            }
+            ref_locations
+                .locations
+                .block_location
+                .extend(locations.block_location);
+            ref_locations
+                .locations
+                .bloom_location
+                .extend(locations.bloom_location);
+            ref_locations
+                .locations
+                .deletion_vector_location
+                .extend(locations.deletion_vector_location);
+        }
+        Ok(ref_locations)
+    }
+
+    /// A cloned table shares the files of its source table, which are only
+    /// purged by the table owning them.
+    pub fn is_owned_location(&self, location: &str) -> bool {
+        location.starts_with(&format!("{}/", self.meta_location_generator().prefix()))
+    }
+
     async fn read_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -351,10 +463,22 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(self.iceberg_manifests_of_segments(chunk.iter().map(|loc| &loc.0)));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.retain(|loc| self.is_owned_location(loc));
 
         Ok(())
     }
@@ -404,6 +528,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            // deletion vectors are plain files without cache, purge them together with blocks.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -445,15 +580,33 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        ref_locations: &RefLocations,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
+        // the root snapshot is pinned by a ref, keep it with all its files.
+        if ref_locations.snapshots.contains(&root_snapshot_location) {
+            return Ok(());
+        }
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| !ref_locations.segments.contains(loc))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        // the locations of the root snapshot were merged with the ones of refs.
+        let protected = &ref_locations.locations;
+        let mut root_location_tuple = root_location_tuple;
+        root_location_tuple
+            .block_location
+            .retain(|loc| !protected.block_location.contains(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| !protected.bloom_location.contains(loc));
+        root_location_tuple
+            .deletion_vector_location
+            .retain(|loc| !protected.deletion_vector_location.contains(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -464,10 +617,13 @@ impl FuseTable {
             }));
         }
 
+        let mut blocks_to_be_purged = root_location_tuple.block_location;
+        blocks_to_be_purged.extend(root_location_tuple.deletion_vector_location);
+
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
@@ -476,7 +632,9 @@ impl FuseTable {
 
         let mut ts_to_be_purged = HashSet::new();
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
-            ts_to_be_purged.insert(ts);
+            if !ref_locations.table_statistics.contains(&ts) {
+                ts_to_be_purged.insert(ts);
+            }
         }
         self.purge_ts_snapshots(
             ctx,
@@ -526,6 +684,12 @@ impl FuseTable {
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
+            let manifests_to_be_purged =
+                self.iceberg_manifests_of_segments(segments_to_be_purged.iter());
+            if !manifests_to_be_purged.is_empty() {
+                self.try_purge_location_files(ctx.clone(), manifests_to_be_purged)
+                    .await?;
+            }
             self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
                 ctx.clone(),
                 segments_to_be_purged,
@@ -535,6 +699,20 @@ impl FuseTable {
         Ok(())
     }
 
+    // The iceberg manifests of the segments, which are written once per segment if the
+    // iceberg metadata is enabled, see `write_iceberg_metadata`.
+    fn iceberg_manifests_of_segments<'a>(
+        &self,
+        segments: impl Iterator<Item = &'a String>,
+    ) -> HashSet<String> {
+        if !iceberg_metadata_enabled(self.table_info.options()) {
+            return HashSet::new();
+        }
+        segments
+            .map(|loc| segment_manifest_location(&self.meta_location_generator, loc))
+            .collect()
+    }
+
     async fn purge_ts_snapshots(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -588,7 +766,10 @@ impl FuseTable {
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
-        let locations = Vec::from_iter(locations_to_be_purged);
+        let locations = locations_to_be_purged
+            .into_iter()
+            .filter(|loc| self.is_owned_location(loc))
+            .collect::<Vec<_>>();
         fuse_file.remove_file_in_batch(&locations).await
     }
 
@@ -623,6 +804,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -646,12 +828,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -671,10 +855,20 @@ struct RootSnapshotInfo {
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
+/// The files kept alive by the refs of a table.
+#[derive(Default)]
+pub struct RefLocations {
+    pub snapshots: HashSet<String>,
+    pub segments: HashSet<Location>,
+    pub table_statistics: HashSet<String>,
+    pub locations: LocationTuple,
+}
+
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -682,16 +876,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
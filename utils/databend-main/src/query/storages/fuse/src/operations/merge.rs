@@ -90,6 +90,7 @@ impl FuseTable {
             io_request_semaphore,
             segment_locations,
             target_build_optimization,
+            self.deletion_vectors_enabled(),
         )?;
         Ok(aggregator.into_pipe_item())
     }
@@ -19,7 +19,6 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use ahash::AHashMap;
-use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::base::ProgressValues;
@@ -45,8 +44,12 @@ use databend_storages_common_table_meta::meta::SegmentInfo;
 use itertools::Itertools;
 use log::info;
 use opendal::Operator;
+use roaring::RoaringBitmap;
 
+use crate::io::deletion_vector_filter;
+use crate::io::read_deletion_vector;
 use crate::io::write_data;
+use crate::io::write_deletion_vector;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
@@ -69,6 +72,7 @@ struct AggregationContext {
     read_settings: ReadSettings,
     block_builder: BlockBuilder,
     block_reader: Arc<BlockReader>,
+    deletion_vectors_enabled: bool,
 }
 
 type UpdateOffset = HashSet<usize>;
@@ -96,6 +100,7 @@ impl MatchedAggregator {
         io_request_semaphore: Arc<Semaphore>,
         segment_locations: Vec<(SegmentIndex, Location)>,
         target_build_optimization: bool,
+        deletion_vectors_enabled: bool,
     ) -> Result<Self> {
         let segment_reader =
             MetaReaders::segment_info_reader(data_accessor.clone(), target_table_schema.clone());
@@ -122,6 +127,7 @@ impl MatchedAggregator {
                 data_accessor,
                 block_builder,
                 block_reader,
+                deletion_vectors_enabled,
             }),
             io_request_semaphore,
             segment_reader,
@@ -376,27 +382,18 @@ impl AggregationContext {
             bytes: 0,
         };
         self.ctx.get_write_progress().incr(&progress_values);
-        let origin_data_block = read_block(
-            self.write_settings.storage_format,
-            &self.block_reader,
-            block_meta,
-            &self.read_settings,
-            self.ctx.get_id(),
-        )
-        .await?;
-        let origin_num_rows = origin_data_block.num_rows();
-        // apply delete
-        let mut bitmap = MutableBitmap::new();
-        for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
-                bitmap.push(false);
-            } else {
-                bitmap.push(true);
+
+        // the rows deleted by the current deletion vector are kept deleted.
+        let mut deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => {
+                read_deletion_vector(&self.data_accessor, deletion_vector).await?
             }
-        }
-        let res_block = origin_data_block.filter_with_bitmap(&bitmap.into())?;
+            None => RoaringBitmap::new(),
+        };
+        deleted_rows.extend(modified_offsets.iter().map(|offset| *offset as u32));
 
-        if res_block.is_empty() {
+        let origin_num_rows = block_meta.row_count as usize;
+        if deleted_rows.len() as usize == origin_num_rows {
             metrics_inc_merge_into_deleted_blocks_counter(1);
             metrics_inc_merge_into_deleted_blocks_rows_counter(origin_num_rows as u32);
             return Ok(Some(MutationLogEntry::DeletedBlock {
@@ -408,6 +405,36 @@ impl AggregationContext {
             }));
         }
 
+        if self.deletion_vectors_enabled {
+            let deletion_vector = write_deletion_vector(
+                &self.data_accessor,
+                &self.block_builder.meta_locations,
+                &deleted_rows,
+            )
+            .await?;
+            metrics_inc_deletion_vector_write_nums(1);
+            return Ok(Some(MutationLogEntry::DeletionVector {
+                index: BlockMetaIndex {
+                    segment_idx,
+                    block_idx,
+                    inner: None,
+                },
+                deletion_vector,
+            }));
+        }
+
+        let origin_data_block = read_block(
+            self.write_settings.storage_format,
+            &self.block_reader,
+            block_meta,
+            &self.read_settings,
+            self.ctx.get_id(),
+        )
+        .await?;
+        // apply delete
+        let bitmap = deletion_vector_filter(&deleted_rows, origin_num_rows);
+        let res_block = origin_data_block.filter_with_bitmap(&bitmap)?;
+
         // serialization and compression is cpu intensive, send them to dedicated thread pool
         // and wait (asyncly, which will NOT block the executor thread)
         let block_builder = self.block_builder.clone();
@@ -15,6 +15,7 @@
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use roaring::RoaringBitmap;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactExtraInfo;
@@ -23,6 +24,7 @@ use crate::operations::mutation::DeletedSegmentInfo;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
+    SerializeDeletionVector(SerializeDeletionVector),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
 }
@@ -55,3 +57,29 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Mark the deleted rows of a block with a deletion vector instead of rewriting it.
+///
+/// The data block carried along with the meta contains the updated rows, if any,
+/// which are appended to the segment of the block as a new block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializeDeletionVector {
+    pub index: BlockMetaIndex,
+    pub stats_type: ClusterStatsGenType,
+    /// The offsets of all the deleted rows, including those of the previous deletion vector.
+    pub deleted_rows: RoaringBitmap,
+}
+
+impl SerializeDeletionVector {
+    pub fn create(
+        index: BlockMetaIndex,
+        stats_type: ClusterStatsGenType,
+        deleted_rows: RoaringBitmap,
+    ) -> Self {
+        SerializeDeletionVector {
+            index,
+            stats_type,
+            deleted_rows,
+        }
+    }
+}
@@ -32,7 +32,10 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use roaring::RoaringBitmap;
 
+use crate::io::deletion_vector_filter;
+use crate::io::read_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::mutation::ClusterStatsGenType;
@@ -46,7 +49,8 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        // the block data, and the rows deleted by the deletion vector of the block.
+        read_res: Vec<(MergeIOReadResult, Option<RoaringBitmap>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -146,13 +150,19 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deleted_rows), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
                         )?;
 
+                        // fold the deleted rows away.
+                        if let Some(deleted_rows) = deleted_rows {
+                            let filter = deletion_vector_filter(&deleted_rows, block.num_rows());
+                            block = block.filter_with_bitmap(&filter)?;
+                        }
+
                         if self.block_reader.update_stream_columns() {
                             let num_rows = block.num_rows();
                             let stream_meta = gen_mutation_stream_meta(None, &meta.location.0)?;
@@ -177,6 +187,12 @@ impl Processor for CompactSource {
                     DataBlock::concat(&blocks)?
                 };
 
+                if block.is_empty() {
+                    // all the rows of the blocks are deleted.
+                    self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
+                    return Ok(());
+                }
+
                 let meta = Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
                     index,
                     ClusterStatsGenType::Generally,
@@ -222,14 +238,25 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let data = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deleted_rows = match &block.deletion_vector {
+                                    Some(deletion_vector) => Some(
+                                        read_deletion_vector(
+                                            &block_reader.operator,
+                                            deletion_vector,
+                                        )
+                                        .await?,
+                                    ),
+                                    None => None,
+                                };
+                                Ok::<_, ErrorCode>((data, deleted_rows))
                             });
                         }
 
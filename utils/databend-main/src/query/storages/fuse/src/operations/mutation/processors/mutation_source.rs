@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::build_origin_block_row_num;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
@@ -42,8 +43,11 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::deletion_vector_filter;
+use crate::io::read_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
@@ -51,6 +55,7 @@ use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
 
@@ -90,9 +95,14 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    deletion_vectors_enabled: bool,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // the rows deleted by the current deletion vector of the block.
+    deleted_rows: Option<RoaringBitmap>,
+    // the rows deleted by the new deletion vector of the block.
+    new_deleted_rows: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -107,6 +117,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        deletion_vectors_enabled: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -119,10 +130,42 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            deletion_vectors_enabled,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deleted_rows: None,
+            new_deleted_rows: None,
         })))
     }
+
+    // Filter out the rows deleted by the current deletion vector of the block.
+    fn filter_deleted_rows(&self, data_block: DataBlock) -> Result<(DataBlock, Option<Bitmap>)> {
+        match &self.deleted_rows {
+            Some(deleted_rows) => {
+                let filter = deletion_vector_filter(deleted_rows, data_block.num_rows());
+                let data_block = data_block.filter_with_bitmap(&filter)?;
+                Ok((data_block, Some(filter)))
+            }
+            None => Ok((data_block, None)),
+        }
+    }
+
+    // The offsets of all the deleted rows of the block, after the rows selected by
+    // `predicate` are deleted. `offsets` maps the rows of the filtered block to the block.
+    fn merge_deleted_rows(
+        &self,
+        predicate: &Bitmap,
+        offsets: &Option<Vec<usize>>,
+    ) -> RoaringBitmap {
+        let mut deleted_rows = self.deleted_rows.clone().unwrap_or_default();
+        for (i, selected) in predicate.iter().enumerate() {
+            if selected {
+                let offset = offsets.as_ref().map_or(i, |offsets| offsets[i]);
+                deleted_rows.insert(offset as u32);
+            }
+        }
+        deleted_rows
+    }
 }
 
 #[async_trait::async_trait]
@@ -178,12 +221,19 @@ impl Processor for MutationSource {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::FilterData(part, read_res) => {
                 let chunks = read_res.columns_chunks()?;
-                let mut data_block = self.block_reader.deserialize_chunks_with_part_info(
+                let data_block = self.block_reader.deserialize_chunks_with_part_info(
                     part.clone(),
                     chunks,
                     &self.storage_format,
                 )?;
+                let origin_num_rows = data_block.num_rows();
+                let (mut data_block, live_filter) = self.filter_deleted_rows(data_block)?;
                 let num_rows = data_block.num_rows();
+                let offsets: Option<Vec<usize>> = live_filter.as_ref().map(|bitmap| {
+                    (0..origin_num_rows)
+                        .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
+                        .collect()
+                });
 
                 let fuse_part = FusePartInfo::from_part(&part)?;
                 if let Some(filter) = self.filter.as_ref() {
@@ -196,8 +246,9 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: offsets.clone(),
                             base_block_ids: None,
+                            matched_rows: None,
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -255,13 +306,39 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.deletion_vectors_enabled {
+                                    // mark the deleted rows instead of rewriting the block.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let deleted_rows =
+                                        self.merge_deleted_rows(&predicate_col, &offsets);
+                                    let meta =
+                                        Box::new(SerializeDataMeta::SerializeDeletionVector(
+                                            SerializeDeletionVector::create(
+                                                self.index.clone(),
+                                                self.stats_type.clone(),
+                                                deleted_rows,
+                                            ),
+                                        ));
+                                    self.state = State::Output(
+                                        self.ctx.get_partition(),
+                                        DataBlock::empty_with_meta(meta),
+                                    );
                                 } else {
                                     if self.block_reader.update_stream_columns {
+                                        let row_num = match (
+                                            build_origin_block_row_num(origin_num_rows),
+                                            &live_filter,
+                                        ) {
+                                            (Value::Column(column), Some(live_filter)) => {
+                                                Value::Column(column.filter(live_filter))
+                                            }
+                                            (row_num, _) => row_num,
+                                        };
                                         let row_num = BlockEntry::new(
                                             DataType::Nullable(Box::new(DataType::Number(
                                                 NumberDataType::UInt64,
                                             ))),
-                                            build_origin_block_row_num(num_rows),
+                                            row_num,
                                         );
                                         data_block.add_column(row_num);
                                     }
@@ -285,10 +362,25 @@ impl Processor for MutationSource {
                             }
 
                             MutationAction::Update => {
-                                data_block.add_column(BlockEntry::new(
-                                    DataType::Boolean,
-                                    Value::upcast(predicates),
-                                ));
+                                let mut filter = None;
+                                if self.deletion_vectors_enabled && affect_rows != num_rows {
+                                    // the updated rows are marked as deleted, and appended
+                                    // to the segment as a new block.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    self.new_deleted_rows =
+                                        Some(self.merge_deleted_rows(&predicate_col, &offsets));
+                                    data_block = data_block.filter_with_bitmap(&predicate_col)?;
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(Value::<BooleanType>::Scalar(true)),
+                                    ));
+                                    filter = Some(Value::Column(predicate_col));
+                                } else {
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(predicates),
+                                    ));
+                                }
                                 if self.remain_reader.is_none() {
                                     self.state = State::PerformOperator(
                                         data_block,
@@ -298,7 +390,7 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
-                                        filter: None,
+                                        filter,
                                     };
                                 }
                             }
@@ -331,9 +423,10 @@ impl Processor for MutationSource {
                         chunks,
                         &self.storage_format,
                     )?;
+                    let (remain_block, _) = self.filter_deleted_rows(remain_block)?;
 
                     let remain_block = if let Some(filter) = filter {
-                        // for deletion.
+                        // for deletion, or the updates with deletion vector.
                         remain_block.filter_boolean_value(&filter)?
                     } else {
                         remain_block
@@ -354,9 +447,19 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
-                    SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
-                ));
+                let inner_meta = match self.new_deleted_rows.take() {
+                    Some(deleted_rows) => Box::new(SerializeDataMeta::SerializeDeletionVector(
+                        SerializeDeletionVector::create(
+                            self.index.clone(),
+                            self.stats_type.clone(),
+                            deleted_rows,
+                        ),
+                    )),
+                    None => Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                    ))),
+                };
                 let meta: BlockMetaInfoPtr = if self.block_reader.update_stream_columns() {
                     Box::new(gen_mutation_stream_meta(Some(inner_meta), &path)?)
                 } else {
@@ -419,6 +522,16 @@ impl Processor for MutationSource {
                                 DataBlock::empty_with_meta(meta),
                             );
                         } else {
+                            self.deleted_rows = match &fuse_part.deletion_vector {
+                                Some(deletion_vector) => Some(
+                                    read_deletion_vector(
+                                        &self.block_reader.operator,
+                                        deletion_vector,
+                                    )
+                                    .await?,
+                                ),
+                                None => None,
+                            };
                             let read_res = self
                                 .block_reader
                                 .read_columns_data_by_merge_io(
@@ -130,6 +130,8 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
                 replaced_segments,
                 removed_statistics: self.removed_statistics.clone(),
                 merged_statistics,
+                // re-clustering does not modify any row
+                modified_rows: 0,
             });
 
         let meta = CommitMeta::new(
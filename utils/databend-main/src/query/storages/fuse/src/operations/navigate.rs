@@ -21,6 +21,7 @@ use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableRefKind;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::TableSnapshot;
@@ -56,25 +57,115 @@ impl FuseTable {
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<FuseTable>> {
-        let snapshot_location = if let Some(loc) = self.snapshot_loc().await? {
-            loc
+        match point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let location = self.history_location().await?;
+                let found = self
+                    .find_snapshot(location, |snapshot| {
+                        snapshot
+                            .snapshot_id
+                            .simple()
+                            .to_string()
+                            .as_str()
+                            .starts_with(snapshot_id.as_str())
+                    })
+                    .await?;
+                let (snapshot, loc, position) = found.ok_or_else(historical_data_not_found)?;
+                self.check_data_retention(
+                    snapshot.timestamp,
+                    position,
+                    &format!("Snapshot {}", snapshot_id),
+                )?;
+                self.load_at_snapshot(&snapshot, loc)
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                let location = self.history_location().await?;
+                let found = self
+                    .find_snapshot(location, |snapshot| {
+                        snapshot.timestamp.is_some_and(|ts| ts <= *time_point)
+                    })
+                    .await?;
+                let Some((snapshot, loc, position)) = found else {
+                    // Report the retention first, the history beyond it may be purged.
+                    self.check_data_retention(
+                        Some(*time_point),
+                        usize::MAX,
+                        &format!("Time point {}", time_point),
+                    )?;
+                    return Err(historical_data_not_found());
+                };
+                self.check_data_retention(
+                    Some(*time_point),
+                    position,
+                    &format!("Time point {}", time_point),
+                )?;
+                self.load_at_snapshot(&snapshot, loc)
+            }
+            NavigationPoint::Tag(name) => self.navigate_to_ref(TableRefKind::Tag, name).await,
+            NavigationPoint::Branch(name) => self.navigate_to_ref(TableRefKind::Branch, name).await,
+            NavigationPoint::Version(_) => Err(version_not_supported()),
+        }
+    }
+
+    /// Rejects points older than the retention period set on the table, unless the
+    /// snapshot at the point is one of the latest `num_snapshots_to_keep` snapshots,
+    /// which are kept by purge even if they are out of the retention period.
+    ///
+    /// `position` is the position of the snapshot in the history, the latest is 0.
+    /// Tables without the option keep the history until it is purged.
+    fn check_data_retention(
+        &self,
+        point: Option<DateTime<Utc>>,
+        position: usize,
+        point_desc: &str,
+    ) -> Result<()> {
+        let Some(days) = self.data_retention_time_in_days() else {
+            return Ok(());
+        };
+        if position < self.get_data_retention_num_snapshots_to_keep() {
+            return Ok(());
+        }
+        let retention_point = Utc::now() - Duration::days(days as i64);
+        if point.map_or(true, |point| point < retention_point) {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "{} is past the data retention period ({} days) of table '{}'",
+                point_desc, days, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn history_location(&self) -> Result<String> {
+        if let Some(loc) = self.snapshot_loc().await? {
+            Ok(loc)
         } else {
             // not an error?
-            return Err(ErrorCode::TableHistoricalDataNotFound(
+            Err(ErrorCode::TableHistoricalDataNotFound(
                 "Empty Table has no historical data",
-            ));
-        };
-
-        match point {
-            NavigationPoint::SnapshotID(snapshot_id) => Ok(self
-                .navigate_to_snapshot(snapshot_location, snapshot_id.as_str())
-                .await?),
-            NavigationPoint::TimePoint(time_point) => Ok(self
-                .navigate_to_time_point(snapshot_location, *time_point)
-                .await?),
+            ))
         }
     }
 
+    /// Loads the table at the snapshot pinned by the tag or branch `name`.
+    ///
+    /// Refs are kept in the table meta, so they are resolved even if the
+    /// referenced snapshot is no longer reachable from the current one.
+    #[async_backtrace::framed]
+    pub async fn navigate_to_ref(&self, kind: TableRefKind, name: &str) -> Result<Arc<FuseTable>> {
+        let table_ref = match self.table_info.meta.refs.get(name) {
+            Some(table_ref) if table_ref.kind == kind => table_ref,
+            _ => {
+                return Err(ErrorCode::UnknownTableRef(format!(
+                    "Unknown {} '{}' of table '{}'",
+                    kind, name, self.table_info.desc
+                )));
+            }
+        };
+        self.find(table_ref.snapshot_location.clone(), |_| true)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_to_snapshot(
         &self,
@@ -93,70 +184,93 @@ impl FuseTable {
     }
 
     #[async_backtrace::framed]
-    pub async fn find<P>(&self, location: String, mut pred: P) -> Result<Arc<FuseTable>>
+    pub async fn find<P>(&self, location: String, pred: P) -> Result<Arc<FuseTable>>
     where P: FnMut(&TableSnapshot) -> bool {
+        match self.find_snapshot(location, pred).await? {
+            Some((snapshot, loc, _)) => self.load_at_snapshot(&snapshot, loc),
+            None => Err(historical_data_not_found()),
+        }
+    }
+
+    /// Finds the latest snapshot in the history that matches `pred`, along with its
+    /// location and its position in the history, the latest snapshot is at 0.
+    #[async_backtrace::framed]
+    async fn find_snapshot<P>(
+        &self,
+        location: String,
+        mut pred: P,
+    ) -> Result<Option<(Arc<TableSnapshot>, String, usize)>>
+    where
+        P: FnMut(&TableSnapshot) -> bool,
+    {
         let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
         let reader = MetaReaders::table_snapshot_reader(self.get_operator());
         // grab the table history as stream
         // snapshots are order by timestamp DESC.
         let mut snapshot_stream = reader.snapshot_history(
-            location,
+            location.clone(),
             snapshot_version,
             self.meta_location_generator().clone(),
         );
 
         // Find the instant which matches the given `time_point`.
-        let mut instant = None;
-        while let Some(snapshot_with_version) = snapshot_stream.try_next().await? {
-            if pred(snapshot_with_version.0.as_ref()) {
-                instant = Some(snapshot_with_version);
-                break;
+        //
+        // The first snapshot is kept at the given location, which may be owned
+        // by another table (e.g. the source of a cloned table); the rest of the
+        // history is located by the generator of this table.
+        let mut snapshot_location = Some(location);
+        let mut position = 0;
+        while let Some((snapshot, format_version)) = snapshot_stream.try_next().await? {
+            let loc = match snapshot_location.take() {
+                Some(loc) => loc,
+                None => self
+                    .meta_location_generator
+                    .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?,
+            };
+            if pred(snapshot.as_ref()) {
+                return Ok(Some((snapshot, loc, position)));
             }
+            position += 1;
         }
+        Ok(None)
+    }
 
-        if let Some((snapshot, format_version)) = instant {
-            // Load the table instance by the snapshot
-
-            // The `seq` of ident that we cloned here is JUST a place holder
-            // we should NOT use it other than a pure place holder.
-            let mut table_info = self.table_info.clone();
-
-            // There are more to be kept in snapshot, like engine_options, ordering keys...
-            // or we could just keep a clone of TableMeta in the snapshot.
-            //
-            // currently, here are what we can recovery from the snapshot:
-
-            // 1. the table schema
-            table_info.meta.schema = Arc::new(snapshot.schema.clone());
-
-            // 2. the table option `snapshot_location`
-            let loc = self
-                .meta_location_generator
-                .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
-            table_info
-                .meta
-                .options
-                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
-
-            // 3. The statistics
-            let summary = &snapshot.summary;
-            table_info.meta.statistics = TableStatistics {
-                number_of_rows: summary.row_count,
-                data_bytes: summary.uncompressed_byte_size,
-                compressed_data_bytes: summary.compressed_byte_size,
-                index_data_bytes: summary.index_size,
-                number_of_segments: Some(snapshot.segments.len() as u64),
-                number_of_blocks: Some(summary.block_count),
-            };
+    /// Loads the table instance by the snapshot.
+    fn load_at_snapshot(&self, snapshot: &TableSnapshot, loc: String) -> Result<Arc<FuseTable>> {
+        // The `seq` of ident that we cloned here is JUST a place holder
+        // we should NOT use it other than a pure place holder.
+        let mut table_info = self.table_info.clone();
 
-            // let's instantiate it
-            let table = FuseTable::do_create(table_info)?;
-            Ok(table.into())
-        } else {
-            Err(ErrorCode::TableHistoricalDataNotFound(
-                "No historical data found at given point",
-            ))
-        }
+        // There are more to be kept in snapshot, like engine_options, ordering keys...
+        // or we could just keep a clone of TableMeta in the snapshot.
+        //
+        // currently, here are what we can recovery from the snapshot:
+
+        // 1. the table schema
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+
+        // 2. the table option `snapshot_location`
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
+
+        // 3. The statistics
+        let summary = &snapshot.summary;
+        table_info.meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(summary.block_count),
+            rows_modified_since_analyze: Some(snapshot.rows_modified_since_analyze),
+            analyzed_on: snapshot.analyzed_on,
+        };
+
+        // let's instantiate it
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
     }
 
     #[async_backtrace::framed]
@@ -165,8 +279,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         instant: Option<NavigationPoint>,
     ) -> Result<(Arc<FuseTable>, Vec<String>)> {
-        let retention =
-            Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64);
+        let retention = self.get_data_retention_period(ctx.as_ref())?;
         let root_snapshot = if let Some(snapshot) = self.read_table_snapshot().await? {
             snapshot
         } else {
@@ -178,6 +291,15 @@ impl FuseTable {
         assert!(root_snapshot.timestamp.is_some());
         let mut time_point = root_snapshot.timestamp.unwrap() - retention;
 
+        // The latest `num_snapshots_to_keep` snapshots survive the purge,
+        // even if they are out of the retention period.
+        let num_snapshots_to_keep = self.get_data_retention_num_snapshots_to_keep();
+        if num_snapshots_to_keep > 0 {
+            if let Some(keep_point) = self.snapshots_to_keep_point(num_snapshots_to_keep).await? {
+                time_point = std::cmp::min(time_point, keep_point - Duration::nanoseconds(1));
+            }
+        }
+
         let (location, files) = match instant {
             Some(NavigationPoint::TimePoint(point)) => {
                 time_point = std::cmp::min(point, time_point);
@@ -187,6 +309,24 @@ impl FuseTable {
                 self.list_by_snapshot_id(snapshot_id.as_str(), time_point)
                     .await
             }
+            Some(point @ NavigationPoint::Tag(_)) | Some(point @ NavigationPoint::Branch(_)) => {
+                let snapshot_id = match self
+                    .navigate_to(&point)
+                    .await?
+                    .read_table_snapshot()
+                    .await?
+                {
+                    Some(snapshot) => snapshot.snapshot_id.simple().to_string(),
+                    None => {
+                        return Err(ErrorCode::TableHistoricalDataNotFound(
+                            "No historical data found at given point",
+                        ));
+                    }
+                };
+                self.list_by_snapshot_id(snapshot_id.as_str(), time_point)
+                    .await
+            }
+            Some(NavigationPoint::Version(_)) => Err(version_not_supported()),
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -195,6 +335,39 @@ impl FuseTable {
         Ok((table, files))
     }
 
+    /// Returns the time point that the purge must not go beyond to keep the `n` latest
+    /// snapshots of the committed history, the orphan snapshots are not counted.
+    ///
+    /// The snapshots newer than the time point survive the purge, and so does the latest
+    /// snapshot older than it, which is taken as the base snapshot of the purge.
+    #[async_backtrace::framed]
+    async fn snapshots_to_keep_point(&self, n: usize) -> Result<Option<DateTime<Utc>>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Err(historical_data_not_found());
+        };
+
+        let mut visited = 0;
+        let mut keep_point = None;
+        let found = self
+            .find_snapshot(location, |snapshot| {
+                visited += 1;
+                if visited + 1 == n {
+                    keep_point = Some(snapshot.timestamp);
+                }
+                visited > n
+            })
+            .await?;
+        // Nothing can be purged if there are no more snapshots than the kept ones.
+        if found.is_none() {
+            return Err(historical_data_not_found());
+        }
+        match keep_point {
+            None => Ok(None),
+            Some(Some(timestamp)) => Ok(Some(timestamp)),
+            Some(None) => Err(historical_data_not_found()),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn list_by_time_point(
         &self,
@@ -301,3 +474,13 @@ impl FuseTable {
         Ok(file_list.into_iter().map(|v| v.0).collect())
     }
 }
+
+fn historical_data_not_found() -> ErrorCode {
+    ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+}
+
+fn version_not_supported() -> ErrorCode {
+    ErrorCode::Unimplemented(
+        "Navigating by VERSION is not supported for fuse table, use SNAPSHOT instead",
+    )
+}
@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_index::statistics_to_domain;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+
+use super::util::need_reserve_block_info;
+use crate::io::BlockReader;
+use crate::FusePartInfo;
+
+/// Answer the blocks fully matching the filters with their statistics instead of reading them.
+///
+/// It's enabled only if the scan just feeds `MIN`/`MAX`/`COUNT` aggregates without group by
+/// (see `PushDownInfo::agg_by_statistics`). The results of such aggregates only depend on the
+/// min/max values, the null counts and the number of rows of the block, so a block can be replaced
+/// by a few blocks of constant columns: for each column the first row is the min value,
+/// the following non-NULL rows are the max value and the rest are NULLs.
+pub struct AggregateByStatistics {
+    func_ctx: FunctionContext,
+    table_schema: Arc<TableSchema>,
+    filter: Option<Expr<String>>,
+    // The name and data type of the output columns.
+    fields: Vec<(String, DataType)>,
+}
+
+impl AggregateByStatistics {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        table_schema: Arc<TableSchema>,
+        block_reader: &BlockReader,
+    ) -> Result<Option<Self>> {
+        let Some(push_downs) = &plan.push_downs else {
+            return Ok(None);
+        };
+        if !push_downs.agg_by_statistics
+            || !push_downs.is_deterministic
+            || push_downs.prewhere.is_some()
+            || push_downs.virtual_columns.is_some()
+            || push_downs.agg_index.is_some()
+            || block_reader.query_internal_columns()
+            || block_reader.update_stream_columns()
+            || need_reserve_block_info(ctx.clone(), plan.table_index).0
+        {
+            return Ok(None);
+        }
+
+        let mut output_schema = plan.schema().as_ref().clone();
+        output_schema.remove_internal_fields();
+        let fields = output_schema
+            .fields()
+            .iter()
+            .map(|field| (field.name().clone(), DataType::from(field.data_type())))
+            .collect();
+        let filter = push_downs
+            .filters
+            .as_ref()
+            .map(|filters| filters.filter.as_expr(&BUILTIN_FUNCTIONS));
+
+        Ok(Some(AggregateByStatistics {
+            func_ctx: ctx.get_function_context()?,
+            table_schema,
+            filter,
+            fields,
+        }))
+    }
+
+    /// Build the blocks having the same `MIN`/`MAX`/`COUNT` as the part.
+    ///
+    /// Returns `None` if the part needs to be read, i.e. it partially matches the filters
+    /// or some statistics are missing.
+    pub fn build_blocks(&self, part: &PartInfoPtr) -> Result<Option<Vec<DataBlock>>> {
+        let part = FusePartInfo::from_part(part)?;
+        let Some(columns_stat) = &part.columns_stat else {
+            return Ok(None);
+        };
+        let num_rows = part.nums_rows;
+        // The statistics of the block do not exclude the rows deleted by the deletion vector.
+        if num_rows == 0 || part.range().is_some() || part.deletion_vector.is_some() {
+            return Ok(None);
+        }
+
+        let column_stat = |name: &String| -> Option<&ColumnStatistics> {
+            match self.table_schema.leaf_columns_of(name).as_slice() {
+                [column_id] => columns_stat.get(column_id),
+                _ => None,
+            }
+        };
+
+        if let Some(filter) = &self.filter {
+            let mut input_domains = HashMap::new();
+            for (name, ty) in filter.column_refs() {
+                let Some(stat) = column_stat(&name) else {
+                    return Ok(None);
+                };
+                input_domains.insert(name, statistics_to_domain(vec![stat], &ty));
+            }
+            let (filter, _) = ConstantFolder::fold_with_domain(
+                filter,
+                &input_domains,
+                &self.func_ctx,
+                &BUILTIN_FUNCTIONS,
+            );
+            if !matches!(filter, Expr::Constant {
+                scalar: Scalar::Boolean(true),
+                ..
+            }) {
+                return Ok(None);
+            }
+        }
+
+        let mut stats = Vec::with_capacity(self.fields.len());
+        for (name, data_type) in self.fields.iter() {
+            let Some(stat) = column_stat(name) else {
+                return Ok(None);
+            };
+            let num_non_nulls = num_rows.saturating_sub(stat.null_count as usize);
+            let valid = match (stat.min.is_null(), stat.max.is_null()) {
+                (true, true) => num_non_nulls == 0,
+                (false, false) => {
+                    stat.min.as_ref().infer_data_type() == data_type.remove_nullable()
+                }
+                _ => false,
+            };
+            if !valid || (num_non_nulls < num_rows && !data_type.is_nullable()) {
+                return Ok(None);
+            }
+            stats.push((stat, num_non_nulls));
+        }
+
+        // Split the rows into ranges in which each column is constant.
+        let mut bounds = vec![0, 1, num_rows];
+        bounds.extend(stats.iter().map(|(_, num_non_nulls)| *num_non_nulls));
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let blocks = bounds
+            .windows(2)
+            .map(|range| {
+                let (start, end) = (range[0], range[1]);
+                let columns = self
+                    .fields
+                    .iter()
+                    .zip(stats.iter())
+                    .map(|((_, data_type), (stat, num_non_nulls))| {
+                        let value = if start >= *num_non_nulls {
+                            Scalar::Null
+                        } else if start == 0 {
+                            stat.min.clone()
+                        } else {
+                            stat.max.clone()
+                        };
+                        BlockEntry::new(data_type.clone(), Value::Scalar(value))
+                    })
+                    .collect();
+                DataBlock::new(columns, end - start)
+            })
+            .collect();
+
+        Ok(Some(blocks))
+    }
+}
@@ -35,6 +35,7 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
+use crate::operations::read::AggregateByStatistics;
 use crate::operations::read::DeserializeDataTransform;
 use crate::operations::read::NativeDeserializeDataTransform;
 use crate::operations::read::ReadNativeDataSource;
@@ -152,6 +153,13 @@ pub fn build_fuse_parquet_source_pipeline(
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
+    let agg_by_statistics = Arc::new(AggregateByStatistics::try_create(
+        ctx.clone(),
+        plan,
+        table_schema.clone(),
+        &block_reader,
+    )?);
+
     let mut source_builder = SourcePipeBuilder::create();
 
     match block_reader.support_blocking_api() {
@@ -173,6 +181,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        agg_by_statistics.clone(),
                     )?,
                 );
             }
@@ -198,6 +207,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        agg_by_statistics.clone(),
                     )?,
                 );
             }
@@ -314,6 +324,7 @@ pub(crate) fn fill_internal_column_meta(
         snapshot_location: block_meta.snapshot_location.clone(),
         offsets,
         base_block_ids,
+        matched_rows: block_meta.matched_rows.clone(),
     };
 
     let meta: Option<BlockMetaInfoPtr> = Some(Box::new(internal_column_meta));
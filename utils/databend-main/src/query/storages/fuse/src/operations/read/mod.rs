@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregate_by_statistics;
 mod fuse_rows_fetcher;
 pub mod fuse_source;
 mod native_data_source;
@@ -26,6 +27,7 @@ mod runtime_filter_prunner;
 
 mod data_source_with_meta;
 mod util;
+pub use aggregate_by_statistics::AggregateByStatistics;
 pub use fuse_rows_fetcher::build_row_fetcher_pipeline;
 pub use fuse_source::build_fuse_parquet_source_pipeline;
 pub use native_data_source_deserializer::NativeDeserializeDataTransform;
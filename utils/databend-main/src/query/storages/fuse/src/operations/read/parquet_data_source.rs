@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::DataBlock;
 
 use crate::io::MergeIOReadResult;
 use crate::io::VirtualMergeIOReadResult;
@@ -21,7 +23,16 @@ use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum ParquetDataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    // The last item is the filter of the rows that are not deleted by the deletion vector.
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            Option<Bitmap>,
+        ),
+    ),
+    // The blocks built from the block statistics, see `AggregateByStatistics`.
+    Statistics(Vec<DataBlock>),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
@@ -260,7 +260,23 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Statistics(mut blocks) => {
+                    // Output the blocks one by one.
+                    let block = blocks.remove(0);
+                    if !blocks.is_empty() {
+                        self.parts.push(part);
+                        self.chunks.push(ParquetDataSource::Statistics(blocks));
+                    }
+
+                    let progress_values = ProgressValues {
+                        rows: block.num_rows(),
+                        bytes: block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+
+                    self.output_data = Some(block);
+                }
+                ParquetDataSource::Normal((data, virtual_data, deletion_filter)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -276,13 +292,20 @@ impl Processor for DeserializeDataTransform {
 
                     let origin_num_rows = data_block.num_rows();
 
-                    let mut filter = None;
+                    // The filter is applied to the whole block at once, so that the offsets
+                    // of the rows kept are still the offsets in the block.
+                    let mut filter = deletion_filter;
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
+                            filter = Some(match filter {
+                                Some(filter) => filter.bitand(&bitmap),
+                                None => bitmap,
+                            });
                         }
                     }
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
+                    }
 
                     // Add optional virtual columns
                     if let Some(virtual_reader) = self.virtual_reader.as_ref() {
@@ -32,8 +32,12 @@ use databend_common_pipeline_sources::SyncSourcer;
 use databend_common_sql::IndexType;
 use log::debug;
 
+use super::aggregate_by_statistics::AggregateByStatistics;
 use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::deletion_vector_filter;
+use crate::io::read_deletion_vector;
+use crate::io::read_deletion_vector_sync;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
@@ -56,6 +60,7 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    agg_by_statistics: Arc<Option<AggregateByStatistics>>,
 
     table_schema: Arc<TableSchema>,
 }
@@ -72,6 +77,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        agg_by_statistics: Arc<Option<AggregateByStatistics>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         let func_ctx = ctx.get_function_context()?;
@@ -88,6 +94,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                agg_by_statistics,
                 table_schema,
             })
         } else {
@@ -105,6 +112,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                agg_by_statistics,
                 table_schema,
             })))
         }
@@ -136,8 +144,28 @@ impl SyncSource for ReadParquetDataSource<true> {
                     return Ok(Some(DataBlock::empty()));
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                if let Some(agg_by_statistics) = self.agg_by_statistics.as_ref() {
+                    if let Some(blocks) = agg_by_statistics.build_blocks(&part)? {
+                        // Answer from the block statistics.
+                        return Ok(Some(DataBlock::empty_with_meta(
+                            DataSourceWithMeta::create(vec![part], vec![
+                                ParquetDataSource::Statistics(blocks),
+                            ]),
+                        )));
+                    }
+                }
+
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index and the virtual columns are built from all the rows
+                // of the block, they can not be used if some rows are deleted.
+                let has_deletion_vector = fuse_part.deletion_vector.is_some();
+
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -157,8 +185,12 @@ impl SyncSource for ReadParquetDataSource<true> {
                 }
 
                 // If virtual column file exists, read the data from the virtual columns directly.
-                let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let virtual_source = if let Some(virtual_reader) = self
+                    .virtual_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -182,10 +214,22 @@ impl SyncSource for ReadParquetDataSource<true> {
                     ignore_column_ids,
                 )?;
 
+                let deletion_filter = match &fuse_part.deletion_vector {
+                    Some(deletion_vector) => {
+                        let deleted_rows = read_deletion_vector_sync(
+                            &self.block_reader.operator,
+                            deletion_vector,
+                        )?;
+                        Some(deletion_vector_filter(&deleted_rows, fuse_part.nums_rows))
+                    }
+                    None => None,
+                };
+
                 Ok(Some(DataBlock::empty_with_meta(
                     DataSourceWithMeta::create(vec![part], vec![ParquetDataSource::Normal((
                         source,
                         virtual_source,
+                        deletion_filter,
                     ))]),
                 )))
             }
@@ -242,6 +286,8 @@ impl Processor for ReadParquetDataSource<false> {
                     .get_min_max_runtime_filter_with_id(self.table_index),
             );
             let mut fuse_part_infos = Vec::with_capacity(parts.len());
+            let mut statistics_parts = vec![];
+            let mut statistics_chunks = vec![];
             for part in parts.into_iter() {
                 if runtime_filter_pruner(
                     self.table_schema.clone(),
@@ -252,6 +298,15 @@ impl Processor for ReadParquetDataSource<false> {
                     continue;
                 }
 
+                if let Some(agg_by_statistics) = self.agg_by_statistics.as_ref() {
+                    if let Some(blocks) = agg_by_statistics.build_blocks(&part)? {
+                        // Answer from the block statistics.
+                        statistics_parts.push(part);
+                        statistics_chunks.push(ParquetDataSource::Statistics(blocks));
+                        continue;
+                    }
+                }
+
                 fuse_part_infos.push(part.clone());
                 let block_reader = self.block_reader.clone();
                 let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
@@ -261,8 +316,15 @@ impl Processor for ReadParquetDataSource<false> {
                 chunks.push(async move {
                     databend_common_base::runtime::spawn(async move {
                         let part = FusePartInfo::from_part(&part)?;
-
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index and the virtual columns are built from all the
+                        // rows of the block, they can not be used if some rows are deleted.
+                        let has_deletion_vector = part.deletion_vector.is_some();
+
+                        if let Some(index_reader) = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| !has_deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -278,7 +340,11 @@ impl Processor for ReadParquetDataSource<false> {
                         }
 
                         // If virtual column file exists, read the data from the virtual columns directly.
-                        let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
+                        let virtual_source = if let Some(virtual_reader) = virtual_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| !has_deletion_vector)
+                        {
                             let loc = TableMetaLocationGenerator::gen_virtual_block_location(
                                 &part.location,
                             );
@@ -305,7 +371,21 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(ParquetDataSource::Normal((source, virtual_source)))
+                        let deletion_filter = match &part.deletion_vector {
+                            Some(deletion_vector) => {
+                                let deleted_rows =
+                                    read_deletion_vector(&block_reader.operator, deletion_vector)
+                                        .await?;
+                                Some(deletion_vector_filter(&deleted_rows, part.nums_rows))
+                            }
+                            None => None,
+                        };
+
+                        Ok(ParquetDataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_filter,
+                        )))
                     })
                     .await
                     .unwrap()
@@ -313,10 +393,10 @@ impl Processor for ReadParquetDataSource<false> {
             }
 
             debug!("ReadParquetDataSource parts: {}", chunks.len());
-            self.output_data = Some((
-                fuse_part_infos,
-                futures::future::try_join_all(chunks).await?,
-            ));
+            let mut chunks = futures::future::try_join_all(chunks).await?;
+            fuse_part_infos.extend(statistics_parts);
+            chunks.extend(statistics_chunks);
+            self.output_data = Some((fuse_part_infos, chunks));
             return Ok(());
         }
 
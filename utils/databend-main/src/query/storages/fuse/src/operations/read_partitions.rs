@@ -49,6 +49,7 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::SegmentsIO;
 use crate::pruning::create_segment_location_vector;
 use crate::pruning::FusePruner;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
@@ -157,6 +158,7 @@ impl FuseTable {
                 table_schema.clone(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -168,9 +170,16 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         };
 
+        if let Some(inverted_index_pruner) =
+            InvertedIndexPruner::try_create(ctx.clone(), self.get_operator(), self, &push_downs)?
+        {
+            pruner.set_inverted_index_pruner(inverted_index_pruner);
+        }
+
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
         let add_blocks: HashSet<Location> = add_blocks.iter().map(|b| b.location.clone()).collect();
@@ -242,6 +251,7 @@ impl FuseTable {
                 table_schema.clone(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -254,9 +264,16 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         };
 
+        if let Some(inverted_index_pruner) =
+            InvertedIndexPruner::try_create(ctx.clone(), self.get_operator(), self, &push_downs)?
+        {
+            pruner.set_inverted_index_pruner(inverted_index_pruner);
+        }
+
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
@@ -420,7 +437,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -470,7 +487,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -543,6 +560,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -591,6 +609,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -248,6 +248,7 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexColumns::None,
             max_concurrency,
         )?;
 
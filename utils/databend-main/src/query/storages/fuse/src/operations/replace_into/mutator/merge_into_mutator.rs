@@ -59,6 +59,7 @@ use log::warn;
 use opendal::Operator;
 
 use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::read_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -425,6 +426,15 @@ impl AggregationContext {
 
         let num_rows = key_columns_data.num_rows();
 
+        // the rows deleted by the deletion vector are left out of the new block as well.
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => {
+                Some(read_deletion_vector(&self.data_accessor, deletion_vector).await?)
+            }
+            None => None,
+        };
+        let previously_deleted = deleted_rows.as_ref().map_or(0, |rows| rows.len() as usize);
+
         let on_conflict_fields = &self.on_conflict_fields;
         let mut columns = Vec::with_capacity(on_conflict_fields.len());
         for (field, _) in on_conflict_fields.iter().enumerate() {
@@ -443,7 +453,12 @@ impl AggregationContext {
 
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deleted_rows
+                .as_ref()
+                .is_some_and(|rows| rows.contains(row as u32))
+            {
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -453,7 +468,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
+        let delete_nums = bitmap.unset_bits() - previously_deleted;
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -476,7 +491,7 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums + previously_deleted == block_meta.row_count as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
@@ -69,6 +69,16 @@ impl FuseTable {
                 snapshot_location,
             )
             .await;
+            if let Some(snapshot) = table_reverting_to.read_table_snapshot().await? {
+                Self::try_write_iceberg_metadata(
+                    ctx,
+                    &table_reverting_to.operator,
+                    &table_reverting_to.meta_location_generator,
+                    table_info,
+                    &snapshot,
+                )
+                .await;
+            }
         };
 
         reply.map(|_| ())
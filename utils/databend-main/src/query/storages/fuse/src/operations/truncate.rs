@@ -97,6 +97,14 @@ impl FuseTable {
                 new_snapshot_loc,
             )
             .await;
+            Self::try_write_iceberg_metadata(
+                ctx.as_ref(),
+                &self.operator,
+                &self.meta_location_generator,
+                &self.table_info,
+                &new_snapshot,
+            )
+            .await;
 
             // best effort to remove historical data. if failed, let `vacuum` to do the job.
             // TODO: consider remove the `purge` option from `truncate`
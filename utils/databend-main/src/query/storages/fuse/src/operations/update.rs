@@ -241,6 +241,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     true,
+                    self.deletion_vectors_enabled(),
                 )
             },
             max_threads,
@@ -108,7 +108,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
                     // Perf.
                     {
@@ -203,6 +203,7 @@ impl BlockPruner {
                         block_location: block_location.clone(),
                         segment_location: segment_location.location.0.clone(),
                         snapshot_location: segment_location.snapshot_loc.clone(),
+                        matched_rows: None,
                     },
                     block,
                 ))
@@ -256,7 +257,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
                 && limit_pruner.within_limit(row_count)
             {
@@ -280,6 +281,7 @@ impl BlockPruner {
                             block_location: block_meta.as_ref().location.0.clone(),
                             segment_location: segment_location.location.0.clone(),
                             snapshot_location: segment_location.snapshot_loc.clone(),
+                            matched_rows: None,
                         },
                         block_meta.clone(),
                     ))
@@ -50,6 +50,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,6 +73,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -77,7 +81,12 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map = ngram_index_cols
+                .bloom_index_fields(schema.clone(), BloomIndex::supported_ngram_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let substring_query_cols = BloomIndex::find_ngram_columns(expr, ngram_column_fields)?;
+
+            if !point_query_cols.is_empty() || !substring_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -92,6 +101,7 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields: substring_query_cols,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -114,7 +124,7 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
+        let mut index_columns = self.index_fields.iter().try_fold(
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
@@ -123,6 +133,14 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        // the n-gram filters are absent in the old index versions, skip them
+        for field in &self.ngram_index_fields {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                if let Ok(name) = BloomIndex::build_ngram_filter_column_name(version, field) {
+                    index_columns.push(name);
+                }
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
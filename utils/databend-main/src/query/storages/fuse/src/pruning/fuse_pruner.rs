@@ -50,6 +50,7 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -77,6 +78,7 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
@@ -129,6 +131,7 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
         // Page pruner, used in native format
@@ -178,6 +181,7 @@ pub struct FusePruner {
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
 }
 
@@ -189,6 +193,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -198,10 +203,12 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -210,6 +217,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -232,6 +240,7 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
             max_concurrency,
         )?;
 
@@ -241,6 +250,7 @@ impl FusePruner {
             push_down: push_down.clone(),
             pruning_ctx,
             inverse_range_index: None,
+            inverted_index_pruner: None,
             deleted_segments: vec![],
         })
     }
@@ -261,13 +271,22 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> inverted index pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
         mut segment_locs: Vec<SegmentLocation>,
         delete_pruning: bool,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if !delete_pruning {
+            if let Some(topn_pruner) = self
+                .topn_pruner()
+                .filter(|pruner| pruner.limit() < segment_locs.len())
+            {
+                return self.topn_segment_pruning(segment_locs, topn_pruner).await;
+            }
+        }
+
         // Segment pruner.
         let segment_pruner =
             SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
@@ -284,6 +303,7 @@ impl FusePruner {
 
             let mut batch = segment_locs.drain(0..batch_size).collect::<Vec<_>>();
             let inverse_range_index = self.get_inverse_range_index();
+            let inverted_index_pruner = self.inverted_index_pruner.clone();
             works.push(
                 self.pruning_ctx
                     .pruning_runtime
@@ -352,6 +372,9 @@ impl FusePruner {
                                     let block_metas = info.block_metas()?;
                                     res.extend(block_pruner.pruning(location, block_metas).await?);
                                 }
+                                if let Some(inverted_index_pruner) = inverted_index_pruner {
+                                    res = inverted_index_pruner.pruning(res).await?;
+                                }
                             }
                             Result::<_, ErrorCode>::Ok((res, deleted_segments))
                         }
@@ -400,6 +423,7 @@ impl FusePruner {
             remain -= gap_size;
 
             let batch = block_metas.drain(0..batch_size).collect::<Vec<_>>();
+            let inverted_index_pruner = self.inverted_index_pruner.clone();
             works.push(
                 self.pruning_ctx
                     .pruning_runtime
@@ -407,7 +431,7 @@ impl FusePruner {
                         let block_pruner = block_pruner.clone();
                         async move {
                             // Build pruning tasks.
-                            let res = block_pruner
+                            let mut res = block_pruner
                                 .pruning(
                                     // unused segment location.
                                     SegmentLocation {
@@ -418,6 +442,9 @@ impl FusePruner {
                                     batch,
                                 )
                                 .await?;
+                            if let Some(inverted_index_pruner) = inverted_index_pruner {
+                                res = inverted_index_pruner.pruning(res).await?;
+                            }
 
                             Result::<_, ErrorCode>::Ok(res)
                         }
@@ -445,26 +472,104 @@ impl FusePruner {
         }
     }
 
+    // Pruning for order by x limit N, the segments which can't have the top N rows
+    // are pruned by their summary before reading their block metas.
+    #[async_backtrace::framed]
+    async fn topn_segment_pruning(
+        &self,
+        mut segment_locs: Vec<SegmentLocation>,
+        topn_pruner: TopNPrunner,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let segment_pruner =
+            SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
+
+        let mut remain = segment_locs.len() % self.max_concurrency;
+        let batch_size = segment_locs.len() / self.max_concurrency;
+        let mut works = Vec::with_capacity(self.max_concurrency);
+
+        while !segment_locs.is_empty() {
+            let gap_size = std::cmp::min(1, remain);
+            let batch_size = batch_size + gap_size;
+            remain -= gap_size;
+
+            let mut batch = segment_locs.drain(0..batch_size).collect::<Vec<_>>();
+            works.push(
+                self.pruning_ctx
+                    .pruning_runtime
+                    .spawn(self.pruning_ctx.ctx.get_id(), {
+                        let segment_pruner = segment_pruner.clone();
+                        let pruning_ctx = self.pruning_ctx.clone();
+
+                        async move {
+                            if let Some(internal_column_pruner) =
+                                &pruning_ctx.internal_column_pruner
+                            {
+                                batch = batch
+                                    .into_iter()
+                                    .filter(|segment| {
+                                        internal_column_pruner
+                                            .should_keep(SEGMENT_NAME_COL_NAME, &segment.location.0)
+                                    })
+                                    .collect::<Vec<_>>();
+                            }
+                            segment_pruner.pruning(batch).await
+                        }
+                    }),
+            );
+        }
+
+        let mut segments = vec![];
+        match futures::future::try_join_all(works).await {
+            Err(e) => {
+                return Err(ErrorCode::StorageOther(format!(
+                    "segment pruning failure, {}",
+                    e
+                )));
+            }
+            Ok(workers) => {
+                for worker in workers {
+                    segments.extend(worker?);
+                }
+            }
+        }
+        let segments = topn_pruner
+            .prune_segments(segments.clone())
+            .unwrap_or(segments);
+
+        let block_pruner = BlockPruner::create(self.pruning_ctx.clone())?;
+        let mut metas = vec![];
+        for (location, info) in segments {
+            let block_metas = info.block_metas()?;
+            metas.extend(block_pruner.pruning(location, block_metas).await?);
+        }
+        if let Some(inverted_index_pruner) = &self.inverted_index_pruner {
+            metas = inverted_index_pruner.pruning(metas).await?;
+        }
+        Ok(topn_pruner.prune(metas.clone()).unwrap_or(metas))
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
+    fn topn_pruner(&self) -> Option<TopNPrunner> {
+        let push_down = self
+            .push_down
+            .as_ref()
+            .filter(|p| !p.order_by.is_empty() && p.limit.is_some() && p.filters.is_none())?;
+        Some(TopNPrunner::create(
+            self.table_schema.clone(),
+            push_down.order_by.clone(),
+            push_down.limit.unwrap(),
+        ))
+    }
+
     fn topn_pruning(
         &self,
         metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
-        let push_down = self.push_down.clone();
-        if push_down
-            .as_ref()
-            .filter(|p| !p.order_by.is_empty() && p.limit.is_some() && p.filters.is_none())
-            .is_some()
-        {
-            let schema = self.table_schema.clone();
-            let push_down = push_down.as_ref().unwrap();
-            let limit = push_down.limit.unwrap();
-            let sort = push_down.order_by.clone();
-            let topn_pruner = TopNPrunner::create(schema, sort, limit);
-            return Ok(topn_pruner.prune(metas.clone()).unwrap_or(metas));
+        match self.topn_pruner() {
+            Some(topn_pruner) => Ok(topn_pruner.prune(metas.clone()).unwrap_or(metas)),
+            None => Ok(metas),
         }
-        Ok(metas)
     }
 
     // Pruning stats.
@@ -497,4 +602,8 @@ impl FusePruner {
     pub fn get_inverse_range_index(&self) -> Option<RangeIndex> {
         self.inverse_range_index.clone()
     }
+
+    pub fn set_inverted_index_pruner(&mut self, pruner: Arc<InvertedIndexPruner>) {
+        self.inverted_index_pruner = Some(pruner)
+    }
 }
@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanDomain;
+use databend_common_expression::types::F32;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Domain;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::SEARCH_MATCHED_COL_NAME;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::stream;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use opendal::Operator;
+
+use crate::io::read::InvertedIndexReader;
+use crate::io::BlockReader;
+use crate::io::InvertedIndexWriter;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+// Inverted index pruner, searches the query text in the inverted index of each block,
+// prunes the blocks that have no matched rows, and records the matched rows
+// of the remaining blocks to generate the `_search_matched` and `_search_score` columns.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+    expr: Option<Expr<String>>,
+    input_domains: HashMap<String, Domain>,
+    inverted_index_info: InvertedIndexInfo,
+    index_reader: InvertedIndexReader,
+    // Used to build the index in memory if the index data of a block is not refreshed.
+    block_reader: Arc<BlockReader>,
+    settings: ReadSettings,
+    storage_format: FuseStorageFormat,
+    // The maximum number of blocks searched at the same time.
+    max_concurrency: usize,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        fuse_table: &FuseTable,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        let Some(inverted_index_info) = push_down.as_ref().and_then(|p| p.inverted_index.as_ref())
+        else {
+            return Ok(None);
+        };
+
+        let func_ctx = ctx.get_function_context()?;
+        let expr = push_down
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
+            .filter(|expr| expr.column_refs().contains_key(SEARCH_MATCHED_COL_NAME));
+        let input_domains = expr
+            .as_ref()
+            .map(|expr| {
+                expr.column_refs()
+                    .into_iter()
+                    .map(|(name, ty)| (name, Domain::full(&ty)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let table_schema = fuse_table.schema();
+        let mut field_indices = Vec::with_capacity(inverted_index_info.index_schema.num_fields());
+        for field in inverted_index_info.index_schema.fields() {
+            field_indices.push(table_schema.index_of(field.name())?);
+        }
+        let projection = Projection::Columns(field_indices);
+        let block_reader =
+            fuse_table.create_block_reader(ctx.clone(), projection, false, false, false)?;
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = fuse_table.get_write_settings().storage_format;
+        let max_concurrency = std::cmp::max(ctx.get_settings().get_max_threads()? as usize, 1);
+
+        Ok(Some(Arc::new(InvertedIndexPruner {
+            func_ctx,
+            expr,
+            input_domains,
+            inverted_index_info: inverted_index_info.clone(),
+            index_reader: InvertedIndexReader::create(dal),
+            block_reader,
+            settings,
+            storage_format,
+            max_concurrency,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        // Search the blocks concurrently, the order of the blocks is kept.
+        let searched = stream::iter(metas.into_iter().map(
+            |(block_meta_index, block_meta)| async move {
+                let matched_rows = self.search_block(&block_meta).await?;
+                Ok::<_, ErrorCode>((block_meta_index, block_meta, matched_rows))
+            },
+        ))
+        .buffered(self.max_concurrency)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let mut res = Vec::with_capacity(searched.len());
+        for (mut block_meta_index, block_meta, matched_rows) in searched {
+            if self.should_keep(&matched_rows, block_meta.row_count as usize) {
+                block_meta_index.matched_rows = Some(matched_rows);
+                res.push((block_meta_index, block_meta));
+            }
+        }
+        Ok(res)
+    }
+
+    #[async_backtrace::framed]
+    async fn search_block(&self, block_meta: &BlockMeta) -> Result<Vec<(usize, Option<F32>)>> {
+        let info = &self.inverted_index_info;
+        let index_loc = TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            &block_meta.location.0,
+            &info.index_name,
+            &info.index_version,
+        );
+
+        let index = match self.index_reader.read_index(&index_loc).await? {
+            Some(index) => index,
+            None => {
+                let block = self
+                    .block_reader
+                    .read_by_meta(&self.settings, block_meta, &self.storage_format)
+                    .await?;
+                let mut index_writer = InvertedIndexWriter::try_create(
+                    info.index_schema.clone(),
+                    &info.index_options,
+                )?;
+                index_writer.add_block(block)?;
+                index_writer.finalize_in_memory()?
+            }
+        };
+
+        InvertedIndexReader::search(
+            &index,
+            &info.query_fields,
+            &info.query_text,
+            info.has_score,
+            block_meta.row_count as usize,
+        )
+    }
+
+    fn should_keep(&self, matched_rows: &[(usize, Option<F32>)], num_rows: usize) -> bool {
+        let Some(expr) = &self.expr else {
+            return true;
+        };
+
+        let mut input_domains = self.input_domains.clone();
+        let domain = Domain::Boolean(BooleanDomain {
+            has_false: matched_rows.len() < num_rows,
+            has_true: !matched_rows.is_empty(),
+        });
+        input_domains.insert(SEARCH_MATCHED_COL_NAME.to_string(), domain);
+
+        let (folded_expr, _) = ConstantFolder::fold_with_domain(
+            expr,
+            &input_domains,
+            &self.func_ctx,
+            &BUILTIN_FUNCTIONS,
+        );
+
+        !matches!(folded_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+}
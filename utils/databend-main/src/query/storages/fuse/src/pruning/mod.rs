@@ -15,6 +15,7 @@
 mod block_pruner;
 mod bloom_pruner;
 mod fuse_pruner;
+mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
@@ -24,6 +25,7 @@ pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use inverted_index_pruner::InvertedIndexPruner;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
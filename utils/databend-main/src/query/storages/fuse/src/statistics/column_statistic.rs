@@ -20,15 +20,18 @@ use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_io::prelude::borsh_deserialize_from_slice;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
 // Don't change this value
@@ -48,6 +51,41 @@ pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u6
     Ok(col[0])
 }
 
+// Same as the one used by `ANALYZE TABLE`, so that the sketches can be merged into the
+// persisted table statistics.
+// 1.04 / math.sqrt(1<<12) --> 0.01625
+const ANALYZE_DISTINCT_ERROR_RATE: f64 = 0.01625;
+
+/// Build the HyperLogLog sketches of the top-level columns of the block.
+pub fn gen_columns_hll(
+    data_block: &DataBlock,
+    schema: &TableSchemaRef,
+) -> Result<HashMap<ColumnId, MetaHLL>> {
+    let rows = data_block.num_rows();
+    let mut hlls = HashMap::new();
+    for (field, entry) in schema.fields().iter().zip(data_block.columns()) {
+        if !RangeIndex::supported_type(&field.data_type().into()) {
+            continue;
+        }
+
+        let column = entry.value.convert_to_full_column(&entry.data_type, rows);
+        let (states, _) = eval_aggr(
+            "approx_count_distinct_state",
+            vec![Scalar::Number(NumberScalar::Float64(
+                ANALYZE_DISTINCT_ERROR_RATE.into(),
+            ))],
+            &[column],
+            rows,
+        )?;
+        if let Some(state) = states.index(0) {
+            let state = state.as_binary().unwrap();
+            let hll: MetaHLL = borsh_deserialize_from_slice(state)?;
+            hlls.insert(field.column_id(), hll);
+        }
+    }
+    Ok(hlls)
+}
+
 pub fn get_traverse_columns_dfs(data_block: &DataBlock) -> traverse::TraverseResult {
     traverse::traverse_columns_dfs(data_block.columns())
 }
@@ -23,6 +23,7 @@ pub use block_statistics::BlockStatistics;
 pub use cluster_statistics::sort_by_cluster_stats;
 pub use cluster_statistics::ClusterStatsGenerator;
 pub use column_statistic::calc_column_distinct_of_values;
+pub use column_statistic::gen_columns_hll;
 pub use column_statistic::gen_columns_statistics;
 pub use column_statistic::get_traverse_columns_dfs;
 pub use column_statistic::scalar_min_max;
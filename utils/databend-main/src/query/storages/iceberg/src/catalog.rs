@@ -35,6 +35,7 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -296,9 +297,31 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
+    /// Creates an unpartitioned iceberg table in the directory of the database.
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db_name = req.db_name();
+        let table_name = req.table_name();
+        // check the existence of database
+        self.get_database(req.tenant(), db_name).await?;
+
+        let tbl_sp = self
+            .operator
+            .params()
+            .map_root(|root| format!("{root}{db_name}/{table_name}/"));
+        let tbl_root = DataOperator::try_create(&tbl_sp).await?;
+        let new_table = IcebergTable::initialize(&tbl_root, &req.table_meta.schema).await?;
+        if !new_table && req.create_option != CreateOption::CreateIfNotExists {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Table '{db_name}.{table_name}' already exists"
+            )));
+        }
+
+        Ok(CreateTableReply {
+            table_id: 0,
+            new_table,
+            spec_vec: None,
+        })
     }
 
     #[async_backtrace::framed]
@@ -500,6 +523,6 @@ impl Catalog for IcebergCatalog {
 
     // Get table engines
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![IcebergTable::description()]
     }
 }
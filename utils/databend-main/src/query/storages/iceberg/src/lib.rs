@@ -93,7 +93,9 @@
 
 mod catalog;
 mod database;
+mod metadata;
 mod partition;
+mod sink;
 mod stats;
 mod table;
 mod table_source;
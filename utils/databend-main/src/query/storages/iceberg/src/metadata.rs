@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metadata of iceberg tables created by databend.
+//!
+//! Tables are laid out as the filesystem catalog does:
+//! ```text
+//! /path/to/table/
+//! ┝-- metadata/version-hint.text
+//! ┝-- metadata/v1.metadata.json
+//! └-- data/
+//! ```
+
+use chrono::Utc;
+use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::DataOperator;
+use databend_storages_common_table_meta::table::iceberg_type;
+use serde_json::json;
+use serde_json::Value;
+
+const VERSION_HINT: &str = "metadata/version-hint.text";
+
+/// Initialize an unpartitioned iceberg table of `schema` at the root of `dop`.
+///
+/// Returns false without touching the storage if there is already a table.
+#[async_backtrace::framed]
+pub async fn initialize_iceberg_table(dop: &DataOperator, schema: &TableSchema) -> Result<bool> {
+    let op = dop.operator();
+    if op.is_exist(VERSION_HINT).await? {
+        return Ok(false);
+    }
+
+    let location = table_location(&dop.params())?;
+    let metadata = new_table_metadata(&location, schema)?;
+    op.write("metadata/v1.metadata.json", metadata.to_string())
+        .await?;
+    op.write(VERSION_HINT, "1").await?;
+    Ok(true)
+}
+
+fn new_table_metadata(location: &str, schema: &TableSchema) -> Result<Value> {
+    let mut fields = Vec::with_capacity(schema.num_fields());
+    let mut last_column_id = 0;
+    for field in schema.fields() {
+        // The column id in iceberg is 1-based while the column id in Databend is 0-based.
+        let id = field.column_id + 1;
+        last_column_id = last_column_id.max(id);
+        let (ty, required) = match field.data_type() {
+            TableDataType::Nullable(inner) => (iceberg_type(inner)?, false),
+            ty => (iceberg_type(ty)?, true),
+        };
+        fields.push(json!({
+            "id": id,
+            "name": field.name(),
+            "required": required,
+            "type": ty,
+        }));
+    }
+
+    Ok(json!({
+        "format-version": 2,
+        "table-uuid": Uuid::new_v4().to_string(),
+        "location": location,
+        "last-sequence-number": 0,
+        "last-updated-ms": Utc::now().timestamp_millis(),
+        "last-column-id": last_column_id,
+        "current-schema-id": 0,
+        "schemas": [{
+            "type": "struct",
+            "schema-id": 0,
+            "fields": fields,
+        }],
+        "default-spec-id": 0,
+        "partition-specs": [{ "spec-id": 0, "fields": [] }],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{ "order-id": 0, "fields": [] }],
+        "properties": {},
+        "current-snapshot-id": -1,
+        "snapshots": [],
+        "snapshot-log": [],
+        "metadata-log": [],
+        "refs": {},
+    }))
+}
+
+/// The location of the table recorded in metadata, other engines resolve
+/// the data files of the table by it.
+fn table_location(sp: &StorageParams) -> Result<String> {
+    let location = match sp {
+        StorageParams::S3(cfg) => format!("s3://{}{}", cfg.bucket, cfg.root),
+        StorageParams::Gcs(cfg) => format!("gs://{}{}", cfg.bucket, cfg.root),
+        StorageParams::Oss(cfg) => format!("oss://{}{}", cfg.bucket, cfg.root),
+        StorageParams::Azblob(cfg) => format!("azblob://{}{}", cfg.container, cfg.root),
+        StorageParams::Hdfs(cfg) => format!("{}{}", cfg.name_node, cfg.root),
+        StorageParams::Fs(cfg) => format!("file://{}", cfg.root),
+        sp => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Creating iceberg table on storage {} is not supported",
+                sp
+            )));
+        }
+    };
+    Ok(location.trim_end_matches('/').to_string())
+}
@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
+use icelake::io::task_writer::TaskWriter;
+use icelake::types::DataFile;
+use log::info;
+
+use crate::IcebergTable;
+
+/// Data files written by one [`IcebergDataFileWriter`], not visible until committed.
+#[derive(Debug)]
+pub struct IcebergDataFiles {
+    pub data_files: Vec<DataFile>,
+}
+
+impl IcebergDataFiles {
+    pub fn create_block(data_files: Vec<DataFile>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(IcebergDataFiles { data_files }))
+    }
+}
+
+impl Clone for IcebergDataFiles {
+    fn clone(&self) -> Self {
+        unreachable!("IcebergDataFiles should not be cloned")
+    }
+}
+
+impl serde::Serialize for IcebergDataFiles {
+    fn serialize<S>(&self, _: S) -> std::result::Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unreachable!("IcebergDataFiles should not be serialized")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IcebergDataFiles {
+    fn deserialize<D>(_: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unreachable!("IcebergDataFiles should not be deserialized")
+    }
+}
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("IcebergDataFiles should not be compared")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("IcebergDataFiles should not be cloned")
+    }
+}
+
+/// Writes the incoming blocks as data files of the table.
+///
+/// Rows are routed to the partitions of the current partition spec by the
+/// task writer, the written files are emitted on finish.
+pub struct IcebergDataFileWriter {
+    table: Arc<IcebergTable>,
+    schema: TableSchemaRef,
+    arrow_schema: Option<ArrowSchemaRef>,
+    writer: Option<TaskWriter>,
+}
+
+impl IcebergDataFileWriter {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        table: Arc<IcebergTable>,
+        schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+            input,
+            output,
+            IcebergDataFileWriter {
+                table,
+                schema,
+                arrow_schema: None,
+                writer: None,
+            },
+        )))
+    }
+
+    // Convert the block to the arrow types of the iceberg schema, e.g.
+    // strings are written as `Utf8` instead of `LargeUtf8`.
+    fn to_record_batch(
+        &self,
+        block: DataBlock,
+        arrow_schema: &ArrowSchemaRef,
+    ) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.schema)?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(arrow_schema.fields())
+            .map(|(column, field)| arrow_cast::cast(column, field.data_type()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(RecordBatch::try_new(arrow_schema.clone(), columns)?)
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        if self.writer.is_none() {
+            let table = self.table.table().await?;
+            self.arrow_schema = Some(Arc::new(IcebergTable::get_arrow_schema(table)?));
+            self.writer = Some(table.task_writer().await.map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot create iceberg task writer: {e:?}"))
+            })?);
+        }
+
+        let arrow_schema = self.arrow_schema.clone().unwrap();
+        let batch = self.to_record_batch(data, &arrow_schema)?;
+        self.writer
+            .as_mut()
+            .unwrap()
+            .write(&batch)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot write iceberg data: {e:?}")))?;
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        let data_files = writer.close().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot close iceberg task writer: {e:?}"))
+        })?;
+        Ok(Some(IcebergDataFiles::create_block(data_files)))
+    }
+}
+
+/// Commits the data files of all the writers as one new snapshot of the table.
+pub struct IcebergCommitSink {
+    table: Arc<IcebergTable>,
+    data_files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table: Arc<IcebergTable>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            ctx,
+            IcebergCommitSink {
+                table,
+                data_files: vec![],
+            },
+        )))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let data_files = std::mem::take(&mut self.data_files);
+        if data_files.is_empty() {
+            return Ok(());
+        }
+        info!(
+            "commit {} data files to iceberg table {}",
+            data_files.len(),
+            self.table.get_table_info().desc
+        );
+        self.table.commit_data_files(data_files).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block.take_meta() {
+            if let Some(files) = IcebergDataFiles::downcast_from(meta) {
+                self.data_files.extend(files.data_files);
+            }
+        }
+        Ok(false)
+    }
+}
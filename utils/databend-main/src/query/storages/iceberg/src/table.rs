@@ -28,6 +28,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,6 +40,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
@@ -48,16 +51,25 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
+use icelake::transaction::Transaction;
+use icelake::types::DataFile;
+use log::warn;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
+use crate::metadata::initialize_iceberg_table;
 use crate::partition::IcebergPartInfo;
+use crate::sink::IcebergCommitSink;
+use crate::sink::IcebergDataFileWriter;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+const MAX_COMMIT_RETRIES: usize = 10;
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
@@ -67,6 +79,14 @@ pub struct IcebergTable {
 }
 
 impl IcebergTable {
+    // The icelake table is loaded again by the writers, to see the latest snapshot.
+    fn clone_without_table(&self) -> Self {
+        Self {
+            info: self.info.clone(),
+            table: OnceCell::new(),
+        }
+    }
+
     /// create a new table on the table directory
     #[async_backtrace::framed]
     pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
@@ -105,12 +125,11 @@ impl IcebergTable {
         })
     }
 
-    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+    /// Build arrow schema from iceberg metadata.
+    pub fn get_arrow_schema(table: &icelake::Table) -> Result<ArrowSchema> {
         let meta = table.current_table_metadata();
 
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
-            .schemas
+        meta.schemas
             .last()
             .ok_or_else(|| {
                 ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
@@ -119,7 +138,11 @@ impl IcebergTable {
             .try_into()
             .map_err(|e| {
                 ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-            })?;
+            })
+    }
+
+    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
 
         // Build arrow2 schema from arrow schema.
         let fields: Vec<Arrow2Field> = arrow_schema
@@ -165,7 +188,16 @@ impl IcebergTable {
         })
     }
 
-    async fn table(&self) -> Result<&icelake::Table> {
+    /// Initialize an iceberg table at the root of `dop` if there is none.
+    ///
+    /// Used by `CREATE TABLE` with column definitions or `AS SELECT`,
+    /// returns false if the table already exists.
+    #[async_backtrace::framed]
+    pub async fn initialize(dop: &DataOperator, schema: &TableSchema) -> Result<bool> {
+        initialize_iceberg_table(dop, schema).await
+    }
+
+    pub(crate) async fn table(&self) -> Result<&icelake::Table> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
@@ -247,6 +279,47 @@ impl IcebergTable {
         )
     }
 
+    /// Commits the data files as a new snapshot of the table.
+    ///
+    /// The table is reloaded before each attempt, so that the new snapshot is
+    /// based on the latest one; the commit is retried if the catalog rejects it,
+    /// e.g. when another writer committed in the meantime.
+    #[async_backtrace::framed]
+    pub async fn commit_data_files(&self, data_files: Vec<DataFile>) -> Result<()> {
+        let dop = DataOperator::try_new(self.get_storage_params()?)?;
+        let mut retries = 0;
+        loop {
+            let mut table = Self::load_iceberg_table(dop.clone()).await?;
+            let base_sequence_number = table.current_table_metadata().last_sequence_number;
+            let mut txn = Transaction::new(&mut table);
+            txn.append_data_file(data_files.clone());
+            let err = match txn.commit().await {
+                Ok(_) => return Ok(()),
+                Err(e) => e,
+            };
+
+            // Only the commits conflicting with another writer are retried, they are
+            // told by the table having moved on since it was loaded.
+            let current = Self::load_iceberg_table(dop.clone()).await?;
+            if current.current_table_metadata().last_sequence_number == base_sequence_number {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Iceberg commit failed: {err:?}"
+                )));
+            }
+            if retries >= MAX_COMMIT_RETRIES {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Iceberg commit failed after {} retries: {err:?}",
+                    retries
+                )));
+            }
+            retries += 1;
+            warn!(
+                "commit to iceberg table {} conflicted, retry {}: {err:?}",
+                self.info.desc, retries
+            );
+        }
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn do_read_partitions(
@@ -357,6 +430,42 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let table = Arc::new(self.clone_without_table());
+        let schema = self.schema();
+        pipeline.add_transform(|input, output| {
+            IcebergDataFileWriter::try_create(input, output, table.clone(), schema.clone())
+        })
+    }
+
+    // Copied files are not recorded for iceberg tables, like the other
+    // non-fuse tables.
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(
+                "INSERT OVERWRITE is not supported for iceberg table",
+            ));
+        }
+
+        let table = Arc::new(self.clone_without_table());
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| IcebergCommitSink::try_create(input, ctx.clone(), table.clone()))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -244,6 +244,7 @@ impl StreamTable {
                 )
             };
         let bloom_index_cols = fuse_table.bloom_index_cols();
+        let ngram_index_cols = fuse_table.ngram_index_cols();
         let mut pruner = FusePruner::create_with_pages(
             &ctx,
             fuse_table.get_operator(),
@@ -252,6 +253,7 @@ impl StreamTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
         let block_metas = pruner.stream_pruning(blocks).await?;
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use hmac::Hmac;
+use hmac::Mac;
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A security event, such as a login or a GRANT.
+///
+/// Events are chained by `prev_hash`: `hash` is the HMAC-SHA256, keyed by the configured
+/// secret key, of every other field and the hash of the previous event, so removing or
+/// editing an event breaks the chain, and it can't be rebuilt without the key.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+
+    // Event.
+    pub category: String,
+    pub event_type: String,
+    pub object: String,
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+
+    // Session.
+    pub session_id: String,
+    pub client_address: String,
+    pub sql_user: String,
+    pub current_role: String,
+
+    // Chain.
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AuditLogElement {
+    /// Link the event to the previous one and compute its hash.
+    pub fn seal(&mut self, prev_hash: String, key: &[u8]) {
+        self.prev_hash = prev_hash;
+        self.hash = self.digest(key);
+    }
+
+    /// Whether the event is unchanged since it was sealed with the key.
+    pub fn verify(&self, key: &[u8]) -> bool {
+        let Ok(hash) = hex::decode(&self.hash) else {
+            return false;
+        };
+        self.mac(key).verify_slice(&hash).is_ok()
+    }
+
+    fn digest(&self, key: &[u8]) -> String {
+        hex::encode(self.mac(key).finalize().into_bytes())
+    }
+
+    fn mac(&self, key: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+        for field in [
+            self.prev_hash.as_str(),
+            &self.event_time.to_string(),
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.category,
+            &self.event_type,
+            &self.object,
+            &self.success.to_string(),
+            &self.error_code.to_string(),
+            &self.error_message,
+            &self.session_id,
+            &self.client_address,
+            &self.sql_user,
+            &self.current_role,
+        ] {
+            mac.update(field.as_bytes());
+            mac.update(&[0]);
+        }
+        mac
+    }
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            // Event.
+            TableField::new("category", TableDataType::String),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            // Session.
+            TableField::new("session_id", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("current_role", TableDataType::String),
+            // Chain.
+            TableField::new("prev_hash", TableDataType::String),
+            TableField::new("hash", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [&self.tenant_id, &self.cluster_id, &self.node_id] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        // Event.
+        for value in [&self.category, &self.event_type, &self.object] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        // Session and chain.
+        for value in [
+            &self.error_message,
+            &self.session_id,
+            &self.client_address,
+            &self.sql_user,
+            &self.current_role,
+            &self.prev_hash,
+            &self.hash,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
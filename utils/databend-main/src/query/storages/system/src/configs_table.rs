@@ -54,7 +54,10 @@ impl SyncSystemTable for ConfigsTable {
         let mut groups: Vec<String> = vec![];
         let mut descs: Vec<String> = vec![];
 
-        let query_config = config.query;
+        let mut query_config = config.query;
+        if let Some(ldap) = query_config.ldap.as_mut() {
+            ldap.bind_password = mask_string(&ldap.bind_password, 3);
+        }
 
         // Obsolete.
         let query_config_value = Self::remove_obsolete_configs(serde_json::to_value(query_config)?);
@@ -70,7 +73,9 @@ impl SyncSystemTable for ConfigsTable {
             query_config_value,
         );
 
-        let log_config = config.log;
+        let mut log_config = config.log;
+        log_config.audit.log_audit_secret_key =
+            mask_string(&log_config.audit.log_audit_secret_key, 3);
         let log_config_value = serde_json::to_value(log_config)?;
         ConfigsTable::extract_config(
             &mut names,
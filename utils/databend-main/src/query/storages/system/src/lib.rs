@@ -20,6 +20,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -64,7 +65,11 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -121,3 +126,4 @@ pub use tracing_table::TracingTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
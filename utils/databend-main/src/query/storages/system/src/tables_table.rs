@@ -164,6 +164,14 @@ where TablesTable<T>: HistoryAware
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
             TableField::new("comment", TableDataType::String),
+            TableField::new(
+                "rows_modified_since_analyze",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "analyzed_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
         ])
     }
 
@@ -385,6 +393,25 @@ where TablesTable<T>: HistoryAware
             .iter()
             .map(|v| v.get_table_info().meta.comment.clone())
             .collect();
+        let rows_modified_since_analyze: Vec<Option<u64>> = database_tables
+            .iter()
+            .map(|v| {
+                v.get_table_info()
+                    .meta
+                    .statistics
+                    .rows_modified_since_analyze
+            })
+            .collect();
+        let analyzed_on: Vec<Option<i64>> = database_tables
+            .iter()
+            .map(|v| {
+                v.get_table_info()
+                    .meta
+                    .statistics
+                    .analyzed_on
+                    .map(|v| v.timestamp_micros())
+            })
+            .collect();
 
         DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
@@ -406,6 +433,8 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(number_of_blocks),
             StringType::from_opt_data(owner),
             StringType::from_data(comment),
+            UInt64Type::from_opt_data(rows_modified_since_analyze),
+            TimestampType::from_opt_data(analyzed_on),
         ])
     }
 
@@ -28,6 +28,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_users::UserApiProvider;
+use databend_common_users::UserPublicKey;
 use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 
 use crate::table::AsyncOneBlockSystemTable;
@@ -65,6 +66,17 @@ impl AsyncSystemTable for UsersTable {
             .map(|x| x.option.default_role().cloned().unwrap_or_default())
             .collect();
         let mut is_configureds: Vec<String> = vec!["NO".to_string(); users.len()];
+        let mut public_key_fingerprints: Vec<String> = users
+            .iter()
+            .map(|x| {
+                x.option
+                    .public_keys()
+                    .into_iter()
+                    .filter_map(|k| UserPublicKey::fingerprint(k).ok())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
 
         let configured_users = UserApiProvider::instance().get_configured_users();
         for (name, auth_info) in configured_users {
@@ -73,6 +85,7 @@ impl AsyncSystemTable for UsersTable {
             auth_types.push(auth_info.get_type().to_str().to_string());
             default_roles.push(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string());
             is_configureds.push("YES".to_string());
+            public_key_fingerprints.push("".to_string());
         }
 
         // please note that do NOT display the auth_string field in the result, because there're risks of
@@ -83,6 +96,7 @@ impl AsyncSystemTable for UsersTable {
             StringType::from_data(auth_types),
             StringType::from_data(default_roles),
             StringType::from_data(is_configureds),
+            StringType::from_data(public_key_fingerprints),
         ]))
     }
 }
@@ -97,6 +111,7 @@ impl UsersTable {
             TableField::new("auth_type", TableDataType::String),
             TableField::new("default_role", TableDataType::String),
             TableField::new("is_configured", TableDataType::String),
+            TableField::new("public_key_fingerprint", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
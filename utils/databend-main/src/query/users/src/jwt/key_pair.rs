@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use jwt_simple::prelude::Clock;
+use jwt_simple::prelude::Ed25519PublicKey;
+use jwt_simple::prelude::EdDSAPublicKeyLike;
+use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::NoCustomClaims;
+use jwt_simple::prelude::RS256PublicKey;
+use jwt_simple::prelude::RSAPublicKeyLike;
+use serde::Deserialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Tokens signed by the client itself must be short-lived.
+const KEY_PAIR_TOKEN_MAX_VALIDITY_SECS: u64 = 3600;
+
+/// The clock of the client may be slightly ahead of the server.
+const KEY_PAIR_TOKEN_MAX_CLOCK_SKEW_SECS: u64 = 60;
+
+/// A public key set on a user with `ALTER USER ... WITH SET PUBLIC_KEY = '<pem>'`.
+pub enum UserPublicKey {
+    RS256(RS256PublicKey),
+    Ed25519(Ed25519PublicKey),
+}
+
+impl UserPublicKey {
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        if let Ok(k) = RS256PublicKey::from_pem(pem) {
+            return Ok(UserPublicKey::RS256(k));
+        }
+        if let Ok(k) = Ed25519PublicKey::from_pem(pem) {
+            return Ok(UserPublicKey::Ed25519(k));
+        }
+        Err(ErrorCode::InvalidArgument(
+            "public key must be a PEM encoded RSA or Ed25519 public key",
+        ))
+    }
+
+    /// `SHA256:<base64 of the sha256 of the DER encoded key>`.
+    pub fn fingerprint(pem: &str) -> Result<String> {
+        Self::from_pem(pem)?;
+        let body = pem
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with("-----"))
+            .collect::<String>();
+        let der = general_purpose::STANDARD
+            .decode(body)
+            .map_err(|e| ErrorCode::InvalidArgument(format!("invalid public key: {}", e)))?;
+        Ok(format!(
+            "SHA256:{}",
+            general_purpose::STANDARD.encode(Sha256::digest(der))
+        ))
+    }
+
+    fn verify_token(&self, token: &str) -> Result<JWTClaims<NoCustomClaims>> {
+        let r = match self {
+            UserPublicKey::RS256(k) => k.verify_token::<NoCustomClaims>(token, None),
+            UserPublicKey::Ed25519(k) => k.verify_token::<NoCustomClaims>(token, None),
+        };
+        r.map_err(|e| ErrorCode::AuthenticateFailure(e.to_string()))
+    }
+}
+
+#[derive(Deserialize)]
+struct SubjectClaim {
+    sub: Option<String>,
+}
+
+/// The `sub` claim of a token, WITHOUT verifying the signature.
+///
+/// Only used to find the user whose public keys the token is then verified with.
+pub fn unverified_token_subject(token: &str) -> Option<String> {
+    let payload = token.split('.').nth(1)?;
+    let payload = general_purpose::URL_SAFE_NO_PAD.decode(payload).ok()?;
+    let claim: SubjectClaim = serde_json::from_slice(&payload).ok()?;
+    claim.sub
+}
+
+/// Verify a token signed by the client with the private key of one of the user's public keys.
+///
+/// The token must be issued for the user (`sub`), not in the future (`iat`), and expire
+/// within an hour after it is issued (`exp`).
+pub fn verify_key_pair_token(token: &str, user: &UserInfo) -> Result<()> {
+    let keys = user.option.public_keys();
+    if keys.is_empty() {
+        return Err(ErrorCode::AuthenticateFailure(format!(
+            "user {} has no public key",
+            user.name
+        )));
+    }
+
+    let mut failure =
+        ErrorCode::AuthenticateFailure("token is not signed by any public key of the user. ");
+    for pem in keys {
+        let claims = match UserPublicKey::from_pem(pem).and_then(|k| k.verify_token(token)) {
+            Ok(claims) => claims,
+            Err(e) => {
+                failure = failure.add_message(e.message());
+                continue;
+            }
+        };
+        if claims.subject.as_deref() != Some(user.name.as_str()) {
+            return Err(ErrorCode::AuthenticateFailure(
+                "token subject does not match the user",
+            ));
+        }
+        let expires_at = claims.expires_at.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("missing field `exp` in key pair token")
+        })?;
+        let issued_at = claims.issued_at.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("missing field `iat` in key pair token")
+        })?;
+        let now = Clock::now_since_epoch();
+        if issued_at.as_secs() > now.as_secs() + KEY_PAIR_TOKEN_MAX_CLOCK_SKEW_SECS {
+            return Err(ErrorCode::AuthenticateFailure(
+                "key pair token is issued in the future",
+            ));
+        }
+        if expires_at.as_secs() > issued_at.as_secs() + KEY_PAIR_TOKEN_MAX_VALIDITY_SECS {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "key pair token must expire within {} seconds after it is issued",
+                KEY_PAIR_TOKEN_MAX_VALIDITY_SECS
+            )));
+        }
+        return Ok(());
+    }
+    Err(failure)
+}
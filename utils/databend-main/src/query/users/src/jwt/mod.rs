@@ -14,8 +14,12 @@
 
 mod authenticator;
 mod jwk;
+mod key_pair;
 
 pub use authenticator::CustomClaims;
 pub use authenticator::EnsureUser;
 pub use authenticator::JwtAuthenticator;
 pub use authenticator::PubKey;
+pub use key_pair::unverified_token_subject;
+pub use key_pair::verify_key_pair_token;
+pub use key_pair::UserPublicKey;
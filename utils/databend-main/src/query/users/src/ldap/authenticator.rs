@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use ldap3::ldap_escape;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use log::warn;
+
+use super::LdapConfig;
+
+/// The user found on the LDAP server after a successful bind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapIdentity {
+    pub dn: String,
+    pub groups: Vec<String>,
+}
+
+pub struct LdapAuthenticator {
+    config: LdapConfig,
+}
+
+fn ldap_error(e: LdapError) -> ErrorCode {
+    ErrorCode::AuthenticateFailure(format!("ldap: {}", e))
+}
+
+impl LdapAuthenticator {
+    pub fn create(config: Option<LdapConfig>) -> Result<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        if config.url.is_empty() {
+            return Ok(None);
+        }
+        if !config.url.starts_with("ldap://") && !config.url.starts_with("ldaps://") {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "ldap url must start with ldap:// or ldaps://, got {}",
+                config.url
+            )));
+        }
+        if config.starttls && config.url.starts_with("ldaps://") {
+            return Err(ErrorCode::InvalidConfig(
+                "ldap starttls can not be used with ldaps://",
+            ));
+        }
+        match (
+            config.bind_dn_template.is_empty(),
+            config.search_base.is_empty(),
+        ) {
+            (false, true) => {
+                if !config.bind_dn_template.contains("{username}") {
+                    return Err(ErrorCode::InvalidConfig(
+                        "ldap bind_dn_template must contain {username}",
+                    ));
+                }
+            }
+            (true, false) => {
+                if !config.search_filter.contains("{username}") {
+                    return Err(ErrorCode::InvalidConfig(
+                        "ldap search_filter must contain {username}",
+                    ));
+                }
+            }
+            _ => {
+                return Err(ErrorCode::InvalidConfig(
+                    "exactly one of ldap bind_dn_template and search_base must be set",
+                ));
+            }
+        }
+        Ok(Some(LdapAuthenticator { config }))
+    }
+
+    pub fn auto_create_user(&self) -> bool {
+        self.config.auto_create_user
+    }
+
+    /// Databend roles mapped from the given LDAP groups.
+    pub fn mapped_roles(&self, groups: &[String]) -> BTreeSet<String> {
+        groups
+            .iter()
+            .filter_map(|g| self.config.group_role_mapping.get(g).cloned())
+            .collect()
+    }
+
+    /// All roles managed by the group mapping, these are granted and revoked on login.
+    pub fn managed_roles(&self) -> BTreeSet<String> {
+        self.config.group_role_mapping.values().cloned().collect()
+    }
+
+    /// Bind as `username` with `password`, and look up the groups of the user.
+    #[async_backtrace::framed]
+    pub async fn authenticate(&self, username: &str, password: &[u8]) -> Result<LdapIdentity> {
+        // An empty password is an unauthenticated bind, which most servers accept.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure("password required"));
+        }
+        let password = std::str::from_utf8(password)
+            .map_err(|_| ErrorCode::AuthenticateFailure("password is not valid utf8"))?;
+
+        let mut ldap = self.connect().await?;
+        let result = self.bind_and_search(&mut ldap, username, password).await;
+        let _ = ldap.unbind().await;
+        result
+    }
+
+    async fn connect(&self) -> Result<Ldap> {
+        let timeout = Duration::from_secs(self.config.timeout_secs);
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(timeout)
+            .set_starttls(self.config.starttls)
+            .set_no_tls_verify(self.config.tls_insecure_skip_verify);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.config.url)
+            .await
+            .map_err(ldap_error)?;
+        spawn(async move {
+            if let Err(e) = conn.drive().await {
+                warn!("ldap connection error: {}", e);
+            }
+        });
+        ldap.with_timeout(timeout);
+        Ok(ldap)
+    }
+
+    async fn bind_and_search(
+        &self,
+        ldap: &mut Ldap,
+        username: &str,
+        password: &str,
+    ) -> Result<LdapIdentity> {
+        let dn = if !self.config.bind_dn_template.is_empty() {
+            self.config
+                .bind_dn_template
+                .replace("{username}", &ldap3::dn_escape(username))
+        } else {
+            self.bind_service_account(ldap).await?;
+            self.search_user_dn(ldap, username).await?
+        };
+
+        ldap.simple_bind(&dn, password)
+            .await
+            .map_err(ldap_error)?
+            .success()
+            .map_err(|_| ErrorCode::AuthenticateFailure("ldap: invalid credentials"))?;
+
+        let groups = if self.config.group_search_base.is_empty() {
+            vec![]
+        } else {
+            // Groups are looked up with the service account if there is one.
+            if self.config.bind_dn_template.is_empty() {
+                self.bind_service_account(ldap).await?;
+            }
+            self.search_groups(ldap, &dn, username).await?
+        };
+
+        Ok(LdapIdentity { dn, groups })
+    }
+
+    async fn bind_service_account(&self, ldap: &mut Ldap) -> Result<()> {
+        if self.config.bind_dn.is_empty() {
+            return Ok(());
+        }
+        ldap.simple_bind(&self.config.bind_dn, &self.config.bind_password)
+            .await
+            .map_err(ldap_error)?
+            .success()
+            .map_err(|e| {
+                ErrorCode::AuthenticateFailure(format!("ldap: service account bind failed: {}", e))
+            })?;
+        Ok(())
+    }
+
+    async fn search_user_dn(&self, ldap: &mut Ldap, username: &str) -> Result<String> {
+        let filter = self
+            .config
+            .search_filter
+            .replace("{username}", &ldap_escape(username));
+        // "1.1" requests no attributes, only the DN is needed.
+        let (entries, _) = ldap
+            .search(&self.config.search_base, Scope::Subtree, &filter, vec![
+                "1.1",
+            ])
+            .await
+            .map_err(ldap_error)?
+            .success()
+            .map_err(ldap_error)?;
+        match entries.len() {
+            0 => Err(ErrorCode::AuthenticateFailure(format!(
+                "ldap: user {} not found",
+                username
+            ))),
+            1 => Ok(SearchEntry::construct(entries.into_iter().next().unwrap()).dn),
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "ldap: user {} is not unique",
+                username
+            ))),
+        }
+    }
+
+    async fn search_groups(
+        &self,
+        ldap: &mut Ldap,
+        dn: &str,
+        username: &str,
+    ) -> Result<Vec<String>> {
+        let filter = self
+            .config
+            .group_search_filter
+            .replace("{dn}", &ldap_escape(dn))
+            .replace("{username}", &ldap_escape(username));
+        let attr = self.config.group_name_attribute.as_str();
+        let (entries, _) = ldap
+            .search(
+                &self.config.group_search_base,
+                Scope::Subtree,
+                &filter,
+                vec![attr],
+            )
+            .await
+            .map_err(ldap_error)?
+            .success()
+            .map_err(ldap_error)?;
+
+        let mut groups = vec![];
+        for entry in entries {
+            let mut entry = SearchEntry::construct(entry);
+            if let Some(names) = entry.attrs.remove(attr) {
+                groups.extend(names);
+            }
+        }
+        Ok(groups)
+    }
+}
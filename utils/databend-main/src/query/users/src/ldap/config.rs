@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use databend_common_base::base::mask_string;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config of the LDAP server used by users created `IDENTIFIED WITH ldap`.
+///
+/// Two bind modes are supported:
+/// - simple bind: `bind_dn_template` is set, the user DN is built from the login name.
+/// - search+bind: `search_base` is set, the user DN is looked up with the service
+///   account `bind_dn`/`bind_password` (or anonymously) before binding as the user.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LdapConfig {
+    /// Server address, `ldap://host:389` or `ldaps://host:636`.
+    pub url: String,
+    /// Upgrade a plain `ldap://` connection with StartTLS.
+    pub starttls: bool,
+    /// Do not verify the server certificate, only for testing.
+    pub tls_insecure_skip_verify: bool,
+    /// Connect and operation timeout in seconds.
+    pub timeout_secs: u64,
+
+    /// DN template for simple bind, e.g. `uid={username},ou=people,dc=example,dc=org`.
+    pub bind_dn_template: String,
+
+    /// Service account for search+bind, anonymous if empty.
+    pub bind_dn: String,
+    pub bind_password: String,
+    pub search_base: String,
+    /// User search filter, `{username}` is replaced with the escaped login name.
+    pub search_filter: String,
+
+    /// Base DN of groups, group lookup is disabled if empty.
+    pub group_search_base: String,
+    /// Group search filter, `{dn}` and `{username}` are replaced with the escaped user DN and name.
+    pub group_search_filter: String,
+    /// Attribute holding the group name.
+    pub group_name_attribute: String,
+
+    /// Create a Databend user on the first successful LDAP login.
+    pub auto_create_user: bool,
+    /// LDAP group name to Databend role name.
+    pub group_role_mapping: BTreeMap<String, String>,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        Self {
+            url: "".to_string(),
+            starttls: false,
+            tls_insecure_skip_verify: false,
+            timeout_secs: 10,
+            bind_dn_template: "".to_string(),
+            bind_dn: "".to_string(),
+            bind_password: "".to_string(),
+            search_base: "".to_string(),
+            search_filter: "(uid={username})".to_string(),
+            group_search_base: "".to_string(),
+            group_search_filter: "(member={dn})".to_string(),
+            group_name_attribute: "cn".to_string(),
+            auto_create_user: false,
+            group_role_mapping: BTreeMap::new(),
+        }
+    }
+}
+
+impl Debug for LdapConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LdapConfig")
+            .field("url", &self.url)
+            .field("starttls", &self.starttls)
+            .field("tls_insecure_skip_verify", &self.tls_insecure_skip_verify)
+            .field("timeout_secs", &self.timeout_secs)
+            .field("bind_dn_template", &self.bind_dn_template)
+            .field("bind_dn", &self.bind_dn)
+            .field("bind_password", &mask_string(&self.bind_password, 3))
+            .field("search_base", &self.search_base)
+            .field("search_filter", &self.search_filter)
+            .field("group_search_base", &self.group_search_base)
+            .field("group_search_filter", &self.group_search_filter)
+            .field("group_name_attribute", &self.group_name_attribute)
+            .field("auto_create_user", &self.auto_create_user)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .finish()
+    }
+}
@@ -18,6 +18,7 @@
 extern crate core;
 
 mod jwt;
+mod ldap;
 mod network_policy;
 mod password_policy;
 mod role_mgr;
@@ -28,14 +29,17 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod connection;
 pub mod file_format;
 pub mod idm_config;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod workload_group_mgr;
 
 pub use jwt::*;
+pub use ldap::*;
 pub use password_policy::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -43,3 +47,7 @@ pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
 pub use visibility_checker::GrantObjectVisibilityChecker;
+pub use workload_group::*;
+pub use workload_group_mgr::WorkloadGroupManager;
+pub use workload_group_mgr::WorkloadGroupPermit;
+pub use workload_group_mgr::WorkloadGroupStatus;
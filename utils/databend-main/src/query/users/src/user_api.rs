@@ -37,6 +37,8 @@ use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupApi;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::tenant::TenantQuota;
@@ -183,6 +185,16 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_workload_group_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl WorkloadGroupApi>> {
+        Ok(Arc::new(WorkloadGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -68,7 +68,18 @@ impl UserApiProvider {
         client_ip: Option<&str>,
     ) -> Result<UserInfo> {
         let user_info = self.get_user(tenant, user).await?;
+        self.check_client_ip(tenant, &user_info, client_ip).await?;
+        Ok(user_info)
+    }
 
+    // Check the client ip against the network policy of the user, if any.
+    #[async_backtrace::framed]
+    pub async fn check_client_ip(
+        &self,
+        tenant: &NonEmptyString,
+        user_info: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
         if let Some(name) = user_info.option.network_policy() {
             let ip_addr: Ipv4Addr = match client_ip {
                 Some(client_ip) => client_ip.parse().unwrap(),
@@ -104,7 +115,7 @@ impl UserApiProvider {
                 )));
             }
         }
-        Ok(user_info)
+        Ok(())
     }
 
     // Get the tenant all users list.
@@ -158,6 +169,18 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.workload_group() {
+            if self
+                .get_workload_group(tenant.as_str(), name)
+                .await
+                .is_err()
+            {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -320,6 +343,18 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.workload_group() {
+                if self
+                    .get_workload_group(tenant.as_str(), name)
+                    .await
+                    .is_err()
+                {
+                    return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                        "workload group `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
@@ -125,7 +125,8 @@ impl GrantObjectVisibilityChecker {
                         // if table is visible, the table's database is also treated as visible
                         extra_databases.insert((catalog.to_string(), db.to_string()));
                     }
-                    GrantObject::TableById(catalog, db, table) => {
+                    GrantObject::TableById(catalog, db, table)
+                    | GrantObject::TableColumnById(catalog, db, table, _) => {
                         granted_tables_id.insert((catalog.to_string(), *db, *table));
                         // if table is visible, the table's database is also treated as visible
                         extra_databases_id.insert((catalog.to_string(), *db));
@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::WorkloadGroupApi;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::NonEmptyString;
+
+use crate::UserApiProvider;
+
+// maximum value of the workload group cpu share, in percent
+pub const MAX_WORKLOAD_GROUP_CPU_SHARE: u64 = 100;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        workload_group: WorkloadGroup,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        check_workload_group(&workload_group)?;
+
+        let client = self.get_workload_group_api_client(tenant)?;
+        client
+            .add_workload_group(workload_group, create_option)
+            .await
+    }
+
+    // Update workload group.
+    #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        max_concurrency: Option<u64>,
+        max_memory_usage: Option<u64>,
+        cpu_share: Option<u64>,
+        queue_timeout_secs: Option<u64>,
+        roles: Option<Vec<String>>,
+        comment: Option<String>,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let seq_workload_group = match client.get_workload_group(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter workload group)"));
+                }
+            }
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        if let Some(max_concurrency) = max_concurrency {
+            workload_group.max_concurrency = max_concurrency;
+        }
+        if let Some(max_memory_usage) = max_memory_usage {
+            workload_group.max_memory_usage = max_memory_usage;
+        }
+        if let Some(cpu_share) = cpu_share {
+            workload_group.cpu_share = cpu_share;
+        }
+        if let Some(queue_timeout_secs) = queue_timeout_secs {
+            workload_group.queue_timeout_secs = queue_timeout_secs;
+        }
+        if let Some(roles) = roles {
+            workload_group.roles = roles;
+        }
+        if let Some(comment) = comment {
+            workload_group.comment = comment;
+        }
+        check_workload_group(&workload_group)?;
+
+        workload_group.update_on = Some(Utc::now());
+
+        match client
+            .update_workload_group(workload_group, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter workload group).")),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &NonEmptyString,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if let Some(workload_group) = user_info.option.workload_group() {
+                if workload_group == name {
+                    return Err(ErrorCode::WorkloadGroupIsUsedByUser(format!(
+                        "workload group `{}` is used by user",
+                        name,
+                    )));
+                }
+            }
+        }
+
+        let client = self.get_workload_group_api_client(tenant.as_str())?;
+        match client.drop_workload_group(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop workload group)"))
+                }
+            }
+        }
+    }
+
+    // Get a workload group by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &str, name: &str) -> Result<WorkloadGroup> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_group = client.get_workload_group(name, MatchSeq::GE(0)).await?.data;
+        Ok(workload_group)
+    }
+
+    // Get all workload groups by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_groups = client
+            .get_workload_groups()
+            .await
+            .map_err(|e| e.add_message_back(" (while get workload groups)."))?;
+        Ok(workload_groups)
+    }
+
+    // Find the workload group a query of the user runs in.
+    // The group set on the user wins, otherwise the first group bound to the current role is used.
+    #[async_backtrace::framed]
+    pub async fn resolve_workload_group(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+        current_role: Option<&str>,
+    ) -> Result<Option<WorkloadGroup>> {
+        if let Some(name) = user_info.option.workload_group() {
+            return match self.get_workload_group(tenant, name).await {
+                Ok(workload_group) => Ok(Some(workload_group)),
+                // The group may be dropped concurrently, fall back to no limits.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP => Ok(None),
+                Err(e) => Err(e),
+            };
+        }
+
+        let Some(current_role) = current_role else {
+            return Ok(None);
+        };
+        let workload_groups = self.get_workload_groups(tenant).await?;
+        Ok(workload_groups
+            .into_iter()
+            .find(|group| group.roles.iter().any(|role| role == current_role)))
+    }
+}
+
+fn check_workload_group(workload_group: &WorkloadGroup) -> Result<()> {
+    if workload_group.cpu_share > MAX_WORKLOAD_GROUP_CPU_SHARE {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid workload group cpu share, supported range: 0 to {}, but got {}",
+            MAX_WORKLOAD_GROUP_CPU_SHARE, workload_group.cpu_share
+        )));
+    }
+
+    Ok(())
+}
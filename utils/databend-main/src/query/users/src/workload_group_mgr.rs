@@ -0,0 +1,212 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use log::info;
+use parking_lot::Mutex;
+
+/// Runtime state of the workload groups on this node.
+///
+/// The definition of a group lives in the meta service, this manager only keeps the
+/// admission queue and the shared memory tracker of each group that has been used.
+pub struct WorkloadGroupManager {
+    slots: Mutex<HashMap<(String, String), Arc<WorkloadGroupSlot>>>,
+}
+
+struct WorkloadGroupSlot {
+    name: String,
+    state: Mutex<SlotState>,
+    notify: Notify,
+    mem_stat: Arc<MemStat>,
+}
+
+#[derive(Default)]
+struct SlotState {
+    max_concurrency: u64,
+    running: u64,
+    queued: u64,
+}
+
+impl SlotState {
+    fn admissible(&self) -> bool {
+        self.max_concurrency == 0 || self.running < self.max_concurrency
+    }
+}
+
+/// Running and queued queries of a workload group on this node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkloadGroupStatus {
+    pub name: String,
+    pub running: u64,
+    pub queued: u64,
+}
+
+/// Held by a query while it runs in a workload group, releases its slot on drop.
+pub struct WorkloadGroupPermit {
+    slot: Arc<WorkloadGroupSlot>,
+}
+
+impl WorkloadGroupPermit {
+    pub fn name(&self) -> &str {
+        &self.slot.name
+    }
+
+    /// Memory tracker shared by all running queries of the group.
+    pub fn mem_stat(&self) -> Arc<MemStat> {
+        self.slot.mem_stat.clone()
+    }
+}
+
+impl Drop for WorkloadGroupPermit {
+    fn drop(&mut self) {
+        self.slot.state.lock().running -= 1;
+        self.slot.notify.notify_waiters();
+    }
+}
+
+// Leaves the queue when the waiting query is admitted, times out or is cancelled.
+struct QueuedGuard<'a> {
+    slot: &'a WorkloadGroupSlot,
+}
+
+impl<'a> Drop for QueuedGuard<'a> {
+    fn drop(&mut self) {
+        self.slot.state.lock().queued -= 1;
+    }
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadGroupManager {
+            slots: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Wait until the group admits one more query, or fail after its queue timeout.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        &self,
+        tenant: &str,
+        group: &WorkloadGroup,
+    ) -> Result<WorkloadGroupPermit> {
+        let slot = self.get_slot(tenant, group);
+
+        {
+            let mut state = slot.state.lock();
+            if state.admissible() {
+                state.running += 1;
+                return Ok(WorkloadGroupPermit { slot: slot.clone() });
+            }
+            state.queued += 1;
+        }
+
+        info!(
+            "query is queued by workload group {}, queue timeout: {}s",
+            group.name, group.queue_timeout_secs
+        );
+
+        let _queued = QueuedGuard { slot: &slot };
+        let wait = async {
+            loop {
+                // Register before checking, so a release between the check and the await is not lost.
+                let notified = slot.notify.notified();
+                {
+                    let mut state = slot.state.lock();
+                    if state.admissible() {
+                        state.running += 1;
+                        return;
+                    }
+                }
+                notified.await;
+            }
+        };
+
+        if group.queue_timeout_secs == 0 {
+            wait.await;
+        } else if tokio::time::timeout(Duration::from_secs(group.queue_timeout_secs), wait)
+            .await
+            .is_err()
+        {
+            return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                "query waited more than {}s in the queue of workload group `{}`",
+                group.queue_timeout_secs, group.name
+            )));
+        }
+
+        Ok(WorkloadGroupPermit { slot: slot.clone() })
+    }
+
+    pub fn status(&self, tenant: &str) -> Vec<WorkloadGroupStatus> {
+        let slots = self.slots.lock();
+        slots
+            .iter()
+            .filter(|((slot_tenant, _), _)| slot_tenant == tenant)
+            .map(|(_, slot)| {
+                let state = slot.state.lock();
+                WorkloadGroupStatus {
+                    name: slot.name.clone(),
+                    running: state.running,
+                    queued: state.queued,
+                }
+            })
+            .collect()
+    }
+
+    // Get the slot of the group, refreshing its limits from the latest definition.
+    fn get_slot(&self, tenant: &str, group: &WorkloadGroup) -> Arc<WorkloadGroupSlot> {
+        let slot = {
+            let mut slots = self.slots.lock();
+            slots
+                .entry((tenant.to_string(), group.name.clone()))
+                .or_insert_with(|| {
+                    Arc::new(WorkloadGroupSlot {
+                        name: group.name.clone(),
+                        state: Mutex::new(SlotState::default()),
+                        notify: Notify::new(),
+                        mem_stat: MemStat::create(format!("WorkloadGroupMemStat-{}", group.name)),
+                    })
+                })
+                .clone()
+        };
+
+        slot.mem_stat.set_limit(group.max_memory_usage as i64);
+        let raised = {
+            let mut state = slot.state.lock();
+            let raised = group.max_concurrency == 0
+                || (state.max_concurrency != 0 && group.max_concurrency > state.max_concurrency);
+            state.max_concurrency = group.max_concurrency;
+            raised
+        };
+        if raised {
+            slot.notify.notify_waiters();
+        }
+
+        slot
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_users::unverified_token_subject;
+use databend_common_users::verify_key_pair_token;
+use databend_common_users::UserPublicKey;
+use jwt_simple::prelude::*;
+
+fn key_pair_user(name: &str, public_key: Option<String>, public_key_2: Option<String>) -> UserInfo {
+    let mut user = UserInfo::new(name, "%", AuthInfo::KeyPair);
+    user.option = UserOption::default()
+        .with_public_key(public_key)
+        .with_public_key_2(public_key_2);
+    user
+}
+
+#[test]
+fn test_key_pair_token() -> Result<()> {
+    let rsa = RS256KeyPair::generate(2048)?;
+    let ed = Ed25519KeyPair::generate();
+    let user = key_pair_user(
+        "svc",
+        Some(rsa.public_key().to_pem()?),
+        Some(ed.public_key().to_pem()),
+    );
+
+    // both slots are accepted
+    let claims = Claims::create(Duration::from_mins(5)).with_subject("svc");
+    let token = rsa.sign(claims.clone())?;
+    assert_eq!(unverified_token_subject(&token), Some("svc".to_string()));
+    verify_key_pair_token(&token, &user)?;
+    verify_key_pair_token(&ed.sign(claims)?, &user)?;
+
+    // issued for another user
+    let claims = Claims::create(Duration::from_mins(5)).with_subject("other");
+    let err = verify_key_pair_token(&rsa.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // not short-lived
+    let claims = Claims::create(Duration::from_hours(2)).with_subject("svc");
+    let err = verify_key_pair_token(&rsa.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // no issued time
+    let mut claims = Claims::create(Duration::from_mins(5)).with_subject("svc");
+    claims.issued_at = None;
+    let err = verify_key_pair_token(&rsa.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // issued in the future
+    let mut claims = Claims::create(Duration::from_hours(2)).with_subject("svc");
+    claims.issued_at = Some(Clock::now_since_epoch() + Duration::from_hours(1));
+    let err = verify_key_pair_token(&rsa.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // not short-lived since it is issued
+    let mut claims = Claims::create(Duration::from_mins(5)).with_subject("svc");
+    claims.issued_at = Some(Clock::now_since_epoch() - Duration::from_hours(2));
+    let err = verify_key_pair_token(&rsa.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // signed by a key not set on the user
+    let other = RS256KeyPair::generate(2048)?;
+    let claims = Claims::create(Duration::from_mins(5)).with_subject("svc");
+    let err = verify_key_pair_token(&other.sign(claims)?, &user).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // no public key set
+    let claims = Claims::create(Duration::from_mins(5)).with_subject("svc");
+    let err =
+        verify_key_pair_token(&rsa.sign(claims)?, &key_pair_user("svc", None, None)).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    Ok(())
+}
+
+#[test]
+fn test_public_key_fingerprint() -> Result<()> {
+    let rsa = RS256KeyPair::generate(2048)?;
+    let pem = rsa.public_key().to_pem()?;
+    let fingerprint = UserPublicKey::fingerprint(&pem)?;
+    assert!(fingerprint.starts_with("SHA256:"));
+    assert_eq!(fingerprint, UserPublicKey::fingerprint(&pem)?);
+
+    let other = RS256KeyPair::generate(2048)?.public_key().to_pem()?;
+    assert_ne!(fingerprint, UserPublicKey::fingerprint(&other)?);
+
+    assert!(UserPublicKey::fingerprint("not a key").is_err());
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Copyright 2024 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_users::LdapAuthenticator;
+use databend_common_users::LdapConfig;
+
+fn search_bind_config() -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:1389".to_string(),
+        search_base: "ou=people,dc=example,dc=org".to_string(),
+        group_search_base: "ou=groups,dc=example,dc=org".to_string(),
+        group_role_mapping: [
+            ("analysts".to_string(), "analyst".to_string()),
+            ("admins".to_string(), "admin".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_ldap_authenticator_create() -> Result<()> {
+    assert!(LdapAuthenticator::create(None)?.is_none());
+    assert!(LdapAuthenticator::create(Some(LdapConfig::default()))?.is_none());
+    assert!(LdapAuthenticator::create(Some(search_bind_config()))?.is_some());
+
+    let simple_bind = LdapConfig {
+        url: "ldaps://127.0.0.1:636".to_string(),
+        bind_dn_template: "uid={username},ou=people,dc=example,dc=org".to_string(),
+        ..Default::default()
+    };
+    assert!(LdapAuthenticator::create(Some(simple_bind.clone()))?.is_some());
+
+    let invalid = [
+        LdapConfig {
+            url: "http://127.0.0.1:1389".to_string(),
+            ..search_bind_config()
+        },
+        LdapConfig {
+            starttls: true,
+            ..simple_bind.clone()
+        },
+        LdapConfig {
+            bind_dn_template: "ou=people,dc=example,dc=org".to_string(),
+            ..simple_bind.clone()
+        },
+        LdapConfig {
+            search_base: "ou=people,dc=example,dc=org".to_string(),
+            ..simple_bind
+        },
+        LdapConfig {
+            search_filter: "(uid=alice)".to_string(),
+            ..search_bind_config()
+        },
+    ];
+    for config in invalid {
+        let err = LdapAuthenticator::create(Some(config)).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ldap_group_role_mapping() -> Result<()> {
+    let ldap = LdapAuthenticator::create(Some(search_bind_config()))?.unwrap();
+    let groups = vec!["analysts".to_string(), "others".to_string()];
+    assert_eq!(
+        ldap.mapped_roles(&groups),
+        BTreeSet::from(["analyst".to_string()])
+    );
+    assert_eq!(
+        ldap.managed_roles(),
+        BTreeSet::from(["admin".to_string(), "analyst".to_string()])
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_empty_password() -> Result<()> {
+    // Rejected before connecting, an empty password would be an unauthenticated bind.
+    let ldap = LdapAuthenticator::create(Some(search_bind_config()))?.unwrap();
+    let err = ldap.authenticate("alice", b"").await.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop role if exists role1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u1" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_col" | $BENDSQL_CLIENT_CONNECT
+echo "create database db_col" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_col.t(id int, name string, salary int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_col.t values(1, 'a', 100), (2, 'b', 200)" | $BENDSQL_CLIENT_CONNECT
+echo "create role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant select(id, name) on db_col.t to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant insert(id, name) on db_col.t to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant update(name) on db_col.t to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant select(no_such_column) on db_col.t to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "create user u1 identified by '123' with DEFAULT_ROLE='role1'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role role1 to u1" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role role1" | $BENDSQL_CLIENT_CONNECT
+
+export TEST_U1_CONNECT="bendsql --user=u1 --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== select ==="
+echo "select id, name from db_col.t order by id" | $TEST_U1_CONNECT
+echo "select name from db_col.t where id = 2" | $TEST_U1_CONNECT
+echo "select * from db_col.t" | $TEST_U1_CONNECT
+echo "select id from db_col.t where salary > 100" | $TEST_U1_CONNECT
+
+echo "=== insert ==="
+echo "insert into db_col.t(id, name) values(3, 'c')" | $TEST_U1_CONNECT
+echo "insert into db_col.t values(4, 'd', 400)" | $TEST_U1_CONNECT
+
+echo "=== update ==="
+echo "update db_col.t set name = 'x' where id = 3" | $TEST_U1_CONNECT
+echo "update db_col.t set salary = 0 where id = 3" | $TEST_U1_CONNECT
+echo "update db_col.t set name = salary::string where id = 3" | $TEST_U1_CONNECT
+echo "update db_col.t set name = 'y' where salary > 100" | $TEST_U1_CONNECT
+echo "select id, name from db_col.t order by id" | $TEST_U1_CONNECT
+
+echo "=== revoke ==="
+echo "revoke select(name) on db_col.t from role role1" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role role1" | $BENDSQL_CLIENT_CONNECT
+echo "select id from db_col.t order by id" | $TEST_U1_CONNECT
+echo "select id, name from db_col.t order by id" | $TEST_U1_CONNECT
+
+echo "drop role if exists role1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u1" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_col" | $BENDSQL_CLIENT_CONNECT
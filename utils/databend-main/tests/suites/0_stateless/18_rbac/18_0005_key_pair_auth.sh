@@ -0,0 +1,71 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+KEY_DIR=$(mktemp -d)
+openssl genrsa -out "$KEY_DIR"/key1.pem 2048 2>/dev/null
+openssl genrsa -out "$KEY_DIR"/key2.pem 2048 2>/dev/null
+PUB1=$(openssl rsa -in "$KEY_DIR"/key1.pem -pubout 2>/dev/null)
+PUB2=$(openssl rsa -in "$KEY_DIR"/key2.pem -pubout 2>/dev/null)
+
+b64url() {
+	openssl base64 -e -A | tr '+/' '-_' | tr -d '='
+}
+
+# sign_token <key> <user> <ttl seconds>
+sign_token() {
+	local header payload now sig
+	now=$(date +%s)
+	header=$(echo -n '{"alg":"RS256","typ":"JWT"}' | b64url)
+	payload=$(echo -n "{\"sub\":\"$2\",\"iat\":$now,\"exp\":$((now + $3))}" | b64url)
+	sig=$(echo -n "$header.$payload" | openssl dgst -sha256 -sign "$KEY_DIR/$1.pem" | b64url)
+	echo "$header.$payload.$sig"
+}
+
+query_with_token() {
+	curl -s -H "Authorization: Bearer $1" -H 'Content-Type: application/json' \
+		"http://${QUERY_MYSQL_HANDLER_HOST}:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+		-d '{"sql": "select current_user()"}' | jq -r '.data[0][0]'
+}
+
+status_with_token() {
+	curl -s -o /dev/null -w "%{http_code}\n" -H "Authorization: Bearer $1" -H 'Content-Type: application/json' \
+		"http://${QUERY_MYSQL_HANDLER_HOST}:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+		-d '{"sql": "select 1"}'
+}
+
+echo "drop user if exists svc" | $BENDSQL_CLIENT_CONNECT
+echo "create user svc identified with key_pair" | $BENDSQL_CLIENT_CONNECT
+echo "alter user svc with set public_key = 'not a key'" | $BENDSQL_CLIENT_CONNECT
+echo "alter user svc with set public_key = '$PUB1'" | $BENDSQL_CLIENT_CONNECT
+echo "select name, auth_type, public_key_fingerprint like 'SHA256:%' from system.users where name = 'svc'" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== key1 ==="
+query_with_token "$(sign_token key1 svc 300)"
+echo "=== key2 not set ==="
+status_with_token "$(sign_token key2 svc 300)"
+echo "=== long-lived token ==="
+status_with_token "$(sign_token key1 svc 7200)"
+
+echo "=== rotation ==="
+echo "alter user svc with set public_key_2 = '$PUB2'" | $BENDSQL_CLIENT_CONNECT
+query_with_token "$(sign_token key2 svc 300)"
+echo "alter user svc with unset public_key" | $BENDSQL_CLIENT_CONNECT
+status_with_token "$(sign_token key1 svc 300)"
+query_with_token "$(sign_token key2 svc 300)"
+
+echo "=== lockout ==="
+echo "drop password policy if exists pp_key_pair" | $BENDSQL_CLIENT_CONNECT
+echo "create password policy pp_key_pair PASSWORD_MAX_RETRIES=2" | $BENDSQL_CLIENT_CONNECT
+echo "alter user svc with set password policy = 'pp_key_pair'" | $BENDSQL_CLIENT_CONNECT
+status_with_token "$(sign_token key1 svc 300)"
+status_with_token "$(sign_token key1 svc 300)"
+# a valid token is rejected once the user is locked out
+curl -s -H "Authorization: Bearer $(sign_token key2 svc 300)" -H 'Content-Type: application/json' \
+	"http://${QUERY_MYSQL_HANDLER_HOST}:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+	-d '{"sql": "select 1"}' | grep -o "too many password fails"
+
+echo "drop user svc" | $BENDSQL_CLIENT_CONNECT
+echo "drop password policy pp_key_pair" | $BENDSQL_CLIENT_CONNECT
+rm -rf "$KEY_DIR"
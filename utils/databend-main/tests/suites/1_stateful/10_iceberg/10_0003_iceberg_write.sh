@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(mktemp -d)
+
+stmt "drop table if exists test_iceberg_write;"
+stmt "drop table if exists test_iceberg_ctas;"
+
+echo ">>>> create table test_iceberg_write(id int not null, data string not null) engine = iceberg location = 'fs://\${ROOT}/write/';"
+echo "create table test_iceberg_write(id int not null, data string not null) engine = iceberg location = 'fs://${ROOT}/write/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg_write values (1, 'a'), (2, 'b');"
+stmt "insert into test_iceberg_write values (3, 'c');"
+query "select * from test_iceberg_write order by id;"
+
+echo ">>>> create table test_iceberg_ctas engine = iceberg location = 'fs://\${ROOT}/ctas/' as select * from test_iceberg_write where id > 1;"
+echo "create table test_iceberg_ctas engine = iceberg location = 'fs://${ROOT}/ctas/' as select * from test_iceberg_write where id > 1;" | $BENDSQL_CLIENT_CONNECT
+query "select * from test_iceberg_ctas order by id;"
+
+stmt "insert overwrite test_iceberg_ctas values (4, 'd');"
+
+stmt "drop table test_iceberg_write;"
+stmt "drop table test_iceberg_ctas;"
+
+rm -rf "${ROOT}"
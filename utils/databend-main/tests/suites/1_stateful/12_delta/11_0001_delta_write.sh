@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/delta/simple/. "${ROOT}"
+
+stmt "drop table if exists test_delta_write;"
+
+echo ">>>> create table test_delta_write engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta_write engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_delta_write values (5), (6);"
+stmt "insert into test_delta_write select id + 7 from test_delta_write where id < 2;"
+query "select * from test_delta_write order by id;"
+
+query "select count(*) from test_delta_write at (version => 0);"
+query "select count(*) from test_delta_write at (version => 1);"
+stmt "select count(*) from test_delta_write at (snapshot => 'abc');"
+
+stmt "insert overwrite test_delta_write values (10);"
+
+stmt "drop table test_delta_write;"
+
+rm -rf "${ROOT}"
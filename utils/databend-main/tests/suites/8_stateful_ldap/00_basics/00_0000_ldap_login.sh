@@ -0,0 +1,50 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# alice (group analysts) and bob (no group) exist on the OpenLDAP server from docker/it-ldap,
+# and the analysts group is mapped to role ldap_analyst.
+echo "drop user if exists alice" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists bob" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists carol" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists ldap_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db_ldap" | $BENDSQL_CLIENT_CONNECT
+echo "create database db_ldap" | $BENDSQL_CLIENT_CONNECT
+echo "create table db_ldap.t(id int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db_ldap.t values(1)" | $BENDSQL_CLIENT_CONNECT
+echo "create role ldap_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "grant select on db_ldap.* to role ldap_analyst" | $BENDSQL_CLIENT_CONNECT
+
+ldap_connect() {
+	echo "bendsql --user=$1 --password=$2 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT} --quote-style=never"
+}
+
+echo "=== wrong password ==="
+echo "select 1" | $(ldap_connect alice wrong) 2>&1 | grep -c "invalid credentials"
+echo "select name from system.users where name = 'alice'" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== auto create on first login ==="
+echo "select 1" | $(ldap_connect alice alice_pass)
+echo "select name, auth_type from system.users where name = 'alice'" | $BENDSQL_CLIENT_CONNECT
+echo "select id from db_ldap.t" | $(ldap_connect alice alice_pass)
+
+echo "=== mapped roles follow groups ==="
+echo "select 1" | $(ldap_connect bob bob_pass)
+echo "grant role ldap_analyst to bob" | $BENDSQL_CLIENT_CONNECT
+echo "select id from db_ldap.t" | $(ldap_connect bob bob_pass) 2>&1 | grep -c "Permission denied"
+
+echo "=== user not in ldap ==="
+echo "create user carol identified with ldap" | $BENDSQL_CLIENT_CONNECT
+echo "select 1" | $(ldap_connect carol carol_pass) 2>&1 | grep -c "not found"
+
+echo "=== mysql handler ==="
+mysql --ssl-mode=REQUIRED --enable-cleartext-plugin -ualice -palice_pass --host ${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_MYSQL_HANDLER_PORT} -s -e "select id from db_ldap.t" 2>/dev/null
+mysql --ssl-mode=REQUIRED --enable-cleartext-plugin -ualice -pwrong --host ${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_MYSQL_HANDLER_PORT} -s -e "select 1" 2>&1 | grep -c "Access denied"
+mysql --ssl-mode=DISABLED --enable-cleartext-plugin -ualice -palice_pass --host ${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_MYSQL_HANDLER_PORT} -s -e "select 1" 2>&1 | grep -c "Access denied"
+
+echo "drop user alice" | $BENDSQL_CLIENT_CONNECT
+echo "drop user bob" | $BENDSQL_CLIENT_CONNECT
+echo "drop user carol" | $BENDSQL_CLIENT_CONNECT
+echo "drop role ldap_analyst" | $BENDSQL_CLIENT_CONNECT
+echo "drop database db_ldap" | $BENDSQL_CLIENT_CONNECT